@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLEnum)]
+pub enum Test {
+    #[graphql(internal)]
+    Variant,
+}
+
+fn main() {}
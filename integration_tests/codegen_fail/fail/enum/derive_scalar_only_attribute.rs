@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLEnum)]
+pub enum Test {
+    #[graphql(min_length = 2)]
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLEnum)]
+pub enum Test {
+    #[graphql(transform = [str::trim])]
+    Variant,
+}
+
+fn main() {}
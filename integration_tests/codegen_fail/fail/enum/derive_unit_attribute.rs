@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLEnum)]
+pub enum Test {
+    #[graphql(unit = "seconds")]
+    Variant,
+}
+
+fn main() {}
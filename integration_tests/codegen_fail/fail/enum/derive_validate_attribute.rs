@@ -0,0 +1,11 @@
+#[derive(juniper::GraphQLEnum)]
+pub enum Test {
+    #[graphql(validate = check)]
+    Variant,
+}
+
+fn check(_: &Test) -> Result<(), String> {
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(juniper::GraphQLInputObject)]
+struct Coordinate {
+    latitude: f64,
+    longitude: f64,
+    #[graphql(deprecated = "Altitude is ignored")]
+    altitude: f64,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(juniper::GraphQLArguments)]
+struct Pagination {
+    first: i32,
+}
+
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    first: String,
+    #[graphql(flatten)]
+    pagination: Pagination,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    #[graphql(pattern = "^[0-9a-f]+$")]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    #[graphql(max_length = 3)]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+fn trim(s: &str) -> Option<&str> {
+    Some(s.trim())
+}
+
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    #[graphql(transform = [trim])]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+#[graphql_object]
+impl ObjA {
+    fn greet(&self, #[graphql(deprecated = "Use `name` instead")] nickname: String) -> String {
+        nickname
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use juniper::{graphql_object, GraphQLArguments};
+
+#[derive(GraphQLArguments)]
+struct Filter {
+    since: String,
+}
+
+struct ObjA;
+
+#[graphql_object]
+impl ObjA {
+    fn events(&self, since: String, #[graphql(flatten)] filter: Filter) -> String {
+        format!("{} {}", since, filter.since)
+    }
+}
+
+fn main() {}
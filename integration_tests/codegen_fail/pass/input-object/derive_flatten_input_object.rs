@@ -0,0 +1,48 @@
+use juniper::{
+    execute_sync, graphql_object, graphql_value, EmptyMutation, EmptySubscription, RootNode,
+    Variables,
+};
+
+#[derive(juniper::GraphQLInputObject)]
+struct Pagination {
+    first: i32,
+}
+
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    query: String,
+    #[graphql(flatten)]
+    pagination: Pagination,
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn search(input: Object) -> String {
+        format!("{} {}", input.query, input.pagination.first)
+    }
+}
+
+fn main() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let doc = r#"{ __type(name: "Object") { inputFields { name } } }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((
+            graphql_value!({"__type": {"inputFields": [{"name": "query"}, {"name": "first"}]}}),
+            vec![],
+        )),
+    );
+
+    let doc = r#"{ search(input: {query: "juniper", first: 5}) }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((graphql_value!({"search": "juniper 5"}), vec![])),
+    );
+}
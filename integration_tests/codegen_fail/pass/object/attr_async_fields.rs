@@ -0,0 +1,43 @@
+use juniper::{graphql_object, FieldResult};
+
+struct Context;
+
+impl juniper::Context for Context {}
+
+struct User;
+
+#[graphql_object(context = Context)]
+impl User {
+    async fn id(&self) -> i32 {
+        1
+    }
+
+    async fn name(&self) -> FieldResult<String> {
+        Ok("alice".into())
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    fn api_version() -> &'static str {
+        "1.0"
+    }
+
+    async fn user(&self, ctx: &Context, id: i32) -> FieldResult<User> {
+        let _ = (ctx, id);
+        Ok(User)
+    }
+
+    async fn user_count(ctx: &Context) -> i32 {
+        let _ = ctx;
+        1
+    }
+
+    async fn users(first: Option<i32>) -> Vec<User> {
+        (0..first.unwrap_or(1)).map(|_| User).collect()
+    }
+}
+
+fn main() {}
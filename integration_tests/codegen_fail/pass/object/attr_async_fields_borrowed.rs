@@ -0,0 +1,22 @@
+use juniper::{graphql_object, FieldResult};
+
+struct Query {
+    name: String,
+}
+
+#[graphql_object]
+impl Query {
+    async fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn fallible_name(&self) -> FieldResult<&str> {
+        Ok(&self.name)
+    }
+
+    async fn greeting(&self, greeting: String) -> String {
+        format!("{}, {}", greeting, self.name)
+    }
+}
+
+fn main() {}
@@ -36,3 +36,16 @@ fn test_failing_compiliation() {
     })
     .unwrap();
 }
+
+#[test]
+fn test_passing_compiliation() {
+    let t = trybuild::TestCases::new();
+    let dir = PathBuf::from("pass");
+
+    visit_dirs(dir.as_path(), &|entry: &DirEntry| {
+        if let Some(Some("rs")) = entry.path().extension().map(|os| os.to_str()) {
+            t.pass(entry.path());
+        }
+    })
+    .unwrap();
+}
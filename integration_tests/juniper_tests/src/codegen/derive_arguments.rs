@@ -0,0 +1,110 @@
+use juniper::{
+    execute, graphql_object, graphql_value, parser::SourcePosition, DefaultScalarValue,
+    EmptyMutation, EmptySubscription, ExecutionError, FieldError, GraphQLArguments, RootNode,
+    Value, Variables,
+};
+
+fn not_negative(n: &i32) -> Result<(), &'static str> {
+    if *n >= 0 {
+        Ok(())
+    } else {
+        Err("must not be negative")
+    }
+}
+
+fn positive(n: &i32) -> Result<(), &'static str> {
+    if *n > 0 {
+        Ok(())
+    } else {
+        Err("must be positive")
+    }
+}
+
+fn ordered(range: &Range) -> Result<(), &'static str> {
+    if range.since <= range.until {
+        Ok(())
+    } else {
+        Err("`since` must not be after `until`")
+    }
+}
+
+#[derive(GraphQLArguments)]
+#[graphql(validate = ordered)]
+struct Range {
+    #[graphql(validate = not_negative)]
+    since: i32,
+    until: i32,
+    #[graphql(default = "1", validate = positive)]
+    step: i32,
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn numbers(#[graphql(flatten)] range: Range) -> Option<Vec<i32>> {
+        Some(
+            (range.since..=range.until)
+                .step_by(range.step as usize)
+                .collect(),
+        )
+    }
+}
+
+async fn run_query(query: &str) -> (Value, Vec<ExecutionError<DefaultScalarValue>>) {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+    execute(query, None, &schema, &Variables::new(), &())
+        .await
+        .unwrap()
+}
+
+fn invalid(message: &str) -> (Value, Vec<ExecutionError<DefaultScalarValue>>) {
+    (
+        graphql_value!({ "numbers": None }),
+        vec![ExecutionError::new(
+            SourcePosition::new(2, 0, 2),
+            &["numbers"],
+            FieldError::new(message, graphql_value!({"code": "INVALID_ARGUMENT"})),
+        )],
+    )
+}
+
+#[tokio::test]
+async fn converts_valid_arguments() {
+    assert_eq!(
+        run_query(r#"{ numbers(since: 1, until: 5, step: 2) }"#).await,
+        (graphql_value!({"numbers": [1, 3, 5]}), vec![]),
+    );
+}
+
+#[tokio::test]
+async fn uses_default_of_omitted_argument() {
+    assert_eq!(
+        run_query(r#"{ numbers(since: 1, until: 3) }"#).await,
+        (graphql_value!({"numbers": [1, 2, 3]}), vec![]),
+    );
+}
+
+#[tokio::test]
+async fn validates_each_argument() {
+    assert_eq!(
+        run_query(r#"{ numbers(since: -1, until: 1) }"#).await,
+        invalid("Invalid arguments: argument `since`: must not be negative"),
+    );
+    assert_eq!(
+        run_query(r#"{ numbers(since: 1, until: 5, step: 0) }"#).await,
+        invalid("Invalid arguments: argument `step`: must be positive"),
+    );
+}
+
+#[tokio::test]
+async fn validates_all_arguments() {
+    assert_eq!(
+        run_query(r#"{ numbers(since: 5, until: 1) }"#).await,
+        invalid("Invalid arguments: `since` must not be after `until`"),
+    );
+}
@@ -74,13 +74,13 @@ fn test_derived_enum() {
     // Test no rename variant.
     assert_eq!(
         <_ as ToInputValue>::to_input_value(&NoRenameEnum::AnotherVariant),
-        InputValue::scalar("AnotherVariant")
+        InputValue::enum_value("AnotherVariant")
     );
 
     // Test Regular variant.
     assert_eq!(
         <_ as ToInputValue>::to_input_value(&SomeEnum::Regular),
-        InputValue::scalar("REGULAR")
+        InputValue::enum_value("REGULAR")
     );
     assert_eq!(
         FromInputValue::<DefaultScalarValue>::from_input_value(&InputValue::scalar("REGULAR")),
@@ -90,7 +90,7 @@ fn test_derived_enum() {
     // Test FULL variant.
     assert_eq!(
         <_ as ToInputValue>::to_input_value(&SomeEnum::Full),
-        InputValue::scalar("FULL")
+        InputValue::enum_value("FULL")
     );
     assert_eq!(
         FromInputValue::<DefaultScalarValue>::from_input_value(&InputValue::scalar("FULL")),
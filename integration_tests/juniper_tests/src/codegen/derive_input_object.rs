@@ -1,7 +1,10 @@
+use chrono::NaiveDate;
 use fnv::FnvHashMap;
 use juniper::{
-    marker, DefaultScalarValue, FromInputValue, GraphQLInputObject, GraphQLType, GraphQLValue,
-    InputValue, Registry, ToInputValue,
+    execute, execute_sync, graphql_object, graphql_value, marker, parser::SourcePosition,
+    DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionError, FieldError,
+    FromInputValue, GraphQLInputObject, GraphQLType, GraphQLValue, InputValue, Registry, RootNode,
+    ToInputValue, Variables,
 };
 
 #[derive(GraphQLInputObject, Debug, PartialEq)]
@@ -104,6 +107,92 @@ struct WithLifetime<'a> {
     regular_field: &'a Fake,
 }
 
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+#[graphql(builder)]
+struct BuiltInput {
+    regular_field: String,
+    #[graphql(default = "33")]
+    c: i32,
+    other: Option<bool>,
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+struct DeprecatedFieldInput {
+    regular_field: String,
+    #[graphql(deprecated = "Use `regularField`.")]
+    legacy_field: Option<String>,
+    #[graphql(default, deprecated)]
+    other: bool,
+}
+
+struct DeprecatedFieldQuery;
+
+#[graphql_object]
+impl DeprecatedFieldQuery {
+    fn echo(input: DeprecatedFieldInput) -> String {
+        input.legacy_field.unwrap_or(input.regular_field)
+    }
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+#[graphql(one_of)]
+struct UserBy {
+    id: Option<i32>,
+    slug: Option<String>,
+    email: Option<String>,
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn user(by: UserBy) -> String {
+        match by {
+            UserBy { id: Some(id), .. } => format!("id {}", id),
+            UserBy {
+                slug: Some(slug), ..
+            } => format!("slug {}", slug),
+            UserBy {
+                email: Some(email), ..
+            } => format!("email {}", email),
+            _ => unreachable!("validated to have exactly one field set"),
+        }
+    }
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+struct PaginationInput {
+    #[graphql(default = "10")]
+    first: i32,
+    after: Option<String>,
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+struct AuditInput {
+    reason: String,
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+struct SearchInput {
+    query: String,
+    #[graphql(flatten)]
+    pagination: PaginationInput,
+    #[graphql(flatten)]
+    audit: AuditInput,
+}
+
+struct SearchQuery;
+
+#[graphql_object]
+impl SearchQuery {
+    fn search(input: SearchInput) -> String {
+        format!(
+            "{} {} {:?} {}",
+            input.query, input.pagination.first, input.pagination.after, input.audit.reason,
+        )
+    }
+}
+
 #[test]
 fn test_derived_input_object() {
     assert_eq!(
@@ -169,6 +258,42 @@ fn test_derived_input_object() {
     );
 }
 
+#[test]
+fn test_builder() {
+    let input = BuiltInput::builder()
+        .regular_field("a")
+        .other(true)
+        .build()
+        .unwrap();
+    assert_eq!(
+        input,
+        BuiltInput {
+            regular_field: "a".into(),
+            c: 33,
+            other: Some(true),
+        }
+    );
+
+    let modified = BuiltInputBuilder::from(input)
+        .c(55)
+        .other(None)
+        .build()
+        .unwrap();
+    assert_eq!(
+        modified,
+        BuiltInput {
+            regular_field: "a".into(),
+            c: 55,
+            other: None,
+        }
+    );
+
+    assert_eq!(
+        BuiltInput::builder().c(1).build(),
+        Err("missing required field `regular_field`".into()),
+    );
+}
+
 #[test]
 fn test_doc_comment() {
     let mut registry: Registry = Registry::new(FnvHashMap::default());
@@ -189,3 +314,342 @@ fn test_doc_comment_override() {
     let meta = OverrideDocComment::meta(&(), &mut registry);
     assert_eq!(meta.description(), Some("obj override"));
 }
+
+#[test]
+fn test_deprecated_fields() {
+    let schema = RootNode::new(
+        DeprecatedFieldQuery,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let doc = r#"{
+        __type(name: "DeprecatedFieldInput") {
+            inputFields(includeDeprecated: true) {
+                name
+                isDeprecated
+                deprecationReason
+            }
+        }
+    }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((
+            graphql_value!({"__type": {"inputFields": [
+                {"name": "regularField", "isDeprecated": false, "deprecationReason": None},
+                {"name": "legacyField", "isDeprecated": true, "deprecationReason": "Use `regularField`."},
+                {"name": "other", "isDeprecated": true, "deprecationReason": None},
+            ]}}),
+            vec![],
+        )),
+    );
+
+    let doc = r#"{ __type(name: "DeprecatedFieldInput") { inputFields { name } } }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((
+            graphql_value!({"__type": {"inputFields": [{"name": "regularField"}]}}),
+            vec![],
+        )),
+    );
+
+    let doc = r#"{ echo(input: {regularField: "a", legacyField: "b"}) }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((graphql_value!({"echo": "b"}), vec![])),
+    );
+}
+
+#[test]
+fn test_one_of_meta() {
+    let mut registry: Registry = Registry::new(FnvHashMap::default());
+    assert!(UserBy::meta(&(), &mut registry).is_one_of());
+    assert!(!Input::meta(&(), &mut registry).is_one_of());
+}
+
+#[tokio::test]
+async fn test_one_of_single_field() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    assert_eq!(
+        execute(
+            r#"{ user(by: {slug: "jdoe", email: null}) }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await,
+        Ok((graphql_value!({"user": "slug jdoe"}), vec![])),
+    );
+
+    let sdl = schema.as_sdl();
+    assert!(sdl.contains("input UserBy @oneOf {"), "{}", sdl);
+    assert!(schema.schema.directive_by_name("oneOf").is_some());
+}
+
+#[tokio::test]
+async fn test_one_of_no_fields() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let (res, errs) = execute(
+        "{ user(by: {id: null}) }",
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(res, graphql_value!(None));
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].error().message(),
+        "Invalid value of argument `by`: exactly one of the fields `id`, `slug`, `email` must be \
+         set, but none is",
+    );
+}
+
+#[tokio::test]
+async fn test_one_of_two_fields() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let (res, errs) = execute(
+        r#"{ user(by: {id: 1, email: "jdoe@example.com"}) }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(res, graphql_value!(None));
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].error().message(),
+        "Invalid value of argument `by`: exactly one of the fields `id`, `slug`, `email` must be \
+         set, but `id`, `email` are",
+    );
+
+    let (_, errs) = execute(
+        r#"{ user(by: {id: 1, slug: "jdoe", email: "jdoe@example.com"}) }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        errs[0].error().message(),
+        "Invalid value of argument `by`: exactly one of the fields `id`, `slug`, `email` must be \
+         set, but `id`, `slug`, `email` are",
+    );
+}
+
+#[test]
+fn test_flattened_fields() {
+    let schema = RootNode::new(
+        SearchQuery,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let doc = r#"{
+        __type(name: "SearchInput") {
+            inputFields {
+                name
+                defaultValue
+            }
+        }
+    }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((
+            graphql_value!({"__type": {"inputFields": [
+                {"name": "query", "defaultValue": None},
+                {"name": "first", "defaultValue": "10"},
+                {"name": "after", "defaultValue": None},
+                {"name": "reason", "defaultValue": None},
+            ]}}),
+            vec![],
+        )),
+    );
+
+    let doc = r#"{ search(input: {query: "juniper", after: "abc", reason: "audit"}) }"#;
+    assert_eq!(
+        execute_sync(doc, None, &schema, &Variables::new(), &()),
+        Ok((
+            graphql_value!({"search": "juniper 10 Some(\"abc\") audit"}),
+            vec![],
+        )),
+    );
+
+    let input: InputValue = ::serde_json::from_value(serde_json::json!({
+        "query": "juniper",
+        "first": 5,
+        "reason": "audit",
+    }))
+    .unwrap();
+    let output: SearchInput = FromInputValue::from_input_value(&input).unwrap();
+    assert_eq!(
+        output,
+        SearchInput {
+            query: "juniper".into(),
+            pagination: PaginationInput {
+                first: 5,
+                after: None,
+            },
+            audit: AuditInput {
+                reason: "audit".into(),
+            },
+        },
+    );
+    assert_eq!(
+        ToInputValue::<DefaultScalarValue>::to_input_value(&output),
+        InputValue::object(
+            vec![
+                ("query", InputValue::scalar("juniper")),
+                ("first", InputValue::scalar(5)),
+                ("after", InputValue::null()),
+                ("reason", InputValue::scalar("audit")),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+    );
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+#[graphql(builder)]
+struct EventInput {
+    title: String,
+    date: NaiveDate,
+    until: Option<NaiveDate>,
+}
+
+struct EventQuery;
+
+#[graphql_object]
+impl EventQuery {
+    fn is_pi_day(event: EventInput) -> bool {
+        event
+            == EventInput::builder()
+                .title("Pi day")
+                .date(NaiveDate::from_ymd_opt(2015, 3, 14).unwrap())
+                .build()
+                .unwrap()
+    }
+}
+
+#[tokio::test]
+async fn test_builder_with_dates() {
+    let event = EventInput::builder()
+        .title("Pi day")
+        .date(NaiveDate::from_ymd_opt(2015, 3, 14).unwrap())
+        .until(NaiveDate::from_ymd_opt(2015, 3, 15).unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(event.date, NaiveDate::from_ymd_opt(2015, 3, 14).unwrap());
+    assert_eq!(event.until, NaiveDate::from_ymd_opt(2015, 3, 15));
+
+    let schema = RootNode::new(
+        EventQuery,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    assert_eq!(
+        execute(
+            r#"{ isPiDay(event: {title: "Pi day", date: "2015-03-14"}) }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await,
+        Ok((graphql_value!({"isPiDay": true}), vec![])),
+    );
+}
+
+#[derive(GraphQLInputObject)]
+#[graphql(validate = validate_date_range)]
+struct DateRangeInput {
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+}
+
+fn validate_date_range(range: &DateRangeInput) -> Result<(), String> {
+    if range.start_date > range.end_date {
+        Err("`startDate` must not be after `endDate`".into())
+    } else {
+        Ok(())
+    }
+}
+
+struct DateRangeQuery;
+
+#[graphql_object]
+impl DateRangeQuery {
+    fn days(range: DateRangeInput) -> i32 {
+        (range.end_date - range.start_date).num_days() as i32
+    }
+}
+
+#[tokio::test]
+async fn test_validated_across_fields() {
+    let schema = RootNode::new(
+        DateRangeQuery,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    assert_eq!(
+        execute(
+            r#"{ days(range: {startDate: "2021-07-01", endDate: "2021-07-08"}) }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await,
+        Ok((graphql_value!({"days": 7}), vec![])),
+    );
+    assert_eq!(
+        execute(
+            r#"{ days(range: {startDate: "2021-07-08", endDate: "2021-07-01"}) }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await,
+        Ok((
+            graphql_value!(None),
+            vec![ExecutionError::new(
+                SourcePosition::new(2, 0, 2),
+                &["days"],
+                FieldError::new(
+                    "Invalid value of argument `range`: \
+                     `startDate` must not be after `endDate`",
+                    graphql_value!({"code": "INVALID_ARGUMENT"}),
+                ),
+            )],
+        )),
+    );
+}
@@ -1,6 +1,9 @@
+use std::convert::TryFrom;
+
 use juniper::{
-    execute, graphql_object, graphql_scalar, graphql_value, DefaultScalarValue, EmptyMutation,
-    EmptySubscription, Object, ParseScalarResult, ParseScalarValue, RootNode, Value, Variables,
+    execute, graphql_object, graphql_scalar, graphql_value, meta::MetaType, DefaultScalarValue,
+    EmptyMutation, EmptySubscription, Object, ParseScalarResult, ParseScalarValue, RootNode,
+    SchemaError, Value, Variables,
 };
 
 use crate::custom_scalar::MyScalarValue;
@@ -10,6 +13,16 @@ struct OtherOrder(i32);
 struct Named(i32);
 struct ScalarDescription(i32);
 struct Generated(String);
+struct Email(String);
+struct DurationSeconds(f64);
+struct CountryCode(String);
+#[derive(Debug, PartialEq)]
+struct Date {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+struct ShadowingInt(i32);
 
 struct Root;
 
@@ -19,6 +32,10 @@ Syntax to validate:
 
 * Default name vs. custom name
 * Description vs. no description on the scalar
+* Pattern vs. no pattern on the scalar
+* Unit vs. no unit on the scalar
+* Length constraint vs. no length constraint on the scalar
+* Transform pipeline vs. no transform pipeline on the scalar
 
 */
 
@@ -87,6 +104,110 @@ impl GraphQLScalar for ScalarDescription {
     }
 }
 
+#[graphql_scalar(pattern = r"^[^@\s]+@[^@\s]+\.[^@\s]+$")]
+impl<S> GraphQLScalar for Email
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0.clone())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Email> {
+        v.as_string_value().map(|s| Email(s.to_owned()))
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[graphql_scalar(unit = "seconds")]
+impl GraphQLScalar for DurationSeconds {
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0)
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<DurationSeconds> {
+        v.as_float_value().map(DurationSeconds)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, DefaultScalarValue> {
+        <f64 as ParseScalarValue>::from_str(value)
+    }
+}
+
+#[graphql_scalar(min_length = 2, max_length = 3)]
+impl<S> GraphQLScalar for CountryCode
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0.clone())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<CountryCode> {
+        v.as_string_value().map(|s| CountryCode(s.to_owned()))
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+fn trim(s: &str) -> Option<&str> {
+    Some(s.trim())
+}
+
+fn date(year: u32, month: u32, day: u32) -> Option<Date> {
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then(|| Date { year, month, day })
+}
+
+fn parse_date(s: &str) -> Option<Date> {
+    let mut parts = s.splitn(3, '-');
+    let mut next = || parts.next().filter(|p| !p.is_empty())?.parse().ok();
+    date(next()?, next()?, next()?)
+}
+
+#[graphql_scalar(transform = [trim, parse_date])]
+impl<S> GraphQLScalar for Date
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(format!(
+            "{:04}-{:02}-{:02}",
+            self.year, self.month, self.day
+        ))
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Date> {
+        // String inputs are parsed by the `transform` pipeline, so only the
+        // `YYYYMMDD` integers are left here.
+        let i = u32::try_from(v.as_int_value()?).ok()?;
+        date(i / 10000, i / 100 % 100, i % 100)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[graphql_scalar(name = "Int")]
+impl GraphQLScalar for ShadowingInt {
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0)
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<ShadowingInt> {
+        v.as_scalar_value::<i32>().map(|i| ShadowingInt(*i))
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, DefaultScalarValue> {
+        <i32 as ParseScalarValue>::from_str(value)
+    }
+}
+
 macro_rules! impl_scalar {
     ($name: ident) => {
         #[graphql_scalar]
@@ -130,6 +251,18 @@ impl Root {
     fn generated() -> Generated {
         Generated("foo".to_owned())
     }
+    fn email() -> Email {
+        Email("foo@example.com".to_owned())
+    }
+    fn duration_seconds() -> DurationSeconds {
+        DurationSeconds(1.5)
+    }
+    fn country_code(code: CountryCode) -> CountryCode {
+        code
+    }
+    fn date(date: Date) -> Date {
+        date
+    }
 }
 
 struct WithCustomScalarValue(i32);
@@ -330,6 +463,354 @@ async fn generated_scalar_introspection() {
     .await;
 }
 
+#[test]
+fn scalar_pattern_meta() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let meta = schema.schema.concrete_type_by_name("Email").unwrap();
+    assert_eq!(meta.pattern(), Some(r"^[^@\s]+@[^@\s]+\.[^@\s]+$"));
+
+    let meta = schema.schema.concrete_type_by_name("Generated").unwrap();
+    assert_eq!(meta.pattern(), None);
+}
+
+#[test]
+fn scalar_pattern_schema_language() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let sdl = schema.as_schema_language();
+    assert!(
+        sdl.contains(r#"scalar Email @pattern(regex: "^[^@\\s]+@[^@\\s]+\\.[^@\\s]+$")"#),
+        "{}",
+        sdl,
+    );
+    assert!(sdl.contains("scalar Generated\n"), "{}", sdl);
+    assert!(schema.schema.directive_by_name("pattern").is_some());
+}
+
+#[test]
+fn scalar_unit_meta() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let meta = schema
+        .schema
+        .concrete_type_by_name("DurationSeconds")
+        .unwrap();
+    assert_eq!(meta.unit(), Some("seconds"));
+    match meta {
+        MetaType::Scalar(scalar) => assert_eq!(scalar.unit(), Some("seconds")),
+        _ => panic!("`DurationSeconds` is not a scalar"),
+    }
+
+    let meta = schema.schema.concrete_type_by_name("Email").unwrap();
+    assert_eq!(meta.unit(), None);
+}
+
+#[test]
+fn scalar_unit_schema_language() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let sdl = schema.as_schema_language();
+    assert!(
+        sdl.contains(r#"scalar DurationSeconds @unit(name: "seconds")"#),
+        "{}",
+        sdl,
+    );
+    assert!(schema.schema.directive_by_name("unit").is_some());
+}
+
+#[tokio::test]
+async fn scalar_length_introspection() {
+    let doc = r#"
+    {
+        __type(name: "CountryCode") {
+            name
+            minLength
+            maxLength
+        }
+    }
+    "#;
+
+    run_type_info_query(doc, |type_info| {
+        assert_eq!(
+            type_info.get_field_value("minLength"),
+            Some(&graphql_value!(2)),
+        );
+        assert_eq!(
+            type_info.get_field_value("maxLength"),
+            Some(&graphql_value!(3)),
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn no_scalar_length_introspection() {
+    let doc = r#"
+    {
+        __type(name: "Email") {
+            name
+            minLength
+            maxLength
+        }
+    }
+    "#;
+
+    run_type_info_query(doc, |type_info| {
+        assert_eq!(
+            type_info.get_field_value("minLength"),
+            Some(&graphql_value!(None)),
+        );
+        assert_eq!(
+            type_info.get_field_value("maxLength"),
+            Some(&graphql_value!(None)),
+        );
+    })
+    .await;
+}
+
+#[test]
+fn scalar_length_schema_language() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let sdl = schema.as_schema_language();
+    assert!(
+        sdl.contains("scalar CountryCode @length(min: 2, max: 3)"),
+        "{}",
+        sdl,
+    );
+    assert!(schema.schema.directive_by_name("length").is_some());
+}
+
+#[test]
+fn scalar_directives_are_defined_in_sdl() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    for sdl in &[schema.as_sdl(), schema.as_schema_language()] {
+        for definition in &[
+            "directive @pattern(regex: String!) on SCALAR",
+            "directive @unit(name: String!) on SCALAR",
+            "directive @length(min: Int, max: Int) on SCALAR",
+        ] {
+            assert!(sdl.contains(definition), "{}", sdl);
+        }
+        assert!(
+            sdl.contains("scalar CountryCode @length(min: 2, max: 3)"),
+            "{}",
+            sdl
+        );
+        assert!(!sdl.contains("directive @skip"), "{}", sdl);
+    }
+}
+
+#[tokio::test]
+async fn accepts_scalar_of_valid_length() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    for code in &["PL", "UKR"] {
+        let doc = format!(r#"{{ countryCode(code: "{}") }}"#, code);
+
+        assert_eq!(
+            execute(&doc, None, &schema, &Variables::new(), &()).await,
+            Ok((graphql_value!({ "countryCode": (*code) }), vec![])),
+        );
+    }
+}
+
+#[tokio::test]
+async fn rejects_scalar_of_invalid_length() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    for code in &["P", "UKRA", ""] {
+        let doc = format!(r#"{{ countryCode(code: "{}") }}"#, code);
+
+        assert!(
+            execute(&doc, None, &schema, &Variables::new(), &())
+                .await
+                .is_err(),
+            "for code: {:?}",
+            code,
+        );
+    }
+
+    let mut vars = Variables::new();
+    vars.insert("code".to_owned(), juniper::InputValue::scalar("PLPL"));
+    assert!(execute(
+        "query($code: CountryCode!) { countryCode(code: $code) }",
+        None,
+        &schema,
+        &vars,
+        &(),
+    )
+    .await
+    .is_err(),);
+}
+
+#[tokio::test]
+async fn transforms_scalar_input_in_order() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    assert_eq!(
+        execute(
+            r#"{ date(date: "  2021-03-07\n") }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await,
+        Ok((graphql_value!({ "date": "2021-03-07" }), vec![])),
+    );
+
+    let mut vars = Variables::new();
+    vars.insert(
+        "date".to_owned(),
+        juniper::InputValue::scalar(" 2021-12-31 "),
+    );
+    assert_eq!(
+        execute(
+            "query($date: Date!) { date(date: $date) }",
+            None,
+            &schema,
+            &vars,
+            &(),
+        )
+        .await,
+        Ok((graphql_value!({ "date": "2021-12-31" }), vec![])),
+    );
+
+    assert_eq!(
+        execute(
+            "{ date(date: 20210307) }",
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await,
+        Ok((graphql_value!({ "date": "2021-03-07" }), vec![])),
+    );
+}
+
+#[tokio::test]
+async fn rejects_scalar_failing_transform() {
+    let schema = RootNode::new(
+        Root {},
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    for date in &["2021-13-07", " ", "2021-03", "20"] {
+        let doc = format!(r#"{{ date(date: "{}") }}"#, date);
+
+        assert!(
+            execute(&doc, None, &schema, &Variables::new(), &())
+                .await
+                .is_err(),
+            "for date: {:?}",
+            date,
+        );
+    }
+}
+
+struct RootWithShadowingScalar;
+
+#[graphql_object(scalar = DefaultScalarValue)]
+impl RootWithShadowingScalar {
+    fn shadowing() -> ShadowingInt {
+        ShadowingInt(0)
+    }
+}
+
+struct RootWithShadowingAndBuiltinScalars;
+
+#[graphql_object(scalar = DefaultScalarValue)]
+impl RootWithShadowingAndBuiltinScalars {
+    fn builtin() -> i32 {
+        0
+    }
+
+    fn shadowing() -> ShadowingInt {
+        ShadowingInt(0)
+    }
+}
+
+#[test]
+fn shadowing_builtin_scalar_name() {
+    let err = RootNode::try_new(
+        RootWithShadowingScalar,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    )
+    .err()
+    .expect("schema shadowing `Int` to fail");
+
+    assert!(matches!(
+        &err,
+        SchemaError::ShadowedBuiltinScalar { type_name, name }
+            if type_name.ends_with("ShadowingInt") && name == "Int",
+    ));
+    assert!(err
+        .to_string()
+        .contains("is named `Int`, shadowing the built-in `Int` scalar"));
+}
+
+#[test]
+fn shadowing_registered_builtin_scalar_name() {
+    let err = RootNode::try_new(
+        RootWithShadowingAndBuiltinScalars,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    )
+    .err()
+    .expect("schema shadowing `Int` to fail");
+
+    assert!(matches!(
+        &err,
+        SchemaError::ShadowedBuiltinScalar { type_name, name }
+            if type_name.ends_with("ShadowingInt") && name == "Int",
+    ));
+    assert!(err
+        .to_string()
+        .contains("is named `Int`, shadowing the built-in `Int` scalar"));
+}
+
 #[tokio::test]
 async fn resolves_with_custom_scalar_value() {
     const DOC: &str = r#"{ withCustomScalarValue }"#;
@@ -3,7 +3,7 @@
 use juniper::{
     execute, graphql_interface, graphql_object, graphql_value, DefaultScalarValue, EmptyMutation,
     EmptySubscription, Executor, FieldError, FieldResult, GraphQLInputObject, GraphQLObject,
-    GraphQLType, IntoFieldError, RootNode, ScalarValue, Variables,
+    GraphQLType, IntoFieldError, RootNode, ScalarValue, SchemaError, Variables,
 };
 
 fn schema<'q, C, Q>(query_root: Q) -> RootNode<'q, Q, EmptyMutation<C>, EmptySubscription<C>>
@@ -5736,3 +5736,178 @@ mod external_downcast {
         }
     }
 }
+
+mod implementing_interface {
+    use super::*;
+
+    #[graphql_interface(for = Post)]
+    trait Node {
+        fn id(&self) -> &str;
+    }
+
+    #[graphql_interface(impl = NodeValue, for = Post)]
+    trait Timestamped {
+        fn id(&self) -> &str;
+
+        fn created_at(&self) -> i32;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = [NodeValue, TimestampedValue])]
+    struct Post {
+        id: String,
+        created_at: i32,
+    }
+
+    #[graphql_interface]
+    impl Node for Post {
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    #[graphql_interface]
+    impl Timestamped for Post {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn created_at(&self) -> i32 {
+            self.created_at
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn timestamped(&self) -> TimestampedValue {
+            Post {
+                id: "post-1".to_string(),
+                created_at: 1_600_000_000,
+            }
+            .into()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_inherited_field() {
+        const DOC: &str = r#"{
+            timestamped {
+                id
+                createdAt
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"timestamped": {"id": "post-1", "createdAt": 1_600_000_000}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn registers_implemented_interfaces() {
+        const DOC: &str = r#"{
+            __type(name: "Timestamped") {
+                interfaces {
+                    kind
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"interfaces": [
+                    {"kind": "INTERFACE", "name": "Node"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn prints_implemented_interfaces_in_sdl() {
+        let schema = schema(QueryRoot);
+
+        assert!(schema
+            .as_sdl()
+            .contains("interface Timestamped implements Node {"));
+    }
+}
+
+mod implementing_interface_without_its_field {
+    use super::*;
+
+    #[graphql_interface(for = Post)]
+    trait Node {
+        fn id(&self) -> &str;
+    }
+
+    #[graphql_interface(impl = NodeValue, for = Post)]
+    trait Timestamped {
+        fn created_at(&self) -> i32;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = [NodeValue, TimestampedValue])]
+    struct Post {
+        id: String,
+        created_at: i32,
+    }
+
+    #[graphql_interface]
+    impl Node for Post {
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    #[graphql_interface]
+    impl Timestamped for Post {
+        fn created_at(&self) -> i32 {
+            self.created_at
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn timestamped(&self) -> TimestampedValue {
+            Post {
+                id: "post-1".to_string(),
+                created_at: 1_600_000_000,
+            }
+            .into()
+        }
+    }
+
+    #[test]
+    fn fails_to_build_schema() {
+        let err = RootNode::try_new(
+            QueryRoot,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .err()
+        .expect("schema missing the `id` field to fail");
+
+        assert_eq!(
+            err,
+            SchemaError::MissingInterfaceField {
+                name: "Timestamped".into(),
+                interface: "Node".into(),
+                field: "id".into(),
+            },
+        );
+    }
+}
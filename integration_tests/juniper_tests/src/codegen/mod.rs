@@ -1,3 +1,4 @@
+mod derive_arguments;
 mod derive_enum;
 mod derive_input_object;
 mod derive_object_with_raw_idents;
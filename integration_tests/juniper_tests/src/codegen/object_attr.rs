@@ -2,8 +2,8 @@
 
 use juniper::{
     execute, graphql_object, graphql_value, DefaultScalarValue, EmptyMutation, EmptySubscription,
-    Executor, FieldError, FieldResult, GraphQLInputObject, GraphQLObject, GraphQLType,
-    IntoFieldError, RootNode, ScalarValue, Variables,
+    Executor, FieldError, FieldResult, GraphQLArguments, GraphQLEnum, GraphQLInputObject,
+    GraphQLObject, GraphQLType, IntoFieldError, RootNode, ScalarValue, Variables,
 };
 
 fn schema<'q, C, Q>(query_root: Q) -> RootNode<'q, Q, EmptyMutation<C>, EmptySubscription<C>>
@@ -441,6 +441,136 @@ mod fallible_method {
     }
 }
 
+mod nullable_fallible_method {
+    use super::*;
+
+    use juniper::PathSegment;
+
+    struct CustomError;
+
+    impl<S: ScalarValue> IntoFieldError<S> for CustomError {
+        fn into_field_error(self) -> FieldError<S> {
+            juniper::FieldError::new("Cannot compute", graphql_value!({"code": "some"}))
+        }
+    }
+
+    struct Event;
+
+    #[graphql_object]
+    impl Event {
+        fn option_ok() -> Option<Result<&'static str, CustomError>> {
+            Some(Ok("2021-07-01"))
+        }
+
+        fn option_err() -> Option<Result<&'static str, CustomError>> {
+            Some(Err(CustomError))
+        }
+
+        async fn option_none() -> Option<Result<&'static str, CustomError>> {
+            None
+        }
+
+        fn result_some() -> Result<Option<&'static str>, CustomError> {
+            Ok(Some("2021-07-01"))
+        }
+
+        fn result_none() -> Result<Option<&'static str>, CustomError> {
+            Ok(None)
+        }
+
+        async fn result_err() -> Result<Option<&'static str>, CustomError> {
+            Err(CustomError)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn event() -> Event {
+            Event
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            event {
+                optionOk
+                optionErr
+                optionNone
+                resultSome
+                resultNone
+                resultErr
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res,
+            graphql_value!({"event": {
+                "optionOk": "2021-07-01",
+                "optionErr": None,
+                "optionNone": None,
+                "resultSome": "2021-07-01",
+                "resultNone": None,
+                "resultErr": None,
+            }}),
+        );
+        assert_eq!(
+            errs.iter()
+                .map(|e| (e.path(), e.error().message()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    &[PathSegment::from("event"), "optionErr".into()][..],
+                    "Cannot compute"
+                ),
+                (
+                    &[PathSegment::from("event"), "resultErr".into()][..],
+                    "Cannot compute"
+                ),
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn has_nullable_graphql_type() {
+        const DOC: &str = r#"{
+            __type(name: "Event") {
+                fields {
+                    type {
+                        kind
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"type": {"kind": "SCALAR", "name": "String"}},
+                    {"type": {"kind": "SCALAR", "name": "String"}},
+                    {"type": {"kind": "SCALAR", "name": "String"}},
+                    {"type": {"kind": "SCALAR", "name": "String"}},
+                    {"type": {"kind": "SCALAR", "name": "String"}},
+                    {"type": {"kind": "SCALAR", "name": "String"}},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod generic {
     use super::*;
 
@@ -906,6 +1036,141 @@ mod nested_generic_lifetime_async {
     }
 }
 
+mod mixed_sync_async_methods {
+    use juniper::execute_sync;
+
+    use super::*;
+
+    struct Database {
+        users: Vec<&'static str>,
+    }
+
+    impl juniper::Context for Database {}
+
+    struct User {
+        id: i32,
+        name: &'static str,
+    }
+
+    #[graphql_object(context = Database)]
+    impl User {
+        fn id(&self) -> i32 {
+            self.id
+        }
+
+        async fn name(&self) -> &str {
+            self.name
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database)]
+    impl QueryRoot {
+        fn api_version() -> &'static str {
+            "1.0"
+        }
+
+        async fn user(&self, ctx: &Database, id: i32) -> FieldResult<User> {
+            ctx.users
+                .get(id as usize)
+                .map(|&name| User { id, name })
+                .ok_or_else(|| format!("User {} not found", id).into())
+        }
+
+        async fn user_count(ctx: &Database) -> i32 {
+            ctx.users.len() as i32
+        }
+    }
+
+    fn database() -> Database {
+        Database {
+            users: vec!["alice", "bob"],
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            apiVersion
+            userCount
+            user(id: 1) {
+                id
+                name
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &database()).await,
+            Ok((
+                graphql_value!({
+                    "apiVersion": "1.0",
+                    "userCount": 2,
+                    "user": {"id": 1, "name": "bob"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_fallible_error() {
+        const DOC: &str = r#"{
+            user(id: 5) {
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &database())
+            .await
+            .unwrap();
+        assert_eq!(res, graphql_value!(None));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].error().message(), "User 5 not found");
+        assert_eq!(errs[0].path(), &["user"]);
+    }
+
+    #[test]
+    fn errors_on_async_field_resolved_synchronously() {
+        const DOC: &str = r#"{
+            apiVersion
+            userCount
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute_sync(DOC, None, &schema, &Variables::new(), &database()).unwrap();
+        assert_eq!(res, graphql_value!(None));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].error().message(),
+            "Field `userCount` of type `QueryRoot` is async and can't be resolved synchronously, \
+             use `juniper::execute()` instead of `juniper::execute_sync()`",
+        );
+        assert_eq!(errs[0].path(), &["userCount"]);
+    }
+
+    #[test]
+    fn resolves_sync_fields_synchronously() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(
+                "{ apiVersion }",
+                None,
+                &schema,
+                &Variables::new(),
+                &database(),
+            ),
+            Ok((graphql_value!({"apiVersion": "1.0"}), vec![])),
+        );
+    }
+}
+
 mod argument {
     use super::*;
 
@@ -1156,17 +1421,33 @@ mod default_argument {
     }
 }
 
-mod description_from_doc_comment {
+mod flattened_argument {
     use super::*;
 
+    #[derive(GraphQLArguments, Debug)]
+    struct Filter {
+        since: String,
+        until: Option<String>,
+        #[graphql(default = "10")]
+        limit: i32,
+    }
+
     struct Human;
 
-    /// Rust docs.
     #[graphql_object]
     impl Human {
-        /// Rust `id` docs.
-        fn id() -> &'static str {
-            "human-32"
+        fn events(#[graphql(flatten)] filter: Filter, upcoming: bool) -> String {
+            format!(
+                "{}..{}|{}|{}",
+                filter.since,
+                filter.until.unwrap_or_default(),
+                filter.limit,
+                upcoming,
+            )
+        }
+
+        async fn events_async(#[graphql(flatten)] filter: Filter) -> String {
+            format!("{}|{}", filter.since, filter.limit)
         }
     }
 
@@ -1180,12 +1461,38 @@ mod description_from_doc_comment {
     }
 
     #[tokio::test]
-    async fn uses_doc_comment_as_description() {
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human {
+                events(since: "2021-07-01", until: "2021-08-01", upcoming: true)
+                eventsAsync(since: "2021-07-01", limit: 3)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "events": "2021-07-01..2021-08-01|10|true",
+                    "eventsAsync": "2021-07-01|3",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_flattened_args() {
         const DOC: &str = r#"{
             __type(name: "Human") {
-                description
                 fields {
-                    description
+                    name
+                    args {
+                        name
+                        defaultValue
+                    }
                 }
             }
         }"#;
@@ -1195,87 +1502,142 @@ mod description_from_doc_comment {
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"__type": {
-                    "description": "Rust docs.",
-                    "fields": [{"description": "Rust `id` docs."}],
-                }}),
+                graphql_value!({"__type": {"fields": [{
+                    "name": "events",
+                    "args": [
+                        {"name": "since", "defaultValue": None},
+                        {"name": "until", "defaultValue": None},
+                        {"name": "limit", "defaultValue": "10"},
+                        {"name": "upcoming", "defaultValue": None},
+                    ],
+                }, {
+                    "name": "eventsAsync",
+                    "args": [
+                        {"name": "since", "defaultValue": None},
+                        {"name": "until", "defaultValue": None},
+                        {"name": "limit", "defaultValue": "10"},
+                    ],
+                }]}}),
                 vec![],
             )),
         );
     }
-}
 
-mod deprecation_from_attr {
-    use super::*;
+    #[test]
+    fn does_not_register_input_object() {
+        let schema = schema(QueryRoot);
 
-    struct Human;
+        assert!(schema.schema.concrete_type_by_name("Filter").is_none());
+    }
 
-    #[graphql_object]
-    impl Human {
-        fn id() -> &'static str {
-            "human-32"
-        }
+    #[tokio::test]
+    async fn rejects_missing_required_arg() {
+        const DOC: &str = r#"{ human { events(upcoming: true) } }"#;
 
-        #[deprecated]
-        fn a(&self) -> &str {
-            "a"
-        }
+        let schema = schema(QueryRoot);
 
-        #[deprecated(note = "Use `id`.")]
-        fn b(&self) -> &str {
-            "b"
-        }
+        assert!(matches!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Err(juniper::GraphQLError::ValidationError(_)),
+        ));
+    }
+}
+
+mod flattened_date_argument {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use super::*;
+
+    #[derive(GraphQLArguments)]
+    struct Filter {
+        since: DateTime<Utc>,
+        tag: Option<String>,
     }
 
     struct QueryRoot;
 
     #[graphql_object]
     impl QueryRoot {
-        fn human() -> Human {
-            Human
+        fn events(#[graphql(flatten)] filter: Filter) -> Vec<String> {
+            (1..=3)
+                .map(|day| Utc.with_ymd_and_hms(2021, 7, day, 0, 0, 0).unwrap())
+                .filter(|date| *date >= filter.since)
+                .map(|date| format!("{}{}", filter.tag.as_deref().unwrap_or(""), date))
+                .collect()
         }
     }
 
     #[tokio::test]
-    async fn resolves_id_field() {
-        const DOC: &str = r#"{
-            human {
-                id
-            }
-        }"#;
+    async fn resolves() {
+        const DOC: &str = r#"{ events(since: "2021-07-02T00:00:00Z", tag: "@") }"#;
 
         let schema = schema(QueryRoot);
 
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
-            Ok((graphql_value!({"human": {"id": "human-32"}}), vec![])),
+            Ok((
+                graphql_value!({"events": [
+                    "@2021-07-02 00:00:00 UTC",
+                    "@2021-07-03 00:00:00 UTC",
+                ]}),
+                vec![],
+            )),
         );
     }
+}
 
-    #[tokio::test]
-    async fn resolves_deprecated_fields() {
-        const DOC: &str = r#"{
-            human {
-                a
-                b
-            }
-        }"#;
+mod default_enum_argument {
+    use super::*;
 
-        let schema = schema(QueryRoot);
+    #[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+    enum SortOrder {
+        Asc,
+        Desc,
+    }
 
-        assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &()).await,
-            Ok((graphql_value!({"human": {"a": "a", "b": "b"}}), vec![])),
-        );
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn sorted(#[graphql(default = SortOrder::Desc)] sort_by: SortOrder) -> Vec<i32> {
+            match sort_by {
+                SortOrder::Asc => vec![1, 2, 3],
+                SortOrder::Desc => vec![3, 2, 1],
+            }
+        }
     }
 
     #[tokio::test]
-    async fn deprecates_fields() {
-        const DOC: &str = r#"{
-            __type(name: "Human") {
-                fields(includeDeprecated: true) {
-                    name
-                    isDeprecated
+    async fn resolves_with_default() {
+        let schema = schema(QueryRoot);
+
+        for (input, expected) in &[
+            ("{ sorted }", [3, 2, 1]),
+            ("{ sorted(sortBy: DESC) }", [3, 2, 1]),
+            ("{ sorted(sortBy: ASC) }", [1, 2, 3]),
+        ] {
+            let [a, b, c] = *expected;
+
+            assert_eq!(
+                execute(*input, None, &schema, &Variables::new(), &()).await,
+                Ok((graphql_value!({"sorted": [a, b, c]}), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn has_enum_default() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    args {
+                        name
+                        defaultValue
+                        type {
+                            name
+                            kind
+                        }
+                    }
                 }
             }
         }"#;
@@ -1285,36 +1647,103 @@ mod deprecation_from_attr {
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"__type": {"fields": [
-                    {"name": "id", "isDeprecated": false},
-                    {"name": "a", "isDeprecated": true},
-                    {"name": "b", "isDeprecated": true},
-                ]}}),
+                graphql_value!({"__type": {"fields": [{
+                    "args": [{
+                        "name": "sortBy",
+                        "defaultValue": "DESC",
+                        "type": {"name": "SortOrder", "kind": "ENUM"},
+                    }],
+                }]}}),
                 vec![],
             )),
         );
     }
+}
+
+mod computed_default_argument {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    use super::*;
+
+    static CALLS: AtomicI32 = AtomicI32::new(0);
+
+    fn next_id() -> i32 {
+        CALLS.fetch_add(1, Ordering::SeqCst) + 100
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn id(#[graphql(default_with = next_id)] id: i32) -> i32 {
+            id
+        }
+
+        async fn id_async(#[graphql(default_with = self::next_id)] id: i32) -> i32 {
+            id
+        }
+    }
 
     #[tokio::test]
-    async fn provides_deprecation_reason() {
+    async fn resolves_with_computed_default() {
         const DOC: &str = r#"{
-            __type(name: "Human") {
-                fields(includeDeprecated: true) {
-                    name
-                    deprecationReason
+            explicit: id(id: 1)
+            explicitAsync: idAsync(id: 2)
+            nullified: id(id: null)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"explicit": 1, "explicitAsync": 2, "nullified": 100}),
+                vec![],
+            )),
+        );
+        assert_eq!(
+            execute(
+                "{ a: id b: idAsync }",
+                None,
+                &schema,
+                &Variables::new(),
+                &()
+            )
+            .await,
+            Ok((graphql_value!({"a": 101, "b": 102}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn is_nullable_without_default() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    args {
+                        name
+                        defaultValue
+                        type {
+                            name
+                            kind
+                        }
+                    }
                 }
             }
         }"#;
 
         let schema = schema(QueryRoot);
 
+        let arg = graphql_value!({
+            "name": "id",
+            "defaultValue": None,
+            "type": {"name": "Int", "kind": "SCALAR"},
+        });
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
                 graphql_value!({"__type": {"fields": [
-                    {"name": "id", "deprecationReason": None},
-                    {"name": "a", "deprecationReason": None},
-                    {"name": "b", "deprecationReason": "Use `id`."},
+                    {"args": [(arg.clone())]},
+                    {"args": [(arg)]},
                 ]}}),
                 vec![],
             )),
@@ -1322,51 +1751,90 @@ mod deprecation_from_attr {
     }
 }
 
-mod explicit_name_description_and_deprecation {
+mod computed_default_date_argument {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
     use super::*;
 
-    struct Human;
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap()
+    }
 
-    /// Rust docs.
-    #[graphql_object(name = "MyHuman", desc = "My human.")]
-    impl Human {
-        /// Rust `id` docs.
-        #[graphql(name = "myId", desc = "My human ID.", deprecated = "Not used.")]
-        #[deprecated(note = "Should be omitted.")]
-        fn id(
-            #[graphql(name = "myName", desc = "My argument.", default)] _n: String,
-        ) -> &'static str {
-            "human-32"
-        }
+    struct QueryRoot;
 
-        #[graphql(deprecated)]
-        #[deprecated(note = "Should be omitted.")]
-        fn a(&self) -> &str {
-            "a"
+    #[graphql_object]
+    impl QueryRoot {
+        fn days_since(#[graphql(default_with = now)] since: DateTime<Utc>) -> i32 {
+            (Utc.with_ymd_and_hms(2021, 7, 18, 9, 10, 11).unwrap() - since).num_days() as i32
         }
+    }
 
-        fn b(&self) -> &str {
-            "b"
+    #[tokio::test]
+    async fn resolves_with_computed_default() {
+        let schema = schema(QueryRoot);
+
+        for (doc, expected) in &[
+            ("{ daysSince }", 10),
+            ("{ daysSince(since: null) }", 10),
+            (r#"{ daysSince(since: "2021-07-01T09:10:11Z") }"#, 17),
+        ] {
+            assert_eq!(
+                execute(doc, None, &schema, &Variables::new(), &()).await,
+                Ok((graphql_value!({ "daysSince": (*expected) }), vec![])),
+            );
         }
     }
+}
+
+mod expression_default_argument {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    use super::*;
+
+    const MINUTES_PER_HOUR: i32 = 60;
+
+    static CALLS: AtomicI32 = AtomicI32::new(0);
+
+    static COUNTDOWN_CALLS: AtomicI32 = AtomicI32::new(0);
+
+    #[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+    enum Unit {
+        Minutes,
+        Hours,
+    }
 
     struct QueryRoot;
 
     #[graphql_object]
     impl QueryRoot {
-        fn human() -> Human {
-            Human
+        fn minutes(#[graphql(default = 24 * MINUTES_PER_HOUR)] n: i32) -> i32 {
+            n
+        }
+
+        fn unit(#[graphql(default = Unit::Hours)] unit: Unit) -> Unit {
+            unit
+        }
+
+        fn counter(#[graphql(default = CALLS.fetch_add(1, Ordering::SeqCst) + 100)] n: i32) -> i32 {
+            n
+        }
+
+        fn countdown(
+            #[graphql(default_with = || 100 - COUNTDOWN_CALLS.fetch_add(1, Ordering::SeqCst))]
+            n: i32,
+        ) -> i32 {
+            n
         }
     }
 
     #[tokio::test]
-    async fn resolves_fields() {
+    async fn resolves_with_default() {
         const DOC: &str = r#"{
-            human {
-                myId
-                a
-                b
-            }
+            minutes
+            explicitMinutes: minutes(n: 1)
+            unit
+            explicitUnit: unit(unit: MINUTES)
+            explicitCounter: counter(n: 5)
         }"#;
 
         let schema = schema(QueryRoot);
@@ -1374,21 +1842,65 @@ mod explicit_name_description_and_deprecation {
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"human": {"myId": "human-32", "a": "a", "b": "b"}}),
+                graphql_value!({
+                    "minutes": 1440,
+                    "explicitMinutes": 1,
+                    "unit": "HOURS",
+                    "explicitUnit": "MINUTES",
+                    "explicitCounter": 5,
+                }),
                 vec![],
             )),
         );
     }
 
     #[tokio::test]
-    async fn uses_custom_name() {
+    async fn evaluates_non_const_default_on_every_omission() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                "{ a: counter b: counter(n: null) }",
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"a": 100, "b": 101}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn evaluates_default_closure_on_every_omission() {
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                "{ a: countdown b: countdown(n: null) }",
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"a": 100, "b": 99}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_const_defaults_only() {
         const DOC: &str = r#"{
-            __type(name: "MyHuman") {
-                name
-                fields(includeDeprecated: true) {
+            __type(name: "QueryRoot") {
+                fields {
                     name
                     args {
                         name
+                        defaultValue
+                        type {
+                            name
+                            kind
+                        }
                     }
                 }
             }
@@ -1399,30 +1911,71 @@ mod explicit_name_description_and_deprecation {
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"__type": {
-                    "name": "MyHuman",
-                    "fields": [
-                        {"name": "myId", "args": [{"name": "myName"}]},
-                        {"name": "a", "args": []},
-                        {"name": "b", "args": []},
-                    ],
-                }}),
+                graphql_value!({"__type": {"fields": [{
+                    "name": "minutes",
+                    "args": [{
+                        "name": "n",
+                        "defaultValue": "1440",
+                        "type": {"name": "Int", "kind": "SCALAR"},
+                    }],
+                }, {
+                    "name": "unit",
+                    "args": [{
+                        "name": "unit",
+                        "defaultValue": "HOURS",
+                        "type": {"name": "Unit", "kind": "ENUM"},
+                    }],
+                }, {
+                    "name": "counter",
+                    "args": [{
+                        "name": "n",
+                        "defaultValue": None,
+                        "type": {"name": "Int", "kind": "SCALAR"},
+                    }],
+                }, {
+                    "name": "countdown",
+                    "args": [{
+                        "name": "n",
+                        "defaultValue": None,
+                        "type": {"name": "Int", "kind": "SCALAR"},
+                    }],
+                }]}}),
                 vec![],
             )),
         );
     }
+}
+
+mod description_from_doc_comment {
+    use super::*;
+
+    struct Human;
+
+    /// Rust docs.
+    #[graphql_object]
+    impl Human {
+        /// Rust `id` docs.
+        fn id() -> &'static str {
+            "human-32"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
 
     #[tokio::test]
-    async fn uses_custom_description() {
+    async fn uses_doc_comment_as_description() {
         const DOC: &str = r#"{
-            __type(name: "MyHuman") {
+            __type(name: "Human") {
                 description
-                fields(includeDeprecated: true) {
-                    name
+                fields {
                     description
-                    args {
-                        description
-                    }
                 }
             }
         }"#;
@@ -1433,34 +1986,39 @@ mod explicit_name_description_and_deprecation {
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
                 graphql_value!({"__type": {
-                    "description": "My human.",
-                    "fields": [{
-                        "name": "myId",
-                        "description": "My human ID.",
-                        "args": [{"description": "My argument."}],
-                    }, {
-                        "name": "a",
-                        "description": None,
-                        "args": [],
-                    }, {
-                        "name": "b",
-                        "description": None,
-                        "args": [],
-                    }],
+                    "description": "Rust docs.",
+                    "fields": [{"description": "Rust `id` docs."}],
                 }}),
                 vec![],
             )),
         );
     }
+}
+
+mod multiline_description_from_doc_comment {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use super::*;
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        /// Moment the data was last refreshed.
+        ///
+        /// Always in UTC.
+        fn refreshed_at() -> DateTime<Utc> {
+            Utc.timestamp_opt(61, 0).unwrap()
+        }
+    }
 
     #[tokio::test]
-    async fn uses_custom_deprecation() {
+    async fn uses_doc_comment_as_description() {
         const DOC: &str = r#"{
-            __type(name: "MyHuman") {
-                fields(includeDeprecated: true) {
+            __type(name: "QueryRoot") {
+                fields {
                     name
-                    isDeprecated
-                    deprecationReason
+                    description
                 }
             }
         }"#;
@@ -1470,14 +2028,377 @@ mod explicit_name_description_and_deprecation {
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"__type": {
-                    "fields": [{
-                        "name": "myId",
-                        "isDeprecated": true,
-                        "deprecationReason": "Not used.",
-                    }, {
-                        "name": "a",
-                        "isDeprecated": true,
+                graphql_value!({"__type": {"fields": [{
+                    "name": "refreshedAt",
+                    "description": "Moment the data was last refreshed.\n\nAlways in UTC.",
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod deprecation_from_attr {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        #[deprecated]
+        fn a(&self) -> &str {
+            "a"
+        }
+
+        #[deprecated(note = "Use `id`.")]
+        fn b(&self) -> &str {
+            "b"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_id_field() {
+        const DOC: &str = r#"{
+            human {
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((graphql_value!({"human": {"id": "human-32"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_deprecated_fields() {
+        const DOC: &str = r#"{
+            human {
+                a
+                b
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((graphql_value!({"human": {"a": "a", "b": "b"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn deprecates_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields(includeDeprecated: true) {
+                    name
+                    isDeprecated
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "isDeprecated": false},
+                    {"name": "a", "isDeprecated": true},
+                    {"name": "b", "isDeprecated": true},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn provides_deprecation_reason() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields(includeDeprecated: true) {
+                    name
+                    deprecationReason
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "deprecationReason": None},
+                    {"name": "a", "deprecationReason": None},
+                    {"name": "b", "deprecationReason": "Use `id`."},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod deprecated_argument {
+    use super::*;
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn greet(
+            name: Option<String>,
+            #[graphql(deprecated = "Use `name`.")] nickname: Option<String>,
+            #[graphql(default = false, deprecated)] loud: bool,
+        ) -> String {
+            let greeting = format!("Hello, {}!", name.or(nickname).unwrap_or_default());
+            if loud {
+                greeting.to_uppercase()
+            } else {
+                greeting
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_deprecated_arguments() {
+        const DOC: &str = r#"{
+            greet(nickname: "Bob", loud: true)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((graphql_value!({"greet": "HELLO, BOB!"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn deprecates_arguments() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    args(includeDeprecated: true) {
+                        name
+                        isDeprecated
+                        deprecationReason
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"args": [
+                    {"name": "name", "isDeprecated": false, "deprecationReason": None},
+                    {"name": "nickname", "isDeprecated": true, "deprecationReason": "Use `name`."},
+                    {"name": "loud", "isDeprecated": true, "deprecationReason": None},
+                ]}]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn omits_deprecated_arguments_by_default() {
+        const DOC: &str = r#"{
+            __type(name: "QueryRoot") {
+                fields {
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{"args": [{"name": "name"}]}]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod explicit_name_description_and_deprecation {
+    use super::*;
+
+    struct Human;
+
+    /// Rust docs.
+    #[graphql_object(name = "MyHuman", desc = "My human.")]
+    impl Human {
+        /// Rust `id` docs.
+        #[graphql(name = "myId", desc = "My human ID.", deprecated = "Not used.")]
+        #[deprecated(note = "Should be omitted.")]
+        fn id(
+            #[graphql(name = "myName", desc = "My argument.", default)] _n: String,
+        ) -> &'static str {
+            "human-32"
+        }
+
+        #[graphql(deprecated)]
+        #[deprecated(note = "Should be omitted.")]
+        fn a(&self) -> &str {
+            "a"
+        }
+
+        fn b(&self) -> &str {
+            "b"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                myId
+                a
+                b
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {"myId": "human-32", "a": "a", "b": "b"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_custom_name() {
+        const DOC: &str = r#"{
+            __type(name: "MyHuman") {
+                name
+                fields(includeDeprecated: true) {
+                    name
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "name": "MyHuman",
+                    "fields": [
+                        {"name": "myId", "args": [{"name": "myName"}]},
+                        {"name": "a", "args": []},
+                        {"name": "b", "args": []},
+                    ],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_custom_description() {
+        const DOC: &str = r#"{
+            __type(name: "MyHuman") {
+                description
+                fields(includeDeprecated: true) {
+                    name
+                    description
+                    args {
+                        description
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "description": "My human.",
+                    "fields": [{
+                        "name": "myId",
+                        "description": "My human ID.",
+                        "args": [{"description": "My argument."}],
+                    }, {
+                        "name": "a",
+                        "description": None,
+                        "args": [],
+                    }, {
+                        "name": "b",
+                        "description": None,
+                        "args": [],
+                    }],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_custom_deprecation() {
+        const DOC: &str = r#"{
+            __type(name: "MyHuman") {
+                fields(includeDeprecated: true) {
+                    name
+                    isDeprecated
+                    deprecationReason
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "fields": [{
+                        "name": "myId",
+                        "isDeprecated": true,
+                        "deprecationReason": "Not used.",
+                    }, {
+                        "name": "a",
+                        "isDeprecated": true,
                         "deprecationReason": None,
                     }, {
                         "name": "b",
@@ -1491,54 +2412,564 @@ mod explicit_name_description_and_deprecation {
     }
 }
 
-mod renamed_all_fields_and_args {
+mod renamed_all_fields_and_args {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(rename_all = "none")]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        async fn home_planet(&self, planet_name: String) -> String {
+            planet_name
+        }
+
+        async fn r#async_info(r#my_num: i32) -> i32 {
+            r#my_num
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                home_planet(planet_name: "earth")
+                async_info(my_num: 3)
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "home_planet": "earth",
+                    "async_info": 3,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_correct_fields_and_args_names() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                    args {
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "args": []},
+                    {"name": "home_planet", "args": [{"name": "planet_name"}]},
+                    {"name": "async_info", "args": [{"name": "my_num"}]},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod explicit_scalar {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl Human {
+        fn id(&self) -> &str {
+            "human-32"
+        }
+
+        async fn home_planet() -> &'static str {
+            "earth"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                homePlanet
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "homePlanet": "earth"}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod custom_scalar {
+    use crate::custom_scalar::MyScalarValue;
+
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(scalar = MyScalarValue)]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        async fn home_planet(&self) -> &str {
+            "earth"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(scalar = MyScalarValue)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                homePlanet
+            }
+        }"#;
+
+        let schema = schema_with_scalar::<MyScalarValue, _, _>(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "homePlanet": "earth"}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod explicit_generic_scalar {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    struct Human<S>(PhantomData<S>);
+
+    #[graphql_object(scalar = S)]
+    impl<S: ScalarValue> Human<S> {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        async fn another(&self, _executor: &Executor<'_, '_, (), S>) -> Human<S> {
+            Human(PhantomData)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human<__S>() -> Human<__S> {
+            Human(PhantomData)
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                another {
+                    id
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "another": {"id": "human-32"},
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod bounded_generic_scalar {
     use super::*;
 
     struct Human;
 
-    #[graphql_object(rename_all = "none")]
-    impl Human {
-        fn id() -> &'static str {
-            "human-32"
+    #[graphql_object(scalar = S: ScalarValue + Clone)]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        async fn another<S>(&self, _executor: &Executor<'_, '_, (), S>) -> Human {
+            Human
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                another {
+                    id
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "another": {"id": "human-32"},
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod explicit_custom_context {
+    use super::*;
+
+    struct CustomContext(String);
+
+    impl juniper::Context for CustomContext {}
+
+    struct Human;
+
+    #[graphql_object(context = CustomContext)]
+    impl Human {
+        async fn id<'c>(&self, context: &'c CustomContext) -> &'c str {
+            context.0.as_str()
+        }
+
+        async fn info(_ctx: &()) -> &'static str {
+            "human being"
+        }
+
+        fn more(#[graphql(context)] custom: &CustomContext) -> &str {
+            custom.0.as_str()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = CustomContext)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                info
+                more
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = CustomContext("ctx!".into());
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &ctx).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "ctx!",
+                    "info": "human being",
+                    "more": "ctx!",
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod rate_limited_field {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use juniper::{RateLimit, RateLimiter};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingLimiter(AtomicU32);
+
+    impl juniper::Context for CountingLimiter {}
+
+    impl RateLimiter for CountingLimiter {
+        fn acquire(&self, key: &str, limit: RateLimit) -> bool {
+            assert!(key == "QueryRoot.ping" || key == "QueryRoot.asyncPing");
+            assert_eq!(limit.to_string(), "2/min");
+
+            self.0.fetch_add(1, Ordering::SeqCst) < limit.max()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = CountingLimiter)]
+    impl QueryRoot {
+        #[graphql(rate_limit = "2/min")]
+        fn ping() -> Option<i32> {
+            Some(1)
+        }
+
+        #[graphql(rate_limit = "2/min")]
+        async fn async_ping() -> Option<i32> {
+            Some(2)
+        }
+
+        fn pong() -> i32 {
+            3
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_over_limit() {
+        const DOC: &str = r#"{
+            a: ping
+            b: ping
+            c: ping
+            pong
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = CountingLimiter::default();
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!({"a": 1, "b": 1, "c": None, "pong": 3}));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].error().message(),
+            "Rate limit of 2/min exceeded for field `QueryRoot.ping`",
+        );
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"code": "RATE_LIMITED"}),
+        );
+        assert_eq!(ctx.0.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn rejects_async_over_limit() {
+        const DOC: &str = r#"{
+            a: ping
+            b: asyncPing
+            c: asyncPing
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = CountingLimiter::default();
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!({"a": 1, "b": 2, "c": None}));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].error().message(),
+            "Rate limit of 2/min exceeded for field `QueryRoot.asyncPing`",
+        );
+    }
+}
+
+mod timed_out_field {
+    use std::time::Duration;
+
+    use juniper::{BoxFuture, Timer};
+
+    use super::*;
+
+    struct TokioTimer;
+
+    impl juniper::Context for TokioTimer {}
+
+    impl Timer for TokioTimer {
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            Box::pin(tokio::time::sleep(duration))
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = TokioTimer)]
+    impl QueryRoot {
+        #[graphql(timeout = "50ms")]
+        async fn fast() -> Option<i32> {
+            Some(1)
+        }
+
+        #[graphql(timeout = "50ms")]
+        async fn slow() -> Option<i32> {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Some(2)
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_timed_out() {
+        const DOC: &str = r#"{
+            fast
+            slow
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &TokioTimer)
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!({"fast": 1, "slow": None}));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].error().message(),
+            "Field `QueryRoot.slow` timed out after 50ms",
+        );
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"code": "TIMEOUT"}),
+        );
+    }
+}
+
+mod feature_flagged_field {
+    use std::collections::HashSet;
+
+    use juniper::FeatureFlags;
+
+    use super::*;
+
+    struct Flags(HashSet<&'static str>);
+
+    impl juniper::Context for Flags {}
+
+    impl FeatureFlags for Flags {
+        fn is_enabled(&self, flag: &str) -> bool {
+            self.0.contains(flag)
+        }
+    }
+
+    struct Release;
+
+    #[graphql_object(context = Flags)]
+    impl Release {
+        fn name() -> &'static str {
+            "v1"
         }
 
-        async fn home_planet(&self, planet_name: String) -> String {
-            planet_name
+        #[graphql(feature_flag = "new_dates")]
+        fn released_at() -> Option<&'static str> {
+            Some("2021-07-01")
         }
 
-        async fn r#async_info(r#my_num: i32) -> i32 {
-            r#my_num
+        #[graphql(feature_flag = "new_dates")]
+        async fn released_at_async() -> Option<&'static str> {
+            Some("2021-07-01")
         }
     }
 
     struct QueryRoot;
 
-    #[graphql_object]
+    #[graphql_object(context = Flags)]
     impl QueryRoot {
-        fn human() -> Human {
-            Human
+        fn release() -> Release {
+            Release
         }
     }
 
-    #[tokio::test]
-    async fn resolves_fields() {
-        const DOC: &str = r#"{
-            human {
-                id
-                home_planet(planet_name: "earth")
-                async_info(my_num: 3)
-            }
-        }"#;
+    const DOC: &str = r#"{
+        release {
+            name
+            releasedAt
+            releasedAtAsync
+        }
+    }"#;
 
+    #[tokio::test]
+    async fn resolves_when_enabled() {
         let schema = schema(QueryRoot);
+        let ctx = Flags(vec!["new_dates"].into_iter().collect());
 
         assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            execute(DOC, None, &schema, &Variables::new(), &ctx).await,
             Ok((
-                graphql_value!({"human": {
-                    "id": "human-32",
-                    "home_planet": "earth",
-                    "async_info": 3,
+                graphql_value!({"release": {
+                    "name": "v1",
+                    "releasedAt": "2021-07-01",
+                    "releasedAtAsync": "2021-07-01",
                 }}),
                 vec![],
             )),
@@ -1546,194 +2977,319 @@ mod renamed_all_fields_and_args {
     }
 
     #[tokio::test]
-    async fn uses_correct_fields_and_args_names() {
-        const DOC: &str = r#"{
-            __type(name: "Human") {
-                fields {
-                    name
-                    args {
-                        name
-                    }
-                }
-            }
-        }"#;
-
+    async fn errors_when_disabled() {
         let schema = schema(QueryRoot);
+        let ctx = Flags(HashSet::new());
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &ctx)
+            .await
+            .unwrap();
 
         assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &()).await,
-            Ok((
-                graphql_value!({"__type": {"fields": [
-                    {"name": "id", "args": []},
-                    {"name": "home_planet", "args": [{"name": "planet_name"}]},
-                    {"name": "async_info", "args": [{"name": "my_num"}]},
-                ]}}),
-                vec![],
-            )),
+            res,
+            graphql_value!({"release": {
+                "name": "v1",
+                "releasedAt": None,
+                "releasedAtAsync": None,
+            }}),
         );
+        assert_eq!(errs.len(), 2);
+        for err in &errs {
+            assert_eq!(err.error().message(), "Feature `new_dates` is not enabled");
+            assert_eq!(
+                err.error().extensions(),
+                &graphql_value!({"code": "FEATURE_DISABLED"}),
+            );
+        }
     }
 }
 
-mod explicit_scalar {
+mod guarded_field {
     use super::*;
 
+    struct Permissions(Vec<&'static str>);
+
+    impl juniper::Context for Permissions {}
+
+    fn can_read_birth_date(ctx: &Permissions) -> FieldResult<()> {
+        if ctx.0.contains(&"read:birth_date") {
+            Ok(())
+        } else {
+            Err(FieldError::new(
+                "Missing permission `read:birth_date`",
+                graphql_value!({"code": "FORBIDDEN"}),
+            ))
+        }
+    }
+
     struct Human;
 
-    #[graphql_object(scalar = DefaultScalarValue)]
+    #[graphql_object(context = Permissions)]
     impl Human {
-        fn id(&self) -> &str {
-            "human-32"
+        fn name() -> &'static str {
+            "Alice"
         }
 
-        async fn home_planet() -> &'static str {
-            "earth"
+        #[graphql(guard = can_read_birth_date)]
+        fn birth_date() -> Option<&'static str> {
+            Some("1990-01-02")
+        }
+
+        #[graphql(guard = can_read_birth_date)]
+        async fn birth_date_async() -> Option<&'static str> {
+            Some("1990-01-02")
         }
     }
 
     struct QueryRoot;
 
-    #[graphql_object(scalar = DefaultScalarValue)]
+    #[graphql_object(context = Permissions)]
     impl QueryRoot {
         fn human() -> Human {
             Human
         }
     }
 
-    #[tokio::test]
-    async fn resolves_fields() {
-        const DOC: &str = r#"{
-            human {
-                id
-                homePlanet
-            }
-        }"#;
+    const DOC: &str = r#"{
+        human {
+            name
+            birthDate
+            birthDateAsync
+        }
+    }"#;
 
+    #[tokio::test]
+    async fn resolves_when_allowed() {
         let schema = schema(QueryRoot);
+        let ctx = Permissions(vec!["read:birth_date"]);
 
         assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            execute(DOC, None, &schema, &Variables::new(), &ctx).await,
             Ok((
-                graphql_value!({"human": {"id": "human-32", "homePlanet": "earth"}}),
+                graphql_value!({"human": {
+                    "name": "Alice",
+                    "birthDate": "1990-01-02",
+                    "birthDateAsync": "1990-01-02",
+                }}),
                 vec![],
             )),
         );
     }
+
+    #[tokio::test]
+    async fn errors_when_denied() {
+        let schema = schema(QueryRoot);
+        let ctx = Permissions(vec![]);
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res,
+            graphql_value!({"human": {
+                "name": "Alice",
+                "birthDate": None,
+                "birthDateAsync": None,
+            }}),
+        );
+        assert_eq!(errs.len(), 2);
+        assert_eq!(errs[0].path(), &["human", "birthDate"]);
+        assert_eq!(errs[1].path(), &["human", "birthDateAsync"]);
+        for err in &errs {
+            assert_eq!(
+                err.error().message(),
+                "Missing permission `read:birth_date`",
+            );
+            assert_eq!(
+                err.error().extensions(),
+                &graphql_value!({"code": "FORBIDDEN"}),
+            );
+        }
+    }
 }
 
-mod custom_scalar {
-    use crate::custom_scalar::MyScalarValue;
+mod context_variant {
+    use juniper::ContextVariant;
 
     use super::*;
 
-    struct Human;
+    struct AuthenticatedCtx {
+        last_login: &'static str,
+    }
 
-    #[graphql_object(scalar = MyScalarValue)]
-    impl Human {
-        fn id() -> &'static str {
-            "human-32"
-        }
+    enum Ctx {
+        Anonymous,
+        Authenticated(AuthenticatedCtx),
+    }
 
-        async fn home_planet(&self) -> &str {
-            "earth"
+    impl juniper::Context for Ctx {}
+
+    impl ContextVariant<AuthenticatedCtx> for Ctx {
+        const VARIANT: &'static str = "Authenticated";
+
+        fn as_variant(&self) -> Option<&AuthenticatedCtx> {
+            match self {
+                Self::Authenticated(ctx) => Some(ctx),
+                Self::Anonymous => None,
+            }
         }
     }
 
     struct QueryRoot;
 
-    #[graphql_object(scalar = MyScalarValue)]
+    #[graphql_object(context = Ctx, scalar = DefaultScalarValue)]
     impl QueryRoot {
-        fn human() -> Human {
-            Human
+        fn now() -> &'static str {
+            "2021-07-01"
+        }
+
+        fn last_login(executor: &Executor<'_, '_, Ctx>) -> FieldResult<&'static str> {
+            executor
+                .context_as::<AuthenticatedCtx>()
+                .map(|ctx| ctx.last_login)
         }
     }
 
-    #[tokio::test]
-    async fn resolves_fields() {
-        const DOC: &str = r#"{
-            human {
-                id
-                homePlanet
-            }
-        }"#;
+    const DOC: &str = "{ now lastLogin }";
 
-        let schema = schema_with_scalar::<MyScalarValue, _, _>(QueryRoot);
+    #[tokio::test]
+    async fn resolves_with_required_variant() {
+        let schema = schema(QueryRoot);
+        let ctx = Ctx::Authenticated(AuthenticatedCtx {
+            last_login: "2021-06-30",
+        });
 
         assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            execute(DOC, None, &schema, &Variables::new(), &ctx).await,
             Ok((
-                graphql_value!({"human": {"id": "human-32", "homePlanet": "earth"}}),
+                graphql_value!({"now": "2021-07-01", "lastLogin": "2021-06-30"}),
                 vec![],
             )),
         );
     }
+
+    #[tokio::test]
+    async fn errors_with_wrong_variant() {
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &Variables::new(), &Ctx::Anonymous)
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!(None));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].error().message(),
+            "Field requires `Authenticated` context",
+        );
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"code": "WRONG_CONTEXT"}),
+        );
+    }
 }
 
-mod explicit_generic_scalar {
-    use std::marker::PhantomData;
+mod max_concurrency_list_field {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
 
     use super::*;
 
-    struct Human<S>(PhantomData<S>);
+    #[derive(Default)]
+    struct Counter {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+    }
 
-    #[graphql_object(scalar = S)]
-    impl<S: ScalarValue> Human<S> {
-        fn id() -> &'static str {
-            "human-32"
-        }
+    impl juniper::Context for Counter {}
 
-        async fn another(&self, _executor: &Executor<'_, '_, (), S>) -> Human<S> {
-            Human(PhantomData)
+    struct Item(i32);
+
+    #[graphql_object(context = Counter)]
+    impl Item {
+        async fn timestamp(&self, #[graphql(context)] counter: &Counter) -> i32 {
+            let now = counter.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            counter.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            counter.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.0
         }
     }
 
     struct QueryRoot;
 
-    #[graphql_object]
+    #[graphql_object(context = Counter)]
     impl QueryRoot {
-        fn human<__S>() -> Human<__S> {
-            Human(PhantomData)
+        #[graphql(max_concurrency = 2)]
+        fn limited() -> Vec<Item> {
+            (1..=5).map(Item).collect()
+        }
+
+        fn unlimited() -> Vec<Item> {
+            (1..=5).map(Item).collect()
         }
     }
 
     #[tokio::test]
-    async fn resolves_fields() {
+    async fn limits_concurrency() {
         const DOC: &str = r#"{
-            human {
-                id
-                another {
-                    id
-                }
-            }
+            limited { timestamp }
         }"#;
 
         let schema = schema(QueryRoot);
+        let ctx = Counter::default();
 
         assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            execute(DOC, None, &schema, &Variables::new(), &ctx).await,
             Ok((
-                graphql_value!({"human": {
-                    "id": "human-32",
-                    "another": {"id": "human-32"},
-                }}),
+                graphql_value!({"limited": [
+                    {"timestamp": 1},
+                    {"timestamp": 2},
+                    {"timestamp": 3},
+                    {"timestamp": 4},
+                    {"timestamp": 5},
+                ]}),
                 vec![],
             )),
         );
+        assert_eq!(ctx.peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn is_unlimited_by_default() {
+        const DOC: &str = r#"{
+            unlimited { timestamp }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Counter::default();
+
+        let (_, errs) = execute(DOC, None, &schema, &Variables::new(), &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(errs, vec![]);
+        assert_eq!(ctx.peak.load(Ordering::SeqCst), 5);
     }
 }
 
-mod bounded_generic_scalar {
+mod extensions {
     use super::*;
 
-    struct Human;
+    struct Report;
 
-    #[graphql_object(scalar = S: ScalarValue + Clone)]
-    impl Human {
-        fn id() -> &'static str {
-            "human-32"
+    #[graphql_object(extensions(owner = "billing"))]
+    impl Report {
+        #[graphql(extensions(sla = "24h", owner = "finance"))]
+        fn generated_at() -> &'static str {
+            "2021-07-01"
         }
 
-        async fn another<S>(&self, _executor: &Executor<'_, '_, (), S>) -> Human {
-            Human
+        fn title() -> &'static str {
+            "Monthly"
         }
     }
 
@@ -1741,19 +3297,17 @@ mod bounded_generic_scalar {
 
     #[graphql_object]
     impl QueryRoot {
-        fn human() -> Human {
-            Human
+        fn report() -> Report {
+            Report
         }
     }
 
     #[tokio::test]
-    async fn resolves_fields() {
+    async fn exposes_type_extensions() {
         const DOC: &str = r#"{
-            human {
-                id
-                another {
-                    id
-                }
+            __type(name: "Report") {
+                owner: extension(name: "owner")
+                sla: extension(name: "sla")
             }
         }"#;
 
@@ -1762,74 +3316,48 @@ mod bounded_generic_scalar {
         assert_eq!(
             execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"human": {
-                    "id": "human-32",
-                    "another": {"id": "human-32"},
-                }}),
+                graphql_value!({"__type": {"owner": "billing", "sla": None}}),
                 vec![],
             )),
         );
     }
-}
-
-mod explicit_custom_context {
-    use super::*;
-
-    struct CustomContext(String);
-
-    impl juniper::Context for CustomContext {}
-
-    struct Human;
-
-    #[graphql_object(context = CustomContext)]
-    impl Human {
-        async fn id<'c>(&self, context: &'c CustomContext) -> &'c str {
-            context.0.as_str()
-        }
-
-        async fn info(_ctx: &()) -> &'static str {
-            "human being"
-        }
-
-        fn more(#[graphql(context)] custom: &CustomContext) -> &str {
-            custom.0.as_str()
-        }
-    }
-
-    struct QueryRoot;
-
-    #[graphql_object(context = CustomContext)]
-    impl QueryRoot {
-        fn human() -> Human {
-            Human
-        }
-    }
 
     #[tokio::test]
-    async fn resolves_fields() {
+    async fn exposes_field_extensions() {
         const DOC: &str = r#"{
-            human {
-                id
-                info
-                more
+            __type(name: "Report") {
+                fields {
+                    name
+                    sla: extension(name: "sla")
+                    owner: extension(name: "owner")
+                }
             }
         }"#;
 
         let schema = schema(QueryRoot);
-        let ctx = CustomContext("ctx!".into());
 
         assert_eq!(
-            execute(DOC, None, &schema, &Variables::new(), &ctx).await,
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
             Ok((
-                graphql_value!({"human": {
-                    "id": "ctx!",
-                    "info": "human being",
-                    "more": "ctx!",
-                }}),
+                graphql_value!({"__type": {"fields": [
+                    {"name": "generatedAt", "sla": "24h", "owner": "finance"},
+                    {"name": "title", "sla": None, "owner": None},
+                ]}}),
                 vec![],
             )),
         );
     }
+
+    #[test]
+    fn stores_extensions_in_meta() {
+        let schema = schema(QueryRoot);
+        let meta = schema.schema.concrete_type_by_name("Report").unwrap();
+
+        assert_eq!(
+            meta.extensions().unwrap().get("owner").map(String::as_str),
+            Some("billing"),
+        );
+    }
 }
 
 mod inferred_custom_context_from_field {
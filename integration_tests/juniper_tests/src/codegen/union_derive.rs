@@ -1016,6 +1016,117 @@ mod ignored_enum_variants {
     }
 }
 
+mod named_fields_enum {
+    use super::*;
+
+    #[derive(GraphQLUnion)]
+    enum Character {
+        A(Human),
+        /// Droid being repaired.
+        Repaired {
+            /// ID of the droid.
+            id: String,
+            #[graphql(name = "eta")]
+            hours_left: i32,
+        },
+    }
+
+    enum QueryRoot {
+        Human,
+        Repaired,
+    }
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn character(&self) -> Character {
+            match self {
+                Self::Human => Character::A(Human {
+                    id: "human-32".to_string(),
+                    home_planet: "earth".to_string(),
+                }),
+                Self::Repaired => Character::Repaired {
+                    id: "droid-99".to_string(),
+                    hours_left: 3,
+                },
+            }
+        }
+    }
+
+    const DOC: &str = r#"{
+        character {
+            __typename
+            ... on Human {
+                humanId: id
+            }
+            ... on CharacterRepaired {
+                droidId: id
+                eta
+            }
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn resolves_human() {
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"character": {"__typename": "Human", "humanId": "human-32"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_named_fields_variant() {
+        let schema = schema(QueryRoot::Repaired);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"character": {
+                    "__typename": "CharacterRepaired",
+                    "droidId": "droid-99",
+                    "eta": 3,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn generates_object_from_doc_comments() {
+        const DOC: &str = r#"{
+            __type(name: "CharacterRepaired") {
+                kind
+                description
+                fields {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot::Human);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "kind": "OBJECT",
+                    "description": "Droid being repaired.",
+                    "fields": [
+                        {"name": "id", "description": "ID of the droid."},
+                        {"name": "eta", "description": None},
+                    ],
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod external_resolver_enum {
     use super::*;
 
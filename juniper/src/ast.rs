@@ -150,6 +150,18 @@ pub type Document<'a, S> = [Definition<'a, S>];
 #[doc(hidden)]
 pub type OwnedDocument<'a, S> = Vec<Definition<'a, S>>;
 
+/// Document parsed in the faithful mode, retaining the comments and the
+/// original source, so the whitespace between the spans of its definitions
+/// and comments may be recovered as well.
+#[doc(hidden)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct FaithfulDocument<'a, S> {
+    pub source: &'a str,
+    pub definitions: OwnedDocument<'a, S>,
+    /// Comments in the order of their appearance, without their leading `#`
+    pub comments: Vec<Spanning<&'a str>>,
+}
+
 /// Parse an unstructured input value into a Rust data type.
 ///
 /// The conversion _can_ fail, and must in that case return None. Implemented
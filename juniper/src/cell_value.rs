@@ -0,0 +1,395 @@
+//! Polymorphic scalar values in a tagged representation.
+//!
+//! GraphQL has no unions of scalars, so a value being one of several scalar types is represented
+//! as an object tagging it with its type:
+//!
+//! ```json
+//! {"type": "date", "value": "2021-07-08T09:10:11+00:00"}
+//! {"type": "number", "value": 1.5}
+//! {"type": "string", "value": "foo"}
+//! ```
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ast::{FromInputValue, InputValue, Selection, ToInputValue},
+    executor::{ExecutionResult, Executor, Registry},
+    parser::{ParseError, ScalarToken, Token},
+    schema::meta::MetaType,
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{GraphQLType, GraphQLValue},
+        marker::{IsInputType, IsOutputType},
+    },
+    value::{Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+/// Value of a cell (e.g. of a spreadsheet), being one of several scalar types
+///
+/// Serialized and accepted as an object having a `type` tag (one of `date`,
+/// `number` or `string`) and a `value` of the tagged type. The `date` value is
+/// represented the same way as the `DateTimeUtc` scalar is.
+///
+/// Being a scalar, it can only be provided in its tagged form, not
+/// as a plain scalar literal. The literals inside the tagged form are parsed
+/// by their inferred types, so `number` literals require the schema to
+/// contain the `Int` and `Float` types.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellValue {
+    /// Date and time in UTC
+    Date(DateTime<Utc>),
+
+    /// Number, either an integer or a floating point one
+    Number(f64),
+
+    /// Arbitrary string
+    String(String),
+}
+
+impl CellValue {
+    /// Name of the tag of this value's type
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Date(_) => "date",
+            Self::Number(_) => "number",
+            Self::String(_) => "string",
+        }
+    }
+}
+
+impl<S> GraphQLType<S> for CellValue
+where
+    S: ScalarValue,
+{
+    fn name(_: &()) -> Option<&'static str> {
+        Some("CellValue")
+    }
+
+    fn meta<'r>(_: &(), registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        registry
+            .build_scalar_type::<Self>(&())
+            .description("Value of a cell, tagged with its type")
+            .accepting_objects()
+            .into_meta()
+    }
+}
+
+impl<S> GraphQLValue<S> for CellValue
+where
+    S: ScalarValue,
+{
+    type Context = ();
+    type TypeInfo = ();
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn resolve(
+        &self,
+        _: &(),
+        _: Option<&[Selection<S>]>,
+        _: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        let value = match self {
+            Self::Date(d) => Value::scalar(d.to_rfc3339()),
+            Self::Number(n) => Value::scalar(*n),
+            Self::String(s) => Value::scalar(s.clone()),
+        };
+        Ok(Value::object(
+            vec![
+                ("type", Value::scalar(self.tag().to_owned())),
+                ("value", value),
+            ]
+            .into_iter()
+            .collect::<Object<S>>(),
+        ))
+    }
+}
+
+impl<S> GraphQLValueAsync<S> for CellValue
+where
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> crate::BoxFuture<'a, ExecutionResult<S>> {
+        use futures::future;
+        Box::pin(future::ready(self.resolve(info, selection_set, executor)))
+    }
+}
+
+impl<S> ToInputValue<S> for CellValue
+where
+    S: ScalarValue,
+{
+    fn to_input_value(&self) -> InputValue<S> {
+        let value = match self {
+            Self::Date(d) => InputValue::scalar(d.to_rfc3339()),
+            Self::Number(n) => InputValue::scalar(*n),
+            Self::String(s) => InputValue::scalar(s.clone()),
+        };
+        InputValue::object(
+            vec![
+                ("type", InputValue::scalar(self.tag().to_owned())),
+                ("value", value),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+impl<S> FromInputValue<S> for CellValue
+where
+    S: ScalarValue,
+{
+    fn from_input_value(v: &InputValue<S>) -> Option<CellValue> {
+        let obj = v.to_object_value()?;
+        if obj.len() != 2 {
+            return None;
+        }
+        let value = obj.get("value")?;
+        match obj.get("type")?.as_string_value()? {
+            "date" => value
+                .as_string_value()
+                .and_then(|s| s.parse().ok())
+                .map(Self::Date),
+            "number" => value.as_float_value().map(Self::Number),
+            "string" => value.as_string_value().map(|s| Self::String(s.into())),
+            _ => None,
+        }
+    }
+}
+
+impl<S> ParseScalarValue<S> for CellValue
+where
+    S: ScalarValue,
+{
+    fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+        Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+    }
+}
+
+impl<S> IsInputType<S> for CellValue where S: ScalarValue {}
+
+impl<S> IsOutputType<S> for CellValue where S: ScalarValue {}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone as _, Utc};
+
+    use crate::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+
+    use super::CellValue;
+
+    fn tagged(ty: &str, value: InputValue<DefaultScalarValue>) -> InputValue<DefaultScalarValue> {
+        InputValue::object(
+            vec![("type", InputValue::scalar(ty)), ("value", value)]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    fn round_trip_test(raw: InputValue<DefaultScalarValue>, expected: CellValue) {
+        let parsed: Option<CellValue> = FromInputValue::from_input_value(&raw);
+
+        assert_eq!(parsed.as_ref(), Some(&expected));
+        assert_eq!(expected.to_input_value(), raw);
+    }
+
+    #[test]
+    fn date_round_trip() {
+        round_trip_test(
+            tagged("date", InputValue::scalar("2021-07-08T09:10:11+00:00")),
+            CellValue::Date(Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap()),
+        );
+    }
+
+    #[test]
+    fn number_round_trip() {
+        round_trip_test(
+            tagged("number", InputValue::scalar(1.5)),
+            CellValue::Number(1.5),
+        );
+
+        let parsed: Option<CellValue> =
+            FromInputValue::from_input_value(&tagged("number", InputValue::scalar(2)));
+        assert_eq!(parsed, Some(CellValue::Number(2.0)));
+    }
+
+    #[test]
+    fn string_round_trip() {
+        round_trip_test(
+            tagged("string", InputValue::scalar("foo")),
+            CellValue::String("foo".into()),
+        );
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        for raw in &[
+            tagged("date", InputValue::scalar("yesterday")),
+            tagged("number", InputValue::scalar("1.5")),
+            tagged("string", InputValue::scalar(1)),
+            tagged("bool", InputValue::scalar(true)),
+            InputValue::scalar("foo"),
+            InputValue::object(
+                vec![
+                    ("type", InputValue::scalar("string")),
+                    ("value", InputValue::scalar("foo")),
+                    ("extra", InputValue::scalar("bar")),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        ] {
+            let parsed: Option<CellValue> = FromInputValue::from_input_value(raw);
+            assert_eq!(parsed, None, "for input: {}", raw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        InputValue,
+    };
+
+    use super::CellValue;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(cells: Vec<CellValue>) -> Vec<CellValue> {
+            cells
+        }
+
+        fn text(value: String) -> String {
+            value
+        }
+
+        fn count(cells: Vec<CellValue>) -> i32 {
+            cells.len() as i32
+        }
+
+        fn sum(cells: Vec<CellValue>) -> f64 {
+            cells
+                .iter()
+                .filter_map(|c| match c {
+                    CellValue::Number(n) => Some(n),
+                    _ => None,
+                })
+                .sum()
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips_tagged() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let doc = r#"{ echo(cells: [
+            {type: "date", value: "2021-07-08T09:10:11Z"},
+            {type: "number", value: 3},
+            {type: "string", value: "foo"},
+        ]) }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"echo": [
+                    {"type": "date", "value": "2021-07-08T09:10:11+00:00"},
+                    {"type": "number", "value": 3.0},
+                    {"type": "string", "value": "foo"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn round_trips_variables() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let vars = vec![(
+            "cells".to_owned(),
+            InputValue::list(vec![InputValue::object(
+                vec![
+                    ("type", InputValue::scalar("number")),
+                    ("value", InputValue::scalar(1.5)),
+                ]
+                .into_iter()
+                .collect(),
+            )]),
+        )]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            crate::execute(
+                "query($cells: [CellValue!]!) { echo(cells: $cells) }",
+                None,
+                &schema,
+                &vars,
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"echo": [{"type": "number", "value": 1.5}]}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_literals() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        for doc in &[
+            r#"{ echo(cells: [{type: "number", value: "3"}]) }"#,
+            r#"{ echo(cells: ["foo"]) }"#,
+        ] {
+            assert!(matches!(
+                crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+                Err(crate::GraphQLError::ValidationError(_)),
+            ));
+        }
+    }
+    #[tokio::test]
+    async fn rejects_objects_for_other_scalars() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let vars = vec![(
+            "value".to_owned(),
+            InputValue::object(
+                vec![("type", InputValue::scalar("string"))]
+                    .into_iter()
+                    .collect(),
+            ),
+        )]
+        .into_iter()
+        .collect();
+
+        for (doc, vars) in &[
+            (r#"{ text(value: {type: "string"}) }"#, Variables::new()),
+            ("query($value: String!) { text(value: $value) }", vars),
+        ] {
+            assert!(matches!(
+                crate::execute(doc, None, &schema, vars, &()).await,
+                Err(crate::GraphQLError::ParseError(_))
+                    | Err(crate::GraphQLError::ValidationError(_)),
+            ));
+        }
+    }
+}
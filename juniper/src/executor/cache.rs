@@ -0,0 +1,242 @@
+//! Caching the resolved values of fields.
+
+use std::{collections::HashMap, fmt::Write as _};
+
+use crate::{
+    ast::{Directive, Fragment, InputValue, Selection},
+    parser::Spanning,
+    value::{DefaultScalarValue, ScalarValue, Value},
+};
+
+use super::Variables;
+
+/// Key of the cached value of a field
+///
+/// Two resolutions of a field share a cached value only if all the parts of
+/// their keys are equal.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CacheKey {
+    /// Name of the type the field belongs to
+    pub type_name: String,
+
+    /// Name of the field
+    pub field_name: String,
+
+    /// Coerced arguments of the field (including the default values of the
+    /// omitted ones), rendered as `name: value` pairs ordered by name
+    pub arguments: String,
+
+    /// Fields selected on the value of the field, rendered with all the
+    /// variables and fragments resolved
+    pub selection: String,
+
+    /// Scope of the value, as returned by the `cache_key` function of the
+    /// field, e.g. the identifier of the current user
+    pub scope: String,
+}
+
+/// Context capable of caching the resolved values of fields
+///
+/// A field marked with `#[graphql(cache_key = path::to::fn)]` requires its
+/// context to implement this trait. The function is called with the context
+/// and returns the [`CacheKey::scope`] of the value, so values resolved for
+/// different users (or tenants, locales, etc.) are never mixed up.
+///
+/// As the cached [`Value`]s are of a concrete [`ScalarValue`], the types
+/// declaring cached fields should specify the `scalar` they're resolved with.
+/// Only the values resolved without any errors are stored.
+pub trait Cache<S = DefaultScalarValue> {
+    /// Get the value previously stored under the given `key`, if any.
+    fn get(&self, key: &CacheKey) -> Option<Value<S>>;
+
+    /// Store the resolved `value` under the given `key`.
+    fn set(&self, key: CacheKey, value: Value<S>);
+}
+
+impl<S, T: Cache<S> + ?Sized> Cache<S> for &T {
+    fn get(&self, key: &CacheKey) -> Option<Value<S>> {
+        (**self).get(key)
+    }
+
+    fn set(&self, key: CacheKey, value: Value<S>) {
+        (**self).set(key, value)
+    }
+}
+
+/// Renders the given `selection_set` into the [`CacheKey::selection`] form,
+/// which omits the source positions and resolves variables and fragments.
+pub(crate) fn render_selection<S: ScalarValue>(
+    selection_set: &[Selection<'_, S>],
+    variables: &Variables<S>,
+    fragments: &HashMap<&str, Fragment<'_, S>>,
+    out: &mut String,
+) {
+    out.push('{');
+    for selection in selection_set {
+        match selection {
+            Selection::Field(field) => {
+                let field = &field.item;
+                if let Some(alias) = &field.alias {
+                    write!(out, "{}:", alias.item).unwrap();
+                }
+                out.push_str(field.name.item);
+                if let Some(args) = &field.arguments {
+                    render_arguments(&args.item.items, variables, out);
+                }
+                render_directives(&field.directives, variables, out);
+                if let Some(selection_set) = &field.selection_set {
+                    render_selection(selection_set, variables, fragments, out);
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                let spread = &spread.item;
+                render_directives(&spread.directives, variables, out);
+                if let Some(fragment) = fragments.get(spread.name.item) {
+                    write!(out, "...on {}", fragment.type_condition.item).unwrap();
+                    render_directives(&fragment.directives, variables, out);
+                    render_selection(&fragment.selection_set, variables, fragments, out);
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                let fragment = &fragment.item;
+                out.push_str("...");
+                if let Some(type_condition) = &fragment.type_condition {
+                    write!(out, "on {}", type_condition.item).unwrap();
+                }
+                render_directives(&fragment.directives, variables, out);
+                render_selection(&fragment.selection_set, variables, fragments, out);
+            }
+        }
+        out.push(' ');
+    }
+    out.push('}');
+}
+
+fn render_directives<S: ScalarValue>(
+    directives: &Option<Vec<Spanning<Directive<'_, S>>>>,
+    variables: &Variables<S>,
+    out: &mut String,
+) {
+    for directive in directives.iter().flatten() {
+        write!(out, "@{}", directive.item.name.item).unwrap();
+        if let Some(args) = &directive.item.arguments {
+            render_arguments(&args.item.items, variables, out);
+        }
+    }
+}
+
+fn render_arguments<S: ScalarValue>(
+    arguments: &[(Spanning<&str>, Spanning<InputValue<S>>)],
+    variables: &Variables<S>,
+    out: &mut String,
+) {
+    out.push('(');
+    for (name, value) in arguments {
+        let value = value.item.clone().into_const(variables);
+        write!(out, "{}: {}, ", name.item, value).unwrap();
+    }
+    out.push(')');
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+    #[tokio::test]
+    async fn resolves_cached_field_once_per_scope() {
+        use std::{
+            collections::HashMap,
+            sync::{
+                atomic::{AtomicUsize, Ordering},
+                Mutex,
+            },
+        };
+
+        use crate::{Cache, CacheKey, Value};
+
+        struct Context {
+            user: &'static str,
+            calls: AtomicUsize,
+            cache: Mutex<HashMap<CacheKey, Value>>,
+        }
+
+        impl Context {
+            fn new(user: &'static str) -> Self {
+                Self {
+                    user,
+                    calls: AtomicUsize::new(0),
+                    cache: Mutex::new(HashMap::new()),
+                }
+            }
+        }
+
+        impl crate::Context for Context {}
+
+        impl Cache for Context {
+            fn get(&self, key: &CacheKey) -> Option<Value> {
+                self.cache.lock().unwrap().get(key).cloned()
+            }
+
+            fn set(&self, key: CacheKey, value: Value) {
+                self.cache.lock().unwrap().insert(key, value);
+            }
+        }
+
+        fn user_scope(ctx: &Context) -> String {
+            ctx.user.into()
+        }
+
+        struct Root;
+
+        #[graphql_object(context = Context, scalar = crate::DefaultScalarValue)]
+        impl Root {
+            #[graphql(cache_key = user_scope)]
+            fn days_between(ctx: &Context, from: NaiveDate, to: NaiveDate) -> i32 {
+                ctx.calls.fetch_add(1, Ordering::SeqCst);
+                (to - from).num_days() as i32
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+        let doc = r#"{
+            first: daysBetween(from: "2021-07-01", to: "2021-07-08")
+            second: daysBetween(from: "2021-07-01", to: "2021-07-08")
+            other: daysBetween(from: "2021-07-01", to: "2021-07-09")
+        }"#;
+
+        let ctx = Context::new("alice");
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &ctx).await,
+            Ok((
+                graphql_value!({"first": 7, "second": 7, "other": 8}),
+                vec![],
+            )),
+        );
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 2);
+
+        let doc = r#"query($to: NaiveDate!) { daysBetween(from: "2021-07-01", to: $to) }"#;
+        let mut vars = Variables::new();
+        vars.insert("to".into(), crate::InputValue::scalar("2021-07-08"));
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema, &vars, &ctx),
+            Ok((graphql_value!({"daysBetween": 7}), vec![])),
+        );
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 2);
+
+        let bob = Context {
+            cache: Mutex::new(ctx.cache.lock().unwrap().clone()),
+            ..Context::new("bob")
+        };
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema, &vars, &bob),
+            Ok((graphql_value!({"daysBetween": 7}), vec![])),
+        );
+        assert_eq!(bob.calls.load(Ordering::SeqCst), 1);
+    }
+}
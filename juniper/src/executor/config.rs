@@ -0,0 +1,127 @@
+//! Resolving fields depending on environment-like dynamic configuration.
+
+use std::{borrow::Cow, str::FromStr};
+
+/// Context providing environment-like configuration, which may change at
+/// runtime (e.g. read from environment variables, or reloaded from a
+/// configuration service)
+///
+/// Resolvers read its typed values via [`Config::value`] to alter their
+/// behavior (e.g. whether to serialize fractional seconds of dates) without
+/// rebuilding the schema.
+pub trait Config {
+    /// Look up the raw value of the configuration `key`, if it's set.
+    fn get(&self, key: &str) -> Option<Cow<'_, str>>;
+
+    /// Look up the value of the configuration `key` parsed as `T`, if it's
+    /// set.
+    ///
+    /// The error of parsing an invalid value is returned as is, so the caller
+    /// decides how to report it (e.g. as a [`FieldError`] of the resolved
+    /// field).
+    ///
+    /// [`FieldError`]: crate::FieldError
+    fn value<T: FromStr>(&self, key: &str) -> Result<Option<T>, T::Err> {
+        self.get(key).map(|raw| raw.parse()).transpose()
+    }
+}
+
+impl<T: Config + ?Sized> Config for &T {
+    fn get(&self, key: &str) -> Option<Cow<'_, str>> {
+        (**self).get(key)
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use std::{borrow::Cow, collections::HashMap};
+
+    use chrono::{SecondsFormat, TimeZone as _, Utc};
+
+    use crate::{
+        executor::ExecutionError, graphql_object, parser::SourcePosition, EmptyMutation,
+        EmptySubscription, FieldError, FieldResult, RootNode, Value, Variables,
+    };
+
+    use super::Config;
+
+    struct Context(HashMap<&'static str, &'static str>);
+
+    impl crate::Context for Context {}
+
+    impl Config for Context {
+        fn get(&self, key: &str) -> Option<Cow<'_, str>> {
+            self.0.get(key).map(|v| Cow::Borrowed(*v))
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn started_at(context: &Context) -> FieldResult<String> {
+            let at = Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap()
+                + chrono::Duration::milliseconds(250);
+            let precision = match context
+                .value("dates.fractional_seconds")
+                .map_err(|e| format!("Invalid `dates.fractional_seconds` config: {}", e))?
+            {
+                Some(true) => SecondsFormat::Millis,
+                Some(false) | None => SecondsFormat::Secs,
+            };
+            Ok(at.to_rfc3339_opts(precision, true))
+        }
+    }
+
+    fn schema<'q>() -> RootNode<'q, Query, EmptyMutation<Context>, EmptySubscription<Context>> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn toggles_date_precision() {
+        let schema = schema();
+
+        for (config, expected) in [
+            (vec![], "2021-07-08T09:10:11Z"),
+            (
+                vec![("dates.fractional_seconds", "false")],
+                "2021-07-08T09:10:11Z",
+            ),
+            (
+                vec![("dates.fractional_seconds", "true")],
+                "2021-07-08T09:10:11.250Z",
+            ),
+        ] {
+            let ctx = Context(config.into_iter().collect());
+            assert_eq!(
+                crate::execute("{ startedAt }", None, &schema, &Variables::new(), &ctx).await,
+                Ok((graphql_value!({ "startedAt": expected }), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_invalid_value_as_caller_does() {
+        let schema = schema();
+        let ctx = Context(
+            vec![("dates.fractional_seconds", "yes")]
+                .into_iter()
+                .collect(),
+        );
+
+        assert_eq!(
+            crate::execute("{ startedAt }", None, &schema, &Variables::new(), &ctx).await,
+            Ok((
+                Value::null(),
+                vec![ExecutionError::new(
+                    SourcePosition::new(2, 0, 2),
+                    &["startedAt"],
+                    FieldError::from(
+                        "Invalid `dates.fractional_seconds` config: \
+                         provided string was not `true` or `false`",
+                    ),
+                )],
+            )),
+        );
+    }
+}
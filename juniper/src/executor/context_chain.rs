@@ -0,0 +1,162 @@
+//! Obtaining values from several layered context sources.
+
+use std::fmt;
+
+/// Source of a value, which may be absent in it
+///
+/// Implemented for closures returning an [`Option`], so they can be used as
+/// providers of a [`ContextChain`] directly.
+pub trait ContextProvider<T> {
+    /// Provide the value, if present in this source.
+    fn provide(&self) -> Option<T>;
+}
+
+impl<T, F> ContextProvider<T> for F
+where
+    F: Fn() -> Option<T>,
+{
+    fn provide(&self) -> Option<T> {
+        self()
+    }
+}
+
+/// Chain of [`ContextProvider`]s tried in order until one of them provides a
+/// value
+///
+/// This allows resolving fields from layered configuration, with more
+/// specific sources overriding more general ones:
+///
+/// ```rust
+/// # use juniper::ContextChain;
+/// struct Context {
+///     request_timezone: Option<String>,
+///     user_timezone: Option<String>,
+/// }
+///
+/// impl Context {
+///     fn timezone(&self) -> String {
+///         ContextChain::new()
+///             .with(|| self.request_timezone.clone())
+///             .with(|| self.user_timezone.clone())
+///             .resolve()
+///             .unwrap_or_else(|| "UTC".into())
+///     }
+/// }
+///
+/// let ctx = Context {
+///     request_timezone: None,
+///     user_timezone: Some("Europe/Kyiv".into()),
+/// };
+/// assert_eq!(ctx.timezone(), "Europe/Kyiv");
+/// ```
+///
+/// Providers are only invoked until the value is found, so the later ones may
+/// be expensive.
+pub struct ContextChain<'a, T> {
+    providers: Vec<Box<dyn ContextProvider<T> + 'a>>,
+}
+
+impl<'a, T> ContextChain<'a, T> {
+    /// Construct a new chain without any providers
+    pub fn new() -> Self {
+        Self {
+            providers: Vec::new(),
+        }
+    }
+
+    /// Append the given `provider` to the end of this chain, so it's tried
+    /// after all the providers added before it
+    pub fn with<P>(mut self, provider: P) -> Self
+    where
+        P: ContextProvider<T> + 'a,
+    {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// Obtain the value from the first provider of this chain having it
+    ///
+    /// Returns [`None`] if none of the providers has the value.
+    pub fn resolve(&self) -> Option<T> {
+        self.providers.iter().find_map(|p| p.provide())
+    }
+}
+
+impl<'a, T> Default for ContextChain<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> fmt::Debug for ContextChain<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ContextChain")
+            .field("providers", &self.providers.len())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::{DateTime, FixedOffset, Utc};
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+    #[tokio::test]
+    async fn resolves_first_provided_value() {
+        use crate::ContextChain;
+
+        struct Context {
+            request_offset: Option<FixedOffset>,
+            user_offset: Option<FixedOffset>,
+        }
+
+        impl crate::Context for Context {}
+
+        struct Root;
+
+        #[graphql_object(context = Context)]
+        impl Root {
+            fn local(ctx: &Context, at: DateTime<Utc>) -> String {
+                let offset = ContextChain::new()
+                    .with(|| ctx.request_offset)
+                    .with(|| ctx.user_offset)
+                    .with(|| FixedOffset::east_opt(0))
+                    .resolve()
+                    .unwrap();
+                at.with_timezone(&offset).to_rfc3339()
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+        let doc = r#"{ local(at: "2021-07-08T09:10:11Z") }"#;
+
+        let ctx = Context {
+            request_offset: None,
+            user_offset: FixedOffset::east_opt(2 * 3600),
+        };
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &ctx).await,
+            Ok((
+                graphql_value!({"local": "2021-07-08T11:10:11+02:00"}),
+                vec![],
+            )),
+        );
+
+        let ctx = Context {
+            request_offset: None,
+            user_offset: None,
+        };
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &ctx).await,
+            Ok((
+                graphql_value!({"local": "2021-07-08T09:10:11+00:00"}),
+                vec![],
+            )),
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Resolving fields conditionally based on feature flags.
+
+/// Context capable of telling which feature flags are enabled
+///
+/// A field marked with `#[graphql(feature_flag = "...")]` requires its context
+/// to implement this trait, and is only resolved if the flag is enabled.
+pub trait FeatureFlags {
+    /// Check whether the feature `flag` is enabled.
+    fn is_enabled(&self, flag: &str) -> bool;
+}
+
+impl<T: FeatureFlags + ?Sized> FeatureFlags for &T {
+    fn is_enabled(&self, flag: &str) -> bool {
+        (**self).is_enabled(flag)
+    }
+}
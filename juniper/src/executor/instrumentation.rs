@@ -0,0 +1,78 @@
+//! Measuring the time fields are resolved in.
+
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+
+/// Identifier of the next [`FieldTiming`] to be recorded.
+static NEXT_TIMING_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Timing of resolving a single field
+///
+/// The timings form a tree: the timing of a field is the parent of the
+/// timings of the fields selected on its value (including the values of its
+/// list elements).
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldTiming {
+    /// Identifier of this timing, unique within the process
+    pub id: u64,
+
+    /// Identifier of the timing of the field this one is selected on, or
+    /// `None` for the fields of the root type
+    pub parent: Option<u64>,
+
+    /// Name of the type the resolved field belongs to
+    pub type_name: String,
+
+    /// Name of the resolved field
+    pub field_name: String,
+
+    /// Path of the resolved field in the response, without the indices of
+    /// the list elements it's nested in
+    pub path: Vec<String>,
+
+    /// Time the field started being resolved at
+    pub start: SystemTime,
+
+    /// Time it took to resolve the field, including the fields selected on its
+    /// value
+    pub duration: Duration,
+}
+
+/// Receiver of the [`FieldTiming`]s of all the resolved fields
+///
+/// Registered via [`RootNode::with_instrumentation`], so the timings may be
+/// exported to a tracing backend.
+///
+/// [`RootNode::with_instrumentation`]: crate::RootNode::with_instrumentation
+pub trait Instrumentation: Send + Sync {
+    /// Record the timing of a resolved field.
+    fn record(&self, timing: FieldTiming);
+}
+
+impl fmt::Debug for dyn Instrumentation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Instrumentation")
+    }
+}
+
+/// Identifiers of the [`FieldTiming`] of the field being resolved by an
+/// executor.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TimingSpan {
+    pub(crate) id: u64,
+    pub(crate) parent: Option<u64>,
+}
+
+impl TimingSpan {
+    /// Allocates a new span of a field selected on the field of the given
+    /// `parent` span.
+    pub(crate) fn child_of(parent: Option<TimingSpan>) -> Self {
+        Self {
+            id: NEXT_TIMING_ID.fetch_add(1, Ordering::Relaxed),
+            parent: parent.map(|p| p.id),
+        }
+    }
+}
@@ -0,0 +1,428 @@
+//! Batching the loads of values requested by sibling fields.
+
+use std::{
+    any::{self, Any, TypeId},
+    collections::{HashMap, HashSet},
+    fmt,
+    future::Future,
+    hash::Hash,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use async_trait::async_trait;
+use indexmap::IndexSet;
+
+use crate::BoxFuture;
+
+/// Source of the values loaded in batches by a [`BatchLoader`]
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use juniper::{BoxFuture, Loader};
+/// struct UserAccounts;
+///
+/// impl Loader for UserAccounts {
+///     type Key = i32;
+///     type Value = String;
+///
+///     fn load_batch(&self, keys: Vec<i32>) -> BoxFuture<'_, HashMap<i32, String>> {
+///         // A single `SELECT ... WHERE user_id IN (...)` query goes here.
+///         Box::pin(async move {
+///             keys.into_iter()
+///                 .map(|id| (id, format!("account-{}", id)))
+///                 .collect()
+///         })
+///     }
+/// }
+/// ```
+pub trait Loader: Send + Sync + 'static {
+    /// Key a value is loaded by
+    type Key: Clone + Eq + Hash + Send + Sync + 'static;
+
+    /// Loaded value
+    type Value: Clone + Send + Sync + 'static;
+
+    /// Load the values of all the given `keys` at once.
+    ///
+    /// The keys missing in the returned map are considered to have no value.
+    fn load_batch(&self, keys: Vec<Self::Key>) -> BoxFuture<'_, HashMap<Self::Key, Self::Value>>;
+}
+
+/// Asynchronous repository of values looked up by their keys
+///
+/// It's object safe, so the context may hold it as a trait object, keeping
+/// the resolvers independent from the actual data access layer. An
+/// `Arc<dyn Repository<K, V>>` is a [`Loader`], so the lookups of the fields
+/// resolved concurrently are batched into a single
+/// [`Repository::load_many`] call once it's added to the [`Loaders`]:
+///
+/// ```rust
+/// # use std::{collections::HashMap, sync::Arc};
+/// # use chrono::{DateTime, Utc};
+/// # use juniper::{async_trait, graphql_object, LoaderContext, Loaders, Repository};
+/// struct ExchangeRates;
+///
+/// #[async_trait]
+/// impl Repository<DateTime<Utc>, f64> for ExchangeRates {
+///     async fn load_many(&self, keys: &[DateTime<Utc>]) -> HashMap<DateTime<Utc>, f64> {
+///         // A single `SELECT at, rate FROM rates WHERE at = ANY($1)` query goes here.
+///         keys.iter().map(|at| (*at, 1.1)).collect()
+///     }
+/// }
+///
+/// type Rates = Arc<dyn Repository<DateTime<Utc>, f64>>;
+///
+/// struct Context {
+///     loaders: Loaders,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// impl LoaderContext for Context {
+///     fn loaders(&self) -> &Loaders {
+///         &self.loaders
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     async fn rate(at: DateTime<Utc>, ctx: &Context) -> Option<f64> {
+///         ctx.loader::<Rates>().load(at).await
+///     }
+/// }
+///
+/// let rates: Rates = Arc::new(ExchangeRates);
+/// let ctx = Context {
+///     loaders: Loaders::new().with(rates),
+/// };
+/// ```
+#[async_trait]
+pub trait Repository<K, V>: Send + Sync {
+    /// Load the values of all the given `keys` at once.
+    ///
+    /// The keys missing in the returned map are considered to have no value.
+    async fn load_many(&self, keys: &[K]) -> HashMap<K, V>;
+}
+
+impl<K, V> Loader for Arc<dyn Repository<K, V>>
+where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    type Key = K;
+    type Value = V;
+
+    fn load_batch(&self, keys: Vec<K>) -> BoxFuture<'_, HashMap<K, V>> {
+        Box::pin(async move { self.load_many(&keys).await })
+    }
+}
+
+type BatchFn<K, V> = dyn Fn(Vec<K>) -> BoxFuture<'static, HashMap<K, V>> + Send + Sync;
+
+/// Loader coalescing the loads of single values into batches
+///
+/// All the keys requested via [`BatchLoader::load`] by the fields resolved
+/// concurrently (like the same field of all the elements of a list, or
+/// several sibling fields) are collected and deduplicated before the batch
+/// function is invoked once for all of them. The loaded values are cached, so
+/// each key is loaded at most once during the lifetime of the loader, which
+/// should thus be created for every request (usually as a part of its
+/// context, see [`LoaderContext`]).
+///
+/// Only the loads awaited concurrently are batched, so the elements of a list
+/// field marked with `#[graphql(max_concurrency = ...)]` are loaded in
+/// batches of at most that size.
+pub struct BatchLoader<K, V> {
+    batch_fn: Box<BatchFn<K, V>>,
+    state: Mutex<State<K, V>>,
+}
+
+struct State<K, V> {
+    /// Values loaded so far, with [`None`] for the keys having no value
+    cache: HashMap<K, Option<V>>,
+
+    /// Keys requested, but not dispatched yet, in the order of requesting
+    pending: IndexSet<K>,
+
+    /// Keys of the batches being loaded at the moment
+    in_flight: HashSet<K>,
+
+    /// Loads waiting for the batches being loaded at the moment
+    waiting: Vec<Waker>,
+}
+
+impl<K, V> BatchLoader<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    /// Construct a new loader invoking the given `batch_fn` for every batch of
+    /// keys
+    ///
+    /// The keys missing in the map returned by the `batch_fn` are considered
+    /// to have no value.
+    pub fn new<F>(batch_fn: F) -> Self
+    where
+        F: Fn(Vec<K>) -> BoxFuture<'static, HashMap<K, V>> + Send + Sync + 'static,
+    {
+        Self {
+            batch_fn: Box::new(batch_fn),
+            state: Mutex::new(State {
+                cache: HashMap::new(),
+                pending: IndexSet::new(),
+                in_flight: HashSet::new(),
+                waiting: Vec::new(),
+            }),
+        }
+    }
+
+    /// Construct a new loader of the values from the given [`Loader`]
+    pub fn from_loader<L>(loader: L) -> Self
+    where
+        L: Loader<Key = K, Value = V>,
+        K: Send + 'static,
+    {
+        let loader = Arc::new(loader);
+        Self::new(move |keys| {
+            let loader = loader.clone();
+            Box::pin(async move { loader.load_batch(keys).await })
+        })
+    }
+
+    /// Load the value of the given `key`, batching it with the keys requested
+    /// by all the other loads awaited concurrently
+    ///
+    /// Returns [`None`] if there is no value for the `key`.
+    pub async fn load(&self, key: K) -> Option<V> {
+        {
+            let mut state = self.state.lock().unwrap();
+            if let Some(value) = state.cache.get(&key) {
+                return value.clone();
+            }
+            if !state.in_flight.contains(&key) {
+                state.pending.insert(key.clone());
+            }
+        }
+
+        // Give the concurrently resolved fields a chance to request their keys
+        // before the batch is dispatched.
+        YieldNow(false).await;
+
+        loop {
+            let keys = {
+                let mut state = self.state.lock().unwrap();
+                if let Some(value) = state.cache.get(&key) {
+                    return value.clone();
+                }
+                if state.in_flight.contains(&key) {
+                    None
+                } else {
+                    // The batch of the `key` may have been dropped before
+                    // being loaded, so the `key` should be requested again.
+                    state.pending.insert(key.clone());
+                    let keys = mem::take(&mut state.pending);
+                    state.in_flight.extend(keys.iter().cloned());
+                    Some(keys.into_iter().collect())
+                }
+            };
+
+            match keys {
+                Some(keys) => self.dispatch(keys).await,
+                None => {
+                    WaitForBatch {
+                        loader: self,
+                        key: &key,
+                    }
+                    .await
+                }
+            }
+        }
+    }
+
+    async fn dispatch(&self, keys: Vec<K>) {
+        let mut guard = InFlight {
+            loader: self,
+            keys: Some(keys.clone()),
+        };
+
+        let mut values = (self.batch_fn)(keys.clone()).await;
+
+        let mut state = self.state.lock().unwrap();
+        for key in keys {
+            let value = values.remove(&key);
+            state.in_flight.remove(&key);
+            state.cache.insert(key, value);
+        }
+        guard.keys = None;
+        state.waiting.drain(..).for_each(Waker::wake);
+    }
+}
+
+impl<K, V> fmt::Debug for BatchLoader<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BatchLoader").finish()
+    }
+}
+
+/// Guard releasing the keys of a batch, whose loading was dropped before
+/// completion, so they can be dispatched again
+struct InFlight<'l, K: Eq + Hash, V> {
+    loader: &'l BatchLoader<K, V>,
+    keys: Option<Vec<K>>,
+}
+
+impl<'l, K: Eq + Hash, V> Drop for InFlight<'l, K, V> {
+    fn drop(&mut self) {
+        if let Some(keys) = self.keys.take() {
+            let mut state = self.loader.state.lock().unwrap();
+            for key in &keys {
+                state.in_flight.remove(key);
+            }
+            state.waiting.drain(..).for_each(Waker::wake);
+        }
+    }
+}
+
+/// Future resolving once the batch of the `key` is not being loaded anymore
+struct WaitForBatch<'l, 'k, K, V> {
+    loader: &'l BatchLoader<K, V>,
+    key: &'k K,
+}
+
+impl<'l, 'k, K: Eq + Hash, V> Future for WaitForBatch<'l, 'k, K, V> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.loader.state.lock().unwrap();
+        if state.in_flight.contains(self.key) {
+            state.waiting.push(cx.waker().clone());
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
+
+/// Future yielding to the executor once before resolving
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Set of the [`BatchLoader`]s of a single request, each one of its own
+/// [`Loader`] type
+#[derive(Default)]
+pub struct Loaders {
+    loaders: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Loaders {
+    /// Construct a new set without any loaders
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a [`BatchLoader`] of the values from the given `loader`
+    ///
+    /// This replaces the loader of the same type if any was previously added.
+    pub fn with<L: Loader>(mut self, loader: L) -> Self {
+        self.loaders.insert(
+            TypeId::of::<L>(),
+            Box::new(BatchLoader::from_loader(loader)),
+        );
+        self
+    }
+
+    /// Get the [`BatchLoader`] of the values from the `L` loader, if it was
+    /// added
+    pub fn get<L: Loader>(&self) -> Option<&BatchLoader<L::Key, L::Value>> {
+        self.loaders
+            .get(&TypeId::of::<L>())
+            .and_then(|l| l.downcast_ref())
+    }
+}
+
+impl fmt::Debug for Loaders {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Loaders")
+            .field("count", &self.loaders.len())
+            .finish()
+    }
+}
+
+/// Context providing the [`BatchLoader`]s of the current request
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use juniper::{graphql_object, BoxFuture, Loader, LoaderContext, Loaders};
+/// # struct UserAccounts;
+/// # impl Loader for UserAccounts {
+/// #     type Key = i32;
+/// #     type Value = String;
+/// #     fn load_batch(&self, keys: Vec<i32>) -> BoxFuture<'_, HashMap<i32, String>> {
+/// #         Box::pin(async move { keys.into_iter().map(|id| (id, id.to_string())).collect() })
+/// #     }
+/// # }
+/// struct Context {
+///     loaders: Loaders,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// impl LoaderContext for Context {
+///     fn loaders(&self) -> &Loaders {
+///         &self.loaders
+///     }
+/// }
+///
+/// struct User {
+///     id: i32,
+/// }
+///
+/// #[graphql_object(context = Context)]
+/// impl User {
+///     async fn account(&self, ctx: &Context) -> Option<String> {
+///         ctx.loader::<UserAccounts>().load(self.id).await
+///     }
+/// }
+/// ```
+pub trait LoaderContext {
+    /// The loaders of the current request
+    fn loaders(&self) -> &Loaders;
+
+    /// Get the [`BatchLoader`] of the values from the `L` loader.
+    ///
+    /// # Panics
+    ///
+    /// If the `L` loader wasn't added to the [`LoaderContext::loaders`].
+    fn loader<L: Loader>(&self) -> &BatchLoader<L::Key, L::Value> {
+        self.loaders().get::<L>().unwrap_or_else(|| {
+            panic!(
+                "Loader `{}` is not added to the context",
+                any::type_name::<L>(),
+            )
+        })
+    }
+}
+
+impl<T: LoaderContext + ?Sized> LoaderContext for &T {
+    fn loaders(&self) -> &Loaders {
+        (**self).loaders()
+    }
+}
@@ -0,0 +1,68 @@
+//! Memoizing values computed once per request.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// Values memoized under a single name, keyed by their type
+type Values = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+/// Values memoized during a single request, keyed by their name and type
+///
+/// This backs [`Executor::once`](super::Executor::once), being borrowed by all
+/// the executors of a request. Nothing is allocated until a value is memoized.
+#[derive(Default)]
+pub(crate) struct Memo {
+    values: RwLock<HashMap<String, Values>>,
+}
+
+impl Clone for Memo {
+    fn clone(&self) -> Self {
+        Self {
+            values: RwLock::new(self.values.read().unwrap().clone()),
+        }
+    }
+}
+
+impl Memo {
+    /// Return the value of type `T` memoized under the given `key`, computing
+    /// and memoizing it with `init` if there is none yet
+    ///
+    /// `init` is run without holding any lock, so it may memoize other values
+    /// itself. If another value is memoized under the same `key` meanwhile,
+    /// that one is kept and returned instead.
+    pub(crate) fn get_or_insert_with<T, F>(&self, key: &str, init: F) -> T
+    where
+        T: Clone + Send + Sync + 'static,
+        F: FnOnce() -> T,
+    {
+        let type_id = TypeId::of::<T>();
+        if let Some(value) = self
+            .values
+            .read()
+            .unwrap()
+            .get(key)
+            .and_then(|values| values.get(&type_id))
+        {
+            return Self::downcast(&**value);
+        }
+
+        let value = init();
+        let mut values = self.values.write().unwrap();
+        let value = values
+            .entry(key.to_owned())
+            .or_default()
+            .entry(type_id)
+            .or_insert_with(|| Arc::new(value));
+        Self::downcast(&**value)
+    }
+
+    fn downcast<T: Clone + 'static>(value: &(dyn Any + Send + Sync)) -> T {
+        value
+            .downcast_ref::<T>()
+            .expect("memoized value is keyed by its type")
+            .clone()
+    }
+}
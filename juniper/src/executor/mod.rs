@@ -3,18 +3,23 @@
 use std::{
     borrow::Cow,
     cmp::Ordering,
-    collections::HashMap,
-    fmt::{Debug, Display},
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{self, Debug, Display},
+    future::Future,
     sync::{Arc, RwLock},
+    time::{Duration, Instant, SystemTime},
 };
 
-use fnv::FnvHashMap;
-use futures::Stream;
+use fnv::{FnvHashMap, FnvHashSet};
+use futures::{
+    future::{self, Either},
+    Stream,
+};
 
 use crate::{
     ast::{
-        Definition, Document, Fragment, FromInputValue, InputValue, Operation, OperationType,
-        Selection, ToInputValue, Type,
+        Arguments, Definition, Directive, Document, Fragment, FromInputValue, InputValue,
+        Operation, OperationType, Selection, ToInputValue, Type,
     },
     parser::{SourcePosition, Spanning},
     schema::{
@@ -23,7 +28,8 @@ use crate::{
             InterfaceMeta, ListMeta, MetaType, NullableMeta, ObjectMeta, PlaceholderMeta,
             ScalarMeta, UnionMeta,
         },
-        model::{RootNode, SchemaType, TypeType},
+        model::{RootNode, SchemaError, SchemaType, TypeType},
+        schema::IntrospectionRoot,
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
@@ -31,20 +37,42 @@ use crate::{
         name::Name,
         subscriptions::{GraphQLSubscriptionType, GraphQLSubscriptionValue},
     },
-    value::{DefaultScalarValue, ParseScalarValue, ScalarValue, Value},
+    value::{DefaultScalarValue, Object, ParseScalarValue, ScalarValue, Value},
     GraphQLError,
 };
 
 pub use self::{
+    cache::{Cache, CacheKey},
+    config::Config,
+    context_chain::{ContextChain, ContextProvider},
+    feature_flags::FeatureFlags,
+    instrumentation::{FieldTiming, Instrumentation},
+    loader::{BatchLoader, Loader, LoaderContext, Loaders, Repository},
     look_ahead::{
         Applies, ChildSelection, ConcreteLookAheadSelection, LookAheadArgument, LookAheadMethods,
         LookAheadSelection, LookAheadValue,
     },
     owned_executor::OwnedExecutor,
+    rate_limit::{RateLimit, RateLimiter},
+    timeout::Timer,
 };
 
+pub(crate) use self::{instrumentation::TimingSpan, memo::Memo, timeout::Deadline};
+
+mod cache;
+mod config;
+mod context_chain;
+mod feature_flags;
+mod instrumentation;
+mod loader;
 mod look_ahead;
+mod memo;
 mod owned_executor;
+mod rate_limit;
+mod timeout;
+
+/// Names reserved for the built-in scalars
+const BUILTIN_SCALAR_NAMES: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
 
 /// A type registry used to build schemas
 ///
@@ -54,6 +82,13 @@ mod owned_executor;
 pub struct Registry<'r, S = DefaultScalarValue> {
     /// Currently registered types
     pub types: FnvHashMap<Name, MetaType<'r, S>>,
+
+    /// Rust types named as a built-in scalar, which were already checked not
+    /// to shadow it
+    checked_builtin_names: FnvHashSet<&'static str>,
+
+    /// Errors of the registered types, failing the schema they form
+    pub(crate) errors: Vec<SchemaError>,
 }
 
 #[allow(missing_docs)]
@@ -61,6 +96,7 @@ pub struct Registry<'r, S = DefaultScalarValue> {
 pub enum FieldPath<'a> {
     Root(SourcePosition),
     Field(&'a str, SourcePosition, Arc<FieldPath<'a>>),
+    Index(usize, Arc<FieldPath<'a>>),
 }
 
 /// Query execution engine
@@ -81,6 +117,11 @@ where
     context: &'a CtxT,
     errors: &'r RwLock<Vec<ExecutionError<S>>>,
     field_path: Arc<FieldPath<'a>>,
+    list_index: Option<usize>,
+    max_concurrency: Option<usize>,
+    deadline: Option<&'r Deadline>,
+    timing_span: Option<TimingSpan>,
+    memo: &'r Memo,
 }
 
 /// Error type for errors that occur during query execution
@@ -90,10 +131,48 @@ where
 #[derive(Debug, PartialEq)]
 pub struct ExecutionError<S> {
     location: SourcePosition,
-    path: Vec<String>,
+    path: Vec<PathSegment>,
     error: FieldError<S>,
 }
 
+/// Segment of the path in the response leading to the field that failed to
+/// resolve
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathSegment {
+    /// Field, named by the alias it's selected under.
+    Field(String),
+
+    /// Element of a list, at the given index.
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Field(name) => f.write_str(name),
+            Self::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PathSegment {
+    fn from(name: &'a str) -> Self {
+        Self::Field(name.to_owned())
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl<'a> PartialEq<&'a str> for PathSegment {
+    fn eq(&self, other: &&'a str) -> bool {
+        matches!(self, Self::Field(name) if name == other)
+    }
+}
+
 impl<S> Eq for ExecutionError<S> where Self: PartialEq {}
 
 impl<S> ExecutionError<S> {
@@ -153,6 +232,38 @@ where
 pub struct FieldError<S = DefaultScalarValue> {
     message: String,
     extensions: Value<S>,
+    severity: Option<ErrorSeverity>,
+}
+
+/// Severity level of a [`FieldError`]
+///
+/// Allows clients to distinguish errors which may be safely ignored (like a
+/// lenient coercion falling back to a default) from hard failures. It's
+/// surfaced in the `"severity"` field of the error's `"extensions"`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorSeverity {
+    /// The field was resolved, but the result may be incomplete or degraded.
+    Warning,
+
+    /// The field failed to resolve.
+    Error,
+}
+
+impl ErrorSeverity {
+    /// Returns the name of this severity level used in the `"extensions"` of
+    /// an error (e.g. `"WARNING"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Warning => "WARNING",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+impl Display for ErrorSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl<T: Display, S> From<T> for FieldError<S>
@@ -163,6 +274,7 @@ where
         FieldError {
             message: format!("{}", e),
             extensions: Value::null(),
+            severity: None,
         }
     }
 }
@@ -207,15 +319,94 @@ impl<S> FieldError<S> {
         FieldError {
             message: format!("{}", e),
             extensions,
+            severity: None,
         }
     }
 
+    /// Tags this error with the given [`ErrorSeverity`] level
+    ///
+    /// The severity is added as the `"severity"` field of the error's
+    /// `"extensions"` object in the response:
+    ///
+    /// ```rust
+    /// use juniper::{graphql_value, ErrorSeverity, FieldError};
+    /// # use juniper::DefaultScalarValue;
+    ///
+    /// # fn main() {
+    /// let err: FieldError<DefaultScalarValue> =
+    ///     FieldError::new("Invalid date, using today", graphql_value!({ "code": "LENIENT" }))
+    ///         .with_severity(ErrorSeverity::Warning);
+    ///
+    /// assert_eq!(
+    ///     err.extensions(),
+    ///     &graphql_value!({ "code": "LENIENT", "severity": "WARNING" }),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// If the `extensions` are neither `null` nor an object, the severity is
+    /// only available via [`FieldError::severity()`].
+    pub fn with_severity(mut self, severity: ErrorSeverity) -> Self
+    where
+        S: ScalarValue,
+    {
+        self = self.with_extension("severity", Value::scalar(severity.as_str().to_owned()));
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Adds the given `value` as the `name` field of the error's
+    /// `"extensions"` object, replacing the previous value of the field, if
+    /// any
+    ///
+    /// This allows to attach machine-readable details to an error piece by
+    /// piece:
+    ///
+    /// ```rust
+    /// use juniper::{graphql_value, FieldError, Value};
+    /// # use juniper::DefaultScalarValue;
+    ///
+    /// # fn main() {
+    /// let err: FieldError<DefaultScalarValue> = FieldError::new("User not found", Value::null())
+    ///     .with_extension("code", graphql_value!("NOT_FOUND"))
+    ///     .with_extension("retryable", graphql_value!(false));
+    ///
+    /// assert_eq!(
+    ///     err.extensions(),
+    ///     &graphql_value!({ "code": "NOT_FOUND", "retryable": false }),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// If the `extensions` are neither `null` nor an object, they're left
+    /// untouched.
+    pub fn with_extension<K>(mut self, name: K, value: Value<S>) -> Self
+    where
+        K: AsRef<str> + Into<String>,
+        S: ScalarValue,
+    {
+        if self.extensions.is_null() {
+            self.extensions = Value::object(Object::with_capacity(1));
+        }
+        if let Some(obj) = self.extensions.as_mut_object_value() {
+            obj.add_field(name, value);
+        }
+        self
+    }
+
+    /// Returns the [`ErrorSeverity`] level of this error, if it has been
+    /// tagged with any.
+    pub fn severity(&self) -> Option<ErrorSeverity> {
+        self.severity
+    }
+
     #[doc(hidden)]
     pub fn message(&self) -> &str {
         &self.message
     }
 
-    #[doc(hidden)]
+    /// Returns the `"extensions"` of this error, being `null` if there are
+    /// none.
     pub fn extensions(&self) -> &Value<S> {
         &self.extensions
     }
@@ -229,6 +420,7 @@ impl<S> FieldError<S> {
         FieldError {
             message: self.message,
             extensions: self.extensions.map_scalar_value(),
+            severity: self.severity,
         }
     }
 }
@@ -307,6 +499,22 @@ where
     }
 }
 
+impl<'a, S, T, C, E: IntoFieldError<S>> IntoResolvable<'a, S, Option<T>, C> for Option<Result<T, E>>
+where
+    S: ScalarValue,
+    T: GraphQLValue<S>,
+    T::Context: FromContext<C>,
+{
+    type Type = Option<T>;
+
+    #[allow(clippy::type_complexity)]
+    fn into(self, ctx: &'a C) -> FieldResult<Option<(&'a T::Context, Option<T>)>, S> {
+        self.transpose()
+            .map(|v| Some((<T::Context as FromContext<C>>::from(ctx), v)))
+            .map_err(IntoFieldError::into_field_error)
+    }
+}
+
 impl<'a, S, T, C> IntoResolvable<'a, S, T, C> for (&'a T::Context, T)
 where
     S: ScalarValue,
@@ -377,6 +585,20 @@ pub trait FromContext<T> {
     fn from(value: &T) -> &Self;
 }
 
+/// Access to a specific variant `V` of a context enum
+///
+/// Implement this trait for contexts serving several kinds of requests (e.g.
+/// an enum of authenticated and anonymous contexts), so resolvers may require
+/// a specific variant via [`Executor::context_as`].
+pub trait ContextVariant<V> {
+    /// Name of the variant, used in the error returned when the context is
+    /// of another variant
+    const VARIANT: &'static str;
+
+    /// Returns the variant `V` if this context is of it
+    fn as_variant(&self) -> Option<&V>;
+}
+
 /// Marker trait for types that can act as context objects for `GraphQL` types.
 pub trait Context {}
 
@@ -459,7 +681,8 @@ where
     where
         T: GraphQLValue<S, Context = CtxT> + ?Sized,
     {
-        value.resolve(info, self.current_selection_set, self)
+        let res = value.resolve(info, self.current_selection_set, self);
+        self.check_output(value.type_name(info), res)
     }
 
     /// Resolve a single arbitrary value into an `ExecutionResult`
@@ -470,9 +693,35 @@ where
         CtxT: Sync,
         S: Send + Sync,
     {
-        value
+        let res = value
             .resolve_async(info, self.current_selection_set, self)
-            .await
+            .await;
+        self.check_output(value.type_name(info), res)
+    }
+
+    /// Runs the [`OutputCheck`] registered for the scalar type named
+    /// `type_name` against the resolved value, if any, reporting its error
+    ///
+    /// The check is taken from the current type, so values wrapping the scalar
+    /// (e.g. lists of it) are not checked, only the scalar values themselves.
+    ///
+    /// [`OutputCheck`]: crate::schema::model::OutputCheck
+    fn check_output(&self, type_name: Option<&str>, res: ExecutionResult<S>) -> ExecutionResult<S> {
+        if let (Ok(value), Some(type_name)) = (&res, type_name) {
+            if let MetaType::Scalar(ScalarMeta {
+                name,
+                output_check: Some(check),
+                ..
+            }) = self.current_type.innermost_concrete()
+            {
+                if name == type_name {
+                    if let Some(e) = check.check(value) {
+                        self.push_error(e);
+                    }
+                }
+            }
+        }
+        res
     }
 
     /// Resolve a single arbitrary value, mapping the context to a new type
@@ -538,9 +787,32 @@ where
             context: ctx,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            list_index: self.list_index,
+            max_concurrency: self.max_concurrency,
+            deadline: self.deadline,
+            timing_span: self.timing_span,
+            memo: self.memo,
+        }
+    }
+
+    /// Derive a new executor resolving at most `max` elements of a list
+    /// concurrently
+    ///
+    /// The limit is only honored when a list is resolved asynchronously, and
+    /// doesn't apply to the fields of the list elements.
+    pub fn with_max_concurrency(self, max: usize) -> Self {
+        Self {
+            max_concurrency: Some(max.max(1)),
+            ..self
         }
     }
 
+    /// Maximum number of list elements resolved concurrently by this executor,
+    /// if limited
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.max_concurrency
+    }
+
     #[doc(hidden)]
     pub fn field_sub_executor<'s>(
         &'s self,
@@ -549,27 +821,41 @@ where
         location: SourcePosition,
         selection_set: Option<&'s [Selection<'a, S>]>,
     ) -> Executor<'s, 'a, CtxT, S> {
+        let current_type = self.schema.make_type(
+            &self
+                .current_type
+                .innermost_concrete()
+                .field_by_name(field_name)
+                .expect("Field not found on inner type")
+                .field_type,
+        );
+        let field_path = Arc::new(FieldPath::Field(field_alias, location, self.current_path()));
         Executor {
-            fragments: self.fragments,
-            variables: self.variables,
-            current_selection_set: selection_set,
-            parent_selection_set: self.current_selection_set,
-            current_type: self.schema.make_type(
-                &self
-                    .current_type
-                    .innermost_concrete()
-                    .field_by_name(field_name)
-                    .expect("Field not found on inner type")
-                    .field_type,
-            ),
-            schema: self.schema,
-            context: self.context,
-            errors: self.errors,
-            field_path: Arc::new(FieldPath::Field(
-                field_alias,
-                location,
-                Arc::clone(&self.field_path),
-            )),
+            max_concurrency: None,
+            timing_span: self
+                .schema
+                .instrumentation
+                .as_ref()
+                .map(|_| TimingSpan::child_of(self.timing_span)),
+            ..self.sub_executor(
+                selection_set,
+                self.current_selection_set,
+                current_type,
+                field_path,
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn index_sub_executor(&self, index: usize) -> Executor<'_, 'a, CtxT, S> {
+        Executor {
+            list_index: Some(index),
+            ..self.sub_executor(
+                self.current_selection_set,
+                self.parent_selection_set,
+                self.current_type.clone(),
+                self.current_path(),
+            )
         }
     }
 
@@ -578,23 +864,67 @@ where
         &'s self,
         type_name: Option<&'s str>,
         selection_set: Option<&'s [Selection<'a, S>]>,
+    ) -> Executor<'s, 'a, CtxT, S> {
+        let current_type = match type_name {
+            Some(type_name) => self.schema.type_by_name(type_name).expect("Type not found"),
+            None => self.current_type.clone(),
+        };
+        Executor {
+            list_index: self.list_index,
+            ..self.sub_executor(
+                selection_set,
+                self.current_selection_set,
+                current_type,
+                Arc::clone(&self.field_path),
+            )
+        }
+    }
+
+    /// Derives a new executor resolving the given selection set of the
+    /// `current_type` at the `field_path`, sharing everything else with this
+    /// one.
+    fn sub_executor<'s>(
+        &'s self,
+        current_selection_set: Option<&'s [Selection<'a, S>]>,
+        parent_selection_set: Option<&'s [Selection<'a, S>]>,
+        current_type: TypeType<'a, S>,
+        field_path: Arc<FieldPath<'a>>,
     ) -> Executor<'s, 'a, CtxT, S> {
         Executor {
             fragments: self.fragments,
             variables: self.variables,
-            current_selection_set: selection_set,
-            parent_selection_set: self.current_selection_set,
-            current_type: match type_name {
-                Some(type_name) => self.schema.type_by_name(type_name).expect("Type not found"),
-                None => self.current_type.clone(),
-            },
+            current_selection_set,
+            parent_selection_set,
+            current_type,
             schema: self.schema,
             context: self.context,
             errors: self.errors,
-            field_path: self.field_path.clone(),
+            field_path,
+            list_index: None,
+            max_concurrency: self.max_concurrency,
+            deadline: self.deadline,
+            timing_span: self.timing_span,
+            memo: self.memo,
+        }
+    }
+
+    /// Path to the value currently being resolved, including the index of the
+    /// list element, if any.
+    ///
+    /// The index is only allocated into the path once it's needed, so
+    /// resolving list elements of leaf types allocates nothing.
+    fn current_path(&self) -> Arc<FieldPath<'a>> {
+        match self.list_index {
+            Some(index) => Arc::new(FieldPath::Index(index, Arc::clone(&self.field_path))),
+            None => Arc::clone(&self.field_path),
         }
     }
 
+    fn construct_path(&self, acc: &mut Vec<PathSegment>) {
+        self.field_path.construct_path(acc);
+        acc.extend(self.list_index.map(PathSegment::from));
+    }
+
     /// `Executor`'s current selection set
     pub(crate) fn current_selection_set(&self) -> Option<&[Selection<'a, S>]> {
         self.current_selection_set
@@ -608,6 +938,22 @@ where
         self.context
     }
 
+    /// Access the variant `V` of the current context
+    ///
+    /// Returns an error if the current context is of another variant. See
+    /// [`ContextVariant`] for details.
+    pub fn context_as<V>(&self) -> Result<&'r V, FieldError<S>>
+    where
+        CtxT: ContextVariant<V>,
+    {
+        self.context.as_variant().ok_or_else(|| {
+            FieldError::new(
+                format!("Field requires `{}` context", CtxT::VARIANT),
+                graphql_value!({ "code": "WRONG_CONTEXT" }),
+            )
+        })
+    }
+
     /// The currently executing schema
     pub fn schema(&self) -> &'a SchemaType<S> {
         self.schema
@@ -633,6 +979,28 @@ where
         self.field_path.location()
     }
 
+    /// The alias the currently resolved field is selected under in the query
+    ///
+    /// Defaults to the name of the field if it's selected without an alias,
+    /// and is `None` outside of any field (e.g. for the root type).
+    pub fn current_alias(&self) -> Option<&'a str> {
+        self.field_path.alias()
+    }
+
+    /// Compute the value of `key` once per request
+    ///
+    /// The first call with a `key` runs `init` and memoizes its result, which
+    /// is then returned (cloned) by all the following calls with the same `key`
+    /// and value type while resolving the same request, e.g. to resolve many
+    /// date fields against a single "now".
+    pub fn once<T, F>(&self, key: &str, init: F) -> T
+    where
+        T: Clone + Send + Sync + 'static,
+        F: FnOnce() -> T,
+    {
+        self.memo.get_or_insert_with(key, init)
+    }
+
     /// Add an error to the execution engine at the current executor location
     pub fn push_error(&self, error: FieldError<S>) {
         self.push_error_at(error, *self.location());
@@ -641,7 +1009,7 @@ where
     /// Add an error to the execution engine at a specific location
     pub fn push_error_at(&self, error: FieldError<S>, location: SourcePosition) {
         let mut path = Vec::new();
-        self.field_path.construct_path(&mut path);
+        self.construct_path(&mut path);
 
         let mut errors = self.errors.write().unwrap();
 
@@ -655,7 +1023,7 @@ where
     /// Returns new [`ExecutionError`] at current location
     pub fn new_error(&self, error: FieldError<S>) -> ExecutionError<S> {
         let mut path = Vec::new();
-        self.field_path.construct_path(&mut path);
+        self.construct_path(&mut path);
 
         ExecutionError {
             location: *self.location(),
@@ -664,14 +1032,277 @@ where
         }
     }
 
+    /// Check the [`FeatureFlags`] of the current context before resolving a
+    /// field only available behind the feature `flag`
+    ///
+    /// Returns an error if the `flag` is not enabled.
+    pub fn check_feature_flag(&self, flag: &str) -> Result<(), FieldError<S>>
+    where
+        CtxT: FeatureFlags,
+    {
+        if self.context.is_enabled(flag) {
+            Ok(())
+        } else {
+            Err(FieldError::new(
+                format!("Feature `{}` is not enabled", flag),
+                graphql_value!({ "code": "FEATURE_DISABLED" }),
+            ))
+        }
+    }
+
+    /// Check the given authorization `guard` against the current context
+    /// before resolving a field
+    ///
+    /// Returns the error of the `guard`, if it denies access to the field.
+    pub fn check_guard<F, E>(&self, guard: F) -> Result<(), FieldError<S>>
+    where
+        F: FnOnce(&CtxT) -> Result<(), E>,
+        E: IntoFieldError<S>,
+    {
+        guard(self.context).map_err(IntoFieldError::into_field_error)
+    }
+
+    /// Check the [`RateLimiter`] of the current context before resolving the
+    /// field identified by `key`
+    ///
+    /// Returns an error if the field is over its `limit`.
+    pub fn check_rate_limit(&self, key: &str, limit: RateLimit) -> Result<(), FieldError<S>>
+    where
+        CtxT: RateLimiter,
+    {
+        if self.context.acquire(key, limit) {
+            Ok(())
+        } else {
+            Err(FieldError::new(
+                format!("Rate limit of {} exceeded for field `{}`", limit, key),
+                graphql_value!({ "code": "RATE_LIMITED" }),
+            ))
+        }
+    }
+
+    /// Race the future resolving the field identified by `key` against the
+    /// [`Timer`] of the current context
+    ///
+    /// Returns an error if the future doesn't complete within the `timeout`.
+    pub fn resolve_with_timeout<T, F>(
+        &self,
+        key: &str,
+        timeout: Duration,
+        fut: F,
+    ) -> impl Future<Output = Result<T, FieldError<S>>>
+    where
+        CtxT: Timer,
+        F: Future<Output = Result<T, FieldError<S>>>,
+    {
+        let sleep = self.context.sleep(timeout);
+        let key = key.to_owned();
+        async move {
+            futures::pin_mut!(fut);
+            match future::select(fut, sleep).await {
+                Either::Left((res, _)) => res,
+                Either::Right(_) => Err(FieldError::new(
+                    format!("Field `{}` timed out after {:?}", key, timeout),
+                    graphql_value!({ "code": "TIMEOUT" }),
+                )),
+            }
+        }
+    }
+
+    /// Construct the [`CacheKey`] of the value of the `field_name` field of
+    /// the `type_name` type, resolved with the given `args` in the given
+    /// `scope`
+    pub fn cache_key(
+        &self,
+        type_name: &str,
+        field_name: &str,
+        args: &crate::Arguments<S>,
+        scope: String,
+    ) -> CacheKey {
+        let mut selection = String::new();
+        if let Some(selection_set) = self.current_selection_set {
+            cache::render_selection(
+                selection_set,
+                self.variables,
+                self.fragments,
+                &mut selection,
+            );
+        }
+        CacheKey {
+            type_name: type_name.to_owned(),
+            field_name: field_name.to_owned(),
+            arguments: args.render(),
+            selection,
+            scope,
+        }
+    }
+
+    /// Resolve a field via the given `resolve` function, unless its value is
+    /// already stored under the given `key` in the [`Cache`] of the current
+    /// context
+    ///
+    /// The resolved value is stored only if neither the field nor the fields
+    /// selected on its value have failed.
+    pub fn resolve_cached<F>(&self, key: CacheKey, resolve: F) -> ExecutionResult<S>
+    where
+        CtxT: Cache<S>,
+        F: FnOnce() -> ExecutionResult<S>,
+    {
+        if let Some(value) = self.context.get(&key) {
+            return Ok(value);
+        }
+        let value = resolve()?;
+        if !self.has_errors_within() {
+            self.context.set(key, value.clone());
+        }
+        Ok(value)
+    }
+
+    /// Resolve a field via the future returned by the given `resolve`
+    /// function, unless its value is already stored under the given `key` in
+    /// the [`Cache`] of the current context
+    ///
+    /// The resolved value is stored only if neither the field nor the fields
+    /// selected on its value have failed.
+    pub async fn resolve_cached_async<F, Fut>(
+        &self,
+        key: CacheKey,
+        resolve: F,
+    ) -> ExecutionResult<S>
+    where
+        CtxT: Cache<S>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ExecutionResult<S>>,
+    {
+        if let Some(value) = self.context.get(&key) {
+            return Ok(value);
+        }
+        let value = resolve().await?;
+        if !self.has_errors_within() {
+            self.context.set(key, value.clone());
+        }
+        Ok(value)
+    }
+
+    /// Check whether any errors were recorded for the current field or the
+    /// fields selected on its value
+    fn has_errors_within(&self) -> bool {
+        let mut path = Vec::new();
+        self.construct_path(&mut path);
+        self.errors
+            .read()
+            .unwrap()
+            .iter()
+            .any(|e| e.path.starts_with(&path))
+    }
+
+    /// Resolve a field via the given `resolve` function, unless the deadline
+    /// of the whole execution has passed already
+    pub(crate) fn resolve_before_deadline<F>(&self, resolve: F) -> ExecutionResult<S>
+    where
+        F: FnOnce() -> ExecutionResult<S>,
+    {
+        match self.deadline {
+            Some(deadline) if deadline.has_passed() => deadline.unresolved(),
+            _ => resolve(),
+        }
+    }
+
+    /// Resolve a field via the given `fut`, unless the deadline of the whole
+    /// execution passes first
+    pub(crate) async fn resolve_before_deadline_async<Fut>(&self, fut: Fut) -> ExecutionResult<S>
+    where
+        Fut: Future<Output = ExecutionResult<S>>,
+    {
+        match self.deadline {
+            Some(deadline) => match deadline.race(fut).await {
+                Some(res) => res,
+                None => deadline.unresolved(),
+            },
+            None => fut.await,
+        }
+    }
+
+    /// Resolve the `field_name` field of the `type_name` type via the given
+    /// `resolve` function, recording its timing into the [`Instrumentation`]
+    /// of the schema, if any
+    pub(crate) fn resolve_instrumented<T, F>(
+        &self,
+        type_name: &str,
+        field_name: &str,
+        resolve: F,
+    ) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        if self.timing_span.is_none() {
+            return resolve();
+        }
+        let (start, started) = (SystemTime::now(), Instant::now());
+        let res = resolve();
+        self.record_timing(type_name, field_name, start, started);
+        res
+    }
+
+    /// Resolve the `field_name` field of the `type_name` type via the given
+    /// `resolve` future, recording its timing into the [`Instrumentation`] of
+    /// the schema, if any
+    pub(crate) async fn resolve_instrumented_async<T, Fut>(
+        &self,
+        type_name: &str,
+        field_name: &str,
+        resolve: Fut,
+    ) -> T
+    where
+        Fut: Future<Output = T>,
+    {
+        if self.timing_span.is_none() {
+            return resolve.await;
+        }
+        let (start, started) = (SystemTime::now(), Instant::now());
+        let res = resolve.await;
+        self.record_timing(type_name, field_name, start, started);
+        res
+    }
+
+    fn record_timing(
+        &self,
+        type_name: &str,
+        field_name: &str,
+        start: SystemTime,
+        started: Instant,
+    ) {
+        let (instrumentation, span) = match (&self.schema.instrumentation, self.timing_span) {
+            (Some(instrumentation), Some(span)) => (instrumentation, span),
+            _ => return,
+        };
+        let mut path = Vec::new();
+        self.construct_path(&mut path);
+
+        instrumentation.record(FieldTiming {
+            id: span.id,
+            parent: span.parent,
+            type_name: type_name.to_owned(),
+            field_name: field_name.to_owned(),
+            path: path
+                .into_iter()
+                .filter_map(|segment| match segment {
+                    PathSegment::Field(name) => Some(name),
+                    PathSegment::Index(_) => None,
+                })
+                .collect(),
+            start,
+            duration: started.elapsed(),
+        });
+    }
+
     /// Construct a lookahead selection for the current selection.
     ///
     /// This allows seeing the whole selection and perform operations
     /// affecting the children.
     pub fn look_ahead(&'a self) -> LookAheadSelection<'a, S> {
-        let field_name = match *self.field_path {
-            FieldPath::Field(x, ..) => x,
-            FieldPath::Root(_) => unreachable!(),
+        let field_name = match self.field_path.alias() {
+            Some(x) => x,
+            None => unreachable!(),
         };
         self.parent_selection_set
             .map(|p| {
@@ -737,18 +1368,23 @@ where
             schema: self.schema,
             context: self.context,
             errors: RwLock::new(vec![]),
-            field_path: Arc::clone(&self.field_path),
+            field_path: self.current_path(),
+            memo: Arc::new(self.memo.clone()),
         }
     }
 }
 
 impl<'a> FieldPath<'a> {
-    fn construct_path(&self, acc: &mut Vec<String>) {
+    fn construct_path(&self, acc: &mut Vec<PathSegment>) {
         match self {
             FieldPath::Root(_) => (),
             FieldPath::Field(name, _, parent) => {
                 parent.construct_path(acc);
-                acc.push((*name).to_owned());
+                acc.push(PathSegment::from(*name));
+            }
+            FieldPath::Index(index, parent) => {
+                parent.construct_path(acc);
+                acc.push(PathSegment::from(*index));
             }
         }
     }
@@ -756,6 +1392,15 @@ impl<'a> FieldPath<'a> {
     fn location(&self) -> &SourcePosition {
         match *self {
             FieldPath::Root(ref pos) | FieldPath::Field(_, ref pos, _) => pos,
+            FieldPath::Index(_, ref parent) => parent.location(),
+        }
+    }
+
+    fn alias(&self) -> Option<&'a str> {
+        match *self {
+            FieldPath::Root(_) => None,
+            FieldPath::Field(alias, ..) => Some(alias),
+            FieldPath::Index(_, ref parent) => parent.alias(),
         }
     }
 }
@@ -765,7 +1410,20 @@ impl<S> ExecutionError<S> {
     pub fn new(location: SourcePosition, path: &[&str], error: FieldError<S>) -> ExecutionError<S> {
         ExecutionError {
             location,
-            path: path.iter().map(|s| (*s).to_owned()).collect(),
+            path: path.iter().map(|s| PathSegment::from(*s)).collect(),
+            error,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn new_at_path(
+        location: SourcePosition,
+        path: &[PathSegment],
+        error: FieldError<S>,
+    ) -> ExecutionError<S> {
+        ExecutionError {
+            location,
+            path: path.to_vec(),
             error,
         }
     }
@@ -780,8 +1438,9 @@ impl<S> ExecutionError<S> {
         &self.location
     }
 
-    /// The path of fields leading to the field that generated this error
-    pub fn path(&self) -> &[String] {
+    /// The path of fields and list indices leading to the field that generated
+    /// this error
+    pub fn path(&self) -> &[PathSegment] {
         &self.path
     }
 }
@@ -804,6 +1463,9 @@ where
     if operation.item.operation_type == OperationType::Subscription {
         return Err(GraphQLError::IsSubscription);
     }
+    if is_introspection_operation(document, &operation.item) {
+        return execute_validated_introspection_query(document, operation, root_node, variables);
+    }
 
     let mut fragments = vec![];
     for def in document.iter() {
@@ -824,7 +1486,12 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let deadline = root_node
+        .schema
+        .timeout
+        .map(|timeout| Deadline::after(timeout, None));
     let errors = RwLock::new(Vec::new());
+    let memo = Memo::default();
     let value;
 
     {
@@ -863,6 +1530,11 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            list_index: None,
+            max_concurrency: None,
+            deadline: deadline.as_ref(),
+            timing_span: None,
+            memo: &memo,
         };
 
         value = match operation.item.operation_type {
@@ -875,8 +1547,20 @@ where
     }
 
     let mut errors = errors.into_inner().unwrap();
+    if let Some(error) = deadline.as_ref().and_then(Deadline::error) {
+        errors.push(ExecutionError {
+            location: operation.start,
+            path: Vec::new(),
+            error,
+        });
+    }
     errors.sort();
 
+    let value = match &root_node.response_transform {
+        Some(transform) => transform.apply(value),
+        None => value,
+    };
+
     Ok((value, errors))
 }
 
@@ -889,6 +1573,31 @@ pub async fn execute_validated_query_async<'a, 'b, QueryT, MutationT, Subscripti
     variables: &Variables<S>,
     context: &QueryT::Context,
 ) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    execute_validated_query_until(document, operation, root_node, variables, context, None).await
+}
+
+/// Create new `Executor` and start asynchronous query execution, failing the
+/// fields not resolved by the given `deadline`, if any, or by the timeout of
+/// the schema, whichever passes first.
+/// Returns `IsSubscription` error if subscription is passed.
+pub(crate) async fn execute_validated_query_until<'a, 'b, QueryT, MutationT, SubscriptionT, S>(
+    document: &'b Document<'a, S>,
+    operation: &'b Spanning<Operation<'_, S>>,
+    root_node: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    deadline: Option<Deadline>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>
 where
     QueryT: GraphQLTypeAsync<S>,
     QueryT::TypeInfo: Sync,
@@ -902,6 +1611,9 @@ where
     if operation.item.operation_type == OperationType::Subscription {
         return Err(GraphQLError::IsSubscription);
     }
+    if is_introspection_operation(document, &operation.item) {
+        return execute_validated_introspection_query(document, operation, root_node, variables);
+    }
 
     let mut fragments = vec![];
     for def in document.iter() {
@@ -922,7 +1634,13 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let timeout = root_node
+        .schema
+        .timeout
+        .map(|timeout| Deadline::after(timeout, root_node.context_timer.map(|t| t(context))));
+    let deadline = Deadline::first(deadline, timeout);
     let errors = RwLock::new(Vec::new());
+    let memo = Memo::default();
     let value;
 
     {
@@ -961,6 +1679,11 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            list_index: None,
+            max_concurrency: None,
+            deadline: deadline.as_ref(),
+            timing_span: None,
+            memo: &memo,
         };
 
         value = match operation.item.operation_type {
@@ -979,8 +1702,20 @@ where
     }
 
     let mut errors = errors.into_inner().unwrap();
+    if let Some(error) = deadline.as_ref().and_then(Deadline::error) {
+        errors.push(ExecutionError {
+            location: operation.start,
+            path: Vec::new(),
+            error,
+        });
+    }
     errors.sort();
 
+    let value = match &root_node.response_transform {
+        Some(transform) => transform.apply(value),
+        None => value,
+    };
+
     Ok((value, errors))
 }
 
@@ -996,7 +1731,15 @@ where
     for def in document {
         if let Definition::Operation(op) = def {
             if operation_name.is_none() && operation.is_some() {
-                return Err(GraphQLError::MultipleOperationsProvided);
+                let names = document
+                    .iter()
+                    .filter_map(|def| match def {
+                        Definition::Operation(op) => op.item.name.as_ref(),
+                        _ => None,
+                    })
+                    .map(|name| name.item.to_owned())
+                    .collect();
+                return Err(GraphQLError::MultipleOperationsProvided(names));
             }
 
             let move_op =
@@ -1014,6 +1757,214 @@ where
     Ok(op)
 }
 
+/// Checks whether the given `operation` is a query selecting introspection
+/// fields only (`__schema`, `__type` and `__typename`), so it can be resolved
+/// without the context of the schema.
+pub(crate) fn is_introspection_operation<S>(
+    document: &Document<S>,
+    operation: &Operation<S>,
+) -> bool {
+    operation.operation_type == OperationType::Query
+        && is_introspection_selection_set(document, &operation.selection_set, &mut vec![])
+}
+
+fn is_introspection_selection_set<'d, S>(
+    document: &'d Document<S>,
+    selection_set: &'d [Selection<S>],
+    visited_fragments: &mut Vec<&'d str>,
+) -> bool {
+    selection_set.iter().all(|selection| match selection {
+        Selection::Field(f) => matches!(f.item.name.item, "__schema" | "__type" | "__typename"),
+        Selection::InlineFragment(f) => {
+            is_introspection_selection_set(document, &f.item.selection_set, visited_fragments)
+        }
+        Selection::FragmentSpread(spread) => {
+            let name = spread.item.name.item;
+            if visited_fragments.contains(&name) {
+                return true;
+            }
+            visited_fragments.push(name);
+            document.iter().any(|def| match def {
+                Definition::Fragment(f) if f.item.name.item == name => {
+                    is_introspection_selection_set(
+                        document,
+                        &f.item.selection_set,
+                        visited_fragments,
+                    )
+                }
+                _ => false,
+            })
+        }
+    })
+}
+
+/// Collects the names of variables referenced by the given `operation`, either
+/// directly or via the fragments it spreads, in arguments of its fields and
+/// directives.
+pub(crate) fn used_variables<'d, S>(
+    document: &'d Document<S>,
+    operation: &'d Operation<S>,
+) -> HashSet<&'d str>
+where
+    S: ScalarValue,
+{
+    let mut used = HashSet::new();
+    collect_directives_variables(&operation.directives, &mut used);
+    collect_selection_set_variables(document, &operation.selection_set, &mut vec![], &mut used);
+    used
+}
+
+fn collect_selection_set_variables<'d, S>(
+    document: &'d Document<S>,
+    selection_set: &'d [Selection<S>],
+    visited_fragments: &mut Vec<&'d str>,
+    used: &mut HashSet<&'d str>,
+) where
+    S: ScalarValue,
+{
+    for selection in selection_set {
+        match selection {
+            Selection::Field(f) => {
+                if let Some(args) = &f.item.arguments {
+                    collect_arguments_variables(&args.item, used);
+                }
+                collect_directives_variables(&f.item.directives, used);
+                if let Some(selection_set) = &f.item.selection_set {
+                    collect_selection_set_variables(
+                        document,
+                        selection_set,
+                        visited_fragments,
+                        used,
+                    );
+                }
+            }
+            Selection::InlineFragment(f) => {
+                collect_directives_variables(&f.item.directives, used);
+                collect_selection_set_variables(
+                    document,
+                    &f.item.selection_set,
+                    visited_fragments,
+                    used,
+                );
+            }
+            Selection::FragmentSpread(spread) => {
+                collect_directives_variables(&spread.item.directives, used);
+                let name = spread.item.name.item;
+                if visited_fragments.contains(&name) {
+                    continue;
+                }
+                visited_fragments.push(name);
+                for def in document {
+                    if let Definition::Fragment(f) = def {
+                        if f.item.name.item == name {
+                            collect_directives_variables(&f.item.directives, used);
+                            collect_selection_set_variables(
+                                document,
+                                &f.item.selection_set,
+                                visited_fragments,
+                                used,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn collect_directives_variables<'d, S>(
+    directives: &'d Option<Vec<Spanning<Directive<S>>>>,
+    used: &mut HashSet<&'d str>,
+) where
+    S: ScalarValue,
+{
+    for directive in directives.iter().flatten() {
+        if let Some(args) = &directive.item.arguments {
+            collect_arguments_variables(&args.item, used);
+        }
+    }
+}
+
+fn collect_arguments_variables<'d, S>(args: &'d Arguments<S>, used: &mut HashSet<&'d str>)
+where
+    S: ScalarValue,
+{
+    for (_, value) in args.iter() {
+        used.extend(value.item.referenced_variables());
+    }
+}
+
+/// Create new `Executor` resolving the given introspection-only `operation`
+/// (see [`is_introspection_operation`]) against the schema itself, without
+/// requiring any context.
+pub(crate) fn execute_validated_introspection_query<'a, 'b, QueryT, MutationT, SubscriptionT, S>(
+    document: &'b Document<S>,
+    operation: &'b Spanning<Operation<S>>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    let fragments = document
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Fragment(f) => Some((f.item.name.item, f.item.clone())),
+            _ => None,
+        })
+        .collect();
+
+    let mut all_vars = variables.clone();
+    if let Some(defs) = &operation.item.variable_definitions {
+        for (name, def) in &defs.item.items {
+            if let Some(default) = &def.default_value {
+                all_vars
+                    .entry(name.item.to_owned())
+                    .or_insert_with(|| default.item.clone());
+            }
+        }
+    }
+
+    let errors = RwLock::new(Vec::new());
+    let memo = Memo::default();
+    let value = {
+        let executor = Executor {
+            fragments: &fragments,
+            variables: &all_vars,
+            current_selection_set: Some(&operation.item.selection_set[..]),
+            parent_selection_set: None,
+            current_type: root_node.schema.query_type(),
+            schema: &root_node.schema,
+            context: &root_node.schema,
+            errors: &errors,
+            field_path: Arc::new(FieldPath::Root(operation.start)),
+            list_index: None,
+            max_concurrency: None,
+            deadline: None,
+            timing_span: None,
+            memo: &memo,
+        };
+
+        executor.resolve_into_value(
+            &root_node.schema.query_type_name,
+            &IntrospectionRoot::new(&root_node.schema),
+        )
+    };
+
+    let mut errors = errors.into_inner().unwrap();
+    errors.sort();
+
+    let value = match &root_node.response_transform {
+        Some(transform) => transform.apply(value),
+        None => value,
+    };
+
+    Ok((value, errors))
+}
+
 /// Initialize new `Executor` and start resolving subscription into stream
 /// asynchronously.
 /// Returns `NotSubscription` error if query or mutation is passed
@@ -1070,6 +2021,7 @@ where
     });
 
     let errors = RwLock::new(Vec::new());
+    let memo = Memo::default();
     let value;
 
     {
@@ -1107,6 +2059,11 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.start)),
+            list_index: None,
+            max_concurrency: None,
+            deadline: None,
+            timing_span: None,
+            memo: &memo,
         };
 
         value = match operation.item.operation_type {
@@ -1131,7 +2088,11 @@ where
 {
     /// Construct a new registry
     pub fn new(types: FnvHashMap<Name, MetaType<'r, S>>) -> Registry<'r, S> {
-        Registry { types }
+        Registry {
+            types,
+            checked_builtin_names: FnvHashSet::default(),
+            errors: Vec::new(),
+        }
     }
 
     /// Get the `Type` instance for a given GraphQL type
@@ -1150,7 +2111,17 @@ where
                     Type::NonNullNamed(Cow::Owned(name.to_string())),
                 );
                 let meta = T::meta(info, self);
+                self.check_not_shadowing_builtin::<T>(&meta);
                 self.types.insert(validated_name, meta);
+            } else if BUILTIN_SCALAR_NAMES.contains(&name)
+                && !self
+                    .checked_builtin_names
+                    .contains(std::any::type_name::<T>())
+            {
+                // The built-in scalar may have been registered under this name
+                // already, so whether the type `T` is it has to be checked.
+                let meta = T::meta(info, self);
+                self.check_not_shadowing_builtin::<T>(&meta);
             }
             self.types[name].as_type()
         } else {
@@ -1158,6 +2129,27 @@ where
         }
     }
 
+    /// Checks the `meta` of the type `T` not to use the name of a built-in
+    /// scalar, unless it's the built-in scalar itself, recording a
+    /// [`SchemaError::ShadowedBuiltinScalar`] otherwise.
+    ///
+    /// Checked Rust types are remembered, so the `meta` of each of them is
+    /// only built once.
+    fn check_not_shadowing_builtin<T: ?Sized>(&mut self, meta: &MetaType<S>) {
+        let name = match meta.name() {
+            Some(name) if BUILTIN_SCALAR_NAMES.contains(&name) => name,
+            _ => return,
+        };
+        let type_name = std::any::type_name::<T>();
+        self.checked_builtin_names.insert(type_name);
+        if !matches!(meta, MetaType::Scalar(s) if s.is_builtin) {
+            self.errors.push(SchemaError::ShadowedBuiltinScalar {
+                type_name: type_name.to_owned(),
+                name: name.to_owned(),
+            });
+        }
+    }
+
     /// Create a field with the provided name
     pub fn field<T>(&mut self, name: &str, info: &T::TypeInfo) -> Field<'r, S>
     where
@@ -1169,6 +2161,9 @@ where
             arguments: None,
             field_type: self.get_type::<T>(info),
             deprecation_status: DeprecationStatus::Current,
+            removal_date: None,
+            complexity: None,
+            extensions: BTreeMap::new(),
         }
     }
 
@@ -1187,6 +2182,9 @@ where
             arguments: None,
             field_type: self.get_type::<I>(info),
             deprecation_status: DeprecationStatus::Current,
+            removal_date: None,
+            complexity: None,
+            extensions: BTreeMap::new(),
         }
     }
 
@@ -5,7 +5,7 @@ use std::{
 
 use crate::{
     ast::Fragment,
-    executor::FieldPath,
+    executor::{FieldPath, Memo},
     parser::SourcePosition,
     schema::model::{SchemaType, TypeType},
     ExecutionError, Executor, Selection, Variables,
@@ -23,6 +23,7 @@ pub struct OwnedExecutor<'a, CtxT, S> {
     pub(super) context: &'a CtxT,
     pub(super) errors: RwLock<Vec<ExecutionError<S>>>,
     pub(super) field_path: Arc<FieldPath<'a>>,
+    pub(super) memo: Arc<Memo>,
 }
 
 impl<'a, CtxT, S> Clone for OwnedExecutor<'a, CtxT, S>
@@ -40,6 +41,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            memo: Arc::clone(&self.memo),
         }
     }
 }
@@ -67,6 +69,7 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            memo: Arc::clone(&self.memo),
         }
     }
 
@@ -104,6 +107,7 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            memo: Arc::clone(&self.memo),
         }
     }
 
@@ -119,6 +123,11 @@ where
             context: self.context,
             errors: &self.errors,
             field_path: Arc::clone(&self.field_path),
+            list_index: None,
+            max_concurrency: None,
+            deadline: None,
+            timing_span: None,
+            memo: &self.memo,
         }
     }
 }
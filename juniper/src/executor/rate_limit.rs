@@ -0,0 +1,60 @@
+//! Limiting the rate at which fields are resolved.
+
+use std::{fmt, time::Duration};
+
+/// Maximum number of times a field may be resolved per a period of time
+///
+/// This is usually specified via `#[graphql(rate_limit = "10/min")]` attribute
+/// on a field, and checked against the [`RateLimiter`] of the context before
+/// the field is resolved.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RateLimit {
+    max: u32,
+    per: Duration,
+}
+
+impl RateLimit {
+    /// Construct a new limit of `max` resolutions `per` the given period
+    pub const fn new(max: u32, per: Duration) -> Self {
+        Self { max, per }
+    }
+
+    /// The maximum number of resolutions allowed during a single period
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// The period the limit applies to
+    pub fn per(&self) -> Duration {
+        self.per
+    }
+}
+
+impl fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.per.as_secs();
+        match secs {
+            86_400 => write!(f, "{}/day", self.max),
+            3_600 => write!(f, "{}/hour", self.max),
+            60 => write!(f, "{}/min", self.max),
+            1 => write!(f, "{}/sec", self.max),
+            _ => write!(f, "{} per {}s", self.max, secs),
+        }
+    }
+}
+
+/// Context capable of limiting the rate at which fields are resolved
+///
+/// A field marked with `#[graphql(rate_limit = "...")]` requires its context
+/// to implement this trait.
+pub trait RateLimiter {
+    /// Record an attempt to resolve the field identified by `key` (in the
+    /// `Type.field` form), returning whether it's allowed under the `limit`.
+    fn acquire(&self, key: &str, limit: RateLimit) -> bool;
+}
+
+impl<T: RateLimiter + ?Sized> RateLimiter for &T {
+    fn acquire(&self, key: &str, limit: RateLimit) -> bool {
+        (**self).acquire(key, limit)
+    }
+}
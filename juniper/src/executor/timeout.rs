@@ -0,0 +1,139 @@
+//! Bounding the time fields are resolved in.
+
+use std::{
+    fmt,
+    future::Future,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use futures::future::{self, Either, FutureExt as _, Shared};
+
+use crate::{BoxFuture, FieldError, FieldResult, ScalarValue, Value};
+
+/// Context capable of measuring time, so resolving fields may time out
+///
+/// A field marked with `#[graphql(timeout = "...")]` requires its context to
+/// implement this trait. Juniper doesn't depend on any async runtime, so the
+/// timer is usually backed by the one the schema is executed on, e.g.
+/// `Box::pin(tokio::time::sleep(duration))`.
+pub trait Timer {
+    /// Create a future completing once the given `duration` elapses.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+impl<T: Timer + ?Sized> Timer for &T {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        (**self).sleep(duration)
+    }
+}
+
+impl fmt::Debug for dyn Timer + Send + Sync {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Timer")
+    }
+}
+
+/// Instant the whole execution should be finished by
+///
+/// Created once per execution, so all of its fields race against the same
+/// [`Timer::sleep`] future.
+pub(crate) struct Deadline {
+    at: Instant,
+
+    /// Future completing once the deadline passes, if there is a [`Timer`]
+    /// measuring the time left
+    passed: Option<Shared<BoxFuture<'static, ()>>>,
+
+    /// Timeout registered via [`RootNode::with_timeout`] the deadline is set
+    /// by, if any
+    ///
+    /// [`RootNode::with_timeout`]: crate::RootNode::with_timeout
+    timeout: Option<Duration>,
+
+    /// Whether any field was left unresolved because of the `timeout`
+    skipped: AtomicBool,
+}
+
+impl Deadline {
+    /// Creates a new [`Deadline`] passing at the given instant, with the time
+    /// left measured by the given `timer`, if any.
+    pub(crate) fn at(at: Instant, timer: Option<&dyn Timer>) -> Self {
+        let left = at.saturating_duration_since(Instant::now());
+        Self {
+            at,
+            passed: timer.map(|timer| timer.sleep(left).shared()),
+            timeout: None,
+            skipped: AtomicBool::new(false),
+        }
+    }
+
+    /// Creates a new [`Deadline`] passing once the given `timeout` elapses,
+    /// with the time left measured by the given `timer`, if any.
+    pub(crate) fn after(timeout: Duration, timer: Option<&dyn Timer>) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..Self::at(Instant::now() + timeout, timer)
+        }
+    }
+
+    /// Picks the one of the given deadlines passing first.
+    pub(crate) fn first(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(if b.at < a.at { b } else { a }),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Checks whether the deadline has passed already.
+    pub(crate) fn has_passed(&self) -> bool {
+        Instant::now() >= self.at
+    }
+
+    /// Awaits the given `fut`, unless the deadline passes first.
+    ///
+    /// Returns [`None`] if the deadline has passed already, or passes before
+    /// the `fut` completes.
+    pub(crate) async fn race<T>(&self, fut: impl Future<Output = T>) -> Option<T> {
+        if self.has_passed() {
+            return None;
+        }
+        let passed = match &self.passed {
+            Some(passed) => passed.clone(),
+            None => return Some(fut.await),
+        };
+        futures::pin_mut!(fut);
+        match future::select(fut, passed).await {
+            Either::Left((res, _)) => Some(res),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Result of a field left unresolved because the deadline has passed
+    ///
+    /// Fails the field, unless the deadline is set by a timeout, which is
+    /// reported by a single [`Deadline::error`] of the whole execution instead.
+    pub(crate) fn unresolved<S: ScalarValue>(&self) -> FieldResult<Value<S>, S> {
+        if self.timeout.is_some() {
+            self.skipped.store(true, Ordering::Relaxed);
+            Ok(Value::null())
+        } else {
+            Err(FieldError::new(
+                "Deadline of the execution exceeded",
+                graphql_value!({ "code": "DEADLINE_EXCEEDED" }),
+            ))
+        }
+    }
+
+    /// Error of the whole execution, if any field was left unresolved because
+    /// of the timeout the deadline is set by
+    pub(crate) fn error<S: ScalarValue>(&self) -> Option<FieldError<S>> {
+        match self.timeout {
+            Some(timeout) if self.skipped.load(Ordering::Relaxed) => Some(FieldError::new(
+                format!("Execution timed out after {:?}", timeout),
+                Value::null(),
+            )),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    executor::ExecutionError, graphql_object, parser::SourcePosition, schema::model::RootNode,
+    BoxFuture, EmptyMutation, EmptySubscription, FieldError, Timer, Variables,
+};
+
+/// [`Timer`] backed by `tokio`, counting the sleep futures it creates.
+#[derive(Default)]
+struct TokioTimer {
+    sleeps: AtomicUsize,
+}
+
+impl crate::Context for TokioTimer {}
+
+impl Timer for TokioTimer {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        self.sleeps.fetch_add(1, Ordering::SeqCst);
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+struct Release;
+
+#[graphql_object]
+impl Release {
+    fn version() -> i32 {
+        1
+    }
+
+    async fn next_version() -> Option<i32> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Some(2)
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = TokioTimer)]
+impl Query {
+    async fn version() -> Option<i32> {
+        Some(1)
+    }
+
+    async fn next_version() -> Option<i32> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Some(2)
+    }
+
+    async fn release() -> Release {
+        Release
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<TokioTimer>, EmptySubscription<TokioTimer>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn fails_fields_not_resolved_before_deadline() {
+    let schema = schema();
+    let timer = TokioTimer::default();
+
+    assert_eq!(
+        crate::execute_with_deadline(
+            "{ version nextVersion release { version } }",
+            None,
+            &schema,
+            &Variables::new(),
+            &timer,
+            Instant::now() + Duration::from_millis(50),
+        )
+        .await,
+        Ok((
+            graphql_value!({
+                "version": 1,
+                "nextVersion": None,
+                "release": {"version": 1},
+            }),
+            vec![ExecutionError::new(
+                SourcePosition::new(10, 0, 10),
+                &["nextVersion"],
+                FieldError::new(
+                    "Deadline of the execution exceeded",
+                    graphql_value!({"code": "DEADLINE_EXCEEDED"}),
+                ),
+            )],
+        )),
+    );
+    assert_eq!(timer.sleeps.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn nulls_fields_not_resolved_before_timeout() {
+    let schema = schema().with_timeout(Duration::from_millis(50));
+    let timer = TokioTimer::default();
+
+    assert_eq!(
+        crate::execute(
+            "{ version nextVersion release { version nextVersion } }",
+            None,
+            &schema,
+            &Variables::new(),
+            &timer,
+        )
+        .await,
+        Ok((
+            graphql_value!({
+                "version": 1,
+                "nextVersion": None,
+                "release": {
+                    "version": 1,
+                    "nextVersion": None,
+                },
+            }),
+            vec![ExecutionError::new(
+                SourcePosition::new(0, 0, 0),
+                &[],
+                FieldError::new("Execution timed out after 50ms", graphql_value!(None)),
+            )],
+        )),
+    );
+    assert_eq!(timer.sleeps.load(Ordering::SeqCst), 1);
+}
+
+mod sync {
+    use std::time::Duration;
+
+    use crate::{
+        executor::ExecutionError, graphql_object, parser::SourcePosition, schema::model::RootNode,
+        EmptyMutation, EmptySubscription, FieldError, Variables,
+    };
+
+    use super::TokioTimer;
+
+    struct Query;
+
+    #[graphql_object(context = TokioTimer)]
+    impl Query {
+        fn version() -> Option<i32> {
+            std::thread::sleep(Duration::from_millis(100));
+            Some(1)
+        }
+    }
+
+    fn schema(
+        timeout: Duration,
+    ) -> RootNode<'static, Query, EmptyMutation<TokioTimer>, EmptySubscription<TokioTimer>> {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new()).with_timeout(timeout)
+    }
+
+    #[test]
+    fn nulls_fields_resolved_after_timeout() {
+        let schema = schema(Duration::from_millis(50));
+
+        assert_eq!(
+            crate::execute_sync(
+                "{ version again: version }",
+                None,
+                &schema,
+                &Variables::new(),
+                &TokioTimer::default(),
+            ),
+            Ok((
+                graphql_value!({"version": 1, "again": None}),
+                vec![ExecutionError::new(
+                    SourcePosition::new(0, 0, 0),
+                    &[],
+                    FieldError::new("Execution timed out after 50ms", graphql_value!(None)),
+                )],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_fields_within_timeout() {
+        let schema = schema(Duration::from_secs(5));
+
+        assert_eq!(
+            crate::execute_sync(
+                "{ version }",
+                None,
+                &schema,
+                &Variables::new(),
+                &TokioTimer::default(),
+            ),
+            Ok((graphql_value!({"version": 1}), vec![])),
+        );
+    }
+}
@@ -246,3 +246,95 @@ async fn scalar_include_false_skip_false() {
     })
     .await;
 }
+
+#[cfg(feature = "chrono")]
+mod excluded_fields {
+    use chrono::NaiveDate;
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+    #[tokio::test]
+    async fn are_not_resolved() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::InputValue;
+
+        #[derive(Default)]
+        struct Context {
+            resolved: AtomicUsize,
+        }
+
+        impl crate::Context for Context {}
+
+        struct Root;
+
+        #[graphql_object(context = Context)]
+        impl Root {
+            fn today(context: &Context) -> NaiveDate {
+                context.resolved.fetch_add(1, Ordering::SeqCst);
+                NaiveDate::from_ymd_opt(2021, 7, 8).unwrap()
+            }
+
+            fn zero() -> i32 {
+                0
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+
+        let ctx = Context::default();
+        assert_eq!(
+            crate::execute(
+                "{ zero today @skip(if: true) }",
+                None,
+                &schema,
+                &Variables::new(),
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"zero": 0}), vec![])),
+        );
+        assert_eq!(
+            crate::execute_sync(
+                "{ zero ... on Root @include(if: false) { today } }",
+                None,
+                &schema,
+                &Variables::new(),
+                &ctx,
+            ),
+            Ok((graphql_value!({"zero": 0}), vec![])),
+        );
+        let vars = vec![("skip".to_owned(), InputValue::scalar(true))]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            crate::execute(
+                "query($skip: Boolean!) { zero today @skip(if: $skip) }",
+                None,
+                &schema,
+                &vars,
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"zero": 0}), vec![])),
+        );
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 0);
+
+        assert_eq!(
+            crate::execute(
+                "{ zero today @skip(if: false) }",
+                None,
+                &schema,
+                &Variables::new(),
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"zero": 0, "today": "2021-07-08"}), vec![])),
+        );
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 1);
+    }
+}
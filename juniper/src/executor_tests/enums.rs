@@ -173,3 +173,92 @@ async fn does_not_accept_incorrect_type_in_variables() {
         )])
     );
 }
+
+#[cfg(feature = "lenient-enums")]
+#[tokio::test]
+async fn accepts_enum_literals_case_insensitively() {
+    run_query(
+        "{ lower: toString(color: red) capitalized: toString(color: Red) upper: toString(color: RED) }",
+        |result| {
+            for field in &["lower", "capitalized", "upper"] {
+                assert_eq!(
+                    result.get_field_value(field),
+                    Some(&graphql_value!("Color::Red")),
+                );
+            }
+        },
+    )
+    .await;
+}
+
+#[cfg(feature = "lenient-enums")]
+#[tokio::test]
+async fn accepts_strings_in_variables_case_insensitively() {
+    for color in &["green", "Green", "GREEN"] {
+        run_variable_query(
+            "query q($color: Color!) { toString(color: $color) }",
+            vec![("color".to_owned(), InputValue::scalar(*color))]
+                .into_iter()
+                .collect(),
+            |result| {
+                assert_eq!(
+                    result.get_field_value("toString"),
+                    Some(&graphql_value!("Color::Green")),
+                );
+            },
+        )
+        .await;
+    }
+}
+
+#[cfg(not(feature = "lenient-enums"))]
+#[tokio::test]
+async fn does_not_accept_enum_literals_of_different_case() {
+    let schema = RootNode::new(
+        TestType,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let query = r#"{ toString(color: red) }"#;
+    let vars = vec![].into_iter().collect();
+
+    let error = crate::execute(query, None, &schema, &vars, &())
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        ValidationError(vec![RuleError::new(
+            r#"Invalid value for argument "color", expected type "Color!""#,
+            &[SourcePosition::new(18, 0, 18)],
+        )])
+    );
+}
+
+#[cfg(not(feature = "lenient-enums"))]
+#[tokio::test]
+async fn does_not_accept_strings_of_different_case_in_variables() {
+    let schema = RootNode::new(
+        TestType,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let query = r#"query q($color: Color!) { toString(color: $color) }"#;
+    let vars = vec![("color".to_owned(), InputValue::scalar("Green"))]
+        .into_iter()
+        .collect();
+
+    let error = crate::execute(query, None, &schema, &vars, &())
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        ValidationError(vec![RuleError::new(
+            r#"Variable "$color" got invalid value. Invalid value for enum "Color"."#,
+            &[SourcePosition::new(8, 0, 8)],
+        )])
+    );
+}
@@ -691,7 +691,7 @@ mod dynamic_context_switching {
 
 mod propagates_errors_to_nullable_fields {
     use crate::{
-        executor::{ExecutionError, FieldError, FieldResult, IntoFieldError},
+        executor::{ExecutionError, FieldError, FieldResult, IntoFieldError, PathSegment},
         graphql_object,
         parser::SourcePosition,
         schema::model::RootNode,
@@ -958,9 +958,13 @@ mod propagates_errors_to_nullable_fields {
 
         assert_eq!(
             errs,
-            vec![ExecutionError::new(
+            vec![ExecutionError::new_at_path(
                 SourcePosition::new(11, 0, 11),
-                &["inners", "nonNullableErrorField"],
+                &[
+                    "inners".into(),
+                    PathSegment::Index(0),
+                    "nonNullableErrorField".into()
+                ],
                 FieldError::new("Error for nonNullableErrorField", graphql_value!(None)),
             )]
         );
@@ -990,33 +994,17 @@ mod propagates_errors_to_nullable_fields {
 
         assert_eq!(
             errs,
-            vec![
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(None)),
-                ),
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(None)),
-                ),
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(None)),
-                ),
-                ExecutionError::new(
+            (0..5)
+                .map(|i| ExecutionError::new_at_path(
                     SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
-                    FieldError::new("Error for nonNullableErrorField", graphql_value!(None)),
-                ),
-                ExecutionError::new(
-                    SourcePosition::new(19, 0, 19),
-                    &["nullableInners", "nonNullableErrorField"],
+                    &[
+                        "nullableInners".into(),
+                        PathSegment::Index(i),
+                        "nonNullableErrorField".into(),
+                    ],
                     FieldError::new("Error for nonNullableErrorField", graphql_value!(None)),
-                ),
-            ]
+                ))
+                .collect::<Vec<_>>(),
         );
     }
 }
@@ -1112,7 +1100,26 @@ mod named_operations {
             .await
             .unwrap_err();
 
-        assert_eq!(err, GraphQLError::MultipleOperationsProvided);
+        assert_eq!(
+            err,
+            GraphQLError::MultipleOperationsProvided(vec![
+                "Example".to_owned(),
+                "OtherExample".to_owned(),
+            ]),
+        );
+        assert_eq!(
+            err.to_string(),
+            "Multiple operations provided, one of them must be selected by name: \
+             `Example`, `OtherExample`",
+        );
+    }
+
+    #[test]
+    fn multiple_anonymous_operations_provided_error_message() {
+        assert_eq!(
+            GraphQLError::MultipleOperationsProvided(vec![]).to_string(),
+            "Multiple operations provided, one of them must be selected by name",
+        );
     }
 
     #[tokio::test]
@@ -1133,3 +1140,334 @@ mod named_operations {
         assert_eq!(err, GraphQLError::UnknownOperationName);
     }
 }
+
+mod memoizes_once_per_request {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    use crate::{
+        graphql_object, schema::model::RootNode, Context, EmptyMutation, EmptySubscription,
+        Executor, ScalarValue, Variables,
+    };
+
+    #[derive(Default)]
+    struct Ctx {
+        calls: AtomicI32,
+    }
+
+    impl Context for Ctx {}
+
+    struct Schema;
+
+    #[graphql_object(context = Ctx)]
+    impl Schema {
+        fn created_at<S: ScalarValue>(executor: &Executor<'_, '_, Ctx, S>) -> i32 {
+            now(executor)
+        }
+
+        fn updated_at<S: ScalarValue>(executor: &Executor<'_, '_, Ctx, S>) -> i32 {
+            now(executor)
+        }
+    }
+
+    fn now<S: ScalarValue>(executor: &Executor<'_, '_, Ctx, S>) -> i32 {
+        executor.once("now", || {
+            executor.context().calls.fetch_add(1, Ordering::SeqCst) + 1
+        })
+    }
+
+    #[tokio::test]
+    async fn computes_value_once() {
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<Ctx>::new(),
+            EmptySubscription::<Ctx>::new(),
+        );
+        let ctx = Ctx::default();
+
+        let (res, errs) = crate::execute(
+            "{ createdAt updatedAt }",
+            None,
+            &schema,
+            &Variables::new(),
+            &ctx,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(errs, []);
+        assert_eq!(res, graphql_value!({"createdAt": 1, "updatedAt": 1}));
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn does_not_memoize_across_requests() {
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<Ctx>::new(),
+            EmptySubscription::<Ctx>::new(),
+        );
+        let ctx = Ctx::default();
+
+        for expected in 1..=2 {
+            let (res, errs) =
+                crate::execute("{ createdAt }", None, &schema, &Variables::new(), &ctx)
+                    .await
+                    .unwrap();
+
+            assert_eq!(errs, []);
+            assert_eq!(res, graphql_value!({ "createdAt": expected }));
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod resolves_borrowed_slices {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use crate::{
+        graphql_object, schema::model::RootNode, Context, EmptyMutation, EmptySubscription,
+        Variables,
+    };
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Event {
+        at: DateTime<Utc>,
+    }
+
+    impl Clone for Event {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Self { at: self.at }
+        }
+    }
+
+    #[graphql_object]
+    impl Event {
+        fn at(&self) -> DateTime<Utc> {
+            self.at
+        }
+    }
+
+    struct Ctx {
+        dates: Vec<DateTime<Utc>>,
+        events: Vec<Event>,
+    }
+
+    impl Context for Ctx {}
+
+    struct Schema;
+
+    #[graphql_object(context = Ctx)]
+    impl Schema {
+        fn dates(context: &Ctx) -> &[DateTime<Utc>] {
+            &context.dates
+        }
+
+        fn events(context: &Ctx) -> &[Event] {
+            &context.events
+        }
+    }
+
+    #[tokio::test]
+    async fn without_cloning() {
+        let schema = RootNode::new(
+            Schema,
+            EmptyMutation::<Ctx>::new(),
+            EmptySubscription::<Ctx>::new(),
+        );
+
+        let dates = [
+            Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap(),
+            Utc.with_ymd_and_hms(2022, 1, 2, 3, 4, 5).unwrap(),
+        ];
+        let ctx = Ctx {
+            dates: dates.to_vec(),
+            events: dates.iter().map(|&at| Event { at }).collect(),
+        };
+
+        let expected = graphql_value!({
+            "dates": ["2021-07-08T09:10:11+00:00", "2022-01-02T03:04:05+00:00"],
+            "events": [
+                {"at": "2021-07-08T09:10:11+00:00"},
+                {"at": "2022-01-02T03:04:05+00:00"},
+            ],
+        });
+        let doc = "{ dates events { at } }";
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &ctx).await,
+            Ok((expected.clone(), vec![])),
+        );
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema, &Variables::new(), &ctx),
+            Ok((expected, vec![])),
+        );
+
+        assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod error_severity {
+    use chrono::NaiveDate;
+
+    use crate::{
+        graphql_object, EmptyMutation, EmptySubscription, ErrorSeverity, FieldError, FieldResult,
+        RootNode, Variables,
+    };
+
+    #[tokio::test]
+    async fn warning_in_extensions() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn lenient_date(raw: String) -> FieldResult<NaiveDate> {
+                NaiveDate::parse_from_str(&raw, "%Y-%m-%d").map_err(|e| {
+                    FieldError::new(e, graphql_value!({"input": raw}))
+                        .with_severity(ErrorSeverity::Warning)
+                })
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let (result, errs) = crate::execute(
+            r#"{ lenientDate(raw: "2015-02-30") }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await
+        .expect("Execution failed");
+
+        assert_eq!(result, graphql_value!(None));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].error().severity(), Some(ErrorSeverity::Warning));
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"input": "2015-02-30", "severity": "WARNING"}),
+        );
+        assert_eq!(
+            serde_json::to_value(&errs[0]).unwrap()["extensions"]["severity"],
+            "WARNING",
+        );
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod current_alias {
+    use chrono::{TimeZone as _, Utc};
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+    #[tokio::test]
+    async fn resolves_by_alias() {
+        use crate::{Executor, ScalarValue};
+
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn timestamp<S: ScalarValue>(executor: &Executor<'_, '_, (), S>) -> String {
+                let at = Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap();
+                match executor.current_alias() {
+                    Some("startedAt") => at.to_rfc3339(),
+                    Some(alias) => format!("{}: {}", alias, at.timestamp()),
+                    None => unreachable!("resolving field without alias"),
+                }
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            crate::execute(
+                "{ startedAt: timestamp endedAt: timestamp timestamp }",
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({
+                    "startedAt": "2021-07-08T09:10:11+00:00",
+                    "endedAt": "endedAt: 1625735411",
+                    "timestamp": "timestamp: 1625735411",
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod safelist {
+    use crate::{
+        graphql_object, schema::model::RootNode, EmptyMutation, EmptySubscription, GraphQLError,
+        Variables,
+    };
+
+    struct Schema;
+
+    #[graphql_object]
+    impl Schema {
+        fn a() -> i32 {
+            1
+        }
+    }
+
+    fn schema() -> RootNode<'static, Schema, EmptyMutation, EmptySubscription> {
+        RootNode::new(Schema, EmptyMutation::new(), EmptySubscription::new())
+            .with_safelist(vec![crate::query_hash("{ a }")])
+    }
+
+    #[tokio::test]
+    async fn accepts_safelisted_document() {
+        let schema = schema();
+
+        assert_eq!(
+            crate::execute("{ a }", None, &schema, &Variables::new(), &()).await,
+            Ok((graphql_value!({"a": 1}), vec![])),
+        );
+        assert_eq!(
+            crate::execute_sync("{ a }", None, &schema, &Variables::new(), &()),
+            Ok((graphql_value!({"a": 1}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_document() {
+        let schema = schema();
+
+        assert_eq!(
+            crate::execute("{ __typename }", None, &schema, &Variables::new(), &()).await,
+            Err(GraphQLError::NotSafelisted),
+        );
+        assert_eq!(
+            crate::execute_sync("{ a, }", None, &schema, &Variables::new(), &()),
+            Err(GraphQLError::NotSafelisted),
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_document_before_lexing() {
+        let schema = schema();
+
+        assert_eq!(
+            crate::execute_sync("{ a ?", None, &schema, &Variables::new(), &()),
+            Err(GraphQLError::NotSafelisted),
+        );
+    }
+}
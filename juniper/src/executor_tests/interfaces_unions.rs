@@ -267,3 +267,92 @@ mod union {
         );
     }
 }
+
+#[cfg(feature = "chrono")]
+mod union_of_date_bearing_objects {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+    #[tokio::test]
+    async fn resolves_inline_fragments() {
+        use crate::{GraphQLObject, GraphQLUnion};
+
+        #[derive(GraphQLObject)]
+        struct Event {
+            starts_at: DateTime<Utc>,
+        }
+
+        #[derive(GraphQLObject)]
+        struct Post {
+            published_at: DateTime<Utc>,
+        }
+
+        #[derive(GraphQLObject)]
+        struct User {
+            registered_at: DateTime<Utc>,
+        }
+
+        #[derive(GraphQLUnion)]
+        enum SearchResult {
+            Event(Event),
+            Post(Post),
+            User(User),
+        }
+
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn search() -> Vec<SearchResult> {
+                let at = |d| Utc.with_ymd_and_hms(2021, 7, d, 9, 10, 11).unwrap();
+                vec![
+                    SearchResult::Event(Event { starts_at: at(1) }),
+                    SearchResult::Post(Post {
+                        published_at: at(2),
+                    }),
+                    SearchResult::User(User {
+                        registered_at: at(3),
+                    }),
+                ]
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let doc = r#"{
+            search {
+                __typename
+                ... on Event { startsAt }
+                ... on Post { publishedAt }
+                ... on User { registeredAt }
+            }
+            __type(name: "SearchResult") {
+                possibleTypes { name }
+            }
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({
+                    "search": [
+                        {"__typename": "Event", "startsAt": "2021-07-01T09:10:11+00:00"},
+                        {"__typename": "Post", "publishedAt": "2021-07-02T09:10:11+00:00"},
+                        {"__typename": "User", "registeredAt": "2021-07-03T09:10:11+00:00"},
+                    ],
+                    "__type": {"possibleTypes": [
+                        {"name": "Event"},
+                        {"name": "Post"},
+                        {"name": "User"},
+                    ]},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
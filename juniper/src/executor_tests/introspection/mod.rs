@@ -267,7 +267,7 @@ async fn interface_introspection() {
     );
     assert_eq!(
         type_info.get_field_value("interfaces"),
-        Some(&graphql_value!(None)),
+        Some(&graphql_value!([])),
     );
     assert_eq!(
         type_info.get_field_value("enumValues"),
@@ -535,3 +535,254 @@ async fn scalar_introspection() {
         }),
     );
 }
+
+#[test]
+fn detects_introspection_only_queries() {
+    let schema = RootNode::new(
+        Root,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    for doc in &[
+        "{ __schema { queryType { name } } }",
+        r#"{ __typename t: __type(name: "SampleEnum") { name } }"#,
+        "query A { ...Meta } query B { __typename } fragment Meta on Root { __schema { types { name } } }",
+        "{ ... on Root { __typename } }",
+    ] {
+        assert!(schema.is_introspection_only(doc), "for document: {}", doc);
+    }
+    for doc in &[
+        "{ __typename sampleEnum }",
+        "{ ...Fields } fragment Fields on Root { sampleEnum }",
+        "query A { __typename } query B { sampleEnum }",
+        "mutation { __typename }",
+        "",
+        "{ __typename",
+    ] {
+        assert!(!schema.is_introspection_only(doc), "for document: {}", doc);
+    }
+}
+
+/// Context failing every resolution that uses it.
+struct PanickingContext;
+
+impl PanickingContext {
+    fn value(&self) -> i32 {
+        panic!("Context must not be used for introspection-only queries")
+    }
+}
+
+impl crate::Context for PanickingContext {}
+
+struct ContextRoot;
+
+#[crate::graphql_object(context = PanickingContext)]
+impl ContextRoot {
+    fn value(context: &PanickingContext) -> i32 {
+        context.value()
+    }
+}
+
+#[tokio::test]
+async fn introspection_only_query_skips_context() {
+    let doc = r#"
+    query IntrospectionQuery {
+        __typename
+        __schema { queryType { name } }
+        ...ValueField
+    }
+
+    fragment ValueField on ContextRoot {
+        __type(name: "ContextRoot") { name kind }
+    }
+    "#;
+    let schema = RootNode::new(
+        ContextRoot,
+        EmptyMutation::<PanickingContext>::new(),
+        EmptySubscription::<PanickingContext>::new(),
+    );
+
+    let result = crate::execute(doc, None, &schema, &Variables::new(), &PanickingContext).await;
+
+    assert_eq!(
+        result,
+        Ok((
+            graphql_value!({
+                "__typename": "ContextRoot",
+                "__schema": {"queryType": {"name": "ContextRoot"}},
+                "__type": {"name": "ContextRoot", "kind": "OBJECT"},
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[test]
+fn builtin_introspection_query_skips_context() {
+    let schema = RootNode::new(
+        ContextRoot,
+        EmptyMutation::<PanickingContext>::new(),
+        EmptySubscription::<PanickingContext>::new(),
+    );
+    let doc = crate::introspection::INTROSPECTION_QUERY;
+
+    assert!(schema.is_introspection_only(doc));
+    let (_, errors) =
+        crate::execute_sync(doc, None, &schema, &Variables::new(), &PanickingContext).unwrap();
+    assert_eq!(errors, vec![]);
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_scalars {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+    #[tokio::test]
+    async fn wrapped_scalar_of_type_chain() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn dates() -> Vec<DateTime<Utc>> {
+                vec![]
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let doc = r#"{
+            __type(name: "Root") {
+                fields {
+                    name
+                    type {
+                        kind
+                        name
+                        ofType {
+                            kind
+                            name
+                            ofType {
+                                kind
+                                name
+                                ofType {
+                                    kind
+                                    name
+                                    ofType {
+                                        kind
+                                        name
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "name": "dates",
+                    "type": {
+                        "kind": "NON_NULL",
+                        "name": None,
+                        "ofType": {
+                            "kind": "LIST",
+                            "name": None,
+                            "ofType": {
+                                "kind": "NON_NULL",
+                                "name": None,
+                                "ofType": {
+                                    "kind": "SCALAR",
+                                    "name": "DateTimeUtc",
+                                    "ofType": None,
+                                },
+                            },
+                        },
+                    },
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn deprecation_removal_date() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            #[graphql(
+                deprecated = "Use `createdAt` instead",
+                removal_date = "2022-01-01T00:00:00Z"
+            )]
+            fn created() -> DateTime<Utc> {
+                Utc.timestamp_opt(61, 0).unwrap()
+            }
+
+            fn created_at() -> DateTime<Utc> {
+                Utc.timestamp_opt(61, 0).unwrap()
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let doc = r#"{
+            __type(name: "Root") {
+                fields(includeDeprecated: true) {
+                    name
+                    isDeprecated
+                    deprecationReason
+                    removalDate
+                }
+            }
+        }"#;
+
+        let (res, errors) = crate::execute(doc, None, &schema, &Variables::new(), &())
+            .await
+            .unwrap();
+        assert_eq!(errors, vec![]);
+        assert_eq!(
+            res,
+            graphql_value!({"__type": {"fields": [
+                {
+                    "name": "created",
+                    "isDeprecated": true,
+                    "deprecationReason": "Use `createdAt` instead",
+                    "removalDate": "2022-01-01T00:00:00Z",
+                },
+                {
+                    "name": "createdAt",
+                    "isDeprecated": false,
+                    "deprecationReason": None,
+                    "removalDate": None,
+                },
+            ]}}),
+        );
+
+        let removal_date = res
+            .as_object_value()
+            .and_then(|o| o.get_field_value("__type"))
+            .and_then(|t| t.as_object_value())
+            .and_then(|t| t.get_field_value("fields"))
+            .and_then(|f| f.as_list_value())
+            .and_then(|f| f[0].as_object_value())
+            .and_then(|f| f.get_field_value("removalDate"))
+            .and_then(|d| d.as_string_value())
+            .unwrap();
+        assert_eq!(
+            DateTime::parse_from_rfc3339(removal_date).map(|d| d.with_timezone(&Utc)),
+            Ok(Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap()),
+        );
+    }
+}
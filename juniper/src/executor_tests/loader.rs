@@ -0,0 +1,272 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    graphql_object, BoxFuture, EmptyMutation, EmptySubscription, InputValue, Loader, LoaderContext,
+    Loaders, RootNode, Variables,
+};
+
+type Batches = Arc<Mutex<Vec<Vec<i32>>>>;
+
+struct UserAccounts {
+    batches: Batches,
+}
+
+impl Loader for UserAccounts {
+    type Key = i32;
+    type Value = String;
+
+    fn load_batch(&self, keys: Vec<i32>) -> BoxFuture<'_, HashMap<i32, String>> {
+        self.batches.lock().unwrap().push(keys.clone());
+        Box::pin(async move {
+            keys.into_iter()
+                .filter(|id| *id >= 0)
+                .map(|id| (id, format!("account-{}", id)))
+                .collect()
+        })
+    }
+}
+
+struct Context {
+    loaders: Loaders,
+    batches: Batches,
+}
+
+impl crate::Context for Context {}
+
+impl LoaderContext for Context {
+    fn loaders(&self) -> &Loaders {
+        &self.loaders
+    }
+}
+
+impl Context {
+    fn new() -> Self {
+        let batches = Batches::default();
+        Self {
+            loaders: Loaders::new().with(UserAccounts {
+                batches: batches.clone(),
+            }),
+            batches,
+        }
+    }
+
+    fn batches(&self) -> Vec<Vec<i32>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+struct User {
+    id: i32,
+}
+
+#[graphql_object(context = Context)]
+impl User {
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    async fn account(&self, ctx: &Context) -> Option<String> {
+        ctx.loader::<UserAccounts>().load(self.id).await
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    fn users(ids: Vec<i32>) -> Vec<User> {
+        ids.into_iter().map(|id| User { id }).collect()
+    }
+
+    async fn account(id: i32, ctx: &Context) -> Option<String> {
+        ctx.loader::<UserAccounts>().load(id).await
+    }
+}
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn batches_loads_of_list_elements() {
+    let ctx = Context::new();
+    let ids = (1..=100).collect::<Vec<_>>();
+    let mut vars = Variables::new();
+    vars.insert(
+        "ids".into(),
+        InputValue::list(ids.iter().map(|&id| InputValue::scalar(id)).collect()),
+    );
+
+    let (res, errs) = crate::execute(
+        "query($ids: [Int!]!) { users(ids: $ids) { account } }",
+        None,
+        &schema(),
+        &vars,
+        &ctx,
+    )
+    .await
+    .unwrap();
+    assert_eq!(errs, vec![]);
+
+    let accounts = res
+        .as_object_value()
+        .and_then(|o| o.get_field_value("users"))
+        .and_then(|v| v.as_list_value())
+        .unwrap();
+    assert_eq!(accounts.len(), 100);
+    assert_eq!(accounts[41], graphql_value!({"account": "account-42"}));
+
+    assert_eq!(ctx.batches(), vec![ids]);
+}
+
+#[tokio::test]
+async fn deduplicates_keys_of_sibling_fields() {
+    let ctx = Context::new();
+
+    let doc = r#"{
+        a: account(id: 1)
+        b: account(id: 2)
+        c: account(id: 1)
+        d: account(id: -1)
+        users(ids: [2, 3]) { id account }
+    }"#;
+    assert_eq!(
+        crate::execute(doc, None, &schema(), &Variables::new(), &ctx).await,
+        Ok((
+            graphql_value!({
+                "a": "account-1",
+                "b": "account-2",
+                "c": "account-1",
+                "d": None,
+                "users": [
+                    {"id": 2, "account": "account-2"},
+                    {"id": 3, "account": "account-3"},
+                ],
+            }),
+            vec![],
+        )),
+    );
+
+    assert_eq!(ctx.batches(), vec![vec![1, 2, -1, 3]]);
+}
+
+#[tokio::test]
+async fn caches_loaded_values_per_context() {
+    let ctx = Context::new();
+    let doc = "{ users(ids: [1, 2]) { account } }";
+
+    for _ in 0..2 {
+        assert_eq!(
+            crate::execute(doc, None, &schema(), &Variables::new(), &ctx).await,
+            Ok((
+                graphql_value!({"users": [
+                    {"account": "account-1"},
+                    {"account": "account-2"},
+                ]}),
+                vec![],
+            )),
+        );
+    }
+    assert_eq!(ctx.batches(), vec![vec![1, 2]]);
+
+    let ctx = Context::new();
+    let _ = crate::execute(doc, None, &schema(), &Variables::new(), &ctx).await;
+    assert_eq!(ctx.batches(), vec![vec![1, 2]]);
+}
+
+#[cfg(feature = "chrono")]
+mod repository {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Datelike as _, TimeZone as _, Utc};
+
+    use crate::{
+        graphql_object, EmptyMutation, EmptySubscription, LoaderContext, Loaders, Repository,
+        RootNode, Variables,
+    };
+
+    type Holidays = Arc<dyn Repository<DateTime<Utc>, String>>;
+
+    #[derive(Default)]
+    struct Calendar {
+        calls: Mutex<Vec<Vec<DateTime<Utc>>>>,
+    }
+
+    #[async_trait]
+    impl Repository<DateTime<Utc>, String> for Calendar {
+        async fn load_many(&self, keys: &[DateTime<Utc>]) -> HashMap<DateTime<Utc>, String> {
+            self.calls.lock().unwrap().push(keys.to_vec());
+            keys.iter()
+                .filter(|at| at.month() == 12 && at.day() == 25)
+                .map(|at| (*at, format!("Christmas {}", at.year())))
+                .collect()
+        }
+    }
+
+    struct Context {
+        loaders: Loaders,
+    }
+
+    impl crate::Context for Context {}
+
+    impl LoaderContext for Context {
+        fn loaders(&self) -> &Loaders {
+            &self.loaders
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        async fn holiday(at: DateTime<Utc>, ctx: &Context) -> Option<String> {
+            ctx.loader::<Holidays>().load(at).await
+        }
+    }
+
+    #[tokio::test]
+    async fn batches_lookups_of_trait_object() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+        let calendar = Arc::new(Calendar::default());
+        let holidays: Holidays = calendar.clone();
+        let ctx = Context {
+            loaders: Loaders::new().with(holidays),
+        };
+
+        let doc = r#"{
+            first: holiday(at: "2020-12-25T00:00:00Z")
+            second: holiday(at: "2021-12-25T00:00:00Z")
+            workday: holiday(at: "2021-12-27T00:00:00Z")
+            again: holiday(at: "2020-12-25T00:00:00Z")
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &ctx).await,
+            Ok((
+                graphql_value!({
+                    "first": "Christmas 2020",
+                    "second": "Christmas 2021",
+                    "workday": None,
+                    "again": "Christmas 2020",
+                }),
+                vec![],
+            )),
+        );
+        let at = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+        assert_eq!(
+            *calendar.calls.lock().unwrap(),
+            vec![vec![at(2020, 12, 25), at(2021, 12, 25), at(2021, 12, 27)]],
+        );
+    }
+}
@@ -1,7 +1,9 @@
+mod deadline;
 mod directives;
 mod enums;
 mod executor;
 mod introspection;
+mod loader;
 mod variables;
 
 mod interfaces_unions;
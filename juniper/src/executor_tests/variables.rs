@@ -2,12 +2,12 @@ use crate::{
     ast::InputValue,
     executor::Variables,
     graphql_object, graphql_scalar, graphql_value,
-    parser::SourcePosition,
+    parser::{ParseError, SourcePosition},
     schema::model::RootNode,
     types::scalars::{EmptyMutation, EmptySubscription},
     validation::RuleError,
     value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue},
-    GraphQLError::ValidationError,
+    GraphQLError::{self, ValidationError},
     GraphQLInputObject,
 };
 
@@ -1224,6 +1224,59 @@ mod integers {
         );
     }
 
+    #[tokio::test]
+    async fn rejects_overflowing_literal() {
+        let schema = RootNode::new(
+            TestType,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let query = r#"{ integerInput(value: 2147483648) }"#;
+
+        let error = crate::execute(query, None, &schema, &Variables::new(), &())
+            .await
+            .unwrap_err();
+
+        match error {
+            GraphQLError::ParseError(e) => {
+                assert_eq!(e.item, ParseError::IntOverflow("2147483648"));
+                assert_eq!(
+                    e.item.to_string(),
+                    "Int cannot represent 2147483648, as it's outside of the range \
+                     [-2147483648, 2147483647]",
+                );
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_overflowing_variable() {
+        let schema = RootNode::new(
+            TestType,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let query = r#"query q($var: Int!) { integerInput(value: $var) }"#;
+        let vars = vec![("var".to_owned(), InputValue::scalar(2_147_483_648.0))]
+            .into_iter()
+            .collect();
+
+        let error = crate::execute(query, None, &schema, &vars, &())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            ValidationError(vec![RuleError::new(
+                r#"Variable "$var" got invalid value. Expected "Int", found 2147483648, which is outside of the range [-2147483648, 2147483647]."#,
+                &[SourcePosition::new(8, 0, 8)],
+            )])
+        );
+    }
+
     #[tokio::test]
     async fn does_not_coerce_from_string() {
         let schema = RootNode::new(
@@ -1314,3 +1367,77 @@ mod floats {
         );
     }
 }
+
+mod used_variables {
+    use std::collections::HashSet;
+
+    use crate::{
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        GraphQLError,
+    };
+
+    use super::TestType;
+
+    fn used_variables(query: &str, operation_name: Option<&str>) -> HashSet<String> {
+        let schema = RootNode::new(
+            TestType,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        schema
+            .used_variables(query, operation_name)
+            .expect("Failed to collect used variables")
+    }
+
+    #[test]
+    fn reports_only_referenced_variables() {
+        let query = r#"
+            query Since($since: String) { fieldWithNullableStringInput(input: $since) }
+            query Until($until: String) { fieldWithNullableStringInput(input: $until) }
+        "#;
+
+        assert_eq!(
+            used_variables(query, Some("Since")),
+            vec!["since".to_owned()].into_iter().collect(),
+        );
+    }
+
+    #[test]
+    fn reports_variables_of_fragments_and_directives() {
+        let query = r#"
+            query q($since: String, $skip: Boolean!, $input: String) {
+                ...Fields
+                list(input: ["a", $input]) @skip(if: $skip)
+            }
+
+            fragment Fields on TestType {
+                fieldWithNullableStringInput(input: $since)
+                ...Fields
+            }
+        "#;
+
+        assert_eq!(
+            used_variables(query, None),
+            vec!["since", "skip", "input"]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn fails_on_unknown_operation() {
+        let schema = RootNode::new(
+            TestType,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            schema.used_variables("query Since { list }", Some("Until")),
+            Err(GraphQLError::UnknownOperationName),
+        );
+    }
+}
@@ -1,6 +1,8 @@
 //! Utilities for building HTTP endpoints in a library-agnostic manner
 
 pub mod graphiql;
+#[cfg(feature = "multipart")]
+pub mod multipart;
 pub mod playground;
 
 use serde::{
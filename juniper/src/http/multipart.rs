@@ -0,0 +1,222 @@
+//! Serialization of incrementally delivered execution results in the `multipart/mixed` format
+//! of [GraphQL over HTTP][1].
+//!
+//! The initial response and every [`IncrementalResult`] following it (e.g. the data of a
+//! deferred fragment) are encoded as separate parts of the response body, so each of them may be
+//! flushed to the client as soon as it's ready.
+//!
+//! [1]: https://github.com/graphql/graphql-over-http/blob/main/rfcs/IncrementalDelivery.md
+
+use futures::{future, stream, Stream, StreamExt as _};
+use serde::Serialize;
+
+use crate::{
+    executor::{ExecutionError, PathSegment},
+    value::{DefaultScalarValue, ScalarValue},
+    Value,
+};
+
+use super::GraphQLResponse;
+
+/// Value of the `Content-Type` header of a multipart response.
+pub const CONTENT_TYPE: &str = "multipart/mixed; boundary=\"-\"; deferSpec=20220824";
+
+/// Delimiter and headers preceding the JSON body of every part.
+const PART_HEADER: &str = "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n";
+
+/// JSON body of the last part, following all the incremental results.
+const FINAL_BODY: &str = "{\"hasNext\":false}";
+
+/// Closing delimiter following the last part.
+const TERMINATOR: &str = "\r\n-----\r\n";
+
+/// Result delivered after the initial response, to be merged into its data at the `path`.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(bound = "Value<S>: Serialize, ExecutionError<S>: Serialize")]
+pub struct IncrementalResult<S = DefaultScalarValue> {
+    /// Resolved data.
+    pub data: Value<S>,
+
+    /// Path in the response of the object the `data` is merged into.
+    pub path: Vec<PathSegment>,
+
+    /// Errors raised while resolving the `data`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ExecutionError<S>>,
+}
+
+/// JSON body of the first part.
+#[derive(Serialize)]
+#[serde(bound = "GraphQLResponse<'a, S>: Serialize")]
+struct InitialPart<'r, 'a, S> {
+    #[serde(flatten)]
+    response: &'r GraphQLResponse<'a, S>,
+    #[serde(rename = "hasNext")]
+    has_next: bool,
+}
+
+/// JSON body of the parts following the first one.
+#[derive(Serialize)]
+#[serde(bound = "IncrementalResult<S>: Serialize")]
+struct SubsequentPart<'r, S> {
+    incremental: [&'r IncrementalResult<S>; 1],
+    #[serde(rename = "hasNext")]
+    has_next: bool,
+}
+
+/// Encodes the `initial` response and the `incremental` results following it as the parts of a
+/// multipart response body.
+///
+/// Every yielded chunk is a complete part. The `initial` one is yielded right away, without
+/// waiting for any `incremental` result, and the last one is a `{"hasNext":false}` part holding
+/// the closing delimiter too.
+///
+/// # Errors
+///
+/// Yields an error if serializing the `initial` response or any of the `incremental` results to
+/// JSON fails (e.g. because of a custom [`ScalarValue`] failing to serialize).
+pub fn encode<'a, S, St>(
+    initial: GraphQLResponse<'a, S>,
+    incremental: St,
+) -> impl Stream<Item = serde_json::Result<String>> + 'a
+where
+    S: ScalarValue + 'a,
+    St: Stream<Item = IncrementalResult<S>> + 'a,
+{
+    let initial = to_part(&InitialPart {
+        response: &initial,
+        has_next: true,
+    });
+    let incremental = incremental.map(|result| {
+        to_part(&SubsequentPart {
+            incremental: [&result],
+            has_next: true,
+        })
+    });
+    let last = format!("{}{}{}", PART_HEADER, FINAL_BODY, TERMINATOR);
+
+    stream::once(future::ready(initial))
+        .chain(incremental)
+        .chain(stream::once(future::ready(Ok(last))))
+}
+
+fn to_part<T: Serialize>(body: &T) -> serde_json::Result<String> {
+    Ok(format!("{}{}", PART_HEADER, serde_json::to_string(body)?))
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::NaiveDate;
+    use futures::{stream, FutureExt as _, StreamExt as _};
+
+    use crate::{
+        graphql_object, http::GraphQLResponse, EmptyMutation, EmptySubscription, RootNode,
+        Variables,
+    };
+
+    use super::{encode, IncrementalResult};
+
+    struct Event;
+
+    #[graphql_object]
+    impl Event {
+        fn name() -> &'static str {
+            "Release"
+        }
+
+        fn date() -> NaiveDate {
+            NaiveDate::from_ymd_opt(2021, 7, 8).unwrap()
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn event() -> Event {
+            Event
+        }
+    }
+
+    #[tokio::test]
+    async fn deferred_date_arrives_in_later_part() {
+        let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::new());
+        let vars = Variables::new();
+        let execute = |doc| crate::execute(doc, None, &schema, &vars, &());
+
+        let initial = GraphQLResponse::from_result(execute("{ event { name } }").await);
+        let (data, errors) = execute("{ event { date } }").await.unwrap();
+        let deferred = IncrementalResult {
+            data: data
+                .as_object_value()
+                .and_then(|o| o.get_field_value("event"))
+                .cloned()
+                .unwrap(),
+            path: vec!["event".into()],
+            errors,
+        };
+
+        let parts = encode(initial, stream::iter(vec![deferred]))
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            parts,
+            vec![
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n\
+                 {\"data\":{\"event\":{\"name\":\"Release\"}},\"hasNext\":true}",
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n\
+                 {\"incremental\":[{\"data\":{\"date\":\"2021-07-08\"},\"path\":[\"event\"]}],\
+                 \"hasNext\":true}",
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n\
+                 {\"hasNext\":false}\r\n-----\r\n",
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn yields_initial_part_before_incremental_results() {
+        let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::new());
+        let vars = Variables::new();
+        let initial = GraphQLResponse::from_result(
+            crate::execute("{ event { name } }", None, &schema, &vars, &()).await,
+        );
+
+        let mut parts = Box::pin(encode(initial, stream::pending::<IncrementalResult>()));
+
+        assert_eq!(
+            parts.next().now_or_never().flatten().map(Result::unwrap),
+            Some(
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n\
+                 {\"data\":{\"event\":{\"name\":\"Release\"}},\"hasNext\":true}"
+                    .to_owned(),
+            ),
+        );
+        assert!(parts.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn final_part_without_incremental_results() {
+        let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::new());
+        let vars = Variables::new();
+        let initial = GraphQLResponse::from_result(
+            crate::execute("{ event { date } }", None, &schema, &vars, &()).await,
+        );
+
+        let parts = encode(initial, stream::empty::<IncrementalResult>())
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            parts,
+            vec![
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n\
+                 {\"data\":{\"event\":{\"date\":\"2021-07-08\"}},\"hasNext\":true}",
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n\
+                 {\"hasNext\":false}\r\n-----\r\n",
+            ],
+        );
+    }
+}
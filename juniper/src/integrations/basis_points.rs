@@ -0,0 +1,200 @@
+//! GraphQL support for percentages represented in [basis points][1].
+//!
+//! A basis point is a hundredth of a percent, so integer amounts of them represent percentages
+//! (e.g. interest rates) precisely, without any floating point rounding on either side.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Basis_point
+
+use std::fmt;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// Number of basis points in a whole (a fraction of `1.0`).
+const PER_WHOLE: f64 = 10_000.0;
+
+/// Percentage in basis points, e.g. `100` for 1%.
+///
+/// Bounded by [`BasisPoints::MIN`] and [`BasisPoints::MAX`] (±10000%), so values which are
+/// certainly erroneous are rejected.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BasisPoints(i32);
+
+impl BasisPoints {
+    /// The smallest allowed value, being -10000%.
+    pub const MIN: Self = Self(-1_000_000);
+
+    /// The largest allowed value, being 10000%.
+    pub const MAX: Self = Self(1_000_000);
+
+    /// Creates a new [`BasisPoints`] value of the given number of `basis_points`.
+    ///
+    /// Returns [`None`] if the `basis_points` are out of the [`BasisPoints::MIN`]..=
+    /// [`BasisPoints::MAX`] range.
+    pub fn new(basis_points: i32) -> Option<Self> {
+        if (Self::MIN.0..=Self::MAX.0).contains(&basis_points) {
+            Some(Self(basis_points))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the given decimal `fraction` (e.g. `0.01` for 1%) into [`BasisPoints`], rounding
+    /// to the nearest basis point.
+    ///
+    /// Returns [`None`] if the `fraction` is not finite or is out of the [`BasisPoints::MIN`]..=
+    /// [`BasisPoints::MAX`] range.
+    pub fn from_fraction(fraction: f64) -> Option<Self> {
+        let basis_points = (fraction * PER_WHOLE).round();
+        if (f64::from(Self::MIN.0)..=f64::from(Self::MAX.0)).contains(&basis_points) {
+            Some(Self(basis_points as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of basis points of this value.
+    pub fn get(self) -> i32 {
+        self.0
+    }
+
+    /// Converts this value into a decimal fraction, e.g. `0.01` for 1%.
+    pub fn to_fraction(self) -> f64 {
+        f64::from(self.0) / PER_WHOLE
+    }
+}
+
+impl fmt::Display for BasisPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}bp", self.0)
+    }
+}
+
+#[crate::graphql_scalar(description = "Percentage in basis points (hundredths of a percent)")]
+impl<S> GraphQLScalar for BasisPoints
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0)
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<BasisPoints> {
+        v.as_int_value().and_then(BasisPoints::new)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <i32 as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::BasisPoints;
+
+    fn basis_points_input_test(raw: i32, expected: Option<i32>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw);
+
+        let parsed: Option<BasisPoints> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed.map(BasisPoints::get), expected, "for input: {}", raw);
+    }
+
+    #[test]
+    fn from_input_value() {
+        basis_points_input_test(100, Some(100));
+        basis_points_input_test(0, Some(0));
+        basis_points_input_test(-25, Some(-25));
+        basis_points_input_test(1_000_000, Some(1_000_000));
+        basis_points_input_test(-1_000_000, Some(-1_000_000));
+    }
+
+    #[test]
+    fn out_of_range_from_input_value() {
+        basis_points_input_test(1_000_001, None);
+        basis_points_input_test(-1_000_001, None);
+        basis_points_input_test(i32::MAX, None);
+    }
+
+    #[test]
+    fn non_int_from_input_value() {
+        let input = <InputValue<DefaultScalarValue>>::scalar("100");
+
+        let parsed: Option<BasisPoints> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, None);
+    }
+
+    #[test]
+    fn fraction_conversions() {
+        assert_eq!(BasisPoints::new(100).unwrap().to_fraction(), 0.01);
+        assert_eq!(BasisPoints::new(-2_550).unwrap().to_fraction(), -0.255);
+
+        assert_eq!(BasisPoints::from_fraction(0.01), BasisPoints::new(100));
+        assert_eq!(BasisPoints::from_fraction(0.000_16), BasisPoints::new(2));
+        assert_eq!(BasisPoints::from_fraction(-1.5), BasisPoints::new(-15_000));
+        assert_eq!(BasisPoints::from_fraction(100.0), Some(BasisPoints::MAX));
+        assert_eq!(BasisPoints::from_fraction(100.01), None);
+        assert_eq!(BasisPoints::from_fraction(f64::NAN), None);
+        assert_eq!(BasisPoints::from_fraction(f64::INFINITY), None);
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::BasisPoints;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn doubled(rate: BasisPoints) -> Option<BasisPoints> {
+            BasisPoints::new(rate.get() * 2)
+        }
+    }
+
+    #[tokio::test]
+    async fn serializes_as_int() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                "{ doubled(rate: 100) }",
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"doubled": 200}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_out_of_range_literals() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert!(matches!(
+            crate::execute(
+                "{ doubled(rate: 1000001) }",
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Err(crate::GraphQLError::ValidationError(_)),
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! GraphQL support for 64-bit integers overflowing the built-in `Int` scalar, e.g. database
+//! `BIGINT` identifiers or counters.
+//!
+//! Values are serialized as strings (e.g. `"9007199254740993"`), as JSON numbers beyond 2^53 lose
+//! precision when parsed as IEEE 754 doubles (e.g. by JavaScript clients). Both string and integer
+//! literals are accepted as input.
+
+use crate::{
+    parser::{ParseError, ScalarToken, Token},
+    value::ParseScalarResult,
+    Value,
+};
+
+/// Largest integer a JSON number passed in variables may represent exactly.
+///
+/// Variables' numbers not fitting into `Int` are deserialized as floats, so larger ones may have
+/// already lost their precision, and must be passed as strings instead.
+const MAX_SAFE_FLOAT: f64 = 9_007_199_254_740_991.0;
+
+/// Signed 64-bit integer, serialized as a string.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BigInt(pub i64);
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        Self(n)
+    }
+}
+
+impl From<BigInt> for i64 {
+    fn from(n: BigInt) -> Self {
+        n.0
+    }
+}
+
+#[crate::graphql_scalar(description = "Signed 64-bit integer, serialized as a string")]
+impl<S> GraphQLScalar for BigInt
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<BigInt> {
+        if let Some(s) = v.as_string_value() {
+            return s.parse().ok().map(BigInt);
+        }
+        if let Some(i) = v.as_int_value() {
+            return Some(BigInt(i.into()));
+        }
+        v.as_float_value()
+            .filter(|f| f.fract() == 0.0 && f.abs() <= MAX_SAFE_FLOAT)
+            .map(|f| BigInt(f as i64))
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        // Integer literals are kept as strings, so the ones not fitting into `Int` aren't rejected
+        // by the parser, but are coerced (or fail to) by `from_input_value()` instead.
+        match value {
+            ScalarToken::String(value) | ScalarToken::Int(value) => Ok(S::from(value.to_owned())),
+            _ => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::BigInt;
+
+    fn big_int_input_test(input: InputValue<DefaultScalarValue>, expected: Option<i64>) {
+        let parsed: Option<BigInt> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, expected.map(BigInt), "for input: {:?}", input);
+    }
+
+    #[test]
+    fn string_from_input_value() {
+        for (raw, expected) in &[
+            ("0", 0),
+            ("2147483648", i64::from(i32::MAX) + 1),
+            ("-2147483649", i64::from(i32::MIN) - 1),
+            ("-42", -42),
+            ("9223372036854775807", i64::MAX),
+            ("-9223372036854775808", i64::MIN),
+        ] {
+            big_int_input_test(InputValue::scalar(raw.to_string()), Some(*expected));
+        }
+    }
+
+    #[test]
+    fn number_from_input_value() {
+        big_int_input_test(InputValue::scalar(i32::MAX), Some(i32::MAX.into()));
+        big_int_input_test(InputValue::scalar(-7), Some(-7));
+        big_int_input_test(InputValue::scalar(2_147_483_648.0), Some(2_147_483_648));
+        big_int_input_test(
+            InputValue::scalar(9_007_199_254_740_991.0),
+            Some(9_007_199_254_740_991),
+        );
+    }
+
+    #[test]
+    fn malformed_from_input_value() {
+        for raw in &[
+            "",
+            "1.5",
+            "1e3",
+            "0x10",
+            "9223372036854775808",
+            "-9223372036854775809",
+        ] {
+            big_int_input_test(InputValue::scalar(raw.to_string()), None);
+        }
+
+        big_int_input_test(InputValue::scalar(1.5), None);
+        big_int_input_test(InputValue::scalar(9_007_199_254_740_992.0), None);
+        big_int_input_test(InputValue::scalar(true), None);
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        InputValue,
+    };
+
+    use super::BigInt;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(n: BigInt) -> BigInt {
+            n
+        }
+
+        fn next(n: BigInt) -> Option<BigInt> {
+            n.0.checked_add(1).map(BigInt)
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips_past_int() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{
+                    literal: echo(n: 2147483648)
+                    negative: echo(n: "-9007199254740993")
+                    next(n: 2147483647)
+                }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({
+                    "literal": "2147483648",
+                    "negative": "-9007199254740993",
+                    "next": "2147483648",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_variables() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let mut vars = Variables::new();
+        vars.insert("n".to_owned(), InputValue::scalar("-2147483649"));
+
+        assert_eq!(
+            crate::execute(
+                "query($n: BigInt!) { echo(n: $n) }",
+                None,
+                &schema,
+                &vars,
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"echo": "-2147483649"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_overflow() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        for doc in &[
+            r#"{ echo(n: 9223372036854775808) }"#,
+            r#"{ echo(n: "-9223372036854775809") }"#,
+        ] {
+            assert!(
+                matches!(
+                    crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+                    Err(crate::GraphQLError::ValidationError(_)),
+                ),
+                "for document: {}",
+                doc,
+            );
+        }
+    }
+}
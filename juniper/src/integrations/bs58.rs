@@ -0,0 +1,139 @@
+//! GraphQL support for binary identifiers represented in [base58][1] encoding.
+//!
+//! Uses the Bitcoin alphabet, which omits the ambiguous `0`, `O`, `I` and `l` characters.
+//!
+//! [1]: https://datatracker.ietf.org/doc/html/draft-msporny-base58-03
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// Binary identifier (e.g. a hash or a public key) represented in base58 encoding.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Base58(pub Vec<u8>);
+
+impl Base58 {
+    /// Decodes the given base58 `encoded` string.
+    ///
+    /// Returns [`None`] if the string contains characters outside of the base58 alphabet.
+    pub fn decode(encoded: &str) -> Option<Self> {
+        bs58::decode(encoded).into_vec().ok().map(Self)
+    }
+
+    /// Encodes these bytes into a base58 string.
+    pub fn encode(&self) -> String {
+        bs58::encode(&self.0).into_string()
+    }
+}
+
+#[crate::graphql_scalar(description = "Binary identifier in base58 encoding")]
+impl<S> GraphQLScalar for Base58
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.encode())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Base58> {
+        v.as_string_value().and_then(Base58::decode)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+
+    use super::Base58;
+
+    fn base58_from_input_value(raw: &str) -> Option<Base58> {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        FromInputValue::from_input_value(&input)
+    }
+
+    #[test]
+    fn round_trips() {
+        let bytes = Base58(b"Hello World!".to_vec());
+
+        assert_eq!(
+            ToInputValue::<DefaultScalarValue>::to_input_value(&bytes),
+            InputValue::scalar("2NEpo7TZRRrLZSi2U"),
+        );
+        assert_eq!(base58_from_input_value("2NEpo7TZRRrLZSi2U"), Some(bytes));
+    }
+
+    #[test]
+    fn keeps_leading_zeros() {
+        let bytes = Base58(vec![0, 0, 1, 2]);
+
+        assert_eq!(bytes.encode(), "115T");
+        assert_eq!(base58_from_input_value("115T"), Some(bytes));
+    }
+
+    #[test]
+    fn rejects_ambiguous_characters() {
+        for raw in &[
+            "2NEpo7TZRR0LZSi2U",
+            "2NEpo7TZRROLZSi2U",
+            "2NEpo7TZRRlLZSi2U",
+            "I",
+        ] {
+            assert_eq!(base58_from_input_value(raw), None, "for input: {}", raw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::Base58;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn byte_len(id: Base58) -> i32 {
+            id.0.len() as i32
+        }
+    }
+
+    #[tokio::test]
+    async fn decodes_literal() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{ byteLen(id: "2NEpo7TZRRrLZSi2U") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"byteLen": 12}), vec![])),
+        );
+        assert!(matches!(
+            crate::execute(
+                r#"{ byteLen(id: "0OIl") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Err(crate::GraphQLError::ValidationError(_)),
+        ));
+    }
+}
@@ -43,7 +43,7 @@ where
 
     fn from_input_value(v: &InputValue) -> Option<UtcDateTime> {
         v.as_string_value()
-            .and_then(|s| (s.parse::<DateTime<Utc>>().ok()))
+            .and_then(|s| s.parse::<DateTime<Utc>>().ok())
             .map(Self::from_chrono)
     }
 
@@ -0,0 +1,206 @@
+//! GraphQL support for sizes of data in bytes, represented as human-readable strings.
+//!
+//! Sizes are serialized using binary units (e.g. `"1.5 GiB"`), and are accepted either as a raw
+//! number of bytes (e.g. `"1500000"`) or with a binary (`KiB`, `MiB`, ...) or decimal (`kB`,
+//! `MB`, ...) unit suffix.
+
+use std::fmt;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// Binary units used for serializing, from the largest one.
+const BINARY_UNITS: &[(&str, u64)] = &[
+    ("EiB", 1 << 60),
+    ("PiB", 1 << 50),
+    ("TiB", 1 << 40),
+    ("GiB", 1 << 30),
+    ("MiB", 1 << 20),
+    ("KiB", 1 << 10),
+];
+
+/// Decimal units accepted besides the binary ones.
+const DECIMAL_UNITS: &[(&str, u64)] = &[
+    ("EB", 1_000_000_000_000_000_000),
+    ("PB", 1_000_000_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("GB", 1_000_000_000),
+    ("MB", 1_000_000),
+    ("KB", 1_000),
+];
+
+/// Size of data in bytes, e.g. of a file.
+///
+/// Serialized with the largest binary unit fitting the size, rounded to at most two decimal
+/// places (e.g. `"1.5 GiB"`), so the serialized value may lose precision.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    /// Parses the given `size`, either being a raw number of bytes (e.g. `"1500000"`) or having a
+    /// binary or decimal unit suffix (e.g. `"1.5GiB"`, `"2 MB"`). Unit suffixes are
+    /// case-insensitive.
+    ///
+    /// Returns [`None`] if the `size` is malformed, negative, or doesn't fit into [`u64`].
+    pub fn parse(size: &str) -> Option<Self> {
+        let size = size.trim();
+        let number_len = size
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(size.len());
+        let (number, unit) = size.split_at(number_len);
+        let unit = unit.trim_start();
+        let multiplier = if unit.is_empty() || unit.eq_ignore_ascii_case("B") {
+            1
+        } else {
+            let (_, multiplier) = BINARY_UNITS
+                .iter()
+                .chain(DECIMAL_UNITS)
+                .find(|(name, _)| name.eq_ignore_ascii_case(unit))?;
+            *multiplier
+        };
+
+        if !number.contains('.') {
+            let bytes = number.parse::<u64>().ok()?;
+            return bytes.checked_mul(multiplier).map(Self);
+        }
+        let bytes = (number.parse::<f64>().ok()? * multiplier as f64).round();
+        if bytes.is_finite() && bytes < u64::MAX as f64 {
+            Some(Self(bytes as u64))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (unit, scale) = match BINARY_UNITS.iter().find(|(_, scale)| self.0 >= *scale) {
+            Some(unit) => *unit,
+            None => return write!(f, "{} B", self.0),
+        };
+
+        let value = format!("{:.2}", self.0 as f64 / scale as f64);
+        let value = value.trim_end_matches('0').trim_end_matches('.');
+        write!(f, "{} {}", value, unit)
+    }
+}
+
+#[crate::graphql_scalar(description = "Size of data in bytes, e.g. `1.5 GiB`")]
+impl<S> GraphQLScalar for ByteSize
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<ByteSize> {
+        v.as_string_value().and_then(ByteSize::parse)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::ByteSize;
+
+    fn byte_size_input_test(raw: &str, expected: Option<u64>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<ByteSize> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, expected.map(ByteSize), "for input: {}", raw);
+    }
+
+    #[test]
+    fn binary_from_input_value() {
+        byte_size_input_test("1 KiB", Some(1024));
+        byte_size_input_test("1.5GiB", Some(1_610_612_736));
+        byte_size_input_test("2 mib", Some(2_097_152));
+    }
+
+    #[test]
+    fn decimal_from_input_value() {
+        byte_size_input_test("1 kB", Some(1000));
+        byte_size_input_test("1.5 GB", Some(1_500_000_000));
+    }
+
+    #[test]
+    fn raw_bytes_from_input_value() {
+        byte_size_input_test("1500000", Some(1_500_000));
+        byte_size_input_test("0", Some(0));
+        byte_size_input_test("42 B", Some(42));
+    }
+
+    #[test]
+    fn malformed_from_input_value() {
+        for raw in &[
+            "big",
+            "",
+            "KiB",
+            "-1 KiB",
+            "1.5.0 MB",
+            "1 KiBs",
+            "1 XB",
+            "20 EiB",
+            "18446744073709551616",
+        ] {
+            byte_size_input_test(raw, None);
+        }
+    }
+
+    #[test]
+    fn formats_with_binary_units() {
+        assert_eq!(ByteSize(0).to_string(), "0 B");
+        assert_eq!(ByteSize(1023).to_string(), "1023 B");
+        assert_eq!(ByteSize(1024).to_string(), "1 KiB");
+        assert_eq!(ByteSize(1_610_612_736).to_string(), "1.5 GiB");
+        assert_eq!(ByteSize(1_500_000).to_string(), "1.43 MiB");
+        assert_eq!(ByteSize(u64::MAX).to_string(), "16 EiB");
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::ByteSize;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn double(size: ByteSize) -> ByteSize {
+            ByteSize(size.0 * 2)
+        }
+    }
+
+    #[tokio::test]
+    async fn serializes_human_readable() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{ kib: double(size: "512") mib: double(size: "768KiB") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"kib": "1 KiB", "mib": "1.5 MiB"}), vec![])),
+        );
+    }
+}
@@ -10,18 +10,37 @@
 | `NaiveDateTime`         | float (unix timestamp) | JSON numbers (i.e. IEEE doubles) are not  |
 |                         |                        | precise enough for nanoseconds.           |
 |                         |                        | Values will be truncated to microsecond   |
-|                         |                        | resolution.                               |
-| `NaiveTime`             | H:M:S                  | Optional. Use the `scalar-naivetime`      |
+|                         |                        | resolution. Use the                       |
+|                         |                        | `scalar-naivedatetime-rfc3339` feature    |
+|                         |                        | for the lossless RFC3339 string without   |
+|                         |                        | offset instead.                           |
+| `NaiveTime`             | H:M:S[.f]              | Optional. Use the `scalar-naivetime`      |
 |                         |                        | feature.                                  |
+| `Duration`              | ISO 8601 duration      | Optional. Use the `scalar-duration`       |
+|                         |                        | feature.                                  |
+| `LocalizedDateTime`     | localized string       | Output only, formatted according to the   |
+|                         |                        | `Locale` of the context.                  |
 
 */
 #![allow(clippy::needless_lifetimes)]
+use std::ops::RangeInclusive;
+
 use chrono::prelude::*;
+#[cfg(feature = "scalar-duration")]
+use chrono::Duration;
 
 use crate::{
+    ast::Selection,
+    executor::{ErrorSeverity, ExecutionResult, Executor, FieldError, Registry},
     parser::{ParseError, ScalarToken, Token},
-    value::{ParseScalarResult, ParseScalarValue},
-    Value,
+    schema::meta::MetaType,
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{GraphQLType, GraphQLValue},
+        marker::IsOutputType,
+    },
+    value::{ParseScalarResult, ScalarValue},
+    BoxFuture, Value,
 };
 
 #[doc(hidden)]
@@ -61,7 +80,7 @@ where
 
     fn from_input_value(v: &InputValue) -> Option<DateTime<Utc>> {
         v.as_string_value()
-            .and_then(|s| (s.parse::<DateTime<Utc>>().ok()))
+            .and_then(|s| s.parse::<DateTime<Utc>>().ok())
     }
 
     fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
@@ -73,6 +92,88 @@ where
     }
 }
 
+/// Creates a check warning about resolved `DateTimeUtc` values falling outside of the sane range
+/// of `years`, which often indicates a serialization bug
+///
+/// Register it for the `DateTimeUtc` type via [`RootNode::with_output_check`]. The offending
+/// value is kept in the response, and a warning (see [`ErrorSeverity::Warning`]) is reported for
+/// it.
+///
+/// ```rust
+/// # use juniper::{
+/// #     graphql_object, integrations::chrono::sane_years_check, EmptyMutation,
+/// #     EmptySubscription, RootNode,
+/// # };
+/// # struct Query;
+/// # #[graphql_object]
+/// # impl Query {
+/// #     fn answer() -> i32 { 42 }
+/// # }
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+///     .with_output_check("DateTimeUtc", sane_years_check(1900..=2100));
+/// ```
+///
+/// [`RootNode::with_output_check`]: crate::RootNode::with_output_check
+pub fn sane_years_check<S>(
+    years: RangeInclusive<i32>,
+) -> impl Fn(&Value<S>) -> Option<FieldError<S>> + Send + Sync + 'static
+where
+    S: ScalarValue,
+{
+    move |v| {
+        let raw = v.as_scalar()?.as_str()?;
+        let date = raw.parse::<DateTime<Utc>>().ok()?;
+        if years.contains(&date.year()) {
+            return None;
+        }
+        Some(
+            FieldError::new(
+                format!(
+                    "Date is outside of the sane range of years {}-{}",
+                    years.start(),
+                    years.end(),
+                ),
+                graphql_value!({"code": "DATE_OUT_OF_RANGE", "date": raw}),
+            )
+            .with_severity(ErrorSeverity::Warning),
+        )
+    }
+}
+
+/// Parses the given `input` of a chrono scalar with the given `parse` function, explaining what's
+/// wrong with it on failure.
+///
+/// The chrono scalars can only report that an input value is invalid, so resolvers parsing
+/// dates out of raw strings themselves may use this to let clients know the reason: the message
+/// of the [`chrono::ParseError`] (e.g. `input is out of range`) ends up in the `details`
+/// extension of the returned [`FieldError`].
+///
+/// ```rust
+/// # use chrono::NaiveDate;
+/// # use juniper::{graphql_object, integrations::chrono::parse_input, FieldResult};
+/// # struct Query;
+/// #[graphql_object]
+/// impl Query {
+///     fn weekday(date: String) -> FieldResult<String> {
+///         let date = parse_input(&date, |s| NaiveDate::parse_from_str(s, "%Y-%m-%d"))?;
+///         Ok(date.format("%A").to_string())
+///     }
+/// }
+/// ```
+pub fn parse_input<T, S, F>(input: &str, parse: F) -> Result<T, FieldError<S>>
+where
+    S: ScalarValue,
+    F: FnOnce(&str) -> chrono::ParseResult<T>,
+{
+    parse(input).map_err(|e| {
+        let details = e.to_string();
+        FieldError::new(
+            format!("Invalid date or time `{}`: {}", input, details),
+            graphql_value!({"code": "INVALID_DATE_TIME", "input": input, "details": details}),
+        )
+    })
+}
+
 // Don't use `Date` as the docs say:
 // "[Date] should be considered ambiguous at best, due to the "
 // inherent lack of precision required for the time zone resolution.
@@ -108,12 +209,43 @@ where
     S: ScalarValue,
 {
     fn resolve(&self) -> Value {
-        Value::scalar(self.format("%H:%M:%S").to_string())
+        Value::scalar(self.format("%H:%M:%S%.f").to_string())
     }
 
     fn from_input_value(v: &InputValue) -> Option<NaiveTime> {
-        v.as_string_value()
-            .and_then(|s| NaiveTime::parse_from_str(s, "%H:%M:%S").ok())
+        v.as_string_value().and_then(|s| {
+            NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+                .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M:%S"))
+                .ok()
+        })
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        if let ScalarToken::String(value) = value {
+            Ok(S::from(value.to_owned()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+}
+
+/// Format of the [`NaiveDateTime`] scalar with the `scalar-naivedatetime-rfc3339` feature, being
+/// an RFC3339 date and time without any offset.
+#[cfg(feature = "scalar-naivedatetime-rfc3339")]
+static NAIVE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+#[cfg(feature = "scalar-duration")]
+#[crate::graphql_scalar(name = "Duration", description = "Duration in ISO 8601 format")]
+impl<S> GraphQLScalar for Duration
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(format_iso_duration(self))
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Duration> {
+        v.as_string_value().and_then(parse_iso_duration)
     }
 
     fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
@@ -125,8 +257,120 @@ where
     }
 }
 
+/// Formats the given `duration` in ISO 8601 format, using days as the largest unit (e.g.
+/// `P1DT2H`, `-PT1.5S`).
+#[cfg(feature = "scalar-duration")]
+fn format_iso_duration(duration: &Duration) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    let duration = if *duration < Duration::zero() {
+        out.push('-');
+        -*duration
+    } else {
+        *duration
+    };
+    let secs = duration.num_seconds();
+    let nanos = (duration - Duration::seconds(secs))
+        .num_nanoseconds()
+        .unwrap_or_default();
+    let (days, hours, minutes, seconds) = (
+        secs / 86_400,
+        secs % 86_400 / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+    );
+
+    out.push('P');
+    if days > 0 {
+        let _ = write!(out, "{}D", days);
+    }
+    if days == 0 || hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 {
+        out.push('T');
+        if hours > 0 {
+            let _ = write!(out, "{}H", hours);
+        }
+        if minutes > 0 {
+            let _ = write!(out, "{}M", minutes);
+        }
+        if seconds > 0 || nanos > 0 || (hours == 0 && minutes == 0) {
+            let _ = write!(out, "{}", seconds);
+            if nanos > 0 {
+                let fraction = format!("{:09}", nanos);
+                let _ = write!(out, ".{}", fraction.trim_end_matches('0'));
+            }
+            out.push('S');
+        }
+    }
+    out
+}
+
+/// Parses an ISO 8601 duration consisting of weeks, days, hours, minutes and (possibly
+/// fractional) seconds, optionally negated with a leading `-`.
+///
+/// Returns [`None`] for malformed durations, durations having years or months (as these have no
+/// exact length), and durations out of the [`Duration`] range.
+#[cfg(feature = "scalar-duration")]
+fn parse_iso_duration(raw: &str) -> Option<Duration> {
+    /// Parses the components of a date or time `part`, having any of the `units` designators in
+    /// their order, into the number of seconds and nanoseconds.
+    fn parse_part(mut part: &str, units: &[(char, i64)]) -> Option<(i64, i64)> {
+        let (mut secs, mut nanos) = (0_i64, 0);
+        let mut units = units.iter();
+        while !part.is_empty() {
+            let end = part.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+            let (value, designator) = (&part[..end], part[end..].chars().next()?);
+            let unit = units.find(|(d, _)| *d == designator)?.1;
+            part = &part[end + designator.len_utf8()..];
+
+            let (whole, fraction) = match value.split_once('.') {
+                Some((whole, fraction)) if unit == 1 && part.is_empty() => (whole, Some(fraction)),
+                Some(_) => return None,
+                None => (value, None),
+            };
+            if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            secs = secs.checked_add(whole.parse::<i64>().ok()?.checked_mul(unit)?)?;
+            if let Some(fraction) = fraction {
+                if fraction.is_empty()
+                    || fraction.len() > 9
+                    || !fraction.bytes().all(|b| b.is_ascii_digit())
+                {
+                    return None;
+                }
+                nanos = format!("{:0<9}", fraction).parse().ok()?;
+            }
+        }
+        Some((secs, nanos))
+    }
+
+    let (is_negative, raw) = match raw.strip_prefix('-') {
+        Some(raw) => (true, raw),
+        None => (false, raw),
+    };
+    let raw = raw.strip_prefix('P')?;
+    let (date, time) = match raw.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None if raw.is_empty() => return None,
+        None => (raw, ""),
+    };
+
+    let (date_secs, _) = parse_part(date, &[('W', 604_800), ('D', 86_400)])?;
+    let (time_secs, nanos) = parse_part(time, &[('H', 3_600), ('M', 60), ('S', 1)])?;
+    let secs = date_secs.checked_add(time_secs)?;
+    if secs > i64::MAX / 1_000 {
+        return None;
+    }
+    let duration = Duration::seconds(secs).checked_add(&Duration::nanoseconds(nanos))?;
+
+    Some(if is_negative { -duration } else { duration })
+}
+
 // JSON numbers (i.e. IEEE doubles) are not precise enough for nanosecond
 // datetimes. Values will be truncated to microsecond resolution.
+#[cfg(not(feature = "scalar-naivedatetime-rfc3339"))]
 #[crate::graphql_scalar(description = "NaiveDateTime")]
 impl<S> GraphQLScalar for NaiveDateTime
 where
@@ -138,18 +382,417 @@ where
 
     fn from_input_value(v: &InputValue) -> Option<NaiveDateTime> {
         v.as_float_value()
-            .and_then(|f| NaiveDateTime::from_timestamp_opt(f as i64, 0))
+            .map(|f| f as i64)
+            .or_else(|| v.as_int_value().map(i64::from))
+            .and_then(|t| NaiveDateTime::from_timestamp_opt(t, 0))
     }
 
     fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
-        <f64 as ParseScalarValue<S>>::from_str(value)
+        // Integer literals may exceed the `i32` range of `Int`s.
+        match value {
+            ScalarToken::Int(v) => v
+                .parse()
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map(|t: i64| (t as f64).into()),
+            _ => <f64 as crate::value::ParseScalarValue<S>>::from_str(value),
+        }
+    }
+}
+
+#[cfg(feature = "scalar-naivedatetime-rfc3339")]
+#[crate::graphql_scalar(description = "NaiveDateTime")]
+impl<S> GraphQLScalar for NaiveDateTime
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.format(NAIVE_DATE_TIME_FORMAT).to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<NaiveDateTime> {
+        v.as_string_value()
+            .and_then(|s| NaiveDateTime::parse_from_str(s, NAIVE_DATE_TIME_FORMAT).ok())
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        if let ScalarToken::String(value) = value {
+            Ok(S::from(value.to_owned()))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+}
+
+/// Locale to format [`LocalizedDateTime`]s according to
+///
+/// Acts as a context, so provide it for [`LocalizedDateTime`] fields by
+/// implementing [`FromContext`] for it:
+///
+/// ```rust
+/// # use juniper::{graphql_object, integrations::chrono::{Locale, LocalizedDateTime}, FromContext};
+/// # use chrono::{TimeZone as _, Utc};
+/// struct Context {
+///     locale: Locale,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// impl FromContext<Context> for Locale {
+///     fn from(ctx: &Context) -> &Self {
+///         &ctx.locale
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     fn released_at() -> LocalizedDateTime {
+///         LocalizedDateTime(Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// [`FromContext`]: crate::FromContext
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Locale {
+    /// English, formatted like `July 8, 2021 09:10:11 UTC`
+    En,
+
+    /// German, formatted like `8. Juli 2021 09:10:11 UTC`
+    De,
+
+    /// French, formatted like `8 juillet 2021 09:10:11 UTC`
+    Fr,
+}
+
+impl Locale {
+    /// Names of the months in this locale, starting from January
+    fn months(&self) -> [&'static str; 12] {
+        match self {
+            Self::En => [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Self::De => [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Self::Fr => [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+        }
+    }
+
+    /// Format the given date and time according to this locale
+    ///
+    /// The time is always formatted in UTC, as `HH:MM:SS UTC`, following the
+    /// date written with the month name, in the order specific to the locale.
+    pub fn format(&self, date_time: &DateTime<Utc>) -> String {
+        let (day, year) = (date_time.day(), date_time.year());
+        let month = self.months()[date_time.month0() as usize];
+        let time = date_time.format("%H:%M:%S UTC");
+        match self {
+            Self::En => format!("{} {}, {} {}", month, day, year, time),
+            Self::De => format!("{}. {} {} {}", day, month, year, time),
+            Self::Fr => format!("{} {} {} {}", day, month, year, time),
+        }
+    }
+}
+
+impl crate::Context for Locale {}
+
+/// Date and time in UTC, resolved as a `String` formatted according to the
+/// [`Locale`] of the context
+///
+/// Unlike `DateTime<Utc>`, it's meant to be displayed as is, so can only be
+/// used as an output type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LocalizedDateTime(pub DateTime<Utc>);
+
+impl<S> GraphQLType<S> for LocalizedDateTime
+where
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<&'static str> {
+        Some("String")
+    }
+
+    fn meta<'r>(_: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        <String as GraphQLType<S>>::meta(&(), registry)
+    }
+}
+
+impl<S> GraphQLValue<S> for LocalizedDateTime
+where
+    S: ScalarValue,
+{
+    type Context = Locale;
+    type TypeInfo = ();
+
+    fn type_name(&self, _: &Self::TypeInfo) -> Option<&'static str> {
+        Some("String")
+    }
+
+    fn resolve(
+        &self,
+        _: &Self::TypeInfo,
+        _: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        Ok(Value::scalar(executor.context().format(&self.0)))
+    }
+}
+
+impl<S> GraphQLValueAsync<S> for LocalizedDateTime
+where
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        selection_set: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        let res = self.resolve(info, selection_set, executor);
+        Box::pin(futures::future::ready(res))
+    }
+}
+
+impl<S> IsOutputType<S> for LocalizedDateTime where S: ScalarValue {}
+
+/// Source of the current date and time
+///
+/// Implement it for the context, so resolvers obtain the current time via
+/// `context.now()` rather than from the system directly, and tests may
+/// substitute a [`FixedClock`] for the [`SystemClock`]:
+///
+/// ```rust
+/// # use juniper::{graphql_object, integrations::chrono::{Clock, FixedClock}};
+/// # use chrono::{DateTime, TimeZone as _, Utc};
+/// struct Context {
+///     clock: Box<dyn Clock + Send + Sync>,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// impl Clock for Context {
+///     fn now(&self) -> DateTime<Utc> {
+///         self.clock.now()
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     fn today(context: &Context) -> String {
+///         context.now().format("%Y-%m-%d").to_string()
+///     }
+/// }
+///
+/// let ctx = Context {
+///     clock: Box::new(FixedClock(Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap())),
+/// };
+/// assert_eq!(ctx.now().to_rfc3339(), "2021-07-08T09:10:11+00:00");
+/// ```
+pub trait Clock {
+    /// Return the current date and time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+impl<T: Clock + ?Sized> Clock for Box<T> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+/// [`Clock`] telling the actual time of the system
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// [`Clock`] always telling the same time, so the resolved values are
+/// deterministic in tests
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// Range of `DateTime<Utc>`s (bounds included) to query a [`DateRangeRepository`] by
+///
+/// Built from the `from` and `to` arguments of a field, which are coerced from the
+/// `DateTimeUtc` scalar, failing the field if `from` is later than `to`:
+///
+/// ```rust
+/// # use chrono::{DateTime, TimeZone as _, Utc};
+/// # use juniper::{
+/// #     graphql_object,
+/// #     integrations::chrono::{DateRangeQuery, DateRangeRepository, Timestamped},
+/// #     FieldResult,
+/// # };
+/// #[derive(Clone)]
+/// struct Event {
+///     at: DateTime<Utc>,
+/// }
+///
+/// impl Timestamped for Event {
+///     fn timestamp(&self) -> DateTime<Utc> {
+///         self.at
+///     }
+/// }
+///
+/// #[graphql_object]
+/// impl Event {
+///     fn at(&self) -> DateTime<Utc> {
+///         self.at
+///     }
+/// }
+///
+/// struct Context {
+///     events: Vec<Event>,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// struct Query;
+///
+/// #[graphql_object(context = Context)]
+/// impl Query {
+///     fn events(
+///         ctx: &Context,
+///         from: DateTime<Utc>,
+///         to: DateTime<Utc>,
+///     ) -> FieldResult<Vec<Event>> {
+///         let range = DateRangeQuery::new(from, to)?;
+///         Ok(ctx.events.find_in_range(&range))
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DateRangeQuery {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+}
+
+impl DateRangeQuery {
+    /// Creates a new range between the given `from` and `to` bounds (both included)
+    ///
+    /// Fails if `from` is later than `to`.
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Self, String> {
+        if from > to {
+            return Err(format!(
+                "`from` ({}) must not be later than `to` ({})",
+                from.to_rfc3339(),
+                to.to_rfc3339(),
+            ));
+        }
+        Ok(Self { from, to })
+    }
+
+    /// Lower bound of this range (included)
+    pub fn from(&self) -> DateTime<Utc> {
+        self.from
+    }
+
+    /// Upper bound of this range (included)
+    pub fn to(&self) -> DateTime<Utc> {
+        self.to
+    }
+
+    /// Checks whether the given `datetime` falls into this range.
+    pub fn contains(&self, datetime: &DateTime<Utc>) -> bool {
+        self.from <= *datetime && *datetime <= self.to
+    }
+}
+
+/// Item stored in a [`DateRangeRepository`], which is queried by its timestamp
+pub trait Timestamped {
+    /// Timestamp this item is queried by
+    fn timestamp(&self) -> DateTime<Utc>;
+}
+
+/// Repository of items queried by a [`DateRangeQuery`]
+///
+/// Implement it for the data access layer (e.g. translating the range into a
+/// `WHERE at BETWEEN $1 AND $2` clause), or use the implementation for
+/// in-memory slices of [`Timestamped`] items.
+pub trait DateRangeRepository {
+    /// Items stored in this repository
+    type Item;
+
+    /// Finds the items whose timestamps fall into the given `range`.
+    fn find_in_range(&self, range: &DateRangeQuery) -> Vec<Self::Item>;
+}
+
+impl<T: Timestamped + Clone> DateRangeRepository for [T] {
+    type Item = T;
+
+    fn find_in_range(&self, range: &DateRangeQuery) -> Vec<T> {
+        self.iter()
+            .filter(|item| range.contains(&item.timestamp()))
+            .cloned()
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{value::DefaultScalarValue, InputValue};
+    use crate::{value::DefaultScalarValue, FieldError, InputValue};
     use chrono::prelude::*;
+    #[cfg(feature = "scalar-duration")]
+    use chrono::Duration;
 
     fn datetime_fixedoffset_test(raw: &'static str) {
         let input: crate::InputValue<DefaultScalarValue> = InputValue::scalar(raw.to_string());
@@ -161,6 +804,26 @@ mod test {
         assert_eq!(parsed, expected);
     }
 
+    #[test]
+    fn parse_input_error_details() {
+        let err = super::parse_input::<_, DefaultScalarValue, _>("2014-13-99", |s| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            FieldError::new(
+                "Invalid date or time `2014-13-99`: input is out of range",
+                graphql_value!({
+                    "code": "INVALID_DATE_TIME",
+                    "input": "2014-13-99",
+                    "details": "input is out of range",
+                }),
+            ),
+        );
+    }
+
     #[test]
     fn datetime_fixedoffset_from_input_value() {
         datetime_fixedoffset_test("2014-11-28T21:00:09+09:00");
@@ -235,6 +898,114 @@ mod test {
     }
 
     #[test]
+    #[cfg(feature = "scalar-naivetime")]
+    fn naivetime_from_input_value_with_fractional_seconds() {
+        let input = <InputValue<DefaultScalarValue>>::scalar("16:07:08.250".to_string());
+
+        let parsed: NaiveTime = crate::FromInputValue::from_input_value(&input).unwrap();
+        let expected = NaiveTime::from_hms_milli_opt(16, 7, 8, 250).unwrap();
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[cfg(feature = "scalar-duration")]
+    fn duration_test(raw: &'static str, expected: Duration) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Duration = crate::FromInputValue::from_input_value(&input).unwrap();
+
+        assert_eq!(parsed, expected, "for input: {}", raw);
+        assert_eq!(super::format_iso_duration(&parsed), raw);
+    }
+
+    #[test]
+    #[cfg(feature = "scalar-duration")]
+    fn duration_from_input_value() {
+        duration_test("PT1H30M", Duration::minutes(90));
+        duration_test("P2DT3S", Duration::days(2) + Duration::seconds(3));
+        duration_test("P14D", Duration::weeks(2));
+        duration_test("PT0S", Duration::zero());
+    }
+
+    #[test]
+    #[cfg(feature = "scalar-duration")]
+    fn duration_from_input_value_negative() {
+        duration_test("-PT1H30M", -Duration::minutes(90));
+        duration_test(
+            "-P1DT0.5S",
+            -(Duration::days(1) + Duration::milliseconds(500)),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "scalar-duration")]
+    fn duration_from_input_value_with_fractional_seconds() {
+        duration_test("PT0.25S", Duration::milliseconds(250));
+        duration_test(
+            "PT1M0.000000001S",
+            Duration::minutes(1) + Duration::nanoseconds(1),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "scalar-duration")]
+    fn duration_from_input_value_in_other_forms() {
+        for (raw, expected) in &[
+            ("P1W", Duration::weeks(1)),
+            ("PT90M", Duration::minutes(90)),
+            ("PT1.50S", Duration::milliseconds(1_500)),
+            ("P0D", Duration::zero()),
+        ] {
+            let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+            let parsed: Option<Duration> = crate::FromInputValue::from_input_value(&input);
+
+            assert_eq!(parsed, Some(*expected), "for input: {}", raw);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "scalar-duration")]
+    fn malformed_duration_from_input_value() {
+        for raw in &[
+            "",
+            "P",
+            "PT",
+            "1H",
+            "T1H",
+            "P1H",
+            "PT1D",
+            "P1Y",
+            "P1M",
+            "PT1M1H",
+            "PT1H1H",
+            "PT1.5M",
+            "PT.5S",
+            "PT1.S",
+            "PT1.0000000001S",
+            "PT1S1",
+            "PT-1S",
+            "--PT1S",
+            "P1DT",
+            "PT1HS",
+            "PT9223372036854775807S",
+            "P9223372036854775807W",
+            "PT1ΩS",
+        ] {
+            let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+            let parsed: Option<Duration> = crate::FromInputValue::from_input_value(&input);
+
+            assert_eq!(parsed, None, "for input: {}", raw);
+        }
+
+        let input = <InputValue<DefaultScalarValue>>::scalar(3600);
+        let parsed: Option<Duration> = crate::FromInputValue::from_input_value(&input);
+        assert_eq!(parsed, None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "scalar-naivedatetime-rfc3339"))]
     fn naivedatetime_from_input_value() {
         let raw = 1_000_000_000_f64;
         let input = <InputValue<DefaultScalarValue>>::scalar(raw);
@@ -245,6 +1016,71 @@ mod test {
         assert_eq!(parsed, expected);
         assert_eq!(raw, expected.timestamp() as f64);
     }
+
+    #[test]
+    #[cfg(not(feature = "scalar-naivedatetime-rfc3339"))]
+    fn naivedatetime_from_int_input_value() {
+        let input = <InputValue<DefaultScalarValue>>::scalar(1_000_000_000_i32);
+
+        let parsed: Option<NaiveDateTime> = crate::FromInputValue::from_input_value(&input);
+
+        assert_eq!(
+            parsed,
+            NaiveDate::from_ymd_opt(2001, 9, 9).and_then(|d| d.and_hms_opt(1, 46, 40)),
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "scalar-naivedatetime-rfc3339"))]
+    fn naivedatetime_from_int_token() {
+        use crate::{parser::ScalarToken, ParseScalarValue};
+
+        for (raw, expected) in &[
+            ("1000000000", 1_000_000_000.0),
+            ("3000000000", 3_000_000_000.0),
+        ] {
+            let parsed = <NaiveDateTime as ParseScalarValue<DefaultScalarValue>>::from_str(
+                ScalarToken::Int(raw),
+            );
+
+            assert_eq!(
+                parsed,
+                Ok(DefaultScalarValue::Float(*expected)),
+                "for token: {}",
+                raw,
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "scalar-naivedatetime-rfc3339")]
+    fn naivedatetime_rfc3339_roundtrip() {
+        use crate::{FromInputValue, ToInputValue};
+
+        let raw = "2016-07-08T09:10:11.123456789";
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: NaiveDateTime = FromInputValue::from_input_value(&input).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2016, 7, 8)
+            .unwrap()
+            .and_hms_nano_opt(9, 10, 11, 123_456_789)
+            .unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.nanosecond(), 123_456_789);
+
+        let output: InputValue<DefaultScalarValue> = parsed.to_input_value();
+        assert_eq!(output, input);
+        assert_eq!(
+            <NaiveDateTime as FromInputValue>::from_input_value(&output),
+            Some(expected),
+        );
+
+        let float = <InputValue<DefaultScalarValue>>::scalar(1_467_969_011.5);
+        assert_eq!(
+            <NaiveDateTime as FromInputValue>::from_input_value(&float),
+            None,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -252,7 +1088,7 @@ mod integration_test {
     use chrono::{prelude::*, Utc};
 
     use crate::{
-        executor::Variables,
+        executor::{ErrorSeverity, FieldResult, PathSegment, Variables},
         graphql_object, graphql_value,
         schema::model::RootNode,
         types::scalars::{EmptyMutation, EmptySubscription},
@@ -328,12 +1164,17 @@ mod integration_test {
 
         assert_eq!(errs, []);
 
+        #[cfg(not(feature = "scalar-naivedatetime-rfc3339"))]
+        let naive_date_time = graphql_value!(1_467_969_011.0);
+        #[cfg(feature = "scalar-naivedatetime-rfc3339")]
+        let naive_date_time = graphql_value!("2016-07-08T09:10:11");
+
         #[cfg(feature = "scalar-naivetime")]
         assert_eq!(
             result,
             graphql_value!({
                 "exampleNaiveDate": "2015-03-14",
-                "exampleNaiveDateTime": 1_467_969_011.0,
+                "exampleNaiveDateTime": (naive_date_time),
                 "exampleNaiveTime": "16:07:08",
                 "exampleDateTimeFixedOffset": "1996-12-19T16:39:57-08:00",
                 "exampleDateTimeUtc": "1970-01-01T00:01:01+00:00",
@@ -344,10 +1185,298 @@ mod integration_test {
             result,
             graphql_value!({
                 "exampleNaiveDate": "2015-03-14",
-                "exampleNaiveDateTime": 1_467_969_011.0,
+                "exampleNaiveDateTime": (naive_date_time),
                 "exampleDateTimeFixedOffset": "1996-12-19T16:39:57-08:00",
                 "exampleDateTimeUtc": "1970-01-01T00:01:01+00:00",
             }),
         );
     }
+
+    #[tokio::test]
+    async fn test_sane_years_check() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn dates() -> Vec<DateTime<Utc>> {
+                vec![
+                    Utc.with_ymd_and_hms(2021, 7, 8, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(9999, 12, 31, 0, 0, 0).unwrap(),
+                ]
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .with_output_check("DateTimeUtc", super::sane_years_check(1900..=2100));
+
+        let (result, errs) = crate::execute("{ dates }", None, &schema, &Variables::new(), &())
+            .await
+            .expect("Execution failed");
+
+        assert_eq!(
+            result,
+            graphql_value!({"dates": [
+                "2021-07-08T00:00:00+00:00",
+                "9999-12-31T00:00:00+00:00",
+            ]}),
+        );
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path(), &["dates".into(), PathSegment::Index(1)]);
+        assert_eq!(errs[0].error().severity(), Some(ErrorSeverity::Warning));
+        assert_eq!(
+            errs[0].error().message(),
+            "Date is outside of the sane range of years 1900-2100",
+        );
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({
+                "code": "DATE_OUT_OF_RANGE",
+                "date": "9999-12-31T00:00:00+00:00",
+                "severity": "WARNING",
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_localized_date_time() {
+        use super::{Locale, LocalizedDateTime};
+        use crate::FromContext;
+
+        struct Context {
+            locale: Locale,
+        }
+
+        impl crate::Context for Context {}
+
+        impl FromContext<Context> for Locale {
+            fn from(ctx: &Context) -> &Self {
+                &ctx.locale
+            }
+        }
+
+        struct Root;
+
+        #[graphql_object(context = Context)]
+        impl Root {
+            fn released_at() -> LocalizedDateTime {
+                LocalizedDateTime(Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap())
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+
+        for (locale, expected) in &[
+            (Locale::En, "July 8, 2021 09:10:11 UTC"),
+            (Locale::De, "8. Juli 2021 09:10:11 UTC"),
+            (Locale::Fr, "8 juillet 2021 09:10:11 UTC"),
+        ] {
+            let ctx = Context { locale: *locale };
+
+            assert_eq!(
+                crate::execute("{ releasedAt }", None, &schema, &Variables::new(), &ctx).await,
+                Ok((graphql_value!({ "releasedAt": (*expected) }), vec![])),
+                "for locale: {:?}",
+                locale,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_date_range_query() {
+        use super::{DateRangeQuery, DateRangeRepository, Timestamped};
+
+        #[derive(Clone)]
+        struct Event {
+            name: &'static str,
+            at: DateTime<Utc>,
+        }
+
+        impl Timestamped for Event {
+            fn timestamp(&self) -> DateTime<Utc> {
+                self.at
+            }
+        }
+
+        #[graphql_object]
+        impl Event {
+            fn name(&self) -> &str {
+                self.name
+            }
+        }
+
+        struct Context {
+            events: Vec<Event>,
+        }
+
+        impl crate::Context for Context {}
+
+        struct Root;
+
+        #[graphql_object(context = Context)]
+        impl Root {
+            fn events(
+                ctx: &Context,
+                from: DateTime<Utc>,
+                to: DateTime<Utc>,
+            ) -> FieldResult<Vec<Event>> {
+                let range = DateRangeQuery::new(from, to)?;
+                Ok(ctx.events.find_in_range(&range))
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+        let ctx = Context {
+            events: vec![
+                Event {
+                    name: "kickoff",
+                    at: Utc.with_ymd_and_hms(2021, 7, 1, 9, 0, 0).unwrap(),
+                },
+                Event {
+                    name: "review",
+                    at: Utc.with_ymd_and_hms(2021, 7, 8, 9, 0, 0).unwrap(),
+                },
+                Event {
+                    name: "release",
+                    at: Utc.with_ymd_and_hms(2021, 7, 15, 9, 0, 0).unwrap(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            crate::execute(
+                r#"{ events(from: "2021-07-01T09:00:00-02:00", to: "2021-07-08T09:00:00Z") {
+                    name
+                } }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &ctx,
+            )
+            .await,
+            Ok((graphql_value!({"events": [{"name": "review"}]}), vec![])),
+        );
+
+        let (res, errs) = crate::execute(
+            r#"{ events(from: "2021-07-15T00:00:00Z", to: "2021-07-01T00:00:00Z") { name } }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &ctx,
+        )
+        .await
+        .unwrap();
+        assert_eq!(res, graphql_value!(None));
+        assert_eq!(
+            errs.iter().map(|e| e.error().message()).collect::<Vec<_>>(),
+            vec!["`from` (2021-07-15T00:00:00+00:00) must not be later than `to` (2021-07-01T00:00:00+00:00)"],
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "scalar-naivetime")]
+    async fn test_naive_time_round_trip() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn echo(time: NaiveTime) -> NaiveTime {
+                time
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        for raw in &["16:07:08", "16:07:08.250"] {
+            let doc = format!(r#"{{ echo(time: "{}") }}"#, raw);
+
+            assert_eq!(
+                crate::execute(&doc, None, &schema, &Variables::new(), &()).await,
+                Ok((graphql_value!({ "echo": (*raw) }), vec![])),
+                "for input: {}",
+                raw,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fixed_clock() {
+        use super::{Clock, FixedClock, SystemClock};
+
+        struct Context {
+            clock: Box<dyn Clock + Send + Sync>,
+        }
+
+        impl crate::Context for Context {}
+
+        impl Clock for Context {
+            fn now(&self) -> DateTime<Utc> {
+                self.clock.now()
+            }
+        }
+
+        struct Root;
+
+        #[graphql_object(context = Context)]
+        impl Root {
+            fn current_time(context: &Context) -> DateTime<Utc> {
+                context.now()
+            }
+
+            fn days_until_new_year(context: &Context) -> i32 {
+                let now = context.now();
+                let new_year = Utc.with_ymd_and_hms(now.year() + 1, 1, 1, 0, 0, 0).unwrap();
+                (new_year - now).num_days() as i32
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        );
+
+        let ctx = Context {
+            clock: Box::new(FixedClock(
+                Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap(),
+            )),
+        };
+        assert_eq!(
+            crate::execute(
+                "{ currentTime daysUntilNewYear }",
+                None,
+                &schema,
+                &Variables::new(),
+                &ctx,
+            )
+            .await,
+            Ok((
+                graphql_value!({
+                    "currentTime": "2021-07-08T09:10:11+00:00",
+                    "daysUntilNewYear": 176,
+                }),
+                vec![],
+            )),
+        );
+
+        let ctx = Context {
+            clock: Box::new(SystemClock),
+        };
+        assert!(ctx.now().year() >= 2021);
+    }
 }
@@ -78,3 +78,87 @@ mod test {
         }
     }
 }
+
+#[cfg(all(test, feature = "chrono"))]
+mod integration_test {
+    use chrono::{DateTime, FixedOffset, Offset as _, Utc};
+    use chrono_tz::Tz;
+
+    use crate::{
+        graphql_object, EmptyMutation, EmptySubscription, GraphQLError, InputValue, RootNode,
+        Variables,
+    };
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn local_time(at: DateTime<Utc>, tz: Tz) -> DateTime<FixedOffset> {
+            let local = at.with_timezone(&tz);
+            local.with_timezone(&local.offset().fix())
+        }
+
+        fn zone(tz: Tz) -> Tz {
+            tz
+        }
+    }
+
+    #[tokio::test]
+    async fn composes_with_date_time_across_dst() {
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            crate::execute(
+                r#"{
+                    winter: localTime(at: "2021-01-15T17:00:00Z", tz: "America/New_York")
+                    summer: localTime(at: "2021-07-15T17:00:00Z", tz: "America/New_York")
+                    zone(tz: "America/New_York")
+                }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({
+                    "winter": "2021-01-15T12:00:00-05:00",
+                    "summer": "2021-07-15T13:00:00-04:00",
+                    "zone": "America/New_York",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_zone_variable() {
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let vars = vec![("tz".to_owned(), InputValue::scalar("Mars/Olympus"))]
+            .into_iter()
+            .collect();
+
+        let res = crate::execute(
+            "query($tz: Tz!) { zone(tz: $tz) }",
+            None,
+            &schema,
+            &vars,
+            &(),
+        )
+        .await;
+
+        assert!(
+            matches!(res, Err(GraphQLError::ValidationError(_))),
+            "unexpected result: {:?}",
+            res,
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! GraphQL support for [cron expressions][1] scheduling recurring jobs.
+//!
+//! Expressions are validated structurally only (number of fields, well-formed values within their
+//! ranges), they are never evaluated into schedule times.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Cron#CRON_expression
+
+use std::fmt;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// Names allowed in the month field, in place of the numbers `1..=12`.
+const MONTHS: &[&str] = &[
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Names allowed in the day of week field, in place of the numbers `0..=6`.
+const WEEKDAYS: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Ranges of values and names allowed in the standard fields (minute, hour, day of month, month,
+/// day of week).
+const FIELDS: &[(u32, u32, &[&str])] = &[
+    (0, 59, &[]),
+    (0, 23, &[]),
+    (1, 31, &[]),
+    (1, 12, MONTHS),
+    (0, 7, WEEKDAYS),
+];
+
+/// Range of values allowed in the optional leading second field.
+const SECONDS: (u32, u32, &[&str]) = (0, 59, &[]);
+
+/// Cron expression, e.g. `0 0 * * *`.
+///
+/// Consists either of the standard 5 fields (minute, hour, day of month, month, day of week), or
+/// of 6 fields with the leading one being the second. Stored in a normalized form: single-spaced
+/// and uppercased.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Cron(String);
+
+impl Cron {
+    /// Parses and normalizes the given cron `expression`.
+    ///
+    /// Returns [`None`] if the `expression` is malformed: has neither 5 nor 6 fields, or any of
+    /// its fields contains values out of the field's range or malformed lists, ranges or steps.
+    pub fn new(expression: &str) -> Option<Self> {
+        let expression = expression.to_ascii_uppercase();
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+
+        let (seconds, standard) = match fields.len() {
+            5 => (None, &fields[..]),
+            6 => (Some(fields[0]), &fields[1..]),
+            _ => return None,
+        };
+        let is_valid = seconds.into_iter().all(|s| is_valid_field(s, SECONDS))
+            && standard
+                .iter()
+                .zip(FIELDS)
+                .all(|(field, range)| is_valid_field(field, *range));
+
+        if is_valid {
+            Some(Self(fields.join(" ")))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the normalized string representation of this expression.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Cron {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Checks the `field` being a comma-separated list of `*`, values, ranges or steps, with values
+/// in the `min..=max` range or being one of the `names`.
+fn is_valid_field(field: &str, (min, max, names): (u32, u32, &[&str])) -> bool {
+    let is_value = |v: &str| {
+        names.contains(&v)
+            || (!v.is_empty()
+                && v.bytes().all(|b| b.is_ascii_digit())
+                && matches!(v.parse(), Ok(n) if (min..=max).contains(&n)))
+    };
+    let is_range = |r: &str| match r.split_once('-') {
+        Some((from, to)) => is_value(from) && is_value(to),
+        None => r == "*" || is_value(r),
+    };
+
+    field.split(',').all(|item| match item.split_once('/') {
+        Some((range, step)) => {
+            is_range(range)
+                && !step.is_empty()
+                && step.bytes().all(|b| b.is_ascii_digit())
+                && matches!(step.parse::<u32>(), Ok(n) if n > 0)
+        }
+        None => is_range(item),
+    })
+}
+
+#[crate::graphql_scalar(description = "Cron expression, e.g. `0 0 * * *`")]
+impl<S> GraphQLScalar for Cron
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0.clone())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Cron> {
+        v.as_string_value().and_then(Cron::new)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::Cron;
+
+    fn cron_input_test(raw: &str, expected: Option<&str>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<Cron> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(
+            parsed.as_ref().map(Cron::as_str),
+            expected,
+            "for input: {}",
+            raw,
+        );
+    }
+
+    #[test]
+    fn five_fields_from_input_value() {
+        cron_input_test("0 0 * * *", Some("0 0 * * *"));
+        cron_input_test(
+            " */15  9-17 1,15 jan-jun mon-fri ",
+            Some("*/15 9-17 1,15 JAN-JUN MON-FRI"),
+        );
+    }
+
+    #[test]
+    fn six_fields_from_input_value() {
+        cron_input_test("30 0 0 * * *", Some("30 0 0 * * *"));
+        cron_input_test("*/10 * * * * 0,7", Some("*/10 * * * * 0,7"));
+    }
+
+    #[test]
+    fn too_few_or_many_fields_from_input_value() {
+        cron_input_test("* * *", None);
+        cron_input_test("", None);
+        cron_input_test("* * * * * * *", None);
+    }
+
+    #[test]
+    fn malformed_from_input_value() {
+        for raw in &[
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "* * * FOO *",
+            "*/0 * * * *",
+            "1- * * * *",
+            "1,,2 * * * *",
+            "-1 * * * *",
+            "60 0 0 * * *",
+        ] {
+            cron_input_test(raw, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::Cron;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(schedule: Cron) -> Cron {
+            schedule
+        }
+    }
+
+    #[tokio::test]
+    async fn serializes_normalized() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{ echo(schedule: "0  12 * * sun") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"echo": "0 12 * * SUN"}), vec![])),
+        );
+    }
+}
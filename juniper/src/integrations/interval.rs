@@ -0,0 +1,288 @@
+//! GraphQL support for [ISO 8601 time intervals][1] between two instants in UTC.
+//!
+//! Intervals are accepted in any of the `<start>/<end>`, `<start>/<duration>` and
+//! `<duration>/<end>` forms (e.g. `"2024-01-01T00:00:00Z/P1M"`), and always serialized in the
+//! `<start>/<end>` one, with the end computed from the duration, if specified.
+//!
+//! [1]: https://en.wikipedia.org/wiki/ISO_8601#Time_intervals
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, Months, Utc};
+
+use crate::{
+    integrations::iso_duration::IsoDuration,
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// Time interval between two instants in UTC, e.g. `2024-01-01T00:00:00Z/P1M`.
+///
+/// The end of an interval is never before its start.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Interval {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+impl Interval {
+    /// Constructs a new interval between the given `start` and `end`.
+    ///
+    /// Returns [`None`] if the `end` is before the `start`.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Option<Self> {
+        if end < start {
+            return None;
+        }
+        Some(Self { start, end })
+    }
+
+    /// Parses the given `interval` in any of the `<start>/<end>`, `<start>/<duration>` or
+    /// `<duration>/<end>` forms, with instants in [RFC 3339][1] format and durations in the ISO
+    /// 8601 `PnYnMnDTnHnMnS` (or `PnW`) one, e.g. `"2024-01-01T00:00:00Z/P1M"`.
+    ///
+    /// Years and months of a duration are calendar ones, so `P1M` stands for a different amount
+    /// of days depending on the instant it's applied to.
+    ///
+    /// Returns [`None`] if the `interval` is malformed, or its end is before its start.
+    ///
+    /// [1]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+    pub fn parse(interval: &str) -> Option<Self> {
+        let (start, end) = interval.trim().split_once('/')?;
+
+        let (start, end) = if start.starts_with('P') {
+            let end = end.parse::<DateTime<Utc>>().ok()?;
+            (sub_from(end, &IsoDuration::parse(start)?)?, end)
+        } else if end.starts_with('P') {
+            let start = start.parse::<DateTime<Utc>>().ok()?;
+            (start, add_to(start, &IsoDuration::parse(end)?)?)
+        } else {
+            (start.parse().ok()?, end.parse().ok()?)
+        };
+        Self::new(start, end)
+    }
+
+    /// Returns the start of this interval.
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    /// Returns the end of this interval.
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+
+    /// Returns the exact duration of this interval.
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.start.to_rfc3339(), self.end.to_rfc3339())
+    }
+}
+
+/// Splits the given `duration` into its calendar months (including the ones of years) and the
+/// exact duration of the rest of its components.
+fn calendar_and_exact(duration: &IsoDuration) -> Option<(Months, Duration)> {
+    let months = duration
+        .years
+        .checked_mul(12)?
+        .checked_add(duration.months)?;
+    let exact = Duration::days(duration.days.into())
+        .checked_add(&Duration::hours(duration.hours.into()))?
+        .checked_add(&Duration::minutes(duration.minutes.into()))?
+        .checked_add(&Duration::seconds(duration.seconds.into()))?
+        .checked_add(&Duration::nanoseconds(duration.nanoseconds.into()))?;
+    Some((Months::new(months), exact))
+}
+
+/// Applies the given `duration` forward to the given `start`.
+fn add_to(start: DateTime<Utc>, duration: &IsoDuration) -> Option<DateTime<Utc>> {
+    let (months, exact) = calendar_and_exact(duration)?;
+    start.checked_add_months(months)?.checked_add_signed(exact)
+}
+
+/// Applies the given `duration` backward to the given `end`.
+fn sub_from(end: DateTime<Utc>, duration: &IsoDuration) -> Option<DateTime<Utc>> {
+    let (months, exact) = calendar_and_exact(duration)?;
+    end.checked_sub_months(months)?.checked_sub_signed(exact)
+}
+
+#[crate::graphql_scalar(description = "ISO 8601 time interval, e.g. `2024-01-01T00:00:00Z/P1M`")]
+impl<S> GraphQLScalar for Interval
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Interval> {
+        v.as_string_value().and_then(Interval::parse)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, TimeZone as _, Utc};
+
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::Interval;
+
+    fn interval_input_test(raw: &str, expected: Option<(&str, &str)>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<Interval> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(
+            parsed.map(|i| (i.start().to_rfc3339(), i.end().to_rfc3339())),
+            expected.map(|(start, end)| (start.to_owned(), end.to_owned())),
+            "for input: {}",
+            raw,
+        );
+    }
+
+    #[test]
+    fn start_and_duration_from_input_value() {
+        interval_input_test(
+            "2024-01-01T00:00:00Z/P1M",
+            Some(("2024-01-01T00:00:00+00:00", "2024-02-01T00:00:00+00:00")),
+        );
+        interval_input_test(
+            "2024-01-31T00:00:00Z/P1Y1M2DT3H4M5.5S",
+            Some(("2024-01-31T00:00:00+00:00", "2025-03-02T03:04:05.500+00:00")),
+        );
+        interval_input_test(
+            "2024-01-01T00:00:00Z/P2W",
+            Some(("2024-01-01T00:00:00+00:00", "2024-01-15T00:00:00+00:00")),
+        );
+    }
+
+    #[test]
+    fn start_and_end_from_input_value() {
+        interval_input_test(
+            "2024-01-01T00:00:00Z/2024-01-02T12:00:00+02:00",
+            Some(("2024-01-01T00:00:00+00:00", "2024-01-02T10:00:00+00:00")),
+        );
+    }
+
+    #[test]
+    fn duration_and_end_from_input_value() {
+        interval_input_test(
+            "PT36H/2024-03-01T00:00:00Z",
+            Some(("2024-02-28T12:00:00+00:00", "2024-03-01T00:00:00+00:00")),
+        );
+    }
+
+    #[test]
+    fn malformed_from_input_value() {
+        for raw in &[
+            "2024-01-01T00:00:00Z",
+            "2024-01-01T00:00:00Z/",
+            "2024-01-01/P1M",
+            "2024-01-01T00:00:00Z/P",
+            "2024-01-01T00:00:00Z/PT",
+            "2024-01-01T00:00:00Z/P1H",
+            "2024-01-01T00:00:00Z/PT1D",
+            "2024-01-01T00:00:00Z/P1D1M",
+            "2024-01-01T00:00:00Z/P1W1D",
+            "2024-01-01T00:00:00Z/P1.5D",
+            "2024-01-01T00:00:00Z/P-1D",
+            "2024-01-02T00:00:00Z/2024-01-01T00:00:00Z",
+            "P1D/P1D",
+        ] {
+            interval_input_test(raw, None);
+        }
+    }
+
+    #[test]
+    fn computes_duration() {
+        let interval = Interval::parse("2024-02-01T00:00:00Z/P1M").unwrap();
+
+        assert_eq!(interval.duration(), Duration::days(29));
+        assert_eq!(
+            Interval::new(interval.end(), interval.start()),
+            None,
+            "end before start",
+        );
+        assert_eq!(
+            interval.end(),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::Interval;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(interval: Interval) -> Interval {
+            interval
+        }
+
+        fn hours(interval: Interval) -> i32 {
+            interval.duration().num_hours() as i32
+        }
+    }
+
+    #[tokio::test]
+    async fn serializes_start_and_end() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{
+                    echo(interval: "2024-01-01T00:00:00Z/P1M")
+                    hours(interval: "2024-01-01T00:00:00Z/PT1H30M")
+                }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({
+                    "echo": "2024-01-01T00:00:00+00:00/2024-02-01T00:00:00+00:00",
+                    "hours": 1,
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert!(matches!(
+            crate::execute(
+                r#"{ echo(interval: "2024-01-01T00:00:00Z/P1X") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Err(crate::GraphQLError::ValidationError(_)),
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+//! GraphQL support for the [`std::net`] IP address types.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+#[crate::graphql_scalar(description = "IPv4 or IPv6 address, e.g. `127.0.0.1` or `::1`")]
+impl<S> GraphQLScalar for IpAddr
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<IpAddr> {
+        v.as_string_value().and_then(|s| s.parse().ok())
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[crate::graphql_scalar(description = "IPv4 address, e.g. `127.0.0.1`")]
+impl<S> GraphQLScalar for Ipv4Addr
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Ipv4Addr> {
+        v.as_string_value().and_then(|s| s.parse().ok())
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[crate::graphql_scalar(description = "IPv6 address, e.g. `::1`")]
+impl<S> GraphQLScalar for Ipv6Addr
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Ipv6Addr> {
+        v.as_string_value().and_then(|s| s.parse().ok())
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::{
+        parser::{ParseError, ScalarToken, Token},
+        DefaultScalarValue, FromInputValue, InputValue, ParseScalarValue,
+    };
+
+    fn ip_input_test<T>(raw: &str, expected: Option<T>)
+    where
+        T: FromInputValue<DefaultScalarValue> + PartialEq + std::fmt::Debug,
+    {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<T> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, expected, "for input: {}", raw);
+    }
+
+    #[test]
+    fn v4_from_input_value() {
+        ip_input_test("127.0.0.1", Some(Ipv4Addr::LOCALHOST));
+        ip_input_test("127.0.0.1", Some(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+        ip_input_test::<Ipv6Addr>("127.0.0.1", None);
+    }
+
+    #[test]
+    fn v6_from_input_value() {
+        ip_input_test("::1", Some(Ipv6Addr::LOCALHOST));
+        ip_input_test("::1", Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        ip_input_test::<Ipv4Addr>("::1", None);
+    }
+
+    #[test]
+    fn invalid_from_input_value() {
+        for raw in &["", "foo", "127.0.0.256", "127.0.0.1/8", "::1::", "[::1]"] {
+            ip_input_test::<IpAddr>(raw, None);
+            ip_input_test::<Ipv4Addr>(raw, None);
+            ip_input_test::<Ipv6Addr>(raw, None);
+        }
+    }
+
+    #[test]
+    fn rejects_non_string_tokens() {
+        assert_eq!(
+            <IpAddr as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::Int("1")),
+            Err(ParseError::UnexpectedToken(Token::Scalar(
+                ScalarToken::Int("1")
+            ))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(ip: IpAddr) -> IpAddr {
+            ip
+        }
+
+        fn to_v6(ip: Ipv4Addr) -> Ipv6Addr {
+            ip.to_ipv6_mapped()
+        }
+
+        fn to_v4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+            ip.to_ipv4()
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let doc = r#"{
+            v4: echo(ip: "192.168.0.1")
+            v6: echo(ip: "2001:db8::1")
+            toV6(ip: "192.168.0.1")
+            toV4(ip: "::ffff:192.168.0.1")
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({
+                    "v4": "192.168.0.1",
+                    "v6": "2001:db8::1",
+                    "toV6": "::ffff:192.168.0.1",
+                    "toV4": "192.168.0.1",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_literals() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert!(matches!(
+            crate::execute(
+                r#"{ toV6(ip: "::1") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Err(crate::GraphQLError::ValidationError(_)),
+        ));
+    }
+}
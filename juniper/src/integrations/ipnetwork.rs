@@ -0,0 +1,86 @@
+//! GraphQL support for [ipnetwork](https://docs.rs/ipnetwork) types.
+
+use ipnetwork::IpNetwork;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+#[crate::graphql_scalar(description = "IP network in CIDR notation, e.g. `10.0.0.0/24`")]
+impl<S> GraphQLScalar for IpNetwork
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<IpNetwork> {
+        v.as_string_value()
+            .filter(|s| s.contains('/'))
+            .and_then(|s| s.parse().ok())
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
+
+    use crate::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+
+    fn ipnetwork_input_test(raw: &str, expected: Option<IpNetwork>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<IpNetwork> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, expected, "for input: {}", raw);
+    }
+
+    #[test]
+    fn v4_from_input_value() {
+        ipnetwork_input_test(
+            "10.0.0.0/24",
+            Some(IpNetwork::V4(
+                Ipv4Network::new([10, 0, 0, 0].into(), 24).unwrap(),
+            )),
+        );
+    }
+
+    #[test]
+    fn v6_from_input_value() {
+        ipnetwork_input_test(
+            "2001:db8::/32",
+            Some(IpNetwork::V6(
+                Ipv6Network::new([0x2001, 0xdb8, 0, 0, 0, 0, 0, 0].into(), 32).unwrap(),
+            )),
+        );
+    }
+
+    #[test]
+    fn invalid_from_input_value() {
+        for raw in &[
+            "10.0.0.0/33",
+            "2001:db8::/129",
+            "10.0.0.256/24",
+            "10.0.0.0",
+            "foo",
+        ] {
+            ipnetwork_input_test(raw, None);
+        }
+    }
+
+    #[test]
+    fn serializes_as_cidr() {
+        let net: IpNetwork = "10.0.0.0/24".parse().unwrap();
+
+        assert_eq!(
+            ToInputValue::<DefaultScalarValue>::to_input_value(&net),
+            InputValue::scalar("10.0.0.0/24"),
+        );
+    }
+}
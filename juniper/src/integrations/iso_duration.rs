@@ -0,0 +1,346 @@
+//! GraphQL support for [ISO 8601 durations][1] having both date and time components, e.g.
+//! `"P1Y2M10DT2H30M"`.
+//!
+//! Unlike the `chrono::Duration` scalar, durations here keep their years and months, as these
+//! have no exact length without an instant to apply them to.
+//!
+//! [1]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+
+use std::fmt;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// ISO 8601 duration split into its components, e.g. `P1Y2M10DT2H30M`.
+///
+/// Components are kept as they're specified, so `PT90M` stays 90 minutes rather than becoming
+/// 1 hour and 30 minutes. Weeks are the only exception, being converted into days (`P2W` becomes
+/// `P14D`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IsoDuration {
+    /// Calendar years.
+    pub years: u32,
+
+    /// Calendar months.
+    pub months: u32,
+
+    /// Days, including the ones of weeks.
+    pub days: u32,
+
+    /// Hours.
+    pub hours: u32,
+
+    /// Minutes.
+    pub minutes: u32,
+
+    /// Whole seconds.
+    pub seconds: u32,
+
+    /// Fractional part of the seconds, in nanoseconds.
+    pub nanoseconds: u32,
+}
+
+impl IsoDuration {
+    /// Parses the given `duration` in the `PnYnMnDTnHnMnS` or `PnW` form, with only the seconds
+    /// allowed to have a fractional part (up to nanoseconds).
+    ///
+    /// Returns [`None`] if the `duration` is malformed (e.g. `P1H`, missing the `T` before the
+    /// time components), has no components at all, or any of its components overflows [`u32`].
+    pub fn parse(duration: &str) -> Option<Self> {
+        let (date, time) = match duration.strip_prefix('P')?.split_once('T') {
+            Some((_, "")) => return None,
+            Some((date, time)) => (date, time),
+            None => (duration.strip_prefix('P')?, ""),
+        };
+
+        let date_parts = components(date, &['Y', 'M', 'W', 'D'])?;
+        let time_parts = components(time, &['H', 'M', 'S'])?;
+        if date_parts.is_empty() && time_parts.is_empty() {
+            return None;
+        }
+        if date_parts.iter().any(|(unit, _)| *unit == 'W')
+            && (date_parts.len() > 1 || !time_parts.is_empty())
+        {
+            return None;
+        }
+
+        let mut out = Self::default();
+        for (unit, amount) in date_parts {
+            let amount = whole(amount)?;
+            match unit {
+                'Y' => out.years = amount,
+                'M' => out.months = amount,
+                'W' => out.days = amount.checked_mul(7)?,
+                _ => out.days = amount,
+            }
+        }
+        for (unit, amount) in time_parts {
+            match unit {
+                'H' => out.hours = whole(amount)?,
+                'M' => out.minutes = whole(amount)?,
+                _ => {
+                    let (seconds, nanoseconds) = seconds(amount)?;
+                    out.seconds = seconds;
+                    out.nanoseconds = nanoseconds;
+                }
+            }
+        }
+        Some(out)
+    }
+
+    /// Indicates whether this duration has any time (hours, minutes or seconds) components.
+    fn has_time(&self) -> bool {
+        self.hours > 0 || self.minutes > 0 || self.seconds > 0 || self.nanoseconds > 0
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == Self::default() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+        for (amount, unit) in &[(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if *amount > 0 {
+                write!(f, "{}{}", amount, unit)?;
+            }
+        }
+        if !self.has_time() {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+        for (amount, unit) in &[(self.hours, 'H'), (self.minutes, 'M')] {
+            if *amount > 0 {
+                write!(f, "{}{}", amount, unit)?;
+            }
+        }
+        if self.seconds > 0 || self.nanoseconds > 0 {
+            write!(f, "{}", self.seconds)?;
+            if self.nanoseconds > 0 {
+                let fraction = format!("{:09}", self.nanoseconds);
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+            f.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits the given `part` of a duration into its `(unit, amount)` components, with units going
+/// in the order of the given `units`, each at most once.
+fn components<'a>(mut part: &'a str, units: &[char]) -> Option<Vec<(char, &'a str)>> {
+    let mut out = Vec::new();
+    let mut units = units.iter();
+    while !part.is_empty() {
+        let unit_at = part.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+        let unit = part[unit_at..].chars().next()?;
+        if !units.any(|u| *u == unit) {
+            return None;
+        }
+        out.push((unit, &part[..unit_at]));
+        part = &part[unit_at + unit.len_utf8()..];
+    }
+    Some(out)
+}
+
+/// Parses the given whole `amount` of a duration component.
+fn whole(amount: &str) -> Option<u32> {
+    if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    amount.parse().ok()
+}
+
+/// Parses the given `amount` of seconds into whole seconds and nanoseconds, allowing a fractional
+/// part up to nanoseconds.
+fn seconds(amount: &str) -> Option<(u32, u32)> {
+    match amount.split_once('.') {
+        Some((secs, frac)) => {
+            if frac.is_empty() || frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            Some((whole(secs)?, format!("{:0<9}", frac).parse().ok()?))
+        }
+        None => Some((whole(amount)?, 0)),
+    }
+}
+
+#[crate::graphql_scalar(description = "ISO 8601 duration, e.g. `P1Y2M10DT2H30M`")]
+impl<S> GraphQLScalar for IsoDuration
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<IsoDuration> {
+        v.as_string_value().and_then(IsoDuration::parse)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::IsoDuration;
+
+    fn duration_input_test(raw: &str, expected: Option<IsoDuration>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<IsoDuration> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, expected, "for input: {}", raw);
+    }
+
+    #[test]
+    fn complex_from_input_value() {
+        let expected = IsoDuration {
+            years: 1,
+            months: 2,
+            days: 10,
+            hours: 2,
+            minutes: 30,
+            ..IsoDuration::default()
+        };
+
+        duration_input_test("P1Y2M10DT2H30M", Some(expected));
+        assert_eq!(expected.to_string(), "P1Y2M10DT2H30M");
+    }
+
+    #[test]
+    fn fractional_seconds_from_input_value() {
+        let expected = IsoDuration {
+            minutes: 1,
+            seconds: 5,
+            nanoseconds: 250_000_000,
+            ..IsoDuration::default()
+        };
+
+        duration_input_test("PT1M5.25S", Some(expected));
+        assert_eq!(expected.to_string(), "PT1M5.25S");
+    }
+
+    #[test]
+    fn keeps_components_as_specified() {
+        for (raw, serialized) in &[
+            ("PT90M", "PT90M"),
+            ("P2W", "P14D"),
+            ("P1M", "P1M"),
+            ("PT1M", "PT1M"),
+            ("P0D", "PT0S"),
+            ("PT0S", "PT0S"),
+        ] {
+            let parsed = IsoDuration::parse(raw).unwrap_or_else(|| panic!("for input: {}", raw));
+
+            assert_eq!(parsed.to_string(), *serialized, "for input: {}", raw);
+        }
+    }
+
+    #[test]
+    fn malformed_from_input_value() {
+        for raw in &[
+            "",
+            "P",
+            "PT",
+            "1Y",
+            "P1H",
+            "PT1D",
+            "PT1Y",
+            "P1D1M",
+            "P1W1D",
+            "P1WT1H",
+            "P1.5D",
+            "PT1.5M",
+            "PT.5S",
+            "PT1.S",
+            "PT1.0000000001S",
+            "P-1D",
+            "P1DT",
+            "P4294967296Y",
+            "P613566757W",
+        ] {
+            duration_input_test(raw, None);
+        }
+
+        let input = <InputValue<DefaultScalarValue>>::scalar(3600);
+        let parsed: Option<IsoDuration> = FromInputValue::from_input_value(&input);
+        assert_eq!(parsed, None);
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::IsoDuration;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(duration: IsoDuration) -> IsoDuration {
+            duration
+        }
+
+        fn months(duration: IsoDuration) -> i32 {
+            (duration.years * 12 + duration.months) as i32
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{
+                    echo(duration: "P1Y2M10DT2H30M")
+                    months(duration: "P1Y2M")
+                }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({
+                    "echo": "P1Y2M10DT2H30M",
+                    "months": 14,
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert!(matches!(
+            crate::execute(
+                r#"{ echo(duration: "P1H") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Err(crate::GraphQLError::ValidationError(_)),
+        ));
+    }
+}
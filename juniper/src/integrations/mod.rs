@@ -1,11 +1,39 @@
 //! Provides GraphQLType implementations for some external types
 
+#[cfg(feature = "basis-points")]
+pub mod basis_points;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+#[cfg(feature = "bs58")]
+pub mod bs58;
 #[cfg(feature = "bson")]
 pub mod bson;
+#[cfg(feature = "bytesize")]
+pub mod bytesize;
 #[cfg(feature = "chrono")]
 pub mod chrono;
 #[cfg(feature = "chrono-tz")]
 pub mod chrono_tz;
+#[cfg(feature = "cron")]
+pub mod cron;
+#[cfg(feature = "interval")]
+pub mod interval;
+#[cfg(feature = "std-net")]
+pub mod ip;
+#[cfg(feature = "ipnetwork")]
+pub mod ipnetwork;
+#[cfg(feature = "iso-duration")]
+pub mod iso_duration;
+#[cfg(feature = "money")]
+pub mod money;
+#[cfg(feature = "otel")]
+pub mod otel;
+#[cfg(feature = "rrule")]
+pub mod rrule;
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal;
+#[cfg(feature = "semver")]
+pub mod semver;
 #[doc(hidden)]
 pub mod serde;
 #[cfg(feature = "url")]
@@ -0,0 +1,401 @@
+//! GraphQL support for amounts of money represented in minor units of an [ISO 4217][1] currency.
+//!
+//! Amounts are exposed as the `MoneyAmount` object having `minorUnits` and `currency` fields,
+//! and are accepted in the same shape via the `MoneyAmountInput` input object (or the
+//! `NonNegativeMoneyAmountInput` one, rejecting negative amounts), so no decimal strings have
+//! to be parsed on either side.
+//!
+//! [1]: https://www.iso.org/iso-4217-currency-codes.html
+
+use std::fmt;
+
+use crate::{
+    ast::{FromInputValue, InputValue, ToInputValue},
+    executor::Registry,
+    graphql_object,
+    macros::helper::input_validation::ValidateInput,
+    schema::meta::MetaType,
+    types::{
+        base::{GraphQLType, GraphQLValue},
+        marker::IsInputType,
+    },
+    value::ScalarValue,
+};
+
+/// Currencies having no minor units.
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND",
+    "VUV", "XAF", "XOF", "XPF",
+];
+
+/// Currencies having a thousandth as their minor unit.
+const THREE_DECIMAL_CURRENCIES: &[&str] = &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+
+/// Checks whether the given `code` is an [ISO 4217][1] alphabetic one (three uppercase ASCII
+/// letters).
+///
+/// [1]: https://www.iso.org/iso-4217-currency-codes.html
+fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// Amount of money in minor units of its currency (e.g. cents for `USD`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoneyAmount {
+    minor_units: i32,
+    currency: String,
+}
+
+impl MoneyAmount {
+    /// Creates a new [`MoneyAmount`] of the given `minor_units` of the `currency`.
+    ///
+    /// Returns [`None`] if the `currency` is not an [ISO 4217][1] alphabetic code (three
+    /// uppercase ASCII letters).
+    ///
+    /// [1]: https://www.iso.org/iso-4217-currency-codes.html
+    pub fn new(minor_units: i32, currency: &str) -> Option<Self> {
+        is_currency_code(currency).then(|| Self {
+            minor_units,
+            currency: currency.to_owned(),
+        })
+    }
+
+    /// Number of digits of the minor unit of this amount's currency, e.g. `2` for `USD` and `0`
+    /// for `JPY`.
+    pub fn exponent(&self) -> u32 {
+        let currency = self.currency.as_str();
+        if ZERO_DECIMAL_CURRENCIES.contains(&currency) {
+            0
+        } else if THREE_DECIMAL_CURRENCIES.contains(&currency) {
+            3
+        } else {
+            2
+        }
+    }
+}
+
+#[graphql_object]
+impl MoneyAmount {
+    /// Amount in minor units of the currency (e.g. cents).
+    pub fn minor_units(&self) -> i32 {
+        self.minor_units
+    }
+
+    /// ISO 4217 alphabetic code of the currency.
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+}
+
+impl fmt::Display for MoneyAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let exp = self.exponent();
+        if exp == 0 {
+            return write!(f, "{} {}", self.minor_units, self.currency);
+        }
+
+        let scale = 10_u32.pow(exp);
+        let abs = self.minor_units.unsigned_abs();
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{}{}.{:0width$} {}",
+            sign,
+            abs / scale,
+            abs % scale,
+            self.currency,
+            width = exp as usize,
+        )
+    }
+}
+
+/// Input object accepting a [`MoneyAmount`].
+///
+/// If `NON_NEGATIVE` is `true`, negative amounts are rejected, and the input object is named
+/// `NonNegativeMoneyAmountInput` instead of `MoneyAmountInput`.
+///
+/// Amounts of invalid currencies (and negative ones, if rejected) are reported as errors of the
+/// fields accepting them as arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoneyAmountInput<const NON_NEGATIVE: bool = false>(MoneyAmount);
+
+/// Input object accepting a [`MoneyAmount`] that is not negative.
+pub type NonNegativeMoneyAmountInput = MoneyAmountInput<true>;
+
+impl<const NON_NEGATIVE: bool> MoneyAmountInput<NON_NEGATIVE> {
+    /// Returns the accepted [`MoneyAmount`].
+    pub fn into_inner(self) -> MoneyAmount {
+        self.0
+    }
+}
+
+impl<const NON_NEGATIVE: bool> From<MoneyAmountInput<NON_NEGATIVE>> for MoneyAmount {
+    fn from(input: MoneyAmountInput<NON_NEGATIVE>) -> Self {
+        input.0
+    }
+}
+
+impl<S, const NON_NEGATIVE: bool> GraphQLType<S> for MoneyAmountInput<NON_NEGATIVE>
+where
+    S: ScalarValue,
+{
+    fn name(_: &()) -> Option<&'static str> {
+        Some(if NON_NEGATIVE {
+            "NonNegativeMoneyAmountInput"
+        } else {
+            "MoneyAmountInput"
+        })
+    }
+
+    fn meta<'r>(info: &(), registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = &[
+            registry
+                .arg::<i32>("minorUnits", info)
+                .description("Amount in minor units of the currency (e.g. cents)."),
+            registry
+                .arg::<String>("currency", info)
+                .description("ISO 4217 alphabetic code of the currency."),
+        ];
+
+        registry
+            .build_input_object_type::<Self>(info, fields)
+            .description(if NON_NEGATIVE {
+                "Non-negative amount of money in minor units of its currency"
+            } else {
+                "Amount of money in minor units of its currency"
+            })
+            .into_meta()
+    }
+}
+
+impl<S, const NON_NEGATIVE: bool> GraphQLValue<S> for MoneyAmountInput<NON_NEGATIVE>
+where
+    S: ScalarValue,
+{
+    type Context = ();
+    type TypeInfo = ();
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+}
+
+impl<S, const NON_NEGATIVE: bool> FromInputValue<S> for MoneyAmountInput<NON_NEGATIVE>
+where
+    S: ScalarValue,
+{
+    fn from_input_value(v: &InputValue<S>) -> Option<Self> {
+        let obj = v.to_object_value()?;
+        Some(Self(MoneyAmount {
+            minor_units: obj.get("minorUnits")?.convert()?,
+            currency: obj.get("currency")?.convert()?,
+        }))
+    }
+}
+
+impl<const NON_NEGATIVE: bool> ValidateInput for MoneyAmountInput<NON_NEGATIVE> {
+    fn validate_input(&self) -> Result<(), String> {
+        if !is_currency_code(&self.0.currency) {
+            return Err(format!(
+                "`{}` is not an ISO 4217 currency code",
+                self.0.currency,
+            ));
+        }
+        if NON_NEGATIVE && self.0.minor_units < 0 {
+            return Err("amount must not be negative".into());
+        }
+        Ok(())
+    }
+}
+
+impl<S, const NON_NEGATIVE: bool> ToInputValue<S> for MoneyAmountInput<NON_NEGATIVE>
+where
+    S: ScalarValue,
+{
+    fn to_input_value(&self) -> InputValue<S> {
+        InputValue::object(
+            vec![
+                ("minorUnits", self.0.minor_units.to_input_value()),
+                ("currency", self.0.currency.to_input_value()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+impl<S, const NON_NEGATIVE: bool> IsInputType<S> for MoneyAmountInput<NON_NEGATIVE> where
+    S: ScalarValue
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        macros::helper::input_validation::ValidateInput as _, DefaultScalarValue, FromInputValue,
+        InputValue,
+    };
+
+    use super::{MoneyAmount, MoneyAmountInput, NonNegativeMoneyAmountInput};
+
+    fn input(minor_units: i32, currency: &str) -> InputValue<DefaultScalarValue> {
+        InputValue::object(
+            vec![
+                ("minorUnits", InputValue::scalar(minor_units)),
+                ("currency", InputValue::scalar(currency)),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    fn money_input_test<T>(raw: InputValue<DefaultScalarValue>, expected: Option<T>)
+    where
+        T: FromInputValue<DefaultScalarValue> + std::fmt::Debug + PartialEq,
+    {
+        let parsed: Option<T> = FromInputValue::from_input_value(&raw);
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn usd_from_input_value() {
+        money_input_test(
+            input(1234, "USD"),
+            Some(MoneyAmountInput::<false>(
+                MoneyAmount::new(1234, "USD").unwrap(),
+            )),
+        );
+    }
+
+    #[test]
+    fn jpy_from_input_value() {
+        money_input_test(
+            input(1234, "JPY"),
+            Some(MoneyAmountInput::<false>(
+                MoneyAmount::new(1234, "JPY").unwrap(),
+            )),
+        );
+    }
+
+    #[test]
+    fn validates_negative() {
+        let raw = input(-5, "USD");
+
+        let parsed: MoneyAmountInput = FromInputValue::from_input_value(&raw).unwrap();
+        assert_eq!(parsed.validate_input(), Ok(()));
+        assert_eq!(parsed.into_inner(), MoneyAmount::new(-5, "USD").unwrap());
+
+        let parsed: NonNegativeMoneyAmountInput = FromInputValue::from_input_value(&raw).unwrap();
+        assert_eq!(
+            parsed.validate_input(),
+            Err("amount must not be negative".into()),
+        );
+    }
+
+    #[test]
+    fn validates_currency() {
+        for currency in &["usd", "US", "USDX", "U$D"] {
+            let parsed: MoneyAmountInput =
+                FromInputValue::from_input_value(&input(1, currency)).unwrap();
+            assert_eq!(
+                parsed.validate_input(),
+                Err(format!("`{}` is not an ISO 4217 currency code", currency)),
+            );
+        }
+    }
+
+    #[test]
+    fn scales_by_currency() {
+        assert_eq!(MoneyAmount::new(1234, "USD").unwrap().exponent(), 2);
+        assert_eq!(MoneyAmount::new(1234, "JPY").unwrap().exponent(), 0);
+        assert_eq!(MoneyAmount::new(1234, "KWD").unwrap().exponent(), 3);
+
+        assert_eq!(
+            MoneyAmount::new(1234, "USD").unwrap().to_string(),
+            "12.34 USD"
+        );
+        assert_eq!(
+            MoneyAmount::new(-5, "USD").unwrap().to_string(),
+            "-0.05 USD"
+        );
+        assert_eq!(
+            MoneyAmount::new(1234, "JPY").unwrap().to_string(),
+            "1234 JPY"
+        );
+        assert_eq!(
+            MoneyAmount::new(1234, "KWD").unwrap().to_string(),
+            "1.234 KWD"
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::{MoneyAmount, MoneyAmountInput, NonNegativeMoneyAmountInput};
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn price(currency: String) -> Option<MoneyAmount> {
+            MoneyAmount::new(1234, &currency)
+        }
+
+        fn echo(amount: MoneyAmountInput) -> MoneyAmount {
+            amount.into()
+        }
+
+        fn refund(amount: NonNegativeMoneyAmountInput) -> MoneyAmount {
+            amount.into_inner()
+        }
+    }
+
+    fn schema() -> RootNode<'static, Root, EmptyMutation, EmptySubscription> {
+        RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn resolves_amounts() {
+        let doc = r#"{
+            usd: price(currency: "USD") { minorUnits currency }
+            jpy: price(currency: "JPY") { minorUnits currency }
+            echo(amount: {minorUnits: -100, currency: "JPY"}) { minorUnits currency }
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema(), &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({
+                    "usd": {"minorUnits": 1234, "currency": "USD"},
+                    "jpy": {"minorUnits": 1234, "currency": "JPY"},
+                    "echo": {"minorUnits": (-100), "currency": "JPY"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_literals() {
+        for doc in &[
+            r#"{ echo(amount: {minorUnits: 1, currency: "usd"}) { currency } }"#,
+            r#"{ refund(amount: {minorUnits: -1, currency: "USD"}) { currency } }"#,
+        ] {
+            let (_, errors) = crate::execute(doc, None, &schema(), &Variables::new(), &())
+                .await
+                .unwrap();
+            assert_eq!(errors.len(), 1, "for document: {}", doc);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Export of the timings of resolved fields as [OpenTelemetry][1] spans.
+//!
+//! The [`OtelExporter`] is registered as the [`Instrumentation`] of a schema via
+//! [`RootNode::with_instrumentation`], collecting an [`OtelSpan`] for every resolved field. The
+//! spans follow the [semantic conventions][2] for GraphQL, and may be handed over to any
+//! OpenTelemetry SDK or tracing backend, so Juniper doesn't depend on any of them.
+//!
+//! [1]: https://opentelemetry.io
+//! [2]: https://opentelemetry.io/docs/specs/semconv/graphql/graphql-spans
+//! [`RootNode::with_instrumentation`]: crate::RootNode::with_instrumentation
+
+use std::{
+    sync::Mutex,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use crate::executor::{FieldTiming, Instrumentation};
+
+/// [OpenTelemetry][1] span of resolving a single field.
+///
+/// [1]: https://opentelemetry.io
+#[derive(Clone, Debug, PartialEq)]
+pub struct OtelSpan {
+    /// Name of this span, being `<Type>.<field>` of the resolved field.
+    pub name: String,
+
+    /// Identifier of this span.
+    pub span_id: u64,
+
+    /// Identifier of the span of the field the resolved field is selected on, or [`None`] for
+    /// the fields of the root type.
+    pub parent_span_id: Option<u64>,
+
+    /// Start time of this span, in nanoseconds since the Unix epoch.
+    pub start_time_unix_nano: u64,
+
+    /// Duration of this span.
+    pub duration: Duration,
+
+    /// Attributes of this span: `graphql.field.name`, `graphql.field.path` and
+    /// `graphql.type.name`.
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl OtelSpan {
+    /// End time of this span, in nanoseconds since the Unix epoch.
+    pub fn end_time_unix_nano(&self) -> u64 {
+        self.start_time_unix_nano
+            .saturating_add(self.duration.as_nanos() as u64)
+    }
+}
+
+impl From<FieldTiming> for OtelSpan {
+    fn from(timing: FieldTiming) -> Self {
+        let start_time_unix_nano = timing
+            .start
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+
+        Self {
+            name: format!("{}.{}", timing.type_name, timing.field_name),
+            span_id: timing.id,
+            parent_span_id: timing.parent,
+            start_time_unix_nano,
+            duration: timing.duration,
+            attributes: vec![
+                ("graphql.field.name", timing.field_name),
+                ("graphql.field.path", timing.path.join(".")),
+                ("graphql.type.name", timing.type_name),
+            ],
+        }
+    }
+}
+
+/// [`Instrumentation`] collecting the timings of resolved fields as [`OtelSpan`]s.
+#[derive(Debug, Default)]
+pub struct OtelExporter {
+    spans: Mutex<Vec<OtelSpan>>,
+}
+
+impl OtelExporter {
+    /// Creates a new exporter without any spans collected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes all the spans collected so far, in the order the fields were resolved in.
+    pub fn take_spans(&self) -> Vec<OtelSpan> {
+        std::mem::take(&mut *self.spans.lock().unwrap())
+    }
+}
+
+impl Instrumentation for OtelExporter {
+    fn record(&self, timing: FieldTiming) {
+        self.spans.lock().unwrap().push(timing.into());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::executor::FieldTiming;
+
+    use super::OtelSpan;
+
+    #[test]
+    fn from_field_timing() {
+        let span = OtelSpan::from(FieldTiming {
+            id: 2,
+            parent: Some(1),
+            type_name: "User".into(),
+            field_name: "birthDate".into(),
+            path: vec!["user".into(), "birthDate".into()],
+            start: UNIX_EPOCH + Duration::from_secs(1),
+            duration: Duration::from_millis(5),
+        });
+
+        assert_eq!(span.name, "User.birthDate");
+        assert_eq!(span.span_id, 2);
+        assert_eq!(span.parent_span_id, Some(1));
+        assert_eq!(span.start_time_unix_nano, 1_000_000_000);
+        assert_eq!(span.end_time_unix_nano(), 1_005_000_000);
+        assert_eq!(
+            span.attributes,
+            vec![
+                ("graphql.field.name", "birthDate".to_owned()),
+                ("graphql.field.path", "user.birthDate".to_owned()),
+                ("graphql.type.name", "User".to_owned()),
+            ],
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use std::sync::Arc;
+
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::{OtelExporter, OtelSpan};
+
+    struct User {
+        name: &'static str,
+        friends: Vec<User>,
+    }
+
+    #[graphql_object]
+    impl User {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn birth_date() -> &'static str {
+            "1990-01-02"
+        }
+
+        async fn friends(&self) -> &[User] {
+            &self.friends
+        }
+    }
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn user() -> User {
+            User {
+                name: "Alice",
+                friends: vec![
+                    User {
+                        name: "Bob",
+                        friends: vec![],
+                    },
+                    User {
+                        name: "Carol",
+                        friends: vec![],
+                    },
+                ],
+            }
+        }
+    }
+
+    /// Finds the spans of the given `parent` span, as pairs of their names and paths.
+    fn children(spans: &[OtelSpan], parent: Option<u64>) -> Vec<(&str, &str)> {
+        let mut children = spans
+            .iter()
+            .filter(|s| s.parent_span_id == parent)
+            .map(|s| (s.name.as_str(), s.attributes[1].1.as_str()))
+            .collect::<Vec<_>>();
+        children.sort_unstable();
+        children
+    }
+
+    fn span_id(spans: &[OtelSpan], path: &str) -> Option<u64> {
+        spans
+            .iter()
+            .find(|s| s.attributes[1].1 == path)
+            .map(|s| s.span_id)
+    }
+
+    #[tokio::test]
+    async fn exports_span_tree() {
+        let exporter = Arc::new(OtelExporter::new());
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new())
+            .with_instrumentation(exporter.clone());
+
+        let doc = r#"{
+            user {
+                name
+                friends {
+                    name
+                    birthDate
+                }
+            }
+        }"#;
+        let (_, errs) = crate::execute(doc, None, &schema, &Variables::new(), &())
+            .await
+            .unwrap();
+        assert_eq!(errs, vec![]);
+
+        let spans = exporter.take_spans();
+        assert_eq!(spans.len(), 7);
+        assert!(exporter.take_spans().is_empty());
+
+        assert_eq!(children(&spans, None), vec![("Root.user", "user")]);
+        assert_eq!(
+            children(&spans, span_id(&spans, "user")),
+            vec![("User.friends", "user.friends"), ("User.name", "user.name")],
+        );
+        assert_eq!(
+            children(&spans, span_id(&spans, "user.friends")),
+            vec![
+                ("User.birthDate", "user.friends.birthDate"),
+                ("User.birthDate", "user.friends.birthDate"),
+                ("User.name", "user.friends.name"),
+                ("User.name", "user.friends.name"),
+            ],
+        );
+    }
+
+    #[test]
+    fn exports_span_tree_sync() {
+        let exporter = Arc::new(OtelExporter::new());
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new())
+            .with_instrumentation(exporter.clone());
+
+        let (_, errs) =
+            crate::execute_sync("{ user { name } }", None, &schema, &Variables::new(), &())
+                .unwrap();
+        assert_eq!(errs, vec![]);
+
+        let spans = exporter.take_spans();
+        assert_eq!(children(&spans, None), vec![("Root.user", "user")]);
+        assert_eq!(
+            children(&spans, span_id(&spans, "user")),
+            vec![("User.name", "user.name")],
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! GraphQL support for recurrence rules as defined by [RFC 5545][1] (`RRULE`).
+//!
+//! Rules are validated structurally only (known parts, well-formed values), they are never
+//! expanded into occurrences.
+//!
+//! [1]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+
+use std::fmt;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+/// Values allowed for the `FREQ` part.
+const FREQUENCIES: &[&str] = &[
+    "SECONDLY", "MINUTELY", "HOURLY", "DAILY", "WEEKLY", "MONTHLY", "YEARLY",
+];
+
+/// Values allowed for the `WKST` part, and as weekdays of the `BYDAY` part.
+const WEEKDAYS: &[&str] = &["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// Recurrence rule as defined by [RFC 5545][1], e.g. `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR`.
+///
+/// Stored in a normalized form: uppercased, without the optional `RRULE:` prefix, and with the
+/// `FREQ` part going first.
+///
+/// [1]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Rrule(String);
+
+impl Rrule {
+    /// Parses and normalizes the given recurrence `rule`.
+    ///
+    /// Returns [`None`] if the `rule` is malformed: misses the `FREQ` part, contains unknown or
+    /// repeated parts, has invalid values, or specifies both `COUNT` and `UNTIL`.
+    pub fn new(rule: &str) -> Option<Self> {
+        let rule = rule.trim().to_ascii_uppercase();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(&rule);
+
+        let mut freq = None;
+        let mut parts = Vec::new();
+        for part in rule.split(';') {
+            let (key, value) = part.split_once('=')?;
+            if key == "FREQ" {
+                if freq.replace(value).is_some() || !FREQUENCIES.contains(&value) {
+                    return None;
+                }
+                continue;
+            }
+            if !is_valid_part(key, value) || parts.iter().any(|(k, _)| *k == key) {
+                return None;
+            }
+            parts.push((key, value));
+        }
+
+        let has = |key| parts.iter().any(|(k, _)| *k == key);
+        if has("COUNT") && has("UNTIL") {
+            return None;
+        }
+
+        let mut normalized = format!("FREQ={}", freq?);
+        for (key, value) in parts {
+            normalized.push(';');
+            normalized.push_str(key);
+            normalized.push('=');
+            normalized.push_str(value);
+        }
+        Some(Self(normalized))
+    }
+
+    /// Returns the normalized string representation of this rule.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Rrule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Checks the `value` of a rule part (other than `FREQ`) named `key`.
+fn is_valid_part(key: &str, value: &str) -> bool {
+    let is_number = |v: &str, min: i32, max: i32, signed: bool| {
+        let digits = if signed {
+            v.strip_prefix(&['+', '-'][..]).unwrap_or(v)
+        } else {
+            v
+        };
+        !digits.is_empty()
+            && digits.bytes().all(|b| b.is_ascii_digit())
+            && matches!(digits.parse(), Ok(n) if (min..=max).contains(&n))
+    };
+    let list = |min, max, signed| value.split(',').all(|v| is_number(v, min, max, signed));
+
+    match key {
+        "COUNT" | "INTERVAL" => is_number(value, 1, i32::MAX, false),
+        "UNTIL" => is_valid_until(value),
+        "BYSECOND" => list(0, 60, false),
+        "BYMINUTE" => list(0, 59, false),
+        "BYHOUR" => list(0, 23, false),
+        "BYMONTHDAY" => list(1, 31, true),
+        "BYYEARDAY" | "BYSETPOS" => list(1, 366, true),
+        "BYWEEKNO" => list(1, 53, true),
+        "BYMONTH" => list(1, 12, false),
+        "BYDAY" => value.split(',').all(|day| {
+            let at = day.len().saturating_sub(2);
+            if !day.is_char_boundary(at) {
+                return false;
+            }
+            let (ord, weekday) = day.split_at(at);
+            WEEKDAYS.contains(&weekday) && (ord.is_empty() || is_number(ord, 1, 53, true))
+        }),
+        "WKST" => WEEKDAYS.contains(&value),
+        _ => false,
+    }
+}
+
+/// Checks the value of the `UNTIL` part being either a date (`YYYYMMDD`) or a date-time
+/// (`YYYYMMDDTHHMMSS`, optionally followed by `Z`).
+fn is_valid_until(value: &str) -> bool {
+    let (date, time) = match value.split_once('T') {
+        Some((date, time)) => (date, Some(time.strip_suffix('Z').unwrap_or(time))),
+        None => (value, None),
+    };
+    let digits = |s: &str, len| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+    digits(date, 8) && time.into_iter().all(|t| digits(t, 6))
+}
+
+#[crate::graphql_scalar(description = "Recurrence rule (RFC 5545 RRULE)")]
+impl<S> GraphQLScalar for Rrule
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.0.clone())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Rrule> {
+        v.as_string_value().and_then(Rrule::new)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DefaultScalarValue, FromInputValue, InputValue};
+
+    use super::Rrule;
+
+    fn rrule_input_test(raw: &str, expected: Option<&str>) {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        let parsed: Option<Rrule> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(
+            parsed.as_ref().map(Rrule::as_str),
+            expected,
+            "for input: {}",
+            raw,
+        );
+    }
+
+    #[test]
+    fn daily_from_input_value() {
+        rrule_input_test("FREQ=DAILY;COUNT=10", Some("FREQ=DAILY;COUNT=10"));
+        rrule_input_test(
+            "RRULE:interval=2;freq=daily;until=19971224T000000Z",
+            Some("FREQ=DAILY;INTERVAL=2;UNTIL=19971224T000000Z"),
+        );
+    }
+
+    #[test]
+    fn weekly_by_day_from_input_value() {
+        rrule_input_test(
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR;WKST=SU",
+            Some("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR;WKST=SU"),
+        );
+        rrule_input_test(
+            "FREQ=MONTHLY;BYDAY=-1FR,+2MO",
+            Some("FREQ=MONTHLY;BYDAY=-1FR,+2MO"),
+        );
+    }
+
+    #[test]
+    fn missing_freq_from_input_value() {
+        rrule_input_test("INTERVAL=2;BYDAY=MO", None);
+        rrule_input_test("", None);
+    }
+
+    #[test]
+    fn malformed_from_input_value() {
+        for raw in &[
+            "FREQ=FORTNIGHTLY",
+            "FREQ=DAILY;FREQ=WEEKLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;COUNT=3;UNTIL=19971224",
+            "FREQ=WEEKLY;BYDAY=XX",
+            "FREQ=WEEKLY;BYDAY=MO;BYDAY=TU",
+            "FREQ=YEARLY;BYMONTH=13",
+            "FREQ=DAILY;UNTIL=1997-12-24",
+            "FREQ=DAILY;FOO=BAR",
+            "FREQ=DAILY;",
+            "FREQ=WEEKLY;BYDAY=ÖM",
+            "FREQ=WEEKLY;BYDAY=1ÖM",
+        ] {
+            rrule_input_test(raw, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::Rrule;
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(rule: Rrule) -> Rrule {
+            rule
+        }
+    }
+
+    #[tokio::test]
+    async fn serializes_normalized() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                r#"{ echo(rule: "RRULE:byday=MO,TH;freq=weekly") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"echo": "FREQ=WEEKLY;BYDAY=MO,TH"}), vec![])),
+        );
+    }
+}
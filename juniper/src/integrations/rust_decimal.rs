@@ -0,0 +1,178 @@
+//! GraphQL support for [rust_decimal](https://docs.rs/rust_decimal) types.
+//!
+//! A [`Decimal`] is serialized as a string (e.g. `"0.3"`), as JSON numbers are usually decoded as
+//! IEEE doubles, losing its precision. For convenience, it's accepted as a number too, being
+//! parsed right from the query source, so `0.1` literal means exactly `0.1`.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::{
+    parser::{ParseError, ScalarToken, Token},
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+#[crate::graphql_scalar(description = "Decimal number of fixed precision, serialized as a string")]
+impl<S> GraphQLScalar for Decimal
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Decimal> {
+        if let Some(s) = v.as_string_value() {
+            parse_decimal(s)
+        } else if let Some(i) = v.as_int_value() {
+            Some(Decimal::from(i))
+        } else {
+            // Shortest representation of the `f64` is used, so `0.1` is `0.1` rather than
+            // `0.1000000000000000055511151231257827`.
+            v.as_float_value()
+                .and_then(|f| parse_decimal(&f.to_string()))
+        }
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        match value {
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(value),
+            // Numbers are kept as strings, without being rounded to `f64`.
+            ScalarToken::Int(raw) | ScalarToken::Float(raw) => parse_decimal(raw)
+                .map(|d| S::from(d.to_string()))
+                .ok_or(ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+}
+
+/// Parses the given `raw` decimal number, either in plain or scientific notation.
+fn parse_decimal(raw: &str) -> Option<Decimal> {
+    <Decimal as FromStr>::from_str(raw)
+        .or_else(|_| Decimal::from_scientific(raw))
+        .ok()
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal::Decimal;
+
+    use crate::{
+        parser::{ParseError, ScalarToken, Token},
+        DefaultScalarValue, FromInputValue, InputValue, ParseScalarValue,
+    };
+
+    fn decimal_input_test(input: InputValue<DefaultScalarValue>, expected: Option<Decimal>) {
+        let parsed: Option<Decimal> = FromInputValue::from_input_value(&input);
+
+        assert_eq!(parsed, expected, "for input: {:?}", input);
+    }
+
+    #[test]
+    fn from_input_value() {
+        decimal_input_test(InputValue::scalar("0.1"), Some(Decimal::new(1, 1)));
+        decimal_input_test(
+            InputValue::scalar("-12.345"),
+            Some(Decimal::new(-12_345, 3)),
+        );
+        decimal_input_test(InputValue::scalar("1e-3"), Some(Decimal::new(1, 3)));
+        decimal_input_test(InputValue::scalar(42), Some(Decimal::new(42, 0)));
+        decimal_input_test(InputValue::scalar(0.1), Some(Decimal::new(1, 1)));
+    }
+
+    #[test]
+    fn invalid_from_input_value() {
+        decimal_input_test(InputValue::scalar(""), None);
+        decimal_input_test(InputValue::scalar("0.1.2"), None);
+        decimal_input_test(InputValue::scalar("ten"), None);
+        decimal_input_test(InputValue::scalar(f64::NAN), None);
+        decimal_input_test(InputValue::scalar(true), None);
+    }
+
+    #[test]
+    fn from_str() {
+        for (token, expected) in &[
+            (ScalarToken::String("0.1"), "0.1"),
+            (ScalarToken::Int("42"), "42"),
+            (
+                ScalarToken::Int("12345678901234567890"),
+                "12345678901234567890",
+            ),
+            (
+                ScalarToken::Float("0.12345678901234567890"),
+                "0.12345678901234567890",
+            ),
+            (ScalarToken::Float("1.5e3"), "1500"),
+        ] {
+            assert_eq!(
+                <Decimal as ParseScalarValue<DefaultScalarValue>>::from_str(*token),
+                Ok(DefaultScalarValue::String((*expected).into())),
+                "for token: {:?}",
+                token,
+            );
+        }
+
+        let token = ScalarToken::Float("1e100");
+        assert_eq!(
+            <Decimal as ParseScalarValue<DefaultScalarValue>>::from_str(token),
+            Err(ParseError::UnexpectedToken(Token::Scalar(token))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use rust_decimal::Decimal;
+
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        InputValue,
+    };
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn sum(a: Decimal, b: Decimal) -> Decimal {
+            a + b
+        }
+    }
+
+    #[tokio::test]
+    async fn sums_exactly() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let doc = r#"{
+            strings: sum(a: "0.1", b: "0.2")
+            numbers: sum(a: 0.1, b: 0.2)
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+            Ok((graphql_value!({"strings": "0.3", "numbers": "0.3"}), vec![],)),
+        );
+        assert_ne!(0.1 + 0.2, 0.3);
+    }
+
+    #[tokio::test]
+    async fn sums_variables_exactly() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        let doc = "query($a: Decimal!, $b: Decimal!) { sum(a: $a, b: $b) }";
+        let vars = vec![
+            ("a".to_owned(), InputValue::scalar("0.1")),
+            ("b".to_owned(), InputValue::scalar(0.2)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &vars, &()).await,
+            Ok((graphql_value!({"sum": "0.3"}), vec![])),
+        );
+    }
+}
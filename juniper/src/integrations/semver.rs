@@ -0,0 +1,77 @@
+//! GraphQL support for [semver](https://docs.rs/semver) types.
+
+use semver::VersionReq;
+
+use crate::{
+    value::{ParseScalarResult, ParseScalarValue},
+    Value,
+};
+
+#[crate::graphql_scalar(description = "Semantic version requirement, e.g. `>=1.2, <2.0`")]
+impl<S> GraphQLScalar for VersionReq
+where
+    S: ScalarValue,
+{
+    fn resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<VersionReq> {
+        v.as_string_value().and_then(|s| VersionReq::parse(s).ok())
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use semver::{Version, VersionReq};
+
+    use crate::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+
+    fn version_req_from_input_value(raw: &str) -> Option<VersionReq> {
+        let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+        FromInputValue::from_input_value(&input)
+    }
+
+    #[test]
+    fn caret_from_input_value() {
+        let req = version_req_from_input_value("^1.2.3").unwrap();
+
+        assert_eq!(req, VersionReq::parse("^1.2.3").unwrap());
+        assert!(req.matches(&Version::new(1, 9, 0)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+        assert_eq!(
+            ToInputValue::<DefaultScalarValue>::to_input_value(&req),
+            InputValue::scalar("^1.2.3"),
+        );
+    }
+
+    #[test]
+    fn range_from_input_value() {
+        let req = version_req_from_input_value(">=1.2, <2.0").unwrap();
+
+        assert!(req.matches(&Version::new(1, 2, 0)));
+        assert!(!req.matches(&Version::new(1, 1, 9)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+        assert_eq!(
+            ToInputValue::<DefaultScalarValue>::to_input_value(&req),
+            InputValue::scalar(">=1.2, <2.0"),
+        );
+    }
+
+    #[test]
+    fn invalid_from_input_value() {
+        for raw in &[">=", "1.2.3.4", "~>1.0", "foo"] {
+            assert_eq!(
+                version_req_from_input_value(raw),
+                None,
+                "for input: {}",
+                raw
+            );
+        }
+    }
+}
@@ -9,15 +9,16 @@ use std::fmt;
 
 use crate::{
     ast::InputValue,
-    executor::ExecutionError,
+    executor::{ExecutionError, PathSegment},
+    operation_names,
     parser::{ParseError, SourcePosition, Spanning},
     validation::RuleError,
     GraphQLError, Object, ScalarValue, Value,
 };
 
 #[derive(Serialize)]
-struct SerializeHelper {
-    message: &'static str,
+struct SerializeHelper<'m> {
+    message: &'m str,
 }
 
 impl<T> ser::Serialize for ExecutionError<T>
@@ -49,6 +50,18 @@ where
     }
 }
 
+impl ser::Serialize for PathSegment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self {
+            PathSegment::Field(name) => serializer.serialize_str(name),
+            PathSegment::Index(index) => serializer.serialize_u64(*index as u64),
+        }
+    }
+}
+
 impl<'a> ser::Serialize for GraphQLError<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -61,9 +74,12 @@ impl<'a> ser::Serialize for GraphQLError<'a> {
                 message: "Must provide an operation",
             }]
             .serialize(serializer),
-            GraphQLError::MultipleOperationsProvided => [SerializeHelper {
-                message: "Must provide operation name \
-                          if query contains multiple operations",
+            GraphQLError::MultipleOperationsProvided(ref names) => [SerializeHelper {
+                message: &format!(
+                    "Must provide operation name \
+                     if query contains multiple operations{}",
+                    operation_names(names),
+                ),
             }]
             .serialize(serializer),
             GraphQLError::UnknownOperationName => [SerializeHelper {
@@ -78,6 +94,10 @@ impl<'a> ser::Serialize for GraphQLError<'a> {
                 message: "Expected subscription, got query",
             }]
             .serialize(serializer),
+            GraphQLError::NotSafelisted => [SerializeHelper {
+                message: "Document is not in the safelist",
+            }]
+            .serialize(serializer),
         }
     }
 }
@@ -373,7 +393,7 @@ impl<'a> ser::Serialize for Spanning<ParseError<'a>> {
 
 impl<T> ser::Serialize for Object<T>
 where
-    T: ser::Serialize,
+    Value<T>: ser::Serialize,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -407,9 +427,41 @@ where
     }
 }
 
+/// [`Value`] serialized with its floats having no fractional part serialized
+/// as integers (e.g. `1467969011` instead of `1467969011.0`), for the clients
+/// not accepting the latter.
+#[derive(Clone, Copy, Debug)]
+pub struct IntegerFloats<'a, S>(pub &'a Value<S>);
+
+impl<'a, T> ser::Serialize for IntegerFloats<'a, T>
+where
+    T: ScalarValue,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self.0 {
+            Value::Scalar(s) => match (s.as_int(), s.as_float()) {
+                (None, Some(f))
+                    if f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 =>
+                {
+                    serializer.serialize_i64(f as i64)
+                }
+                _ => s.serialize(serializer),
+            },
+            Value::List(v) => serializer.collect_seq(v.iter().map(IntegerFloats)),
+            Value::Object(o) => {
+                serializer.collect_map(o.iter().map(|(k, v)| (k, IntegerFloats(v))))
+            }
+            Value::Null => serializer.serialize_unit(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{ExecutionError, GraphQLError};
+    use super::{ExecutionError, GraphQLError, IntegerFloats};
     use crate::{
         ast::InputValue,
         value::{DefaultScalarValue, Object},
@@ -459,4 +511,129 @@ mod tests {
             r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[],"extensions":{"foo":"bar"}}"#
         );
     }
+
+    #[test]
+    fn error_extensions_of_resolved_field() {
+        use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn user(id: i32) -> Result<String, FieldError> {
+                Err(
+                    FieldError::new(format!("User {} not found", id), Value::null())
+                        .with_extension("code", graphql_value!("NOT_FOUND"))
+                        .with_extension("retryable", graphql_value!(true)),
+                )
+            }
+        }
+
+        let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::new());
+        let (_, errors) =
+            crate::execute_sync("{ user(id: 42) }", None, &schema, &Variables::new(), &()).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            serde_json::json!([{
+                "message": "User 42 not found",
+                "locations": [{"line": 1, "column": 3}],
+                "path": ["user"],
+                "extensions": {"code": "NOT_FOUND", "retryable": true},
+            }]),
+        );
+    }
+
+    #[test]
+    fn error_path_of_list_element() {
+        use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+        struct Query;
+
+        struct User {
+            id: i32,
+        }
+
+        #[graphql_object]
+        impl User {
+            fn name(&self) -> Result<Option<String>, FieldError> {
+                if self.id == 2 {
+                    Err(FieldError::new("Name is unavailable", Value::null()))
+                } else {
+                    Ok(Some(format!("User {}", self.id)))
+                }
+            }
+        }
+
+        #[graphql_object]
+        impl Query {
+            fn users() -> Vec<User> {
+                (1..=3).map(|id| User { id }).collect()
+            }
+        }
+
+        let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::new());
+        let (data, errors) =
+            crate::execute_sync("{ users { name } }", None, &schema, &Variables::new(), &())
+                .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&data).unwrap(),
+            serde_json::json!({"users": [
+                {"name": "User 1"},
+                {"name": null},
+                {"name": "User 3"},
+            ]}),
+        );
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            serde_json::json!([{
+                "message": "Name is unavailable",
+                "locations": [{"line": 1, "column": 11}],
+                "path": ["users", 1, "name"],
+            }]),
+        );
+    }
+
+    #[test]
+    fn integer_valued_floats() {
+        assert_eq!(
+            to_string(&IntegerFloats(&Value::<DefaultScalarValue>::scalar(
+                1_467_969_011.0
+            )))
+            .unwrap(),
+            "1467969011",
+        );
+        assert_eq!(
+            to_string(&IntegerFloats(&Value::<DefaultScalarValue>::scalar(-3.0))).unwrap(),
+            "-3",
+        );
+        assert_eq!(
+            to_string(&IntegerFloats(&Value::<DefaultScalarValue>::list(vec![
+                Value::scalar(2.0),
+                Value::object(vec![("n", Value::scalar(42.0))].into_iter().collect()),
+            ])))
+            .unwrap(),
+            r#"[2,{"n":42}]"#,
+        );
+        assert_eq!(
+            to_string(&Value::<DefaultScalarValue>::scalar(2.0)).unwrap(),
+            "2.0",
+        );
+    }
+
+    #[test]
+    fn fractional_floats() {
+        assert_eq!(
+            to_string(&IntegerFloats(&Value::<DefaultScalarValue>::scalar(
+                1_467_969_011.5
+            )))
+            .unwrap(),
+            "1467969011.5",
+        );
+        assert_eq!(
+            to_string(&IntegerFloats(&Value::<DefaultScalarValue>::scalar(0.25))).unwrap(),
+            "0.25",
+        );
+    }
 }
@@ -29,7 +29,10 @@ where
 mod test {
     use url::Url;
 
-    use crate::{DefaultScalarValue, InputValue};
+    use crate::{
+        parser::{ParseError, ScalarToken, Token},
+        DefaultScalarValue, InputValue, ParseScalarValue,
+    };
 
     #[test]
     fn url_from_input_value() {
@@ -41,4 +44,110 @@ mod test {
 
         assert_eq!(parsed, url);
     }
+
+    #[test]
+    fn malformed_url_from_input_value() {
+        for raw in &["", "example.net", "https://exa mple.net/", "http://[::1/"] {
+            let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+            let parsed: Option<Url> = crate::FromInputValue::from_input_value(&input);
+
+            assert_eq!(parsed, None, "for input: {}", raw);
+        }
+
+        let input = <InputValue<DefaultScalarValue>>::scalar(42);
+        let parsed: Option<Url> = crate::FromInputValue::from_input_value(&input);
+        assert_eq!(parsed, None);
+    }
+
+    #[test]
+    fn url_from_str() {
+        assert_eq!(
+            <Url as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::String(
+                "https://example.net/"
+            )),
+            Ok(DefaultScalarValue::String("https://example.net/".into())),
+        );
+        assert_eq!(
+            <Url as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::Int("42")),
+            Err(ParseError::UnexpectedToken(Token::Scalar(
+                ScalarToken::Int("42")
+            ))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use url::Url;
+
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn avatar(profile: Url) -> Option<Url> {
+                profile.join("avatar.png").ok()
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            crate::execute(
+                r#"{ avatar(profile: "https://example.net/users/1/") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"avatar": "https://example.net/users/1/avatar.png"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_literals() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn echo(url: Url) -> Url {
+                url
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert!(matches!(
+            crate::execute(
+                r#"{ echo(url: "example.net") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Err(crate::GraphQLError::ValidationError(_)),
+        ));
+    }
 }
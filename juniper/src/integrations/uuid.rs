@@ -24,17 +24,20 @@ where
     }
 
     fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
-        if let ScalarToken::String(value) = value {
-            Ok(S::from(value.to_owned()))
-        } else {
-            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        match value {
+            ScalarToken::String(raw) if Uuid::parse_str(raw).is_ok() => Ok(S::from(raw.to_owned())),
+            _ => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{value::DefaultScalarValue, InputValue};
+    use crate::{
+        parser::{ParseError, ScalarToken, Token},
+        value::{DefaultScalarValue, ParseScalarValue},
+        InputValue,
+    };
     use uuid::Uuid;
 
     #[test]
@@ -47,4 +50,81 @@ mod test {
 
         assert_eq!(parsed, id);
     }
+
+    #[test]
+    fn malformed_uuid_from_input_value() {
+        for raw in &["", "123e4567", "123e4567-e89b-12d3-a456-42665544000g"] {
+            let input = <InputValue<DefaultScalarValue>>::scalar(raw.to_string());
+
+            let parsed: Option<Uuid> = crate::FromInputValue::from_input_value(&input);
+
+            assert_eq!(parsed, None, "for input: {}", raw);
+        }
+    }
+
+    #[test]
+    fn uuid_from_str() {
+        let raw = "123e4567-e89b-12d3-a456-426655440000";
+
+        assert_eq!(
+            <Uuid as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::String(raw)),
+            Ok(DefaultScalarValue::String(raw.into())),
+        );
+        for token in &[ScalarToken::String("123e4567"), ScalarToken::Int("123")] {
+            assert_eq!(
+                <Uuid as ParseScalarValue<DefaultScalarValue>>::from_str(*token),
+                Err(ParseError::UnexpectedToken(Token::Scalar(*token))),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_test {
+    use uuid::Uuid;
+
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    #[tokio::test]
+    async fn test_serialization() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn example_uuid() -> Uuid {
+                Uuid::from_u128(0x123E_4567_E89B_12D3_A456_4266_5544_0000)
+            }
+
+            fn echo(id: Uuid) -> Uuid {
+                id
+            }
+        }
+
+        let doc = r#"{
+            exampleUuid
+            echo(id: "123E4567-E89B-12D3-A456-426655440000")
+        }"#;
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        assert_eq!(
+            crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({
+                    "exampleUuid": "123e4567-e89b-12d3-a456-426655440000",
+                    "echo": "123e4567-e89b-12d3-a456-426655440000",
+                }),
+                vec![],
+            )),
+        );
+    }
 }
@@ -97,12 +97,17 @@ Juniper has not reached 1.0 yet, thus some API instability should be expected.
 // path correctly, without errors.
 extern crate self as juniper;
 
-use std::fmt;
+use std::{fmt, time::Instant};
 
 // These are required by the code generated via the `juniper_codegen` macros.
 #[doc(hidden)]
 pub use {async_trait::async_trait, futures, serde, static_assertions as sa};
 
+// Required by the code generated for flattening and validating input values via the
+// `juniper_codegen` macros.
+#[doc(hidden)]
+pub use crate::macros::helper::{input_flattening, input_validation};
+
 #[doc(inline)]
 pub use futures::future::{BoxFuture, LocalBoxFuture};
 
@@ -111,7 +116,8 @@ pub use futures::future::{BoxFuture, LocalBoxFuture};
 // functionality automatically.
 pub use juniper_codegen::{
     graphql_interface, graphql_object, graphql_scalar, graphql_subscription, graphql_union,
-    GraphQLEnum, GraphQLInputObject, GraphQLObject, GraphQLScalarValue, GraphQLUnion,
+    GraphQLArguments, GraphQLEnum, GraphQLInputObject, GraphQLObject, GraphQLScalarValue,
+    GraphQLUnion,
 };
 
 #[macro_use]
@@ -119,8 +125,11 @@ mod value;
 #[macro_use]
 mod macros;
 mod ast;
+#[cfg(feature = "chrono")]
+pub mod cell_value;
 pub mod executor;
 mod introspection;
+pub mod pagination;
 pub mod parser;
 pub(crate) mod schema;
 mod types;
@@ -143,6 +152,7 @@ mod executor_tests;
 pub use crate::util::to_camel_case;
 
 use crate::{
+    ast::OwnedDocument,
     executor::{execute_validated_query, get_operation},
     introspection::{INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS},
     parser::parse_document_source,
@@ -151,39 +161,49 @@ use crate::{
 
 pub use crate::{
     ast::{
-        Definition, Document, FromInputValue, InputValue, Operation, OperationType, Selection,
-        ToInputValue, Type,
+        Definition, Document, FaithfulDocument, FromInputValue, InputValue, Operation,
+        OperationType, Selection, ToInputValue, Type,
     },
     executor::{
-        Applies, Context, ExecutionError, ExecutionResult, Executor, FieldError, FieldResult,
-        FromContext, IntoFieldError, IntoResolvable, LookAheadArgument, LookAheadMethods,
-        LookAheadSelection, LookAheadValue, OwnedExecutor, Registry, ValuesStream, Variables,
+        Applies, BatchLoader, Cache, CacheKey, Config, Context, ContextChain, ContextProvider,
+        ContextVariant, ErrorSeverity, ExecutionError, ExecutionResult, Executor, FeatureFlags,
+        FieldError, FieldResult, FieldTiming, FromContext, Instrumentation, IntoFieldError,
+        IntoResolvable, Loader, LoaderContext, Loaders, LookAheadArgument, LookAheadMethods,
+        LookAheadSelection, LookAheadValue, OwnedExecutor, PathSegment, RateLimit, RateLimiter,
+        Registry, Repository, Timer, ValuesStream, Variables,
     },
+    integrations::serde::IntegerFloats,
     introspection::IntrospectionFormat,
     macros::helper::{
+        enum_value_eq,
         subscription::{ExtractTypeFromStream, IntoFieldResult},
         AsDynGraphQLValue,
     },
     parser::{ParseError, Spanning},
     schema::{
         meta,
-        model::{RootNode, SchemaType},
+        model::{OutputCheck, ResponseTransform, RootNode, SchemaError, SchemaType},
+        sdl,
     },
     types::{
         async_await::{DynGraphQLValueAsync, GraphQLTypeAsync, GraphQLValueAsync},
         base::{Arguments, DynGraphQLValue, GraphQLType, GraphQLValue, TypeKind},
         marker::{self, GraphQLInterface, GraphQLObject, GraphQLUnion},
-        nullable::Nullable,
+        nullable::{non_null_if, Nullable},
         scalars::{EmptyMutation, EmptySubscription, ID},
+        stream_list::StreamList,
         subscriptions::{
-            ExecutionOutput, GraphQLSubscriptionType, GraphQLSubscriptionValue,
-            SubscriptionConnection, SubscriptionCoordinator,
+            snapshot_then_updates, with_correlation_ids, ExecutionOutput, GraphQLSubscriptionType,
+            GraphQLSubscriptionValue, SubscriptionConnection, SubscriptionCoordinator,
         },
     },
     validation::RuleError,
     value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
 };
 
+#[cfg(feature = "introspection-json")]
+pub use crate::schema::write_introspection_json;
+
 /// An error that prevented query execution
 #[derive(Debug, PartialEq)]
 #[allow(missing_docs)]
@@ -191,10 +211,11 @@ pub enum GraphQLError<'a> {
     ParseError(Spanning<ParseError<'a>>),
     ValidationError(Vec<RuleError>),
     NoOperationProvided,
-    MultipleOperationsProvided,
+    MultipleOperationsProvided(Vec<String>),
     UnknownOperationName,
     IsSubscription,
     NotSubscription,
+    NotSafelisted,
 }
 
 impl<'a> fmt::Display for GraphQLError<'a> {
@@ -208,16 +229,89 @@ impl<'a> fmt::Display for GraphQLError<'a> {
                 Ok(())
             }
             GraphQLError::NoOperationProvided => write!(f, "No operation provided"),
-            GraphQLError::MultipleOperationsProvided => write!(f, "Multiple operations provided"),
+            GraphQLError::MultipleOperationsProvided(names) => write!(
+                f,
+                "Multiple operations provided, one of them must be selected by name{}",
+                operation_names(names),
+            ),
             GraphQLError::UnknownOperationName => write!(f, "Unknown operation name"),
             GraphQLError::IsSubscription => write!(f, "Operation is a subscription"),
             GraphQLError::NotSubscription => write!(f, "Operation is not a subscription"),
+            GraphQLError::NotSafelisted => write!(f, "Document is not in the safelist"),
         }
     }
 }
 
 impl<'a> std::error::Error for GraphQLError<'a> {}
 
+/// Formats the given operation `names` as a comma-separated list of quoted names following a
+/// colon, to be appended to an error message
+///
+/// Anonymous operations can't be selected by name, so nothing is appended if there are no named
+/// ones.
+pub(crate) fn operation_names(names: &[String]) -> String {
+    if names.is_empty() {
+        return String::new();
+    }
+    let names = names
+        .iter()
+        .map(|name| format!("`{}`", name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(": {}", names)
+}
+
+/// Parses the `document_source` and validates it against the `schema`, with
+/// the rules depending on the `variables` taking them into account
+///
+/// Documents not in the safelist of the `schema` (if any) are rejected before
+/// being lexed at all.
+fn parse_validated_document<'a, S>(
+    document_source: &'a str,
+    schema: &SchemaType<S>,
+    variables: &Variables<S>,
+) -> Result<OwnedDocument<'a, S>, GraphQLError<'a>>
+where
+    S: ScalarValue,
+{
+    if !schema.is_safelisted(document_source) {
+        return Err(GraphQLError::NotSafelisted);
+    }
+
+    let document = parse_document_source(document_source, schema)?;
+
+    let mut ctx = ValidatorContext::new(schema, &document).with_variables(variables);
+    visit_all_rules(&mut ctx, &document);
+
+    let errors = ctx.into_errors();
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
+    }
+
+    Ok(document)
+}
+
+/// Selects the operation of the `document` to execute, validating the given
+/// `variables` against it
+fn validated_operation<'b, 'd, S>(
+    document: &'b Document<'d, S>,
+    operation_name: Option<&str>,
+    variables: &Variables<S>,
+    schema: &SchemaType<S>,
+) -> Result<&'b Spanning<Operation<'d, S>>, GraphQLError<'d>>
+where
+    S: ScalarValue,
+{
+    let operation = get_operation(document, operation_name)?;
+
+    let errors = validate_input_values(variables, operation, schema);
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
+    }
+
+    Ok(operation)
+}
+
 /// Execute a query synchronously in a provided schema
 pub fn execute_sync<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
@@ -232,27 +326,8 @@ where
     MutationT: GraphQLType<S, Context = QueryT::Context>,
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+    let document = parse_validated_document(document_source, &root_node.schema, variables)?;
+    let operation = validated_operation(&document, operation_name, variables, &root_node.schema)?;
 
     execute_validated_query(&document, operation, root_node, variables, context)
 }
@@ -275,30 +350,79 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
+    execute_until(
+        document_source,
+        operation_name,
+        root_node,
+        variables,
+        context,
+        None,
+    )
+    .await
+}
 
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
+/// Execute a query in a provided schema, bounding the total time of its
+/// resolution by the given `deadline`
+///
+/// Fields not resolved by the time the `deadline` passes fail with a deadline
+/// error, while the ones resolved already are kept in the returned partial
+/// result. The time left is measured by the [`Timer`] of the `context`.
+pub async fn execute_with_deadline<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    deadline: Instant,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Timer + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    execute_until(
+        document_source,
+        operation_name,
+        root_node,
+        variables,
+        context,
+        Some(executor::Deadline::at(deadline, Some(context))),
+    )
+    .await
+}
 
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+/// Execute a query in a provided schema, failing the fields not resolved by
+/// the given `deadline`, if any
+async fn execute_until<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &QueryT::Context,
+    deadline: Option<executor::Deadline>,
+) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context> + Sync,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let document = parse_validated_document(document_source, &root_node.schema, variables)?;
+    let operation = validated_operation(&document, operation_name, variables, &root_node.schema)?;
 
-    executor::execute_validated_query_async(&document, operation, root_node, variables, context)
-        .await
+    executor::execute_validated_query_until(
+        &document, operation, root_node, variables, context, deadline,
+    )
+    .await
 }
 
 /// Resolve subscription into `ValuesStream`
@@ -319,28 +443,8 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document: crate::ast::OwnedDocument<'a, S> =
-        parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+    let document = parse_validated_document(document_source, &root_node.schema, variables)?;
+    let operation = validated_operation(&document, operation_name, variables, &root_node.schema)?;
 
     executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
         .await
@@ -370,6 +474,21 @@ where
     )
 }
 
+/// Compute the hash identifying a query document for persisted queries
+///
+/// The result is the lowercase hex-encoded SHA-256 digest of the raw document
+/// text, as computed by clients sending persisted queries. Documents differing
+/// only in formatting hash the same once normalized with
+/// [`parser::normalize_document`], if both the clients and the server do so.
+pub fn query_hash(document: &str) -> String {
+    use sha2::{Digest as _, Sha256};
+
+    Sha256::digest(document.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 impl<'a> From<Spanning<ParseError<'a>>> for GraphQLError<'a> {
     fn from(f: Spanning<ParseError<'a>>) -> GraphQLError<'a> {
         GraphQLError::ParseError(f)
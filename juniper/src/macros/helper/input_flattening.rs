@@ -0,0 +1,132 @@
+//! Helper types for inlining the fields of a struct into the arguments of a
+//! field or into the fields of an input object.
+//!
+//! Used in `#[derive(GraphQLArguments)]` and `#[derive(GraphQLInputObject)]`
+//! macros for the `#[graphql(flatten)]` attribute of input object fields, and
+//! in the field macros for the `#[graphql(flatten)]` attribute of arguments.
+
+use indexmap::IndexMap;
+
+use crate::{
+    meta::Argument, parser::Spanning, DefaultScalarValue, InputValue, Registry, ScalarValue,
+};
+
+/// Fields of an input object value, as returned by [`InputValue::to_object_value`], or
+/// arguments of a field.
+pub type InputFields<'a, S> = IndexMap<&'a str, &'a InputValue<S>>;
+
+/// Fields of an [`InputValue::Object`].
+pub type ObjectFields<S> = Vec<(Spanning<String>, Spanning<InputValue<S>>)>;
+
+/// Names of the fields of an input object, including the ones inlined into it via
+/// `#[graphql(flatten)]`.
+#[derive(Clone, Copy, Debug)]
+pub struct InputObjectFieldNames {
+    /// Names of the fields declared by the input object itself.
+    pub own: &'static [&'static str],
+
+    /// Names of the fields of the structs flattened into this one.
+    pub flattened: &'static [&'static InputObjectFieldNames],
+}
+
+impl InputObjectFieldNames {
+    /// Checks whether a field with the given `name` is among these ones.
+    pub const fn contains(&self, name: &str) -> bool {
+        let mut i = 0;
+        while i < self.own.len() {
+            if str_eq(self.own[i], name) {
+                return true;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < self.flattened.len() {
+            if self.flattened[i].contains(name) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Checks whether any of these field names is among the `other` ones.
+    pub const fn intersects(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < self.own.len() {
+            if other.contains(self.own[i]) {
+                return true;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < self.flattened.len() {
+            if self.flattened[i].intersects(other) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Checks whether the fields inlined via `#[graphql(flatten)]` collide with each other or
+    /// with the [`InputObjectFieldNames::own`] ones.
+    ///
+    /// The [`InputObjectFieldNames::own`] fields are assumed to be unique already.
+    pub const fn have_collisions(&self) -> bool {
+        let own = Self {
+            own: self.own,
+            flattened: &[],
+        };
+        let mut i = 0;
+        while i < self.flattened.len() {
+            if own.intersects(self.flattened[i]) {
+                return true;
+            }
+            let mut j = i + 1;
+            while j < self.flattened.len() {
+                if self.flattened[i].intersects(self.flattened[j]) {
+                    return true;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+/// Compares the given strings in `const` context.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Struct, whose fields are inlined via `#[graphql(flatten)]` into the arguments of a field
+/// or into the fields of an input object.
+///
+/// Implemented by the `#[derive(GraphQLArguments)]` and `#[derive(GraphQLInputObject)]` macros.
+pub trait FlattenInputObject<S: ScalarValue = DefaultScalarValue>: Sized {
+    /// Names of all the fields of this struct.
+    const FIELD_NAMES: InputObjectFieldNames;
+
+    /// Registers the fields of this struct as arguments of a field or fields of an input object.
+    fn meta_fields<'r>(registry: &mut Registry<'r, S>) -> Vec<Argument<'r, S>>
+    where
+        S: 'r;
+
+    /// Converts the given arguments of a field or fields of an input object to this struct.
+    fn from_input_fields(fields: &InputFields<'_, S>) -> Option<Self>;
+
+    /// Pushes the fields of this struct to the given fields of an input object.
+    fn to_input_fields(&self, fields: &mut ObjectFields<S>);
+}
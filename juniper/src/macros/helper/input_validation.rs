@@ -0,0 +1,110 @@
+//! Helper types for validating input values against the constraints not
+//! expressible in the schema.
+//!
+//! Used in `#[derive(GraphQLInputObject)]` and `#[derive(GraphQLArguments)]`
+//! macros for the `#[graphql(one_of)]` and `#[graphql(validate = ...)]`
+//! attributes, and in the field macros for validating the converted arguments.
+
+use std::sync::Arc;
+
+use crate::{FieldError, Nullable, ScalarValue};
+
+/// Input value checked against the constraints not expressible in the schema
+/// (e.g. across several fields of an input object) after its conversion.
+///
+/// Implemented by the `#[derive(GraphQLInputObject)]` and
+/// `#[derive(GraphQLArguments)]` macros.
+pub trait ValidateInput {
+    /// Checks this value, returning the message of the violated constraint.
+    fn validate_input(&self) -> Result<(), String>;
+}
+
+impl<T: ValidateInput> ValidateInput for Option<T> {
+    fn validate_input(&self) -> Result<(), String> {
+        self.as_ref().map_or(Ok(()), T::validate_input)
+    }
+}
+
+impl<T: ValidateInput> ValidateInput for Nullable<T> {
+    fn validate_input(&self) -> Result<(), String> {
+        match self {
+            Self::Some(v) => v.validate_input(),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidateInput> ValidateInput for Vec<T> {
+    fn validate_input(&self) -> Result<(), String> {
+        self.iter().try_for_each(T::validate_input)
+    }
+}
+
+impl<T: ValidateInput, const N: usize> ValidateInput for [T; N] {
+    fn validate_input(&self) -> Result<(), String> {
+        self.iter().try_for_each(T::validate_input)
+    }
+}
+
+impl<T: ValidateInput + ?Sized> ValidateInput for Box<T> {
+    fn validate_input(&self) -> Result<(), String> {
+        (**self).validate_input()
+    }
+}
+
+impl<T: ValidateInput + ?Sized> ValidateInput for Arc<T> {
+    fn validate_input(&self) -> Result<(), String> {
+        (**self).validate_input()
+    }
+}
+
+/// Reference to a converted input value, validated via [`ValidateInput`] if
+/// its type implements it, and accepted as it is otherwise.
+///
+/// The generated code doesn't know whether the types of the arguments and
+/// fields implement [`ValidateInput`], so it uses [autoref-based
+/// specialization][1]: `(&MaybeValidate(&value)).validate()` resolves to
+/// [`ViaValidateInput::validate`] for the types implementing it, and to
+/// [`NoValidation::validate`] for the others, with both traits in scope.
+///
+/// [1]: https://github.com/dtolnay/case-studies/tree/master/autoref-specialization
+pub struct MaybeValidate<'a, T>(pub &'a T);
+
+/// Validation of a [`MaybeValidate`] value implementing [`ValidateInput`].
+pub trait ViaValidateInput {
+    /// Checks the value via [`ValidateInput::validate_input`].
+    fn validate(&self) -> Result<(), String>;
+}
+
+impl<T: ValidateInput> ViaValidateInput for MaybeValidate<'_, T> {
+    fn validate(&self) -> Result<(), String> {
+        self.0.validate_input()
+    }
+}
+
+/// Validation of a [`MaybeValidate`] value not implementing [`ValidateInput`].
+pub trait NoValidation {
+    /// Accepts any value.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<T> NoValidation for &MaybeValidate<'_, T> {}
+
+/// Error of the argument named `name` failing the given validation `error`.
+pub fn invalid_argument<S: ScalarValue>(name: &str, error: String) -> FieldError<S> {
+    FieldError::new(
+        format!("Invalid value of argument `{}`: {}", name, error),
+        graphql_value!({ "code": "INVALID_ARGUMENT" }),
+    )
+}
+
+/// Error of the arguments flattened via `#[graphql(flatten)]` failing the
+/// given validation `error`.
+pub fn invalid_arguments<S: ScalarValue>(error: String) -> FieldError<S> {
+    FieldError::new(
+        format!("Invalid arguments: {}", error),
+        graphql_value!({ "code": "INVALID_ARGUMENT" }),
+    )
+}
@@ -1,5 +1,7 @@
 //! Helper traits and definitions for macros.
 
+pub mod input_flattening;
+pub mod input_validation;
 pub mod subscription;
 
 use crate::{DefaultScalarValue, DynGraphQLValue, DynGraphQLValueAsync, ScalarValue};
@@ -32,3 +34,16 @@ pub trait AsDynGraphQLValue<S: ScalarValue = DefaultScalarValue> {
 }
 
 crate::sa::assert_obj_safe!(AsDynGraphQLValue<Context = (), TypeInfo = ()>);
+
+/// Checks whether the given `input` represents the enum value named `name`.
+///
+/// With the `lenient-enums` feature enabled, the `input` is compared case-insensitively (so
+/// `"monday"` represents the `MONDAY` value), otherwise it should match the `name` exactly.
+#[inline]
+pub fn enum_value_eq(input: &str, name: &str) -> bool {
+    if cfg!(feature = "lenient-enums") {
+        input.eq_ignore_ascii_case(name)
+    } else {
+        input == name
+    }
+}
@@ -0,0 +1,181 @@
+//! Helpers for offset-based pagination of list fields.
+
+use crate::GraphQLInputObject;
+
+/// Slice of items requested by pagination, along with the total number of
+/// items available
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page<T> {
+    /// Items of the requested slice
+    pub items: Vec<T>,
+
+    /// Total number of items available before paginating
+    pub total_count: usize,
+}
+
+/// Reusable `offset`/`limit` pagination arguments
+///
+/// Accept it as a single argument of a field to paginate its results:
+///
+/// ```rust
+/// # use juniper::{graphql_object, pagination::Pagination};
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn numbers(#[graphql(default)] page: Pagination) -> Vec<i32> {
+///         page.paginate((1..=100).collect()).items
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, GraphQLInputObject, PartialEq)]
+#[graphql(description = "Offset-based pagination arguments")]
+pub struct Pagination {
+    /// Number of items to skip
+    pub offset: Option<i32>,
+
+    /// Maximum number of items to return
+    pub limit: Option<i32>,
+}
+
+impl Pagination {
+    /// Paginates the given `items` according to these arguments
+    ///
+    /// See [`paginate`] for details.
+    pub fn paginate<T>(&self, items: Vec<T>) -> Page<T> {
+        paginate(items, self.offset, self.limit)
+    }
+}
+
+/// Selects the slice of `items` starting at `offset` containing at most
+/// `limit` items
+///
+/// A missing or negative `offset` is treated as zero, and a missing or
+/// negative `limit` as no limit at all. The total count of the returned
+/// [`Page`] is always the number of the original `items`.
+pub fn paginate<T>(items: Vec<T>, offset: Option<i32>, limit: Option<i32>) -> Page<T> {
+    let total_count = items.len();
+    let offset = offset.map_or(0, |o| o.max(0) as usize);
+    let limit = limit.filter(|l| *l >= 0).map_or(usize::MAX, |l| l as usize);
+
+    Page {
+        items: items.into_iter().skip(offset).take(limit).collect(),
+        total_count,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{paginate, Page, Pagination};
+
+    #[test]
+    fn paginates_slice() {
+        assert_eq!(
+            paginate((1..=10).collect(), Some(2), Some(3)),
+            Page {
+                items: vec![3, 4, 5],
+                total_count: 10,
+            },
+        );
+    }
+
+    #[test]
+    fn paginates_past_end() {
+        assert_eq!(
+            paginate((1..=10).collect(), Some(8), Some(5)),
+            Page {
+                items: vec![9, 10],
+                total_count: 10,
+            },
+        );
+        assert_eq!(
+            paginate((1..=10).collect::<Vec<i32>>(), Some(20), None).items,
+            Vec::<i32>::new(),
+        );
+    }
+
+    #[test]
+    fn defaults_to_everything() {
+        assert_eq!(
+            Pagination::default().paginate(vec![1, 2, 3]),
+            Page {
+                items: vec![1, 2, 3],
+                total_count: 3,
+            },
+        );
+        assert_eq!(
+            paginate(vec![1, 2, 3], Some(-1), Some(-1)).items,
+            vec![1, 2, 3],
+        );
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod integration_test {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use crate::{
+        executor::Variables,
+        graphql_object,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::Pagination;
+
+    struct DatePage {
+        dates: Vec<DateTime<Utc>>,
+        total_count: i32,
+    }
+
+    #[graphql_object]
+    impl DatePage {
+        fn dates(&self) -> &[DateTime<Utc>] {
+            &self.dates
+        }
+
+        fn total_count(&self) -> i32 {
+            self.total_count
+        }
+    }
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn dates(#[graphql(default)] page: Pagination) -> DatePage {
+            let dates = (1..=5)
+                .map(|day| Utc.with_ymd_and_hms(2021, 7, day, 0, 0, 0).unwrap())
+                .collect();
+            let page = page.paginate(dates);
+            DatePage {
+                dates: page.items,
+                total_count: page.total_count as i32,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn paginates_dates() {
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+
+        assert_eq!(
+            crate::execute(
+                "{ dates(page: {offset: 1, limit: 2}) { dates totalCount } }",
+                None,
+                &schema,
+                &Variables::new(),
+                &(),
+            )
+            .await,
+            Ok((
+                graphql_value!({"dates": {
+                    "dates": ["2021-07-02T00:00:00+00:00", "2021-07-03T00:00:00+00:00"],
+                    "totalCount": 5,
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
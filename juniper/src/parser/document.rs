@@ -1,9 +1,9 @@
 use std::borrow::Cow;
 
 use crate::ast::{
-    Arguments, Definition, Directive, Field, Fragment, FragmentSpread, InlineFragment, InputValue,
-    Operation, OperationType, OwnedDocument, Selection, Type, VariableDefinition,
-    VariableDefinitions,
+    Arguments, Definition, Directive, FaithfulDocument, Field, Fragment, FragmentSpread,
+    InlineFragment, InputValue, Operation, OperationType, OwnedDocument, Selection, Type,
+    VariableDefinition, VariableDefinitions,
 };
 
 use crate::{
@@ -31,6 +31,26 @@ where
     parse_document(&mut parser, schema)
 }
 
+/// Parses the document in the faithful mode, retaining its comments and the
+/// original source along with the parsed definitions.
+#[doc(hidden)]
+pub fn parse_document_source_faithful<'a, 'b, S>(
+    s: &'a str,
+    schema: &'b SchemaType<'b, S>,
+) -> UnlocatedParseResult<'a, FaithfulDocument<'a, S>>
+where
+    S: ScalarValue,
+{
+    let mut lexer = Lexer::new(s).preserve_comments();
+    let mut parser = Parser::new(&mut lexer).map_err(|s| s.map(ParseError::LexerError))?;
+    let definitions = parse_document(&mut parser, schema)?;
+    Ok(FaithfulDocument {
+        source: s,
+        definitions,
+        comments: lexer.take_comments(),
+    })
+}
+
 fn parse_document<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
@@ -15,6 +15,7 @@ pub struct Lexer<'a> {
     length: usize,
     position: SourcePosition,
     has_reached_eof: bool,
+    comments: Option<Vec<Spanning<&'a str>>>,
 }
 
 /// A single scalar value literal
@@ -34,6 +35,8 @@ pub enum ScalarToken<'a> {
 pub enum Token<'a> {
     Name(&'a str),
     Scalar(ScalarToken<'a>),
+    /// Raw contents of a `"""` block string, used for descriptions in SDL
+    BlockString(&'a str),
     ExclamationMark,
     Dollar,
     ParenOpen,
@@ -47,6 +50,7 @@ pub enum Token<'a> {
     Equals,
     At,
     Pipe,
+    Ampersand,
     EndOfFile,
 }
 
@@ -105,9 +109,27 @@ impl<'a> Lexer<'a> {
             length: source.len(),
             position: SourcePosition::new_origin(),
             has_reached_eof: false,
+            comments: None,
         }
     }
 
+    /// Makes this lexer collect the comments it skips over, so they may be
+    /// retrieved via [`Lexer::take_comments`]
+    pub fn preserve_comments(mut self) -> Lexer<'a> {
+        self.comments = Some(Vec::new());
+        self
+    }
+
+    /// Takes the comments collected so far, without their leading `#`
+    ///
+    /// Always empty unless [`Lexer::preserve_comments`] was called.
+    pub fn take_comments(&mut self) -> Vec<Spanning<&'a str>> {
+        self.comments
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
     fn peek_char(&mut self) -> Option<(usize, char)> {
         assert!(self.position.index() <= self.length);
         assert!(!self.has_reached_eof);
@@ -132,6 +154,10 @@ impl<'a> Lexer<'a> {
         next
     }
 
+    fn is_at_block_string(&mut self) -> bool {
+        matches!(self.peek_char(), Some((idx, _)) if self.source[idx..].starts_with("\"\"\""))
+    }
+
     fn emit_single_char(&mut self, t: Token<'a>) -> Spanning<Token<'a>> {
         assert!(self.position.index() <= self.length);
 
@@ -148,18 +174,27 @@ impl<'a> Lexer<'a> {
             if ch == '\t' || ch == ' ' || ch == '\n' || ch == '\r' || ch == ',' {
                 self.next_char();
             } else if ch == '#' {
-                self.next_char();
+                let start_pos = self.position;
+                let (start_idx, _) = self.next_char().unwrap();
 
-                while let Some((_, ch)) = self.peek_char() {
-                    if is_source_char(ch) && (ch == '\n' || ch == '\r') {
-                        self.next_char();
-                        break;
-                    } else if is_source_char(ch) {
+                // Line terminators are left for the outer loop to skip over.
+                let mut end_idx = self.length;
+                while let Some((idx, ch)) = self.peek_char() {
+                    if is_source_char(ch) && ch != '\n' && ch != '\r' {
                         self.next_char();
                     } else {
+                        end_idx = idx;
                         break;
                     }
                 }
+
+                if let Some(comments) = &mut self.comments {
+                    comments.push(Spanning::start_end(
+                        &start_pos,
+                        &self.position,
+                        &self.source[start_idx + 1..end_idx],
+                    ));
+                }
             } else {
                 break;
             }
@@ -273,6 +308,46 @@ impl<'a> Lexer<'a> {
         ))
     }
 
+    fn scan_block_string(&mut self) -> LexerResult<'a> {
+        let start_pos = self.position;
+        let (start_idx, _) = self
+            .peek_char()
+            .ok_or_else(|| Spanning::zero_width(&self.position, LexerError::UnexpectedEndOfFile))?;
+        for _ in 0..3 {
+            self.next_char();
+        }
+
+        while let Some((idx, ch)) = self.peek_char() {
+            let rest = &self.source[idx..];
+            if rest.starts_with("\"\"\"") {
+                for _ in 0..3 {
+                    self.next_char();
+                }
+                return Ok(Spanning::start_end(
+                    &start_pos,
+                    &self.position,
+                    Token::BlockString(&self.source[start_idx + 3..idx]),
+                ));
+            }
+            if !is_source_char(ch) {
+                return Err(Spanning::zero_width(
+                    &self.position,
+                    LexerError::UnknownCharacterInString(ch),
+                ));
+            }
+            // Escaped triple quotes don't terminate the string.
+            let escape_len = if rest.starts_with("\\\"\"\"") { 4 } else { 1 };
+            for _ in 0..escape_len {
+                self.next_char();
+            }
+        }
+
+        Err(Spanning::zero_width(
+            &self.position,
+            LexerError::UnterminatedString,
+        ))
+    }
+
     fn scan_escaped_unicode(
         &mut self,
         start_pos: &SourcePosition,
@@ -458,7 +533,9 @@ impl<'a> Iterator for Lexer<'a> {
             Some('=') => Ok(self.emit_single_char(Token::Equals)),
             Some('@') => Ok(self.emit_single_char(Token::At)),
             Some('|') => Ok(self.emit_single_char(Token::Pipe)),
+            Some('&') => Ok(self.emit_single_char(Token::Ampersand)),
             Some('.') => self.scan_ellipsis(),
+            Some('"') if self.is_at_block_string() => self.scan_block_string(),
             Some('"') => self.scan_string(),
             Some(ch) => {
                 if is_number_start(ch) {
@@ -490,6 +567,7 @@ impl<'a> fmt::Display for Token<'a> {
             Token::Scalar(ScalarToken::String(s)) => {
                 write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
             }
+            Token::BlockString(s) => write!(f, "\"\"\"{}\"\"\"", s),
             Token::ExclamationMark => write!(f, "!"),
             Token::Dollar => write!(f, "$"),
             Token::ParenOpen => write!(f, "("),
@@ -503,6 +581,7 @@ impl<'a> fmt::Display for Token<'a> {
             Token::Equals => write!(f, "="),
             Token::At => write!(f, "@"),
             Token::Pipe => write!(f, "|"),
+            Token::Ampersand => write!(f, "&"),
             Token::EndOfFile => write!(f, "End of file"),
         }
     }
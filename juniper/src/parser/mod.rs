@@ -3,6 +3,7 @@
 
 mod document;
 mod lexer;
+mod normalize;
 mod parser;
 mod utils;
 mod value;
@@ -10,10 +11,11 @@ mod value;
 #[cfg(test)]
 mod tests;
 
-pub use self::document::parse_document_source;
+pub use self::document::{parse_document_source, parse_document_source_faithful};
 
 pub use self::{
     lexer::{Lexer, LexerError, ScalarToken, Token},
+    normalize::normalize_document,
     parser::{OptionParseResult, ParseError, ParseResult, Parser, UnlocatedParseResult},
     utils::{SourcePosition, Spanning},
 };
@@ -0,0 +1,55 @@
+use crate::parser::{Lexer, LexerError, ScalarToken, Spanning, Token};
+
+/// Normalizes the given GraphQL document source into a canonical textual form.
+///
+/// The normalized form contains no comments, commas or superfluous whitespace:
+/// tokens are separated by a single space only where it's required to keep
+/// them apart (i.e. between two names or scalar literals). Top-level
+/// definitions (operations and fragments) are sorted, so their order in the
+/// original source doesn't matter.
+///
+/// The document is only tokenized, not parsed or validated, so no schema is
+/// required.
+pub fn normalize_document(source: &str) -> Result<String, Spanning<LexerError>> {
+    let mut definitions = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut needs_space = false;
+
+    for token in Lexer::new(source) {
+        let token = token?.item;
+        let is_word = matches!(token, Token::Name(_) | Token::Scalar(_));
+
+        match token {
+            Token::EndOfFile => break,
+            Token::CurlyOpen => depth += 1,
+            Token::CurlyClose => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        if is_word && needs_space {
+            current.push(' ');
+        }
+        match token {
+            // Keep string literals exactly as written, including escapes.
+            Token::Scalar(ScalarToken::String(s)) => {
+                current.push('"');
+                current.push_str(s);
+                current.push('"');
+            }
+            t => current.push_str(&t.to_string()),
+        }
+        needs_space = is_word;
+
+        if token == Token::CurlyClose && depth == 0 {
+            definitions.push(std::mem::take(&mut current));
+            needs_space = false;
+        }
+    }
+    if !current.is_empty() {
+        definitions.push(current);
+    }
+
+    definitions.sort();
+    Ok(definitions.join(" "))
+}
@@ -16,6 +16,10 @@ pub enum ParseError<'a> {
 
     /// A scalar of unexpected type occurred in the source
     ExpectedScalarError(&'static str),
+
+    /// An `Int` literal occurred in the source, not fitting into a 32-bit
+    /// signed integer
+    IntOverflow(&'a str),
 }
 
 #[doc(hidden)]
@@ -200,6 +204,13 @@ impl<'a> fmt::Display for ParseError<'a> {
             ParseError::UnexpectedEndOfFile => write!(f, "Unexpected end of input"),
             ParseError::LexerError(ref err) => err.fmt(f),
             ParseError::ExpectedScalarError(err) => err.fmt(f),
+            ParseError::IntOverflow(value) => write!(
+                f,
+                "Int cannot represent {}, as it's outside of the range [{}, {}]",
+                value,
+                i32::MIN,
+                i32::MAX,
+            ),
         }
     }
 }
@@ -3,7 +3,10 @@ use crate::{
         Arguments, Definition, Field, InputValue, Operation, OperationType, OwnedDocument,
         Selection,
     },
-    parser::{document::parse_document_source, ParseError, SourcePosition, Spanning, Token},
+    parser::{
+        document::{parse_document_source, parse_document_source_faithful},
+        ParseError, SourcePosition, Spanning, Token,
+    },
     schema::model::SchemaType,
     types::scalars::{EmptyMutation, EmptySubscription},
     validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
@@ -123,6 +126,48 @@ fn simple_ast() {
     )
 }
 
+#[test]
+fn faithful_mode_retains_comments() {
+    let source = "# Fetch the node\n{\n  node(id: 4) { # by its ID\n    id\n  }\n}\n#trailing";
+    let doc = parse_document_source_faithful::<DefaultScalarValue>(
+        source,
+        &SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &()),
+    )
+    .expect("Parse error");
+
+    assert_eq!(doc.source, source);
+    assert_eq!(
+        doc.definitions,
+        parse_document::<DefaultScalarValue>(source)
+    );
+    assert_eq!(
+        doc.comments,
+        vec![
+            Spanning::start_end(
+                &SourcePosition::new(0, 0, 0),
+                &SourcePosition::new(16, 0, 16),
+                " Fetch the node",
+            ),
+            Spanning::start_end(
+                &SourcePosition::new(35, 2, 16),
+                &SourcePosition::new(46, 2, 27),
+                " by its ID",
+            ),
+            Spanning::start_end(
+                &SourcePosition::new(60, 6, 0),
+                &SourcePosition::new(69, 6, 9),
+                "trailing",
+            ),
+        ],
+    );
+    for comment in &doc.comments {
+        assert_eq!(
+            &source[comment.start.index()..comment.end.index()],
+            format!("#{}", comment.item),
+        );
+    }
+}
+
 #[test]
 fn errors() {
     assert_eq!(
@@ -201,6 +201,55 @@ fn strings() {
     );
 }
 
+#[test]
+fn block_strings() {
+    assert_eq!(
+        tokenize_single(r#""""simple""""#),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(12, 0, 12),
+            Token::BlockString("simple")
+        )
+    );
+
+    assert_eq!(
+        tokenize_single("\"\"\"\n  multi\n  line \"quoted\" \\\"\"\"\n\"\"\""),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(36, 3, 3),
+            Token::BlockString("\n  multi\n  line \"quoted\" \\\"\"\"\n")
+        )
+    );
+
+    assert_eq!(
+        tokenize_single(r#""""""""#),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(6, 0, 6),
+            Token::BlockString("")
+        )
+    );
+}
+
+#[test]
+fn block_string_errors() {
+    assert_eq!(
+        tokenize_error(r#""""no end quotes"#),
+        Spanning::zero_width(
+            &SourcePosition::new(16, 0, 16),
+            LexerError::UnterminatedString
+        )
+    );
+
+    assert_eq!(
+        tokenize_error("\"\"\"contains \u{0007} control char\"\"\""),
+        Spanning::zero_width(
+            &SourcePosition::new(12, 0, 12),
+            LexerError::UnknownCharacterInString('\u{0007}')
+        )
+    );
+}
+
 #[test]
 fn string_errors() {
     assert_eq!(
@@ -623,6 +672,11 @@ fn punctuation() {
         tokenize_single("|"),
         Spanning::single_width(&SourcePosition::new(0, 0, 0), Token::Pipe)
     );
+
+    assert_eq!(
+        tokenize_single("&"),
+        Spanning::single_width(&SourcePosition::new(0, 0, 0), Token::Ampersand)
+    );
 }
 
 #[test]
@@ -697,4 +751,10 @@ fn display() {
     assert_eq!(format!("{}", Token::Equals), "=");
     assert_eq!(format!("{}", Token::At), "@");
     assert_eq!(format!("{}", Token::Pipe), "|");
+    assert_eq!(format!("{}", Token::Ampersand), "&");
+
+    assert_eq!(
+        format!("{}", Token::BlockString("block \"string\"")),
+        "\"\"\"block \"string\"\"\"\""
+    );
 }
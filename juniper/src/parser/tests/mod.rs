@@ -1,3 +1,4 @@
 mod document;
 mod lexer;
+mod normalize;
 mod value;
@@ -0,0 +1,86 @@
+use crate::parser::{normalize_document, LexerError, SourcePosition, Spanning};
+
+#[test]
+fn collapses_whitespace_and_commas() {
+    assert_eq!(
+        normalize_document(
+            r#"
+            query  Hero( $episode : Episode = JEDI ,  $x: [Int!]! ) {
+                hero(episode: $episode) {
+                    name,
+                    ... on Droid { primaryFunction }
+                }
+            }
+            "#,
+        ),
+        Ok(
+            "query Hero($episode:Episode=JEDI$x:[Int!]!){hero(episode:$episode){name...on Droid\
+             {primaryFunction}}}"
+                .to_owned()
+        ),
+    );
+}
+
+#[test]
+fn strips_comments() {
+    assert_eq!(
+        normalize_document("# leading\n{ a # trailing\n b }"),
+        normalize_document("{a b}"),
+    );
+}
+
+#[test]
+fn keeps_string_literals_verbatim() {
+    assert_eq!(
+        normalize_document(r#"{ a(s: "x  \"y\"  # z") }"#),
+        Ok(r#"{a(s:"x  \"y\"  # z")}"#.to_owned()),
+    );
+}
+
+#[test]
+fn sorts_definitions() {
+    assert_eq!(
+        normalize_document("query B { ...F } fragment F on Query { a }"),
+        normalize_document("fragment F on Query { a }\nquery B { ...F }"),
+    );
+}
+
+#[test]
+fn reports_lexer_errors() {
+    assert_eq!(
+        normalize_document("{ a ? }"),
+        Err(Spanning::zero_width(
+            &SourcePosition::new(4, 0, 4),
+            LexerError::UnknownCharacter('?'),
+        )),
+    );
+}
+
+#[test]
+fn query_hash_is_sha256_of_raw_document() {
+    // `echo -n '{a b}' | sha256sum`
+    assert_eq!(
+        crate::query_hash("{a b}"),
+        "868ac4149a25b2b532f6162b88bebf967ebcb0615e1d2b96f4e31ec2cede3333",
+    );
+    assert_ne!(crate::query_hash("{ a, b }"), crate::query_hash("{a b}"));
+}
+
+#[test]
+fn query_hash_of_normalized_documents_ignores_formatting() {
+    let compact = "query Q($id:ID!){user(id:$id){name friends{name}}}";
+    let pretty = r#"
+        # Fetch a user and their friends.
+        query Q($id: ID!) {
+            user(id: $id) {
+                name,
+                friends { name } # nested
+            }
+        }
+    "#;
+
+    let hash = |doc| crate::query_hash(&normalize_document(doc).unwrap());
+
+    assert_eq!(hash(compact), hash(pretty));
+    assert_ne!(hash(compact), hash("{ user }"));
+}
@@ -3,7 +3,7 @@ use crate::ast::InputValue;
 use crate::{
     parser::{ParseError, ParseResult, Parser, ScalarToken, SourcePosition, Spanning, Token},
     schema::{
-        meta::{InputObjectMeta, MetaType},
+        meta::{InputObjectMeta, MetaType, ScalarMeta},
         model::SchemaType,
     },
     value::ScalarValue,
@@ -31,7 +31,11 @@ where
                 item: Token::CurlyOpen,
                 ..
             },
-            None,
+            None
+            | Some(&MetaType::Scalar(ScalarMeta {
+                accepts_objects: true,
+                ..
+            })),
         ) => parse_object_literal(parser, is_const, schema, None),
         (
             &Spanning {
@@ -2,13 +2,14 @@
 
 use std::{
     borrow::{Cow, ToOwned},
+    collections::BTreeMap,
     fmt,
 };
 
 use crate::{
     ast::{FromInputValue, InputValue, Type},
     parser::{ParseError, ScalarToken},
-    schema::model::SchemaType,
+    schema::model::{OutputCheck, SchemaType},
     types::base::TypeKind,
     value::{DefaultScalarValue, ParseScalarValue, ScalarValue},
 };
@@ -46,6 +47,17 @@ pub struct ScalarMeta<'a, S> {
     pub name: Cow<'a, str>,
     #[doc(hidden)]
     pub description: Option<String>,
+    #[doc(hidden)]
+    pub pattern: Option<String>,
+    #[doc(hidden)]
+    pub unit: Option<String>,
+    #[doc(hidden)]
+    pub min_length: Option<usize>,
+    #[doc(hidden)]
+    pub max_length: Option<usize>,
+    pub(crate) is_builtin: bool,
+    pub(crate) accepts_objects: bool,
+    pub(crate) output_check: Option<OutputCheck<S>>,
     pub(crate) try_parse_fn: for<'b> fn(&'b InputValue<S>) -> bool,
     pub(crate) parse_fn: for<'b> fn(ScalarToken<'b>) -> Result<S, ParseError<'b>>,
 }
@@ -78,6 +90,8 @@ pub struct ObjectMeta<'a, S> {
     pub fields: Vec<Field<'a, S>>,
     #[doc(hidden)]
     pub interface_names: Vec<String>,
+    #[doc(hidden)]
+    pub extensions: BTreeMap<String, String>,
 }
 
 /// Enum type metadata
@@ -100,6 +114,10 @@ pub struct InterfaceMeta<'a, S> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub fields: Vec<Field<'a, S>>,
+    #[doc(hidden)]
+    pub interface_names: Vec<String>,
+    #[doc(hidden)]
+    pub extensions: BTreeMap<String, String>,
 }
 
 /// Union type metadata
@@ -121,6 +139,8 @@ pub struct InputObjectMeta<'a, S> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub input_fields: Vec<Argument<'a, S>>,
+    #[doc(hidden)]
+    pub is_one_of: bool,
     pub(crate) try_parse_fn: for<'b> fn(&'b InputValue<S>) -> bool,
 }
 
@@ -170,6 +190,12 @@ pub struct Field<'a, S> {
     pub field_type: Type<'a>,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub removal_date: Option<String>,
+    #[doc(hidden)]
+    pub complexity: Option<usize>,
+    #[doc(hidden)]
+    pub extensions: BTreeMap<String, String>,
 }
 
 impl<'a, S> Field<'a, S> {
@@ -191,6 +217,8 @@ pub struct Argument<'a, S> {
     pub arg_type: Type<'a>,
     #[doc(hidden)]
     pub default_value: Option<InputValue<S>>,
+    #[doc(hidden)]
+    pub deprecation_status: DeprecationStatus,
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -248,6 +276,70 @@ impl<'a, S> MetaType<'a, S> {
         }
     }
 
+    /// Access the regular expression valid values of the type match, if applicable
+    ///
+    /// Only scalars may have a pattern.
+    pub fn pattern(&self) -> Option<&str> {
+        match self {
+            MetaType::Scalar(ScalarMeta { pattern, .. }) => pattern.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Access the unit values of the type are measured in, if applicable
+    ///
+    /// Only scalars may have a unit.
+    pub fn unit(&self) -> Option<&str> {
+        match self {
+            MetaType::Scalar(ScalarMeta { unit, .. }) => unit.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Access the minimum length of the type's values, if applicable
+    ///
+    /// Only scalars may have a length constraint.
+    pub fn min_length(&self) -> Option<usize> {
+        match self {
+            MetaType::Scalar(ScalarMeta { min_length, .. }) => *min_length,
+            _ => None,
+        }
+    }
+
+    /// Access the maximum length of the type's values, if applicable
+    ///
+    /// Only scalars may have a length constraint.
+    pub fn max_length(&self) -> Option<usize> {
+        match self {
+            MetaType::Scalar(ScalarMeta { max_length, .. }) => *max_length,
+            _ => None,
+        }
+    }
+
+    /// Check whether exactly one of the type's fields is expected to be set
+    ///
+    /// Only input objects may be `@oneOf` ones.
+    pub fn is_one_of(&self) -> bool {
+        matches!(
+            self,
+            MetaType::InputObject(InputObjectMeta {
+                is_one_of: true,
+                ..
+            })
+        )
+    }
+
+    /// Access the extension data attached to the type, if applicable
+    ///
+    /// Only objects and interfaces may have extension data attached.
+    pub fn extensions(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            MetaType::Object(ObjectMeta { extensions, .. })
+            | MetaType::Interface(InterfaceMeta { extensions, .. }) => Some(extensions),
+            _ => None,
+        }
+    }
+
     /// Construct a `TypeKind` for a given type
     ///
     /// # Panics
@@ -419,6 +511,13 @@ where
         ScalarMeta {
             name,
             description: None,
+            pattern: None,
+            unit: None,
+            min_length: None,
+            max_length: None,
+            is_builtin: false,
+            accepts_objects: false,
+            output_check: None,
             try_parse_fn: try_parse_fn::<S, T>,
             parse_fn: <T as ParseScalarValue<S>>::from_str,
         }
@@ -432,6 +531,93 @@ where
         self
     }
 
+    /// Set the regular expression valid values of the given scalar type match
+    ///
+    /// It's exposed to clients via the `@pattern(regex: "...")` directive in
+    /// the schema, so they may reuse it for their own validation.
+    ///
+    /// If a pattern already was set prior to calling this method, it will be overwritten.
+    pub fn with_pattern(mut self, regex: &str) -> ScalarMeta<'a, S> {
+        self.pattern = Some(regex.to_owned());
+        self
+    }
+
+    /// Access the regular expression valid values of this scalar type match, if any
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Set the unit values of the given scalar type are measured in (e.g.
+    /// `seconds` or `meters`)
+    ///
+    /// It's exposed to clients via the `@unit(name: "...")` directive in the
+    /// schema.
+    ///
+    /// If a unit already was set prior to calling this method, it will be overwritten.
+    pub fn with_unit(mut self, unit: &str) -> ScalarMeta<'a, S> {
+        self.unit = Some(unit.to_owned());
+        self
+    }
+
+    /// Access the unit values of this scalar type are measured in, if any
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_deref()
+    }
+
+    /// Set the minimum length (in characters) of the given string-backed scalar type's values
+    ///
+    /// It's exposed to clients via the `@length(min: ..., max: ...)` directive
+    /// in the schema, and via the `minLength` field on introspection. The
+    /// `#[graphql_scalar]` macro enforces it before calling `from_input_value`,
+    /// while manual `FromInputValue` implementations have to enforce it
+    /// themselves.
+    ///
+    /// If a minimum length already was set prior to calling this method, it will be overwritten.
+    pub fn with_min_length(mut self, min_length: usize) -> ScalarMeta<'a, S> {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Set the maximum length (in characters) of the given string-backed scalar type's values
+    ///
+    /// It's exposed to clients via the `@length(min: ..., max: ...)` directive
+    /// in the schema, and via the `maxLength` field on introspection. The
+    /// `#[graphql_scalar]` macro enforces it before calling `from_input_value`,
+    /// while manual `FromInputValue` implementations have to enforce it
+    /// themselves.
+    ///
+    /// If a maximum length already was set prior to calling this method, it will be overwritten.
+    pub fn with_max_length(mut self, max_length: usize) -> ScalarMeta<'a, S> {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Access the minimum length of this scalar type's values, if any
+    pub fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    /// Access the maximum length of this scalar type's values, if any
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Mark the given scalar type as one of the built-in ones
+    ///
+    /// Only the built-in scalars may use the names reserved for them.
+    pub(crate) fn builtin(mut self) -> ScalarMeta<'a, S> {
+        self.is_builtin = true;
+        self
+    }
+
+    /// Mark the given scalar type as represented by objects in input (e.g. tagged ones)
+    ///
+    /// Object values are rejected for the other scalar types without being parsed.
+    pub(crate) fn accepting_objects(mut self) -> ScalarMeta<'a, S> {
+        self.accepts_objects = true;
+        self
+    }
+
     /// Wrap the scalar in a generic meta type
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Scalar(self)
@@ -476,6 +662,7 @@ where
             description: None,
             fields: fields.to_vec(),
             interface_names: vec![],
+            extensions: BTreeMap::new(),
         }
     }
 
@@ -499,6 +686,17 @@ where
         self
     }
 
+    /// Attach the extension data under the given key to the object
+    ///
+    /// It's exposed to clients via the `extension(name: ...)` field on introspection.
+    ///
+    /// If a value already was attached under the key prior to calling this method, it will be
+    /// overwritten.
+    pub fn with_extension(mut self, key: &str, value: &str) -> ObjectMeta<'a, S> {
+        self.extensions.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
     /// Wrap this object type in a generic meta type
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Object(self)
@@ -546,6 +744,8 @@ where
             name,
             description: None,
             fields: fields.to_vec(),
+            interface_names: vec![],
+            extensions: BTreeMap::new(),
         }
     }
 
@@ -557,6 +757,29 @@ where
         self
     }
 
+    /// Set the interfaces this interface implements
+    ///
+    /// If a list of interfaces already was provided prior to calling this method, they will be
+    /// overwritten.
+    pub fn interfaces(mut self, interfaces: &[Type<'a>]) -> InterfaceMeta<'a, S> {
+        self.interface_names = interfaces
+            .iter()
+            .map(|t| t.innermost_name().to_owned())
+            .collect();
+        self
+    }
+
+    /// Attach the extension data under the given key to the interface
+    ///
+    /// It's exposed to clients via the `extension(name: ...)` field on introspection.
+    ///
+    /// If a value already was attached under the key prior to calling this method, it will be
+    /// overwritten.
+    pub fn with_extension(mut self, key: &str, value: &str) -> InterfaceMeta<'a, S> {
+        self.extensions.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
     /// Wrap this interface type in a generic meta type
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Interface(self)
@@ -603,6 +826,7 @@ where
             name,
             description: None,
             input_fields: input_fields.to_vec(),
+            is_one_of: false,
             try_parse_fn: try_parse_fn::<S, T>,
         }
     }
@@ -615,6 +839,17 @@ where
         self
     }
 
+    /// Mark the given input object type as a `@oneOf` one, expecting exactly
+    /// one of its fields to be set (to a non-null value)
+    ///
+    /// It's exposed to clients via the `@oneOf` directive in the schema, and
+    /// via the `isOneOf` field on introspection. Enforcing it is up to the
+    /// `FromInputValue` implementation of the input object.
+    pub fn one_of(mut self) -> InputObjectMeta<'a, S> {
+        self.is_one_of = true;
+        self
+    }
+
     /// Wrap this union type in a generic meta type
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::InputObject(self)
@@ -630,6 +865,16 @@ impl<'a, S> Field<'a, S> {
         self
     }
 
+    /// Add multiple arguments to the field
+    ///
+    /// Arguments are unordered and can't contain duplicates by name.
+    pub fn extend_arguments<I>(self, arguments: I) -> Self
+    where
+        I: IntoIterator<Item = Argument<'a, S>>,
+    {
+        arguments.into_iter().fold(self, Self::argument)
+    }
+
     /// Add an argument to the field
     ///
     /// Arguments are unordered and can't contain duplicates by name.
@@ -653,6 +898,51 @@ impl<'a, S> Field<'a, S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(ToOwned::to_owned));
         self
     }
+
+    /// Set the date the deprecated field is going to be removed at, in the
+    /// RFC 3339 format (e.g. `2022-01-01T00:00:00Z`)
+    ///
+    /// It's exposed to clients via the `removalDate` field on introspection,
+    /// along with the deprecation reason.
+    ///
+    /// If a removal date already was set prior to calling this method, it will be overwritten.
+    ///
+    /// # Panics
+    ///
+    /// If the `date` is not an RFC 3339 date-time.
+    pub fn with_removal_date(mut self, date: &str) -> Self {
+        assert!(
+            is_rfc3339_date_time(date),
+            "removal date of field `{}` is not an RFC 3339 date-time: {}",
+            self.name,
+            date,
+        );
+        self.removal_date = Some(date.to_owned());
+        self
+    }
+
+    /// Set the weight of the field in the query complexity, being `1` by
+    /// default
+    ///
+    /// See [`RootNode::with_max_complexity`] for how the complexity is
+    /// computed.
+    ///
+    /// [`RootNode::with_max_complexity`]: crate::RootNode::with_max_complexity
+    pub fn complexity(mut self, weight: usize) -> Self {
+        self.complexity = Some(weight);
+        self
+    }
+
+    /// Attach the extension data under the given key to the field (e.g. its SLA)
+    ///
+    /// It's exposed to clients via the `extension(name: ...)` field on introspection.
+    ///
+    /// If a value already was attached under the key prior to calling this method, it will be
+    /// overwritten.
+    pub fn with_extension(mut self, key: &str, value: &str) -> Self {
+        self.extensions.insert(key.to_owned(), value.to_owned());
+        self
+    }
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -663,6 +953,7 @@ impl<'a, S> Argument<'a, S> {
             description: None,
             arg_type,
             default_value: None,
+            deprecation_status: DeprecationStatus::Current,
         }
     }
 
@@ -681,6 +972,14 @@ impl<'a, S> Argument<'a, S> {
         self.default_value = Some(default_value);
         self
     }
+
+    /// Set the argument to be deprecated with an optional reason.
+    ///
+    /// This overwrites the deprecation reason if any was previously set.
+    pub fn deprecated(mut self, reason: Option<&str>) -> Self {
+        self.deprecation_status = DeprecationStatus::Deprecated(reason.map(ToOwned::to_owned));
+        self
+    }
 }
 
 impl EnumValue {
@@ -745,3 +1044,99 @@ where
 {
     <T as FromInputValue<S>>::from_input_value(v).is_some()
 }
+
+/// Checks whether the given string is an [RFC 3339] date-time (e.g.
+/// `2022-01-01T00:00:00Z`).
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+fn is_rfc3339_date_time(s: &str) -> bool {
+    let b = s.as_bytes();
+    let num = |from: usize, len: usize| -> Option<u32> {
+        let digits = b.get(from..from + len)?;
+        digits.iter().try_fold(0, |n, d| {
+            d.is_ascii_digit().then(|| n * 10 + u32::from(d - b'0'))
+        })
+    };
+    let sep = |at: usize, seps: &[u8]| matches!(b.get(at), Some(c) if seps.contains(c));
+
+    let is_date_time = matches!(
+        (
+            num(0, 4),
+            num(5, 2),
+            num(8, 2),
+            num(11, 2),
+            num(14, 2),
+            num(17, 2)
+        ),
+        (
+            Some(_),
+            Some(1..=12),
+            Some(1..=31),
+            Some(0..=23),
+            Some(0..=59),
+            Some(0..=60)
+        ),
+    ) && sep(4, b"-")
+        && sep(7, b"-")
+        && sep(10, b"Tt")
+        && sep(13, b":")
+        && sep(16, b":");
+    if !is_date_time {
+        return false;
+    }
+
+    let mut offset = 19;
+    if sep(offset, b".") {
+        let frac = b[offset + 1..]
+            .iter()
+            .take_while(|d| d.is_ascii_digit())
+            .count();
+        if frac == 0 {
+            return false;
+        }
+        offset += frac + 1;
+    }
+    match &b[offset..] {
+        [b'Z'] | [b'z'] => true,
+        [b'+' | b'-', _, _, b':', _, _] => {
+            matches!(
+                (num(offset + 1, 2), num(offset + 4, 2)),
+                (Some(0..=23), Some(0..=59))
+            )
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_rfc3339_date_time;
+
+    #[test]
+    fn rfc3339_date_times() {
+        for valid in &[
+            "2022-01-01T00:00:00Z",
+            "2022-12-31t23:59:60z",
+            "2022-01-01T00:00:00.123Z",
+            "2022-01-01T00:00:00+03:00",
+            "2022-01-01T00:00:00.5-23:59",
+        ] {
+            assert!(is_rfc3339_date_time(valid), "{}", valid);
+        }
+        for invalid in &[
+            "",
+            "2022-01-01",
+            "2022-13-01T00:00:00Z",
+            "2022-01-01T24:00:00Z",
+            "2022-01-01 00:00:00Z",
+            "2022-01-01T00:00:00",
+            "2022-01-01T00:00:00.Z",
+            "2022-01-01T00:00:00+3:00",
+            "2022-01-01T00:00:00+03:60",
+            "2022-01-01T00:00:00Zé",
+            "next year",
+        ] {
+            assert!(!is_rfc3339_date_time(invalid), "{}", invalid);
+        }
+    }
+}
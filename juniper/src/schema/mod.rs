@@ -3,4 +3,92 @@
 pub mod meta;
 pub mod model;
 pub mod schema;
+pub mod sdl;
 pub mod translate;
+
+#[cfg(feature = "introspection-json")]
+use std::{fs, io, path::Path};
+
+#[cfg(feature = "introspection-json")]
+use crate::{
+    executor::{self, get_operation, Variables},
+    introspection::INTROSPECTION_QUERY,
+    parser::parse_document_source,
+    types::base::GraphQLType,
+    value::ScalarValue,
+    RootNode,
+};
+
+/// Write the result of the reference introspection query in the provided
+/// schema to the file at `path` as pretty-printed JSON
+///
+/// The written JSON is the `{"__schema": ...}` object expected by most client
+/// tooling, so this is suitable for keeping a snapshot of the schema in a
+/// repository, either from a `build.rs` script or from a test. The query is
+/// resolved against the schema itself, so no context is required.
+#[cfg(feature = "introspection-json")]
+pub fn write_introspection_json<QueryT, MutationT, SubscriptionT, S>(
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    path: impl AsRef<Path>,
+) -> io::Result<()>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+
+    let document = parse_document_source(INTROSPECTION_QUERY, &root_node.schema)
+        .map_err(|e| invalid(e.to_string()))?;
+    let operation = get_operation(&document, None).map_err(|e| invalid(e.to_string()))?;
+    let (value, errors) = executor::execute_validated_introspection_query(
+        &document,
+        operation,
+        root_node,
+        &Variables::new(),
+    )
+    .map_err(|e| invalid(e.to_string()))?;
+    if let Some(e) = errors.first() {
+        return Err(invalid(e.error().message().to_owned()));
+    }
+
+    let json = serde_json::to_string_pretty(&value).map_err(io::Error::from)?;
+    fs::write(path, json)
+}
+
+#[cfg(all(test, feature = "chrono", feature = "introspection-json"))]
+mod test {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode};
+
+    use super::write_introspection_json;
+
+    #[test]
+    fn writes_introspection_json() {
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn now() -> DateTime<Utc> {
+                Utc.timestamp_opt(61, 0).unwrap()
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let path =
+            std::env::temp_dir().join(format!("juniper-introspection-{}.json", std::process::id()));
+
+        write_introspection_json(&schema, &path).unwrap();
+        let json = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(json.contains(r#""DateTimeUtc""#), "{}", json);
+        assert!(json.contains(r#""__schema""#), "{}", json);
+    }
+}
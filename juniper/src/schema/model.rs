@@ -1,20 +1,28 @@
-use std::fmt;
+use std::{collections::HashSet, fmt, future::Future, sync::Arc, time::Duration};
 
 use fnv::FnvHashMap;
 #[cfg(feature = "graphql-parser-integration")]
 use graphql_parser::schema::Document;
 
 use crate::{
-    ast::Type,
-    executor::{Context, Registry},
-    schema::meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+    ast::{Definition, Type},
+    executor::{
+        get_operation, is_introspection_operation, used_variables, Context, FieldError,
+        Instrumentation, Registry, Timer,
+    },
+    parser::parse_document_source,
+    schema::{
+        meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+        translate::{sdl::SdlTranslator, SchemaTranslator},
+    },
     types::{base::GraphQLType, name::Name},
-    value::{DefaultScalarValue, ScalarValue},
-    GraphQLEnum,
+    validation::{visit_all_rules, ValidatorContext},
+    value::{DefaultScalarValue, ScalarValue, Value},
+    GraphQLEnum, GraphQLError,
 };
 
 #[cfg(feature = "graphql-parser-integration")]
-use crate::schema::translate::{graphql_parser::GraphQLParserTranslator, SchemaTranslator};
+use crate::schema::translate::graphql_parser::GraphQLParserTranslator;
 
 /// Root query node of a schema
 ///
@@ -44,8 +52,167 @@ pub struct RootNode<
     pub subscription_info: SubscriptionT::TypeInfo,
     #[doc(hidden)]
     pub schema: SchemaType<'a, S>,
+    #[doc(hidden)]
+    pub response_transform: Option<ResponseTransform<S>>,
+    #[doc(hidden)]
+    pub context_timer: Option<fn(&QueryT::Context) -> &dyn Timer>,
 }
 
+/// Transformation of the whole response [`Value`] of a query or mutation
+///
+/// Applied once the operation is fully resolved, so it receives the final
+/// [`Value`] tree and may rewrite any of its leaves (e.g. for masking or
+/// redacting fields across the whole response). Registered via
+/// [`RootNode::with_response_transform`].
+pub struct ResponseTransform<S>(Arc<dyn Fn(Value<S>) -> Value<S> + Send + Sync>);
+
+impl<S> ResponseTransform<S> {
+    /// Construct a new transformation from the provided function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(Value<S>) -> Value<S> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Apply this transformation to the given response `value`
+    pub fn apply(&self, value: Value<S>) -> Value<S> {
+        (self.0)(value)
+    }
+}
+
+impl<S> Clone for ResponseTransform<S> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<S> fmt::Debug for ResponseTransform<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ResponseTransform")
+    }
+}
+
+/// Check of every resolved [`Value`] of a specific output type
+///
+/// Returns a [`FieldError`] to be reported alongside the resolved value (which
+/// is kept as is), usually tagged as a warning via
+/// [`FieldError::with_severity`]. Registered via [`RootNode::with_output_check`].
+#[allow(clippy::type_complexity)]
+pub struct OutputCheck<S>(Arc<dyn Fn(&Value<S>) -> Option<FieldError<S>> + Send + Sync>);
+
+impl<S> OutputCheck<S> {
+    /// Construct a new check from the provided function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Value<S>) -> Option<FieldError<S>> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Run this check against the given resolved `value`
+    pub fn check(&self, value: &Value<S>) -> Option<FieldError<S>> {
+        (self.0)(value)
+    }
+}
+
+impl<S> Clone for OutputCheck<S> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<S> fmt::Debug for OutputCheck<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("OutputCheck")
+    }
+}
+
+/// Error of building a schema out of types not forming a valid one
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaError {
+    /// Rust type is named after a built-in scalar, shadowing it.
+    ShadowedBuiltinScalar {
+        /// Name of the Rust type.
+        type_name: String,
+
+        /// Name of the shadowed built-in scalar.
+        name: String,
+    },
+
+    /// Type implements a type, which is not an interface.
+    NotAnInterface {
+        /// Name of the implementing type.
+        name: String,
+
+        /// Name of the implemented type.
+        interface: String,
+    },
+
+    /// Type implements an interface, but doesn't declare one of its fields.
+    MissingInterfaceField {
+        /// Name of the implementing type.
+        name: String,
+
+        /// Name of the implemented interface.
+        interface: String,
+
+        /// Name of the missing field.
+        field: String,
+    },
+
+    /// Type implements an interface, but not the interfaces it implements in
+    /// turn.
+    MissingTransitiveInterface {
+        /// Name of the implementing type.
+        name: String,
+
+        /// Name of the implemented interface.
+        interface: String,
+
+        /// Name of the interface implemented by the `interface`.
+        transitive: String,
+    },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ShadowedBuiltinScalar { type_name, name } => write!(
+                f,
+                "Type `{}` is named `{}`, shadowing the built-in `{}` scalar. \
+                 Specify another name for it, e.g. via `#[graphql(name = \"...\")]`.",
+                type_name, name, name,
+            ),
+            Self::NotAnInterface { name, interface } => write!(
+                f,
+                "Type `{}` implements `{}`, which is not an interface type",
+                name, interface,
+            ),
+            Self::MissingInterfaceField {
+                name,
+                interface,
+                field,
+            } => write!(
+                f,
+                "Type `{}` implements `{}`, but misses its field `{}`",
+                name, interface, field,
+            ),
+            Self::MissingTransitiveInterface {
+                name,
+                interface,
+                transitive,
+            } => write!(
+                f,
+                "Type `{}` implements `{}`, but not `{}` implemented by it",
+                name, interface, transitive,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
 /// Metadata for a schema
 #[derive(Debug)]
 pub struct SchemaType<'a, S> {
@@ -53,6 +220,15 @@ pub struct SchemaType<'a, S> {
     pub(crate) query_type_name: String,
     pub(crate) mutation_type_name: Option<String>,
     pub(crate) subscription_type_name: Option<String>,
+    pub(crate) field_allowlist: Option<HashSet<String>>,
+    pub(crate) safelist: Option<HashSet<String>>,
+    pub(crate) max_complexity: Option<usize>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) max_root_fields: Option<usize>,
+    pub(crate) max_selection_breadth: Option<usize>,
+    pub(crate) max_stream_items: Option<usize>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) instrumentation: Option<Arc<dyn Instrumentation>>,
     directives: FnvHashMap<String, DirectiveType<'a, S>>,
 }
 
@@ -86,6 +262,9 @@ pub enum DirectiveLocation {
     FragmentSpread,
     #[graphql(name = "INLINE_FRAGMENT")]
     InlineFragment,
+    Scalar,
+    #[graphql(name = "INPUT_OBJECT")]
+    InputObject,
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT>
@@ -97,9 +276,44 @@ where
 {
     /// Constructs a new [`RootNode`] from `query`, `mutation` and `subscription` nodes,
     /// parametrizing it with a [`DefaultScalarValue`].
+    ///
+    /// # Panics
+    ///
+    /// If the types don't form a valid schema. Use [`RootNode::try_new`] to
+    /// handle it as a [`SchemaError`] instead.
     pub fn new(query: QueryT, mutation: MutationT, subscription: SubscriptionT) -> Self {
         Self::new_with_info(query, mutation, subscription, (), (), ())
     }
+
+    /// Constructs a new [`RootNode`] from `query`, `mutation` and `subscription` nodes,
+    /// parametrizing it with a [`DefaultScalarValue`].
+    ///
+    /// # Errors
+    ///
+    /// If the types don't form a valid schema (e.g. a custom scalar is named
+    /// after a built-in one).
+    pub fn try_new(
+        query: QueryT,
+        mutation: MutationT,
+        subscription: SubscriptionT,
+    ) -> Result<Self, SchemaError> {
+        Self::try_new_with_info(query, mutation, subscription, (), (), ())
+    }
+
+    /// Constructs a new [`RootNode`] from `query`, `mutation` and `subscription` nodes
+    /// resolved by the provided async `factory`, parametrizing it with a
+    /// [`DefaultScalarValue`].
+    ///
+    /// Useful when the root nodes require some async setup (like loading data or connecting
+    /// to a database) before the schema can be built. The `factory` is awaited exactly once,
+    /// so this is intended to be called once at startup.
+    pub async fn new_async<F>(factory: F) -> Self
+    where
+        F: Future<Output = (QueryT, MutationT, SubscriptionT)>,
+    {
+        let (query, mutation, subscription) = factory.await;
+        Self::new(query, mutation, subscription)
+    }
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT, S> RootNode<'a, QueryT, MutationT, SubscriptionT, S>
@@ -130,6 +344,12 @@ where
     /// Construct a new root node from query and mutation nodes,
     /// while also providing type info objects for the query and
     /// mutation types.
+    ///
+    /// # Panics
+    ///
+    /// If the types don't form a valid schema. Use
+    /// [`RootNode::try_new_with_info`] to handle it as a [`SchemaError`]
+    /// instead.
     pub fn new_with_info(
         query_obj: QueryT,
         mutation_obj: MutationT,
@@ -138,21 +358,354 @@ where
         mutation_info: MutationT::TypeInfo,
         subscription_info: SubscriptionT::TypeInfo,
     ) -> Self {
-        RootNode {
+        Self::try_new_with_info(
+            query_obj,
+            mutation_obj,
+            subscription_obj,
+            query_info,
+            mutation_info,
+            subscription_info,
+        )
+        .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Construct a new root node from query and mutation nodes,
+    /// while also providing type info objects for the query and
+    /// mutation types.
+    ///
+    /// # Errors
+    ///
+    /// If the types don't form a valid schema (e.g. a custom scalar is named
+    /// after a built-in one).
+    pub fn try_new_with_info(
+        query_obj: QueryT,
+        mutation_obj: MutationT,
+        subscription_obj: SubscriptionT,
+        query_info: QueryT::TypeInfo,
+        mutation_info: MutationT::TypeInfo,
+        subscription_info: SubscriptionT::TypeInfo,
+    ) -> Result<Self, SchemaError> {
+        Ok(RootNode {
             query_type: query_obj,
             mutation_type: mutation_obj,
             subscription_type: subscription_obj,
-            schema: SchemaType::new::<QueryT, MutationT, SubscriptionT>(
+            schema: SchemaType::try_new::<QueryT, MutationT, SubscriptionT>(
                 &query_info,
                 &mutation_info,
                 &subscription_info,
-            ),
+            )?,
             query_info,
             mutation_info,
             subscription_info,
+            response_transform: None,
+            context_timer: None,
+        })
+    }
+
+    /// Registers the `transform` applied to the whole response [`Value`] of
+    /// every query and mutation executed against this schema
+    ///
+    /// Replaces any previously registered transformation. Subscription events
+    /// are not transformed.
+    pub fn with_response_transform<F>(mut self, transform: F) -> Self
+    where
+        F: Fn(Value<S>) -> Value<S> + Send + Sync + 'static,
+    {
+        self.response_transform = Some(ResponseTransform::new(transform));
+        self
+    }
+
+    /// Registers the `check` run against every resolved value of the scalar
+    /// type named `type_name`
+    ///
+    /// The error returned by the `check` is reported alongside the value, which
+    /// is kept in the response as is. Replaces any check previously registered
+    /// for the same type, and does nothing if the schema has no such type.
+    ///
+    /// # Panics
+    ///
+    /// If the type named `type_name` is not a scalar.
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, graphql_value, EmptyMutation, EmptySubscription, ErrorSeverity,
+    /// #     FieldError, RootNode,
+    /// # };
+    /// struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn answer() -> i32 {
+    ///         42
+    ///     }
+    /// }
+    ///
+    /// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+    ///     .with_output_check("Int", |v| {
+    ///         (v.as_scalar_value::<i32>() == Some(&42)).then(|| {
+    ///             FieldError::new("Suspiciously meaningful", graphql_value!(None))
+    ///                 .with_severity(ErrorSeverity::Warning)
+    ///         })
+    ///     });
+    /// ```
+    pub fn with_output_check<F>(mut self, type_name: &str, check: F) -> Self
+    where
+        F: Fn(&Value<S>) -> Option<FieldError<S>> + Send + Sync + 'static,
+    {
+        match self.schema.types.get_mut(type_name) {
+            Some(MetaType::Scalar(meta)) => meta.output_check = Some(OutputCheck::new(check)),
+            Some(_) => panic!(
+                "Output checks can't be registered for non-scalar type `{}`",
+                type_name
+            ),
+            None => {}
+        }
+        self
+    }
+
+    /// Restricts the fields which may be selected by queries executed against
+    /// this schema to the given `fields` only
+    ///
+    /// The `fields` are specified as `Type.field` coordinates (e.g.
+    /// `Query.publicDate`), where `Type` is the type the field is selected on.
+    /// Selecting any other field fails the query validation. Introspection
+    /// fields (`__typename`, `__schema` and `__type`) and fields of the
+    /// introspection types are always allowed.
+    ///
+    /// Replaces any previously registered allowlist.
+    pub fn with_field_allowlist<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.schema.field_allowlist = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Restricts the documents which may be executed against this schema to
+    /// the persisted ones whose [`query_hash`]es are in the given `hashes` only
+    ///
+    /// Any other document is rejected with [`GraphQLError::NotSafelisted`]
+    /// right away, as its raw text is hashed without being lexed, so only
+    /// trusted documents ever reach the parser and the executor.
+    ///
+    /// Replaces any previously registered safelist.
+    ///
+    /// [`GraphQLError::NotSafelisted`]: crate::GraphQLError::NotSafelisted
+    /// [`query_hash`]: crate::query_hash
+    pub fn with_safelist<I>(mut self, hashes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.schema.safelist = Some(hashes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Limits the complexity of an operation executed against this schema to
+    /// the given `max`
+    ///
+    /// Every selected field adds its weight to the complexity, being `1`
+    /// unless specified otherwise via `#[graphql(complexity = ...)]`. The
+    /// complexity of the fields selected on a list field is multiplied by its
+    /// `first` or `last` argument, if specified literally or via a variable
+    /// (its value the query is executed with, or its default one when the
+    /// document is [validated](RootNode::validate) on its own). The
+    /// introspection fields aren't counted at all.
+    /// Operations of higher complexity fail the query validation.
+    pub fn with_max_complexity(mut self, max: usize) -> Self {
+        self.schema.max_complexity = Some(max);
+        self
+    }
+
+    /// Limits how deep the fields may be nested in an operation executed
+    /// against this schema to the given `max`, with the root fields being at
+    /// the depth of 1
+    ///
+    /// The fields selected via fragments are counted at the depth the
+    /// fragments are spread at, while the introspection fields (and the ones
+    /// selected on them) aren't counted at all. Nesting the fields deeper fails
+    /// the query validation.
+    pub fn with_max_depth(mut self, max: usize) -> Self {
+        self.schema.max_depth = Some(max);
+        self
+    }
+
+    /// Limits the number of fields which may be selected in the root selection
+    /// set of an operation executed against this schema to the given `max`
+    ///
+    /// Fields selected via fragments count as well, and so do the aliased
+    /// selections of the same field. Selecting more fields fails the query
+    /// validation.
+    pub fn with_max_root_fields(mut self, max: usize) -> Self {
+        self.schema.max_root_fields = Some(max);
+        self
+    }
+
+    /// Limits the number of fields which may be selected on any single object
+    /// in an operation executed against this schema to the given `max`
+    ///
+    /// Every selection set (the root one included) is limited on its own, with
+    /// the fields selected via fragments counting as well, and so do the
+    /// aliased selections of the same field, while repeated selections of the
+    /// same response key count once. Selections on abstract types are counted
+    /// per each of their concrete types, as only the fields applying to one of
+    /// them are resolved. The introspection fields (and the ones selected on
+    /// them) aren't counted at all. Selecting more fields on an object fails
+    /// the query validation.
+    pub fn with_max_selection_breadth(mut self, max: usize) -> Self {
+        self.schema.max_selection_breadth = Some(max);
+        self
+    }
+
+    /// Limits the number of items collected from the stream of a
+    /// [`StreamList`](crate::StreamList) to the given `max`, bounding the
+    /// memory used to resolve it
+    ///
+    /// Resolving a [`StreamList`](crate::StreamList) whose stream yields more
+    /// items fails with a field error.
+    pub fn with_max_stream_items(mut self, max: usize) -> Self {
+        self.schema.max_stream_items = Some(max);
+        self
+    }
+
+    /// Limits the time an operation executed against this schema may take to
+    /// the given `timeout`
+    ///
+    /// Once the `timeout` elapses, the fields not resolved yet are resolved as
+    /// `null`, while the ones already resolved are kept in the partial result,
+    /// and a single `Execution timed out after ...` error is reported for the
+    /// whole operation. Asynchronous execution stops awaiting the pending
+    /// fields once the [`Timer`] of the context tells the `timeout` has
+    /// elapsed, while synchronous execution can only check the time elapsed
+    /// before resolving every field.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self
+    where
+        QueryT::Context: Timer,
+    {
+        fn context_timer<T: Timer>(context: &T) -> &dyn Timer {
+            context
+        }
+
+        self.schema.timeout = Some(timeout);
+        self.context_timer = Some(context_timer::<QueryT::Context>);
+        self
+    }
+
+    /// Records the [`FieldTiming`](crate::FieldTiming) of every field resolved
+    /// by queries executed against this schema into the given
+    /// `instrumentation`
+    ///
+    /// Measuring the fields adds some overhead to their resolution, so it's
+    /// disabled unless an instrumentation is registered.
+    pub fn with_instrumentation(mut self, instrumentation: Arc<dyn Instrumentation>) -> Self {
+        self.schema.instrumentation = Some(instrumentation);
+        self
+    }
+
+    /// Checks whether the given query document selects introspection fields
+    /// only (`__schema`, `__type` and `__typename`) in all its operations
+    ///
+    /// Such queries are resolved against the schema itself, without touching
+    /// the context, so the caller may skip constructing an expensive one for
+    /// them. Returns `false` for unparsable documents and for documents
+    /// containing mutations or subscriptions.
+    pub fn is_introspection_only(&self, document_source: &str) -> bool {
+        let document = match parse_document_source(document_source, &self.schema) {
+            Ok(document) => document,
+            Err(_) => return false,
+        };
+        let mut operations = document
+            .iter()
+            .filter_map(|def| match def {
+                Definition::Operation(op) => Some(&op.item),
+                _ => None,
+            })
+            .peekable();
+        operations.peek().is_some()
+            && operations.all(|op| is_introspection_operation(&document, op))
+    }
+
+    /// Validates the given query document against this schema without
+    /// executing it
+    ///
+    /// This is the same validation [`execute`](crate::execute) performs before
+    /// executing a query, so it may be used to detect failing queries early,
+    /// e.g. when registering persisted queries. All the problems of the
+    /// document (like selecting unknown fields, or omitting required arguments)
+    /// are reported at once.
+    pub fn validate<'d>(&self, document_source: &'d str) -> Result<(), GraphQLError<'d>> {
+        let document = parse_document_source(document_source, &self.schema)?;
+
+        let mut ctx = ValidatorContext::new(&self.schema, &document);
+        visit_all_rules(&mut ctx, &document);
+
+        let errors = ctx.into_errors();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(GraphQLError::ValidationError(errors))
         }
     }
 
+    /// Collects the names of variables referenced by the operation of the
+    /// given query document, either directly or via the fragments it spreads
+    ///
+    /// Variables provided for the operation but not in the returned set don't
+    /// affect its result, so they may be ignored, e.g. when computing a cache
+    /// key. The operation is selected by its `operation_name` the same way as
+    /// by [`execute`](crate::execute), though the document isn't validated.
+    pub fn used_variables<'d>(
+        &self,
+        document_source: &'d str,
+        operation_name: Option<&str>,
+    ) -> Result<HashSet<String>, GraphQLError<'d>> {
+        let document = parse_document_source(document_source, &self.schema)?;
+        let operation = get_operation(&document, operation_name)?;
+        Ok(used_variables(&document, &operation.item)
+            .into_iter()
+            .map(str::to_owned)
+            .collect())
+    }
+
+    /// Enumerates the `(type name, field name)` pairs of all the object and
+    /// interface fields defined by this schema
+    ///
+    /// Built-in types (like `__Schema`) and introspection meta-fields (like
+    /// `__typename`) are excluded, so a schema-stitching gateway may build its
+    /// routing table out of the returned pairs. They're sorted by type name,
+    /// and then by field name.
+    pub fn owned_fields(&self) -> Vec<(String, String)> {
+        let mut owned = self
+            .schema
+            .concrete_type_list()
+            .into_iter()
+            .filter(|t| !t.is_builtin())
+            .filter_map(|t| match t {
+                MetaType::Object(ObjectMeta { name, fields, .. })
+                | MetaType::Interface(InterfaceMeta { name, fields, .. }) => Some((name, fields)),
+                _ => None,
+            })
+            .flat_map(|(type_name, fields)| {
+                fields
+                    .iter()
+                    .filter(|f| !f.is_builtin())
+                    .map(move |f| (type_name.to_string(), f.name.to_string()))
+            })
+            .collect::<Vec<_>>();
+        owned.sort();
+        owned
+    }
+
+    /// The schema definition as a `String` in the
+    /// [SDL](https://spec.graphql.org/June2018/#sec-Type-System) format.
+    ///
+    /// Unlike [`RootNode::as_schema_language()`], this doesn't require the `schema-language`
+    /// feature, and prints the types sorted by their names, so the output is stable between runs.
+    pub fn as_sdl(&self) -> String {
+        SdlTranslator::translate_schema(&self.schema)
+    }
+
     #[cfg(feature = "schema-language")]
     /// The schema definition as a `String` in the
     /// [GraphQL Schema Language](https://graphql.org/learn/schema/#type-language)
@@ -170,8 +723,73 @@ where
     }
 }
 
+/// Checks the given object or interface `meta_type` implementing interfaces to
+/// declare all of their fields, and to implement the interfaces they implement
+/// in turn.
+///
+/// Being applied to every interface, the latter makes the check transitive.
+pub(crate) fn check_implemented_interfaces<S>(
+    types: &FnvHashMap<Name, MetaType<'_, S>>,
+    meta_type: &MetaType<'_, S>,
+) -> Result<(), SchemaError> {
+    let (name, fields, interface_names) = match meta_type {
+        MetaType::Object(ObjectMeta {
+            name,
+            fields,
+            interface_names,
+            ..
+        })
+        | MetaType::Interface(InterfaceMeta {
+            name,
+            fields,
+            interface_names,
+            ..
+        }) => (name, fields, interface_names),
+        _ => return Ok(()),
+    };
+    for iface_name in interface_names {
+        let iface = match types.get(iface_name.as_str()) {
+            Some(MetaType::Interface(iface)) => iface,
+            _ => {
+                return Err(SchemaError::NotAnInterface {
+                    name: name.to_string(),
+                    interface: iface_name.clone(),
+                })
+            }
+        };
+        if let Some(f) = iface
+            .fields
+            .iter()
+            .find(|f| fields.iter().all(|own| own.name != f.name))
+        {
+            return Err(SchemaError::MissingInterfaceField {
+                name: name.to_string(),
+                interface: iface_name.clone(),
+                field: f.name.to_string(),
+            });
+        }
+        if let Some(transitive) = iface
+            .interface_names
+            .iter()
+            .find(|t| !interface_names.contains(t))
+        {
+            return Err(SchemaError::MissingTransitiveInterface {
+                name: name.to_string(),
+                interface: iface_name.clone(),
+                transitive: transitive.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
 impl<'a, S> SchemaType<'a, S> {
     /// Create a new schema.
+    ///
+    /// # Panics
+    ///
+    /// If the types don't form a valid schema. Use [`SchemaType::try_new`] to
+    /// handle it as a [`SchemaError`] instead.
     pub fn new<QueryT, MutationT, SubscriptionT>(
         query_info: &QueryT::TypeInfo,
         mutation_info: &MutationT::TypeInfo,
@@ -183,7 +801,26 @@ impl<'a, S> SchemaType<'a, S> {
         MutationT: GraphQLType<S>,
         SubscriptionT: GraphQLType<S>,
     {
-        let mut directives = FnvHashMap::default();
+        Self::try_new::<QueryT, MutationT, SubscriptionT>(
+            query_info,
+            mutation_info,
+            subscription_info,
+        )
+        .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a new schema, failing if the types don't form a valid one.
+    pub fn try_new<QueryT, MutationT, SubscriptionT>(
+        query_info: &QueryT::TypeInfo,
+        mutation_info: &MutationT::TypeInfo,
+        subscription_info: &SubscriptionT::TypeInfo,
+    ) -> Result<Self, SchemaError>
+    where
+        S: ScalarValue + 'a,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
         let query_type_name: String;
         let mutation_type_name: String;
         let subscription_type_name: String;
@@ -204,6 +841,40 @@ impl<'a, S> SchemaType<'a, S> {
             .innermost_name()
             .to_owned();
 
+        Self::from_registry(
+            registry,
+            query_type_name,
+            Some(mutation_type_name).filter(|name| name != "_EmptyMutation"),
+            Some(subscription_type_name).filter(|name| name != "_EmptySubscription"),
+        )
+    }
+
+    /// Create a new schema of the types registered in the given `registry`,
+    /// with the root operation types of the given names.
+    ///
+    /// # Errors
+    ///
+    /// If the `registry` has recorded any [`SchemaError`] of its types.
+    ///
+    /// # Panics
+    ///
+    /// If the query type isn't a registered object, or if any of the
+    /// registered types is still a placeholder.
+    pub(crate) fn from_registry(
+        mut registry: Registry<'a, S>,
+        query_type_name: String,
+        mutation_type_name: Option<String>,
+        subscription_type_name: Option<String>,
+    ) -> Result<Self, SchemaError>
+    where
+        S: ScalarValue + 'a,
+    {
+        if let Some(e) = registry.errors.first() {
+            return Err(e.clone());
+        }
+
+        let mut directives = FnvHashMap::default();
+
         registry.get_type::<SchemaType<S>>(&());
 
         directives.insert("skip".to_owned(), DirectiveType::new_skip(&mut registry));
@@ -211,6 +882,28 @@ impl<'a, S> SchemaType<'a, S> {
             "include".to_owned(),
             DirectiveType::new_include(&mut registry),
         );
+        if registry.types.values().any(|t| t.pattern().is_some()) {
+            directives.insert(
+                "pattern".to_owned(),
+                DirectiveType::new_pattern(&mut registry),
+            );
+        }
+        if registry.types.values().any(|t| t.unit().is_some()) {
+            directives.insert("unit".to_owned(), DirectiveType::new_unit(&mut registry));
+        }
+        if registry
+            .types
+            .values()
+            .any(|t| t.min_length().is_some() || t.max_length().is_some())
+        {
+            directives.insert(
+                "length".to_owned(),
+                DirectiveType::new_length(&mut registry),
+            );
+        }
+        if registry.types.values().any(MetaType::is_one_of) {
+            directives.insert("oneOf".to_owned(), DirectiveType::new_one_of());
+        }
 
         let mut meta_fields = vec![
             registry.field::<SchemaType<S>>("__schema", &()),
@@ -233,21 +926,50 @@ impl<'a, S> SchemaType<'a, S> {
             if let MetaType::Placeholder(PlaceholderMeta { ref of_type }) = *meta_type {
                 panic!("Type {:?} is still a placeholder type", of_type);
             }
+            // Objects generated from Rust types may leave the fields of their interfaces to be
+            // resolved by the interfaces themselves, so only the interfaces are checked.
+            if let MetaType::Interface(_) = meta_type {
+                check_implemented_interfaces(&registry.types, meta_type)?;
+            }
         }
-        SchemaType {
+        Ok(SchemaType {
             types: registry.types,
             query_type_name,
-            mutation_type_name: if &mutation_type_name != "_EmptyMutation" {
-                Some(mutation_type_name)
-            } else {
-                None
-            },
-            subscription_type_name: if &subscription_type_name != "_EmptySubscription" {
-                Some(subscription_type_name)
-            } else {
-                None
-            },
+            mutation_type_name,
+            subscription_type_name,
+            field_allowlist: None,
+            safelist: None,
+            max_complexity: None,
+            max_depth: None,
+            max_root_fields: None,
+            max_selection_breadth: None,
+            max_stream_items: None,
+            timeout: None,
+            instrumentation: None,
             directives,
+        })
+    }
+
+    /// Checks whether the given `document` may be executed, according to the
+    /// safelist registered via [`RootNode::with_safelist`].
+    pub fn is_safelisted(&self, document: &str) -> bool {
+        match &self.safelist {
+            Some(safelist) => safelist.contains(&crate::query_hash(document)),
+            None => true,
+        }
+    }
+
+    /// Checks whether the field named `field_name` of the type named
+    /// `type_name` may be selected, according to the allowlist registered via
+    /// [`RootNode::with_field_allowlist`].
+    pub fn is_field_allowed(&self, type_name: &str, field_name: &str) -> bool {
+        match &self.field_allowlist {
+            Some(allowlist) => {
+                type_name.starts_with("__")
+                    || field_name.starts_with("__")
+                    || allowlist.contains(&format!("{}.{}", type_name, field_name))
+            }
+            None => true,
         }
     }
 
@@ -408,7 +1130,7 @@ impl<'a, S> SchemaType<'a, S> {
     ) -> bool {
         self.possible_types(abstract_type)
             .into_iter()
-            .any(|t| (std::ptr::eq(t, possible_type)))
+            .any(|t| std::ptr::eq(t, possible_type))
     }
 
     /// If the type is a subtype of another type.
@@ -528,10 +1250,64 @@ where
         )
     }
 
+    fn new_pattern(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            "pattern",
+            &[DirectiveLocation::Scalar],
+            &[registry.arg::<String>("regex", &())],
+        )
+        .description("Regular expression valid values of the scalar match")
+    }
+
+    fn new_unit(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            "unit",
+            &[DirectiveLocation::Scalar],
+            &[registry.arg::<String>("name", &())],
+        )
+        .description("Unit values of the scalar are measured in")
+    }
+
+    fn new_length(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            "length",
+            &[DirectiveLocation::Scalar],
+            &[
+                registry.arg::<Option<i32>>("min", &()),
+                registry.arg::<Option<i32>>("max", &()),
+            ],
+        )
+        .description("Bounds (in characters) of the length of the scalar's values")
+    }
+
+    fn new_one_of() -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new("oneOf", &[DirectiveLocation::InputObject], &[])
+            .description("Exactly one field of the input object must be set")
+    }
+
     pub fn description(mut self, description: &str) -> DirectiveType<'a, S> {
         self.description = Some(description.to_owned());
         self
     }
+
+    /// Indicates whether this directive is defined by the GraphQL specification, so it's not
+    /// defined in SDL.
+    pub fn is_builtin(&self) -> bool {
+        // <https://spec.graphql.org/June2018/#sec-Type-System.Directives>
+        self.name == "skip" || self.name == "include"
+    }
 }
 
 impl fmt::Display for DirectiveLocation {
@@ -544,6 +1320,8 @@ impl fmt::Display for DirectiveLocation {
             DirectiveLocation::FragmentDefinition => "fragment definition",
             DirectiveLocation::FragmentSpread => "fragment spread",
             DirectiveLocation::InlineFragment => "inline fragment",
+            DirectiveLocation::Scalar => "scalar",
+            DirectiveLocation::InputObject => "input object",
         })
     }
 }
@@ -704,4 +1482,329 @@ mod test {
             assert_eq!(format!("{}", ast), schema.as_schema_language());
         }
     }
+
+    mod sdl {
+        use crate::{
+            graphql_object, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
+            GraphQLObject, GraphQLUnion, RootNode,
+        };
+
+        #[test]
+        fn sdl() {
+            #[derive(GraphQLObject, Default)]
+            struct Cake {
+                fresh: bool,
+            }
+            #[derive(GraphQLObject, Default)]
+            struct IceCream {
+                cold: bool,
+            }
+            #[derive(GraphQLUnion)]
+            enum GlutenFree {
+                Cake(Cake),
+                IceCream(IceCream),
+            }
+            /// Fruits on the menu.
+            #[derive(GraphQLEnum)]
+            enum Fruit {
+                Apple,
+                /// Only in winter.
+                Orange,
+            }
+            #[derive(GraphQLInputObject)]
+            struct Coordinate {
+                latitude: f64,
+                longitude: f64,
+            }
+            struct Query;
+            #[graphql_object(description = "The \"root\" of it all.")]
+            impl Query {
+                /// This is whatever's description.
+                ///
+                /// It spans several lines.
+                fn whatever() -> String {
+                    "foo".to_string()
+                }
+                fn arr(stuff: Vec<Coordinate>) -> Option<&'static str> {
+                    (!stuff.is_empty()).then_some("stuff")
+                }
+                fn fruits(
+                    #[graphql(default = 3)] limit: i32,
+                    #[graphql(default = "a\"b")] prefix: String,
+                ) -> Vec<Fruit> {
+                    let _ = (limit, prefix);
+                    vec![Fruit::Apple]
+                }
+                fn gluten_free(
+                    #[graphql(description = "Either `sweet` or `savory`.")] flavor: String,
+                ) -> GlutenFree {
+                    if flavor == "savory" {
+                        GlutenFree::Cake(Cake::default())
+                    } else {
+                        GlutenFree::IceCream(IceCream::default())
+                    }
+                }
+                #[deprecated]
+                fn old() -> i32 {
+                    42
+                }
+                #[deprecated(note = "This field is deprecated, use another.")]
+                fn really_old() -> f64 {
+                    42.0
+                }
+            }
+
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            assert_eq!(
+                schema.as_sdl(),
+                r#"schema {
+  query: Query
+}
+
+type Cake {
+  fresh: Boolean!
+}
+
+input Coordinate {
+  latitude: Float!
+  longitude: Float!
+}
+
+"""Fruits on the menu."""
+enum Fruit {
+  APPLE
+  """Only in winter."""
+  ORANGE
+}
+
+union GlutenFree = Cake | IceCream
+
+type IceCream {
+  cold: Boolean!
+}
+
+"""The "root" of it all."""
+type Query {
+  """
+  This is whatever's description.
+
+  It spans several lines.
+  """
+  whatever: String!
+  arr(stuff: [Coordinate!]!): String
+  fruits(limit: Int = 3, prefix: String = "a\"b"): [Fruit!]!
+  glutenFree(
+    """Either `sweet` or `savory`."""
+    flavor: String!
+  ): GlutenFree!
+  old: Int! @deprecated
+  reallyOld: Float! @deprecated(reason: "This field is deprecated, use another.")
+}
+"#,
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    mod root_node {
+        use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone as _, Utc};
+
+        use crate::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+        #[tokio::test]
+        async fn new_async_factory() {
+            struct Root {
+                loaded_at: DateTime<Utc>,
+            }
+
+            #[graphql_object]
+            impl Root {
+                fn loaded_at(&self) -> DateTime<Utc> {
+                    self.loaded_at
+                }
+            }
+
+            let schema = RootNode::new_async(async {
+                tokio::task::yield_now().await;
+                (
+                    Root {
+                        loaded_at: Utc.timestamp_opt(61, 0).unwrap(),
+                    },
+                    EmptyMutation::<()>::new(),
+                    EmptySubscription::<()>::new(),
+                )
+            })
+            .await;
+
+            assert_eq!(
+                crate::execute("{ loadedAt }", None, &schema, &Variables::new(), &()).await,
+                Ok((
+                    graphql_value!({"loadedAt": "1970-01-01T00:01:01+00:00"}),
+                    vec![],
+                )),
+            );
+        }
+
+        #[tokio::test]
+        async fn response_transform() {
+            use crate::{DefaultScalarValue, Value};
+
+            fn truncate_dates(value: Value) -> Value {
+                match value {
+                    Value::Scalar(DefaultScalarValue::String(s))
+                        if DateTime::parse_from_rfc3339(&s).is_ok() =>
+                    {
+                        Value::scalar(s[..10].to_owned())
+                    }
+                    Value::List(items) => {
+                        Value::list(items.into_iter().map(truncate_dates).collect())
+                    }
+                    Value::Object(obj) => Value::object(
+                        obj.into_iter()
+                            .map(|(k, v)| (k, truncate_dates(v)))
+                            .collect(),
+                    ),
+                    v => v,
+                }
+            }
+
+            struct Event;
+
+            #[graphql_object]
+            impl Event {
+                fn name() -> &'static str {
+                    "2021-07-01T10:00:00 launch"
+                }
+                fn starts_at() -> DateTime<Utc> {
+                    Utc.with_ymd_and_hms(2021, 7, 1, 10, 0, 0).unwrap()
+                }
+            }
+
+            struct Root;
+
+            #[graphql_object]
+            impl Root {
+                fn created_at() -> DateTime<FixedOffset> {
+                    DateTime::parse_from_rfc3339("1996-12-19T16:39:57-08:00").unwrap()
+                }
+                fn events() -> Vec<Event> {
+                    vec![Event]
+                }
+            }
+
+            let schema = RootNode::new(
+                Root,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .with_response_transform(truncate_dates);
+
+            assert_eq!(
+                crate::execute(
+                    "{ createdAt events { name startsAt } }",
+                    None,
+                    &schema,
+                    &Variables::new(),
+                    &(),
+                )
+                .await,
+                Ok((
+                    graphql_value!({
+                        "createdAt": "1996-12-19",
+                        "events": [{
+                            "name": "2021-07-01T10:00:00 launch",
+                            "startsAt": "2021-07-01",
+                        }],
+                    }),
+                    vec![],
+                )),
+            );
+            assert_eq!(
+                crate::execute_sync("{ createdAt }", None, &schema, &Variables::new(), &()),
+                Ok((graphql_value!({"createdAt": "1996-12-19"}), vec![])),
+            );
+        }
+
+        #[test]
+        fn validate_missing_arguments() {
+            use crate::{parser::SourcePosition, validation::RuleError, GraphQLError};
+
+            struct Root;
+
+            #[graphql_object]
+            impl Root {
+                fn days_between(from: NaiveDate, to: NaiveDate) -> i32 {
+                    (to - from).num_days() as i32
+                }
+            }
+
+            let schema = RootNode::new(
+                Root,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+
+            assert_eq!(
+                schema.validate(r#"{ daysBetween(from: "2021-07-01", to: "2021-07-08") }"#),
+                Ok(()),
+            );
+            assert_eq!(
+                schema.validate("{ daysBetween }"),
+                Err(GraphQLError::ValidationError(vec![
+                    RuleError::new(
+                        r#"Field "daysBetween" argument "from" of type "NaiveDate!" is required but not provided"#,
+                        &[SourcePosition::new(2, 0, 2)],
+                    ),
+                    RuleError::new(
+                        r#"Field "daysBetween" argument "to" of type "NaiveDate!" is required but not provided"#,
+                        &[SourcePosition::new(2, 0, 2)],
+                    ),
+                ])),
+            );
+        }
+
+        #[test]
+        fn owned_fields() {
+            use crate::GraphQLObject;
+
+            #[derive(GraphQLObject)]
+            struct Event {
+                title: String,
+                starts_at: DateTime<Utc>,
+            }
+
+            struct Root;
+
+            #[graphql_object]
+            impl Root {
+                fn events() -> Vec<Event> {
+                    vec![]
+                }
+            }
+
+            let schema = RootNode::new(
+                Root,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+
+            let owned = schema.owned_fields();
+
+            assert_eq!(
+                owned,
+                vec![
+                    ("Event".to_owned(), "startsAt".to_owned()),
+                    ("Event".to_owned(), "title".to_owned()),
+                    ("Root".to_owned(), "events".to_owned()),
+                ],
+            );
+            assert!(owned
+                .iter()
+                .all(|(ty, field)| !ty.starts_with("__") && !field.starts_with("__")));
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use std::convert::TryFrom;
+
 use crate::{
     ast::Selection,
     executor::{ExecutionResult, Executor, Registry},
@@ -130,6 +132,52 @@ where
     }
 }
 
+/// Root of a schema resolving its introspection fields only, so that it can be
+/// resolved with the [`SchemaType`] being the context.
+pub(crate) struct IntrospectionRoot<'r, 'a, S> {
+    schema: &'r SchemaType<'a, S>,
+}
+
+impl<'r, 'a, S> IntrospectionRoot<'r, 'a, S> {
+    pub(crate) fn new(schema: &'r SchemaType<'a, S>) -> Self {
+        Self { schema }
+    }
+}
+
+impl<'r, 'a, S> GraphQLValue<S> for IntrospectionRoot<'r, 'a, S>
+where
+    S: ScalarValue,
+{
+    type Context = SchemaType<'a, S>;
+    /// Name of the query type.
+    type TypeInfo = String;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        Some(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        info.clone()
+    }
+
+    fn resolve_field(
+        &self,
+        _: &Self::TypeInfo,
+        field: &str,
+        args: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match field {
+            "__schema" => executor.resolve(&(), self.schema),
+            "__type" => {
+                let type_name: String = args.get("name").unwrap();
+                executor.resolve(&(), &self.schema.type_by_name(&type_name))
+            }
+            _ => panic!("Field {} is not an introspection one", field),
+        }
+    }
+}
+
 #[graphql_object(
     name = "__Schema"
     context = SchemaType<'a, S>,
@@ -221,12 +269,20 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
         }
     }
 
-    fn input_fields(&self) -> Option<&[Argument<S>]> {
+    fn input_fields(
+        &self,
+        #[graphql(default)] include_deprecated: bool,
+    ) -> Option<Vec<&Argument<S>>> {
         match self {
             TypeType::Concrete(&MetaType::InputObject(InputObjectMeta {
                 ref input_fields,
                 ..
-            })) => Some(input_fields.as_slice()),
+            })) => Some(
+                input_fields
+                    .iter()
+                    .filter(|f| include_deprecated || !f.deprecation_status.is_deprecated())
+                    .collect(),
+            ),
             _ => None,
         }
     }
@@ -236,6 +292,10 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
             TypeType::Concrete(&MetaType::Object(ObjectMeta {
                 ref interface_names,
                 ..
+            }))
+            | TypeType::Concrete(&MetaType::Interface(InterfaceMeta {
+                ref interface_names,
+                ..
             })) => Some(
                 interface_names
                     .iter()
@@ -296,6 +356,41 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
             _ => None,
         }
     }
+
+    fn min_length(&self) -> Option<i32> {
+        match self {
+            // `Int` is 32-bit, so lengths not fitting into it are as good as unbounded.
+            TypeType::Concrete(t) => t
+                .min_length()
+                .map(|len| i32::try_from(len).unwrap_or(i32::MAX)),
+            _ => None,
+        }
+    }
+
+    fn max_length(&self) -> Option<i32> {
+        match self {
+            TypeType::Concrete(t) => t
+                .max_length()
+                .map(|len| i32::try_from(len).unwrap_or(i32::MAX)),
+            _ => None,
+        }
+    }
+
+    fn is_one_of(&self) -> Option<bool> {
+        match self {
+            TypeType::Concrete(&MetaType::InputObject(InputObjectMeta { is_one_of, .. })) => {
+                Some(is_one_of)
+            }
+            _ => None,
+        }
+    }
+
+    fn extension(&self, name: String) -> Option<&str> {
+        match self {
+            TypeType::Concrete(t) => t.extensions()?.get(&name).map(String::as_str),
+            _ => None,
+        }
+    }
 }
 
 #[graphql_object(
@@ -314,10 +409,12 @@ impl<'a, S: ScalarValue + 'a> Field<'a, S> {
         self.description.as_deref()
     }
 
-    fn args(&self) -> Vec<&Argument<S>> {
-        self.arguments
-            .as_ref()
-            .map_or_else(Vec::new, |v| v.iter().collect())
+    fn args(&self, #[graphql(default)] include_deprecated: bool) -> Vec<&Argument<S>> {
+        self.arguments.as_ref().map_or_else(Vec::new, |v| {
+            v.iter()
+                .filter(|a| include_deprecated || !a.deprecation_status.is_deprecated())
+                .collect()
+        })
     }
 
     #[graphql(name = "type")]
@@ -332,6 +429,14 @@ impl<'a, S: ScalarValue + 'a> Field<'a, S> {
     fn deprecation_reason(&self) -> Option<&str> {
         self.deprecation_status.reason()
     }
+
+    fn removal_date(&self) -> Option<&str> {
+        self.removal_date.as_deref()
+    }
+
+    fn extension(&self, name: String) -> Option<&str> {
+        self.extensions.get(&name).map(String::as_str)
+    }
 }
 
 #[graphql_object(
@@ -359,6 +464,14 @@ impl<'a, S: ScalarValue + 'a> Argument<'a, S> {
     fn default_value_(&self) -> Option<String> {
         self.default_value.as_ref().map(ToString::to_string)
     }
+
+    fn is_deprecated(&self) -> bool {
+        self.deprecation_status.is_deprecated()
+    }
+
+    fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_status.reason()
+    }
 }
 
 #[graphql_object(name = "__EnumValue", internal)]
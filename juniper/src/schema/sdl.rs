@@ -0,0 +1,1085 @@
+//! Parsing of schemas declared in the [GraphQL Schema Definition Language][1] (SDL).
+//!
+//! [`parse_schema`] reads an SDL document into the same [`SchemaType`] a [`RootNode`] is built
+//! around, so the schema declared by a contract may be compared against the one generated from
+//! the Rust resolvers:
+//!
+//! ```rust
+//! # use juniper::{meta::MetaType, sdl, DefaultScalarValue};
+//! let schema = sdl::parse_schema::<DefaultScalarValue>(r#"
+//!     type Query {
+//!         "Greeting of the given person"
+//!         hello(name: String = "World"): String!
+//!     }
+//! "#).unwrap();
+//!
+//! assert!(matches!(schema.concrete_query_type(), MetaType::Object(_)));
+//! ```
+//!
+//! The declared scalars, enums and input objects have no Rust types to parse their values into,
+//! so any values are accepted for them.
+//!
+//! [`RootNode`]: crate::RootNode
+//! [1]: https://spec.graphql.org/June2018/#sec-Type-System
+
+use std::{borrow::Cow, collections::BTreeMap, convert::TryFrom, error::Error, fmt};
+
+use fnv::FnvHashMap;
+use indexmap::IndexMap;
+
+use crate::{
+    ast::{InputValue, Type},
+    executor::Registry,
+    parser::{self, Lexer, LexerError, Parser, ScalarToken, SourcePosition, Spanning, Token},
+    schema::{
+        meta::{
+            Argument, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
+            InterfaceMeta, MetaType, ObjectMeta, ScalarMeta, UnionMeta,
+        },
+        model::{check_implemented_interfaces, SchemaType},
+    },
+    types::{name::Name, scalars::ID},
+    value::{ParseScalarResult, ParseScalarValue, ScalarValue},
+};
+
+/// Error of parsing an SDL document
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    message: String,
+    position: SourcePosition,
+}
+
+impl ParseError {
+    fn new<M: Into<String>>(message: M, position: SourcePosition) -> Self {
+        Self {
+            message: message.into(),
+            position,
+        }
+    }
+
+    /// Description of this error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Position in the SDL document this error occurred at.
+    pub fn position(&self) -> &SourcePosition {
+        &self.position
+    }
+
+    /// Zero-based line of the SDL document this error occurred at.
+    pub fn line(&self) -> usize {
+        self.position.line()
+    }
+
+    /// Zero-based column of the SDL document this error occurred at.
+    pub fn column(&self) -> usize {
+        self.position.column()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message,
+            self.line() + 1,
+            self.column() + 1,
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl<'a> From<Spanning<parser::ParseError<'a>>> for ParseError {
+    fn from(e: Spanning<parser::ParseError<'a>>) -> Self {
+        Self::new(e.item.to_string(), e.start)
+    }
+}
+
+impl From<Spanning<LexerError>> for ParseError {
+    fn from(e: Spanning<LexerError>) -> Self {
+        Self::new(e.item.to_string(), e.start)
+    }
+}
+
+/// Parses the given `sdl` document into a [`SchemaType`].
+///
+/// The document may declare object types, interfaces, unions, enums, input objects and scalars,
+/// along with the `schema` definition of the root operation types. Without the latter, the
+/// `Query`, `Mutation` and `Subscription` types are the root ones, if declared. Directive
+/// definitions are skipped, as only the directives known to juniper (like `@deprecated` or
+/// `@pattern`) have any effect.
+///
+/// # Errors
+///
+/// If the `sdl` is malformed, declares type extensions, refers to undeclared types, or declares
+/// types not implementing the fields of their interfaces.
+pub fn parse_schema<S>(sdl: &str) -> Result<SchemaType<'static, S>, ParseError>
+where
+    S: ScalarValue + 'static,
+{
+    let mut parser = SdlParser {
+        parser: Parser::new(&mut Lexer::new(sdl))?,
+        references: Vec::new(),
+    };
+
+    let mut registry = Registry::new(FnvHashMap::default());
+    registry.get_type::<bool>(&());
+    registry.get_type::<f64>(&());
+    registry.get_type::<i32>(&());
+    registry.get_type::<ID>(&());
+    registry.get_type::<String>(&());
+
+    let mut roots = None;
+    let mut implementing = Vec::new();
+    while parser.parser.peek().item != Token::EndOfFile {
+        let description = parser.description::<S>()?;
+        let keyword = parser.parser.expect_name()?;
+        let (name, meta) = match keyword.item {
+            "schema" if roots.is_none() => {
+                roots = Some(parser.schema_definition::<S>()?);
+                continue;
+            }
+            "schema" => return Err(ParseError::new("Schema is already defined", keyword.start)),
+            "directive" => {
+                parser.directive_definition::<S>()?;
+                continue;
+            }
+            "scalar" => parser.scalar_definition(description)?,
+            "type" => parser.object_definition(description)?,
+            "interface" => parser.interface_definition(description)?,
+            "union" => parser.union_definition(description)?,
+            "enum" => parser.enum_definition(description)?,
+            "input" => parser.input_object_definition(description)?,
+            "extend" => {
+                return Err(ParseError::new(
+                    "`extend` definitions are not supported",
+                    keyword.start,
+                ))
+            }
+            _ => {
+                return Err(ParseError::new(
+                    format!("Expected a definition, found `{}`", keyword.item),
+                    keyword.start,
+                ))
+            }
+        };
+        let type_name = name
+            .item
+            .parse::<Name>()
+            .map_err(|e| ParseError::new(e.to_string(), name.start))?;
+        if registry.types.contains_key(&type_name) {
+            return Err(ParseError::new(
+                format!("Type `{}` is already defined", name.item),
+                name.start,
+            ));
+        }
+        if matches!(
+            &meta,
+            MetaType::Object(ObjectMeta { interface_names, .. })
+                | MetaType::Interface(InterfaceMeta { interface_names, .. })
+                if !interface_names.is_empty()
+        ) {
+            implementing.push(name);
+        }
+        registry.types.insert(type_name, meta);
+    }
+
+    for reference in &parser.references {
+        if !registry.types.contains_key(reference.item.as_str()) {
+            return Err(ParseError::new(
+                format!("Unknown type `{}`", reference.item),
+                reference.start,
+            ));
+        }
+    }
+
+    for name in &implementing {
+        check_implemented_interfaces(&registry.types, &registry.types[name.item])
+            .map_err(|e| ParseError::new(e.to_string(), name.start))?;
+    }
+
+    let end = parser.parser.peek().start;
+    let (query, mutation, subscription) = match roots {
+        Some(roots) => roots,
+        None => {
+            let root = |name: &str| {
+                registry
+                    .types
+                    .contains_key(name)
+                    .then(|| Spanning::zero_width(&end, name.to_owned()))
+            };
+            match root("Query") {
+                Some(query) => (query, root("Mutation"), root("Subscription")),
+                None => return Err(ParseError::new("Schema has no query type", end)),
+            }
+        }
+    };
+    for root in std::iter::once(&query)
+        .chain(&mutation)
+        .chain(&subscription)
+    {
+        if !matches!(
+            registry.types.get(root.item.as_str()),
+            Some(MetaType::Object(_))
+        ) {
+            return Err(ParseError::new(
+                format!("Root type `{}` is not an object type", root.item),
+                root.start,
+            ));
+        }
+    }
+
+    SchemaType::from_registry(
+        registry,
+        query.item,
+        mutation.map(|m| m.item),
+        subscription.map(|s| s.item),
+    )
+    .map_err(|e| ParseError::new(e.to_string(), SourcePosition::new_origin()))
+}
+
+/// Accepts any input value of the declared scalars, enums and input objects, as there are no Rust
+/// types to parse them into.
+fn accept_any<S>(_: &InputValue<S>) -> bool {
+    true
+}
+
+/// Parses the literals of the declared scalars as the built-in scalar of the same kind.
+fn parse_scalar_token<S: ScalarValue>(token: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+    match token {
+        ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(token),
+        ScalarToken::Int(_) => <i32 as ParseScalarValue<S>>::from_str(token),
+        ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(token),
+    }
+}
+
+/// Block string value of the given `raw` contents, with the escaped triple quotes unescaped, and
+/// the common indentation and the leading and trailing blank lines removed.
+///
+/// See the [spec](https://spec.graphql.org/June2018/#BlockStringValue()) for details.
+fn block_string_value(raw: &str) -> String {
+    let is_blank = |line: &str| line.trim_matches(&[' ', '\t'][..]).is_empty();
+
+    let raw = raw.replace("\\\"\"\"", "\"\"\"");
+    let lines = raw.lines().collect::<Vec<_>>();
+    let indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start_matches(&[' ', '\t'][..]).len())
+        .min()
+        .unwrap_or(0);
+
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                line
+            } else {
+                line.get(indent..).unwrap_or("")
+            }
+        })
+        .collect::<Vec<_>>();
+    while matches!(lines.first(), Some(line) if is_blank(line)) {
+        lines.remove(0);
+    }
+    while matches!(lines.last(), Some(line) if is_blank(line)) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// Directive applied to a definition in an SDL document
+struct Directive<'a, S> {
+    name: &'a str,
+    arguments: Vec<(&'a str, InputValue<S>)>,
+}
+
+impl<'a, S: ScalarValue> Directive<'a, S> {
+    fn find<'d>(directives: &'d [Self], name: &str) -> Option<&'d Self> {
+        directives.iter().find(|d| d.name == name)
+    }
+
+    fn string_argument(&self, name: &str) -> Option<String> {
+        self.arguments
+            .iter()
+            .find(|(n, _)| *n == name)
+            .and_then(|(_, v)| v.as_string_value())
+            .map(str::to_owned)
+    }
+
+    fn int_argument(&self, name: &str) -> Option<i32> {
+        self.arguments
+            .iter()
+            .find(|(n, _)| *n == name)
+            .and_then(|(_, v)| v.as_int_value())
+    }
+
+    fn length_argument(
+        directives: &[Self],
+        name: &str,
+        pos: SourcePosition,
+    ) -> Result<Option<usize>, ParseError> {
+        Self::find(directives, "length")
+            .and_then(|d| d.int_argument(name))
+            .map(|len| {
+                usize::try_from(len).map_err(|_| {
+                    ParseError::new(format!("`@length({})` must not be negative", name), pos)
+                })
+            })
+            .transpose()
+    }
+
+    fn deprecation_status(directives: &[Self]) -> DeprecationStatus {
+        Self::find(directives, "deprecated").map_or(DeprecationStatus::Current, |d| {
+            DeprecationStatus::Deprecated(d.string_argument("reason"))
+        })
+    }
+}
+
+/// Root operation types of a schema: the query, mutation and subscription ones
+type Roots = (
+    Spanning<String>,
+    Option<Spanning<String>>,
+    Option<Spanning<String>>,
+);
+
+/// Parser of the type system definitions of an SDL document, on top of the tokens of the query
+/// [`Parser`].
+struct SdlParser<'a> {
+    parser: Parser<'a>,
+
+    /// Names of all the types referenced in the document, checked to be declared after parsing it
+    references: Vec<Spanning<String>>,
+}
+
+impl<'a> SdlParser<'a> {
+    fn skip(&mut self, token: &Token) -> Result<bool, ParseError> {
+        Ok(self.parser.skip(token)?.is_some())
+    }
+
+    fn skip_keyword(&mut self, keyword: &str) -> Result<bool, ParseError> {
+        self.skip(&Token::Name(keyword))
+    }
+
+    fn expect_type_name(&mut self) -> Result<String, ParseError> {
+        let name = self.parser.expect_name()?;
+        self.references.push(name.map(str::to_owned));
+        Ok(name.item.to_owned())
+    }
+
+    fn description<S: ScalarValue>(&mut self) -> Result<Option<String>, ParseError> {
+        Ok(match self.parser.peek().item {
+            Token::BlockString(raw) => {
+                self.parser.next_token()?;
+                Some(block_string_value(raw))
+            }
+            Token::Scalar(token @ ScalarToken::String(_)) => {
+                let start = self.parser.next_token()?.start;
+                <String as ParseScalarValue<S>>::from_str(token)
+                    .map_err(|e| ParseError::new(e.to_string(), start))?
+                    .into_string()
+            }
+            _ => None,
+        })
+    }
+
+    fn schema_definition<S: ScalarValue>(&mut self) -> Result<Roots, ParseError> {
+        let start = self.parser.peek().start;
+        self.directives::<S>()?;
+        self.parser.expect(&Token::CurlyOpen)?;
+        let (mut query, mut mutation, mut subscription) = (None, None, None);
+        while !self.skip(&Token::CurlyClose)? {
+            let operation = self.parser.expect_name()?;
+            let root = match operation.item {
+                "query" => &mut query,
+                "mutation" => &mut mutation,
+                "subscription" => &mut subscription,
+                _ => {
+                    return Err(ParseError::new(
+                        format!("Expected an operation type, found `{}`", operation.item),
+                        operation.start,
+                    ))
+                }
+            };
+            self.parser.expect(&Token::Colon)?;
+            let name = self.parser.expect_name()?;
+            self.references.push(name.map(str::to_owned));
+            *root = Some(name.map(str::to_owned));
+        }
+        let query = query.ok_or_else(|| ParseError::new("Schema has no query type", start))?;
+        Ok((query, mutation, subscription))
+    }
+
+    fn directive_definition<S: ScalarValue>(&mut self) -> Result<(), ParseError> {
+        self.parser.expect(&Token::At)?;
+        self.parser.expect_name()?;
+        if self.skip(&Token::ParenOpen)? {
+            while !self.skip(&Token::ParenClose)? {
+                self.input_value_definition::<S>()?;
+            }
+        }
+        self.skip_keyword("repeatable")?;
+        self.parser.expect(&Token::Name("on"))?;
+        self.skip(&Token::Pipe)?;
+        self.parser.expect_name()?;
+        while self.skip(&Token::Pipe)? {
+            self.parser.expect_name()?;
+        }
+        Ok(())
+    }
+
+    fn scalar_definition<S: ScalarValue>(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<(Spanning<&'a str>, MetaType<'static, S>), ParseError> {
+        let name = self.parser.expect_name()?;
+        let directives = self.directives::<S>()?;
+        let meta = ScalarMeta {
+            name: Cow::Owned(name.item.to_owned()),
+            description,
+            pattern: Directive::find(&directives, "pattern")
+                .and_then(|d| d.string_argument("regex")),
+            unit: Directive::find(&directives, "unit").and_then(|d| d.string_argument("name")),
+            min_length: Directive::length_argument(&directives, "min", name.start)?,
+            max_length: Directive::length_argument(&directives, "max", name.start)?,
+            is_builtin: false,
+            // The representation of the scalar is unknown, so any value is accepted.
+            accepts_objects: true,
+            output_check: None,
+            try_parse_fn: accept_any::<S>,
+            parse_fn: parse_scalar_token::<S>,
+        };
+        Ok((name, MetaType::Scalar(meta)))
+    }
+
+    fn object_definition<S: ScalarValue>(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<(Spanning<&'a str>, MetaType<'static, S>), ParseError> {
+        let name = self.parser.expect_name()?;
+        let interface_names = self.implements_interfaces()?;
+        self.directives::<S>()?;
+        let meta = ObjectMeta {
+            name: Cow::Owned(name.item.to_owned()),
+            description,
+            fields: self.fields_definition()?,
+            interface_names,
+            extensions: BTreeMap::new(),
+        };
+        Ok((name, MetaType::Object(meta)))
+    }
+
+    fn interface_definition<S: ScalarValue>(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<(Spanning<&'a str>, MetaType<'static, S>), ParseError> {
+        let name = self.parser.expect_name()?;
+        let interface_names = self.implements_interfaces()?;
+        self.directives::<S>()?;
+        let meta = InterfaceMeta {
+            name: Cow::Owned(name.item.to_owned()),
+            description,
+            fields: self.fields_definition()?,
+            interface_names,
+            extensions: BTreeMap::new(),
+        };
+        Ok((name, MetaType::Interface(meta)))
+    }
+
+    fn implements_interfaces(&mut self) -> Result<Vec<String>, ParseError> {
+        let mut interface_names = Vec::new();
+        if self.skip_keyword("implements")? {
+            self.skip(&Token::Ampersand)?;
+            interface_names.push(self.expect_type_name()?);
+            while self.skip(&Token::Ampersand)? || matches!(self.parser.peek().item, Token::Name(_))
+            {
+                interface_names.push(self.expect_type_name()?);
+            }
+        }
+        Ok(interface_names)
+    }
+
+    fn union_definition<S: ScalarValue>(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<(Spanning<&'a str>, MetaType<'static, S>), ParseError> {
+        let name = self.parser.expect_name()?;
+        self.directives::<S>()?;
+        self.parser.expect(&Token::Equals)?;
+        self.skip(&Token::Pipe)?;
+        let mut of_type_names = vec![self.expect_type_name()?];
+        while self.skip(&Token::Pipe)? {
+            of_type_names.push(self.expect_type_name()?);
+        }
+        let meta = UnionMeta {
+            name: Cow::Owned(name.item.to_owned()),
+            description,
+            of_type_names,
+        };
+        Ok((name, MetaType::Union(meta)))
+    }
+
+    fn enum_definition<S: ScalarValue>(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<(Spanning<&'a str>, MetaType<'static, S>), ParseError> {
+        let name = self.parser.expect_name()?;
+        self.directives::<S>()?;
+        self.parser.expect(&Token::CurlyOpen)?;
+        let mut values = Vec::new();
+        while !self.skip(&Token::CurlyClose)? {
+            let description = self.description::<S>()?;
+            let value = self.parser.expect_name()?;
+            let directives = self.directives::<S>()?;
+            values.push(EnumValue {
+                name: value.item.to_owned(),
+                description,
+                deprecation_status: Directive::deprecation_status(&directives),
+            });
+        }
+        let meta = EnumMeta {
+            name: Cow::Owned(name.item.to_owned()),
+            description,
+            values,
+            try_parse_fn: accept_any::<S>,
+        };
+        Ok((name, MetaType::Enum(meta)))
+    }
+
+    fn input_object_definition<S: ScalarValue>(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<(Spanning<&'a str>, MetaType<'static, S>), ParseError> {
+        let name = self.parser.expect_name()?;
+        let directives = self.directives::<S>()?;
+        self.parser.expect(&Token::CurlyOpen)?;
+        let mut input_fields = Vec::new();
+        while !self.skip(&Token::CurlyClose)? {
+            input_fields.push(self.input_value_definition()?);
+        }
+        let meta = InputObjectMeta {
+            name: Cow::Owned(name.item.to_owned()),
+            description,
+            input_fields,
+            is_one_of: Directive::find(&directives, "oneOf").is_some(),
+            try_parse_fn: accept_any::<S>,
+        };
+        Ok((name, MetaType::InputObject(meta)))
+    }
+
+    fn fields_definition<S: ScalarValue>(&mut self) -> Result<Vec<Field<'static, S>>, ParseError> {
+        self.parser.expect(&Token::CurlyOpen)?;
+        let mut fields = Vec::new();
+        while !self.skip(&Token::CurlyClose)? {
+            let description = self.description::<S>()?;
+            let name = self.parser.expect_name()?;
+            let arguments = if self.skip(&Token::ParenOpen)? {
+                let mut arguments = Vec::new();
+                while !self.skip(&Token::ParenClose)? {
+                    arguments.push(self.input_value_definition()?);
+                }
+                Some(arguments)
+            } else {
+                None
+            };
+            self.parser.expect(&Token::Colon)?;
+            let field_type = self.type_reference()?;
+            let directives = self.directives::<S>()?;
+            fields.push(Field {
+                name: name.item.into(),
+                description,
+                arguments,
+                field_type,
+                deprecation_status: Directive::deprecation_status(&directives),
+                removal_date: None,
+                complexity: None,
+                extensions: BTreeMap::new(),
+            });
+        }
+        Ok(fields)
+    }
+
+    fn input_value_definition<S: ScalarValue>(
+        &mut self,
+    ) -> Result<Argument<'static, S>, ParseError> {
+        let description = self.description::<S>()?;
+        let name = self.parser.expect_name()?;
+        self.parser.expect(&Token::Colon)?;
+        let arg_type = self.type_reference()?;
+        let default_value = if self.skip(&Token::Equals)? {
+            Some(self.value()?)
+        } else {
+            None
+        };
+        let directives = self.directives::<S>()?;
+        Ok(Argument {
+            name: name.item.to_owned(),
+            description,
+            arg_type,
+            default_value,
+            deprecation_status: Directive::deprecation_status(&directives),
+        })
+    }
+
+    fn type_reference(&mut self) -> Result<Type<'static>, ParseError> {
+        let ty = if self.skip(&Token::BracketOpen)? {
+            let of_type = self.type_reference()?;
+            self.parser.expect(&Token::BracketClose)?;
+            Type::List(Box::new(of_type), None)
+        } else {
+            Type::Named(Cow::Owned(self.expect_type_name()?))
+        };
+        if !self.skip(&Token::ExclamationMark)? {
+            return Ok(ty);
+        }
+        Ok(match ty {
+            Type::Named(name) => Type::NonNullNamed(name),
+            Type::List(of_type, size) => Type::NonNullList(of_type, size),
+            ty => ty,
+        })
+    }
+
+    fn directives<S: ScalarValue>(&mut self) -> Result<Vec<Directive<'a, S>>, ParseError> {
+        let mut directives = Vec::new();
+        while self.skip(&Token::At)? {
+            let name = self.parser.expect_name()?.item;
+            let mut arguments = Vec::new();
+            if self.skip(&Token::ParenOpen)? {
+                while !self.skip(&Token::ParenClose)? {
+                    let name = self.parser.expect_name()?.item;
+                    self.parser.expect(&Token::Colon)?;
+                    arguments.push((name, self.value()?));
+                }
+            }
+            directives.push(Directive { name, arguments });
+        }
+        Ok(directives)
+    }
+
+    fn value<S: ScalarValue>(&mut self) -> Result<InputValue<S>, ParseError> {
+        let Spanning { item, start, .. } = self.parser.next_token()?;
+        let error = |e: parser::ParseError| ParseError::new(e.to_string(), start);
+        Ok(match item {
+            Token::Scalar(token) => InputValue::Scalar(parse_scalar_token(token).map_err(error)?),
+            Token::BlockString(raw) => InputValue::scalar(block_string_value(raw)),
+            Token::Name("true") => InputValue::scalar(true),
+            Token::Name("false") => InputValue::scalar(false),
+            Token::Name("null") => InputValue::null(),
+            Token::Name(name) => InputValue::enum_value(name),
+            Token::BracketOpen => {
+                let mut items = Vec::new();
+                while !self.skip(&Token::BracketClose)? {
+                    items.push(self.value()?);
+                }
+                InputValue::list(items)
+            }
+            Token::CurlyOpen => {
+                let mut fields = IndexMap::new();
+                while !self.skip(&Token::CurlyClose)? {
+                    let name = self.parser.expect_name()?.item;
+                    self.parser.expect(&Token::Colon)?;
+                    fields.insert(name, self.value()?);
+                }
+                InputValue::object(fields)
+            }
+            item => return Err(error(parser::ParseError::UnexpectedToken(item))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ast::{InputValue, Type},
+        schema::meta::{DeprecationStatus, MetaType},
+        DefaultScalarValue,
+    };
+
+    use super::{parse_schema, ParseError};
+
+    fn parse_error(sdl: &str) -> ParseError {
+        match parse_schema::<DefaultScalarValue>(sdl) {
+            Ok(_) => panic!("SDL parsed successfully:\n{}", sdl),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn parses_all_definitions() {
+        let schema = parse_schema::<DefaultScalarValue>(
+            r#"
+            """
+            Date and time at UTC.
+
+              Serialized as RFC 3339.
+            """
+            scalar DateTime @unit(name: "UTC")
+
+            "Anything having an identifier"
+            interface Node {
+                id: ID!
+            }
+
+            type Event implements Node & Dated {
+                id: ID!
+                at: DateTime!
+                "Attendees, ordered by name"
+                attendees(first: Int = 10, after: String): [String!]!
+                legacyAt: String @deprecated(reason: "Use `at`.")
+            }
+
+            interface Dated implements Node {
+                id: ID!
+                at: DateTime!
+            }
+
+            union SearchResult = | Event
+
+            "Fields of an entity being its key"
+            directive @key(fields: String!) repeatable on | OBJECT | INTERFACE
+
+            enum Weekday {
+                MONDAY
+                "Deprecated weekday"
+                FUNDAY @deprecated
+            }
+
+            input Period {
+                from: DateTime!
+                weekdays: [Weekday!] = [MONDAY]
+                limits: Limits = { max: 1.5, strict: true }
+            }
+
+            input Limits {
+                max: Float
+                strict: Boolean
+            }
+
+            type Root {
+                events(period: Period): [Event]
+                search(text: String!): [SearchResult!]!
+            }
+
+            schema {
+                query: Root
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(schema.concrete_query_type().name(), Some("Root"));
+        assert!(schema.concrete_mutation_type().is_none());
+
+        match schema.concrete_type_by_name("DateTime") {
+            Some(MetaType::Scalar(s)) => {
+                assert_eq!(
+                    s.description.as_deref(),
+                    Some("Date and time at UTC.\n\n  Serialized as RFC 3339."),
+                );
+                assert_eq!(s.unit.as_deref(), Some("UTC"));
+            }
+            t => panic!("unexpected type: {:?}", t),
+        }
+
+        match schema.concrete_type_by_name("Event") {
+            Some(MetaType::Object(o)) => {
+                assert_eq!(o.interface_names, vec!["Node", "Dated"]);
+                assert_eq!(o.fields.len(), 4);
+
+                let attendees = &o.fields[2];
+                assert_eq!(
+                    attendees.description.as_deref(),
+                    Some("Attendees, ordered by name"),
+                );
+                assert_eq!(
+                    attendees.field_type,
+                    Type::NonNullList(Box::new(Type::NonNullNamed("String".into())), None),
+                );
+                let args = attendees.arguments.as_ref().unwrap();
+                assert_eq!(args[0].name, "first");
+                assert_eq!(args[0].default_value, Some(InputValue::scalar(10)));
+                assert_eq!(args[1].arg_type, Type::Named("String".into()));
+                assert_eq!(args[1].default_value, None);
+
+                assert_eq!(
+                    o.fields[3].deprecation_status,
+                    DeprecationStatus::Deprecated(Some("Use `at`.".into())),
+                );
+            }
+            t => panic!("unexpected type: {:?}", t),
+        }
+
+        match schema.concrete_type_by_name("Dated") {
+            Some(MetaType::Interface(i)) => assert_eq!(i.interface_names, vec!["Node"]),
+            t => panic!("unexpected type: {:?}", t),
+        }
+
+        match schema.concrete_type_by_name("SearchResult") {
+            Some(MetaType::Union(u)) => assert_eq!(u.of_type_names, vec!["Event"]),
+            t => panic!("unexpected type: {:?}", t),
+        }
+
+        match schema.concrete_type_by_name("Weekday") {
+            Some(MetaType::Enum(e)) => {
+                assert_eq!(e.values[0].name, "MONDAY");
+                assert_eq!(
+                    e.values[1].description.as_deref(),
+                    Some("Deprecated weekday")
+                );
+                assert_eq!(
+                    e.values[1].deprecation_status,
+                    DeprecationStatus::Deprecated(None),
+                );
+            }
+            t => panic!("unexpected type: {:?}", t),
+        }
+
+        match schema.concrete_type_by_name("Period") {
+            Some(MetaType::InputObject(i)) => {
+                assert_eq!(
+                    i.input_fields[1].default_value,
+                    Some(InputValue::list(vec![InputValue::enum_value("MONDAY")])),
+                );
+                assert_eq!(
+                    i.input_fields[2].default_value,
+                    Some(InputValue::object(
+                        vec![
+                            ("max", InputValue::scalar(1.5)),
+                            ("strict", InputValue::scalar(true)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )),
+                );
+            }
+            t => panic!("unexpected type: {:?}", t),
+        }
+    }
+
+    #[test]
+    fn defaults_root_types_by_name() {
+        let schema = parse_schema::<DefaultScalarValue>(
+            "type Query { a: Int } type Mutation { b: Int } type Other { c: Int }",
+        )
+        .unwrap();
+
+        assert_eq!(schema.concrete_query_type().name(), Some("Query"));
+        assert_eq!(
+            schema.concrete_mutation_type().and_then(MetaType::name),
+            Some("Mutation"),
+        );
+        assert!(schema.concrete_subscription_type().is_none());
+    }
+
+    #[test]
+    fn validates_queries_against_parsed_schema() {
+        let schema =
+            parse_schema::<DefaultScalarValue>("type Query { hello(name: String!): String! }")
+                .unwrap();
+        let root = |query| {
+            let document = crate::parse_document_source(query, &schema).unwrap();
+            let mut ctx = crate::ValidatorContext::new(&schema, &document);
+            crate::visit_all_rules(&mut ctx, &document);
+            ctx.into_errors()
+        };
+
+        assert_eq!(root(r#"{ hello(name: "Bob") }"#), vec![]);
+        assert_eq!(root("{ hello }").len(), 1);
+        assert_eq!(root(r#"{ goodbye(name: "Bob") }"#).len(), 1);
+    }
+
+    #[test]
+    fn reports_errors_with_positions() {
+        let e = parse_error("type Query {\n    hello String\n}");
+        assert_eq!(e.message(), "Unexpected \"String\"");
+        assert_eq!((e.line(), e.column()), (1, 10));
+        assert_eq!(e.to_string(), "Unexpected \"String\" at line 2, column 11");
+
+        let e = parse_error("type Query {\n  event: Event\n}");
+        assert_eq!(e.message(), "Unknown type `Event`");
+        assert_eq!((e.line(), e.column()), (1, 9));
+
+        let e = parse_error("type Query {\n  \"hello: String\n}");
+        assert_eq!(e.message(), "Unterminated string literal");
+        assert_eq!((e.line(), e.column()), (1, 16));
+
+        let e = parse_error("type Query { a: Int }\n\"\"\" Query");
+        assert_eq!(e.message(), "Unterminated string literal");
+
+        let e = parse_error("type Query { a: Int }\ntype Query { b: Int }");
+        assert_eq!(e.message(), "Type `Query` is already defined");
+        assert_eq!((e.line(), e.column()), (1, 5));
+
+        let e = parse_error("type Query { a: Int = 99999999999 }");
+        assert_eq!(e.message(), "Unexpected \"=\"");
+
+        let e = parse_error("type Query { a(b: Int = 99999999999): Int }");
+        assert_eq!(
+            e.message(),
+            "Int cannot represent 99999999999, as it's outside of the range \
+             [-2147483648, 2147483647]",
+        );
+        assert_eq!((e.line(), e.column()), (0, 24));
+
+        let e = parse_error("type Query { a: Int } extend type Query { b: Int }");
+        assert_eq!(e.message(), "`extend` definitions are not supported");
+
+        let e = parse_error("type Root { a: Int }");
+        assert_eq!(e.message(), "Schema has no query type");
+
+        let e = parse_error("scalar Date schema { query: Date }");
+        assert_eq!(e.message(), "Root type `Date` is not an object type");
+
+        let e = parse_error(
+            "type Query { a: Int }\ninterface Node { id: ID! }\ninterface Dated implements Node { at: Int }",
+        );
+        assert_eq!(
+            e.message(),
+            "Type `Dated` implements `Node`, but misses its field `id`",
+        );
+        assert_eq!((e.line(), e.column()), (2, 10));
+
+        let e = parse_error(
+            "type Query { a: Int }\ninterface Node { id: ID! }\ntype Event implements Node { at: Int }",
+        );
+        assert_eq!(
+            e.message(),
+            "Type `Event` implements `Node`, but misses its field `id`",
+        );
+        assert_eq!((e.line(), e.column()), (2, 5));
+
+        let e = parse_error("type Query { a: Int }\ninterface Dated implements Query { a: Int }");
+        assert_eq!(
+            e.message(),
+            "Type `Dated` implements `Query`, which is not an interface type",
+        );
+
+        let e = parse_error(
+            "type Query { a: Int }\n\
+             interface Node { id: ID! }\n\
+             interface Dated implements Node { id: ID! }\n\
+             interface Event implements Dated { id: ID! }",
+        );
+        assert_eq!(
+            e.message(),
+            "Type `Event` implements `Dated`, but not `Node` implemented by it",
+        );
+        assert_eq!((e.line(), e.column()), (3, 10));
+
+        let e = parse_error("type Query { a: Int } ?");
+        assert_eq!(e.message(), "Unknown character \"?\"");
+        assert_eq!((e.line(), e.column()), (0, 22));
+    }
+
+    mod round_trip {
+        use crate::{
+            graphql_interface, graphql_object,
+            schema::translate::{sdl::SdlTranslator, SchemaTranslator},
+            DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
+            GraphQLObject, GraphQLUnion, RootNode,
+        };
+
+        use super::parse_schema;
+
+        /// Date in `YYYY-MM-DD` format
+        #[derive(GraphQLObject)]
+        struct Day {
+            year: i32,
+            weekday: Weekday,
+        }
+
+        #[derive(GraphQLObject)]
+        #[graphql(impl = NodeValue)]
+        struct Holiday {
+            id: String,
+            /// Name of the holiday,
+            /// possibly localized.
+            name: String,
+        }
+
+        #[graphql_interface(for = Holiday)]
+        trait Node {
+            fn id(&self) -> &str;
+        }
+
+        impl Node for Holiday {
+            fn id(&self) -> &str {
+                &self.id
+            }
+        }
+
+        #[derive(GraphQLUnion)]
+        enum Entry {
+            Day(Day),
+            Holiday(Holiday),
+        }
+
+        #[derive(GraphQLEnum)]
+        enum Weekday {
+            Monday,
+            #[graphql(deprecated = "No such day.")]
+            Funday,
+        }
+
+        #[derive(GraphQLInputObject)]
+        struct Period {
+            from: String,
+            days: Option<i32>,
+            #[graphql(deprecated = "Use `days`.")]
+            weeks: Option<i32>,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn entries(period: Period, #[graphql(default = true)] holidays: bool) -> Vec<Entry> {
+                let mut entries = vec![Entry::Day(Day {
+                    year: 2021,
+                    weekday: Weekday::Monday,
+                })];
+                if holidays {
+                    entries.push(Entry::Holiday(Holiday {
+                        id: format!("{}+{}", period.from, period.days.unwrap_or(0)),
+                        name: "New Year".into(),
+                    }));
+                }
+                entries
+            }
+
+            fn node(id: String, #[graphql(deprecated)] kind: Option<String>) -> Option<NodeValue> {
+                let _ = (id, kind);
+                None
+            }
+
+            #[deprecated(note = "Use `entries`.")]
+            fn today() -> Day {
+                Day {
+                    year: 2021,
+                    weekday: Weekday::Monday,
+                }
+            }
+        }
+
+        #[test]
+        fn round_trips_generated_schema() {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            );
+            let sdl = schema.as_sdl();
+            assert!(sdl.contains("kind: String @deprecated"));
+            assert!(sdl.contains("weeks: Int @deprecated(reason: \"Use `days`.\")"));
+
+            let parsed = parse_schema::<DefaultScalarValue>(&sdl).unwrap();
+
+            assert_eq!(SdlTranslator::translate_schema(&parsed), sdl);
+        }
+    }
+}
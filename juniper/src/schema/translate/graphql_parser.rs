@@ -3,10 +3,12 @@ use std::{boxed::Box, collections::BTreeMap};
 use graphql_parser::{
     query::{Directive as ExternalDirective, Number as ExternalNumber, Type as ExternalType},
     schema::{
-        Definition, Document, EnumType as ExternalEnum, EnumValue as ExternalEnumValue,
-        Field as ExternalField, InputObjectType as ExternalInputObjectType,
-        InputValue as ExternalInputValue, InterfaceType as ExternalInterfaceType,
-        ObjectType as ExternalObjectType, ScalarType as ExternalScalarType, SchemaDefinition, Text,
+        Definition, DirectiveDefinition as ExternalDirectiveDefinition,
+        DirectiveLocation as ExternalDirectiveLocation, Document, EnumType as ExternalEnum,
+        EnumValue as ExternalEnumValue, Field as ExternalField,
+        InputObjectType as ExternalInputObjectType, InputValue as ExternalInputValue,
+        InterfaceType as ExternalInterfaceType, ObjectType as ExternalObjectType,
+        ScalarType as ExternalScalarType, SchemaDefinition, Text,
         TypeDefinition as ExternalTypeDefinition, UnionType as ExternalUnionType,
         Value as ExternalValue,
     },
@@ -16,9 +18,9 @@ use graphql_parser::{
 use crate::{
     ast::{InputValue, Type},
     schema::{
-        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType},
-        model::SchemaType,
-        translate::SchemaTranslator,
+        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType, ScalarMeta},
+        model::{DirectiveLocation, DirectiveType, SchemaType},
+        translate::{scalar_directives, DirectiveValue, SchemaTranslator},
     },
     value::ScalarValue,
 };
@@ -46,6 +48,16 @@ where
     {
         let mut doc = Document::default();
 
+        // Translate the definitions of the directives applied to types.
+        let mut directives = input
+            .directive_list()
+            .into_iter()
+            .filter(|directive| !directive.is_builtin())
+            .map(GraphQLParserTranslator::translate_directive)
+            .map(Definition::DirectiveDefinition)
+            .collect();
+        doc.definitions.append(&mut directives);
+
         // Translate type defs.
         let mut types = input
             .types
@@ -90,7 +102,7 @@ impl GraphQLParserTranslator {
                 .default_value
                 .as_ref()
                 .map(|x| GraphQLParserTranslator::translate_value(x)),
-            directives: vec![],
+            directives: generate_directives(&input.deprecation_status),
         }
     }
 
@@ -162,7 +174,7 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(From::from),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: GraphQLParserTranslator::translate_scalar_directives(x),
             }),
             MetaType::Enum(x) => ExternalTypeDefinition::Enum(ExternalEnum {
                 position: Pos::default(),
@@ -203,7 +215,15 @@ impl GraphQLParserTranslator {
                     position: Pos::default(),
                     description: x.description.as_ref().map(|s| From::from(s.as_str())),
                     name: From::from(x.name.as_ref()),
-                    directives: vec![],
+                    directives: if x.is_one_of {
+                        vec![ExternalDirective {
+                            position: Pos::default(),
+                            name: From::from("oneOf"),
+                            arguments: vec![],
+                        }]
+                    } else {
+                        vec![]
+                    },
                     fields: x
                         .input_fields
                         .iter()
@@ -233,6 +253,65 @@ impl GraphQLParserTranslator {
         }
     }
 
+    fn translate_scalar_directives<'a, S, T>(input: &ScalarMeta<S>) -> Vec<ExternalDirective<'a, T>>
+    where
+        T: Text<'a>,
+    {
+        scalar_directives(input)
+            .into_iter()
+            .map(|(name, arguments)| ExternalDirective {
+                position: Pos::default(),
+                name: From::from(name),
+                arguments: arguments
+                    .into_iter()
+                    .map(|(arg, value)| {
+                        let value = match value {
+                            DirectiveValue::String(s) => ExternalValue::String(s.to_owned()),
+                            DirectiveValue::Int(i) => ExternalValue::Int(ExternalNumber::from(i)),
+                        };
+                        (From::from(arg), value)
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn translate_directive<'a, S, T>(
+        input: &'a DirectiveType<S>,
+    ) -> ExternalDirectiveDefinition<'a, T>
+    where
+        S: ScalarValue,
+        T: Text<'a>,
+    {
+        ExternalDirectiveDefinition {
+            position: Pos::default(),
+            description: input.description.clone(),
+            name: From::from(input.name.as_str()),
+            arguments: input
+                .arguments
+                .iter()
+                .map(GraphQLParserTranslator::translate_argument)
+                .collect(),
+            locations: input
+                .locations
+                .iter()
+                .map(|location| match location {
+                    DirectiveLocation::Query => ExternalDirectiveLocation::Query,
+                    DirectiveLocation::Mutation => ExternalDirectiveLocation::Mutation,
+                    DirectiveLocation::Subscription => ExternalDirectiveLocation::Subscription,
+                    DirectiveLocation::Field => ExternalDirectiveLocation::Field,
+                    DirectiveLocation::FragmentDefinition => {
+                        ExternalDirectiveLocation::FragmentDefinition
+                    }
+                    DirectiveLocation::FragmentSpread => ExternalDirectiveLocation::FragmentSpread,
+                    DirectiveLocation::InlineFragment => ExternalDirectiveLocation::InlineFragment,
+                    DirectiveLocation::Scalar => ExternalDirectiveLocation::Scalar,
+                    DirectiveLocation::InputObject => ExternalDirectiveLocation::InputObject,
+                })
+                .collect(),
+        }
+    }
+
     fn translate_enum_value<'a, T>(input: &'a EnumValue) -> ExternalEnumValue<'a, T>
     where
         T: Text<'a>,
@@ -1,8 +1,47 @@
-use crate::{ScalarValue, SchemaType};
+use std::convert::TryFrom;
+
+use crate::{schema::meta::ScalarMeta, ScalarValue, SchemaType};
 
 pub trait SchemaTranslator<'a, T> {
     fn translate_schema<S: 'a + ScalarValue>(s: &'a SchemaType<S>) -> T;
 }
 
+/// Value of an argument of a directive applied to a type.
+pub(crate) enum DirectiveValue<'a> {
+    String(&'a str),
+    Int(i32),
+}
+
+/// Name of a directive applied to a type, along with its arguments.
+pub(crate) type AppliedDirective<'a> = (&'static str, Vec<(&'static str, DirectiveValue<'a>)>);
+
+/// Returns the directives exposing the constraints of the given scalar (`@pattern`, `@unit` and
+/// `@length`) to be applied to it in SDL.
+pub(crate) fn scalar_directives<'m, S>(input: &'m ScalarMeta<S>) -> Vec<AppliedDirective<'m>> {
+    let mut directives = vec![];
+    if let Some(regex) = &input.pattern {
+        directives.push(("pattern", vec![("regex", DirectiveValue::String(regex))]));
+    }
+    if let Some(unit) = &input.unit {
+        directives.push(("unit", vec![("name", DirectiveValue::String(unit))]));
+    }
+    let length: Vec<_> = input
+        .min_length
+        .map(|len| ("min", len))
+        .into_iter()
+        .chain(input.max_length.map(|len| ("max", len)))
+        .map(|(arg, len)| {
+            // `Int` is 32-bit, so lengths not fitting into it are as good as unbounded.
+            let len = i32::try_from(len).unwrap_or(i32::MAX);
+            (arg, DirectiveValue::Int(len))
+        })
+        .collect();
+    if !length.is_empty() {
+        directives.push(("length", length));
+    }
+    directives
+}
+
 #[cfg(feature = "graphql-parser-integration")]
 pub mod graphql_parser;
+pub mod sdl;
@@ -0,0 +1,306 @@
+use std::fmt::{self, Write as _};
+
+use crate::{
+    ast::InputValue,
+    schema::{
+        meta::{Argument, DeprecationStatus, Field, MetaType, ScalarMeta},
+        model::{DirectiveLocation, DirectiveType, SchemaType},
+        translate::{scalar_directives, DirectiveValue, SchemaTranslator},
+    },
+    value::ScalarValue,
+};
+
+/// Translator of a [`SchemaType`] into its
+/// [SDL](https://spec.graphql.org/June2018/#sec-Type-System) representation.
+///
+/// Directives applied to types and the types themselves are printed sorted by their names, with the
+/// built-in ones omitted, so the output is stable between runs.
+pub struct SdlTranslator;
+
+impl<'a> SchemaTranslator<'a, String> for SdlTranslator {
+    fn translate_schema<S: 'a + ScalarValue>(input: &'a SchemaType<S>) -> String {
+        let mut out = String::new();
+        Self::write_schema(&mut out, input).expect("writing to a `String` never fails");
+        out
+    }
+}
+
+impl SdlTranslator {
+    fn write_schema<S: ScalarValue>(out: &mut String, input: &SchemaType<S>) -> fmt::Result {
+        writeln!(out, "schema {{")?;
+        writeln!(out, "  query: {}", input.query_type_name)?;
+        if let Some(name) = &input.mutation_type_name {
+            writeln!(out, "  mutation: {}", name)?;
+        }
+        if let Some(name) = &input.subscription_type_name {
+            writeln!(out, "  subscription: {}", name)?;
+        }
+        writeln!(out, "}}")?;
+
+        let mut directives: Vec<_> = input
+            .directive_list()
+            .into_iter()
+            .filter(|directive| !directive.is_builtin())
+            .collect();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for directive in directives {
+            writeln!(out)?;
+            Self::write_directive(out, directive)?;
+        }
+
+        let mut types: Vec<_> = input
+            .types
+            .values()
+            .filter(|meta| !meta.is_builtin())
+            .filter_map(|meta| meta.name().map(|name| (name, meta)))
+            .collect();
+        types.sort_by_key(|(name, _)| *name);
+
+        for (_, meta) in types {
+            writeln!(out)?;
+            Self::write_meta(out, meta)?;
+        }
+        Ok(())
+    }
+
+    fn write_meta<S: ScalarValue>(out: &mut String, input: &MetaType<S>) -> fmt::Result {
+        match input {
+            MetaType::Scalar(x) => {
+                write_description(out, x.description.as_deref(), "")?;
+                write!(out, "scalar {}", x.name)?;
+                Self::write_scalar_directives(out, x)?;
+                writeln!(out)
+            }
+            MetaType::Enum(x) => {
+                write_description(out, x.description.as_deref(), "")?;
+                writeln!(out, "enum {} {{", x.name)?;
+                for value in &x.values {
+                    write_description(out, value.description.as_deref(), "  ")?;
+                    write!(out, "  {}", value.name)?;
+                    write_deprecation(out, &value.deprecation_status)?;
+                    writeln!(out)?;
+                }
+                writeln!(out, "}}")
+            }
+            MetaType::Union(x) => {
+                write_description(out, x.description.as_deref(), "")?;
+                writeln!(out, "union {} = {}", x.name, x.of_type_names.join(" | "))
+            }
+            MetaType::Interface(x) => {
+                write_description(out, x.description.as_deref(), "")?;
+                write!(out, "interface {}", x.name)?;
+                if !x.interface_names.is_empty() {
+                    write!(out, " implements {}", x.interface_names.join(" & "))?;
+                }
+                writeln!(out, " {{")?;
+                Self::write_fields(out, &x.fields)?;
+                writeln!(out, "}}")
+            }
+            MetaType::InputObject(x) => {
+                write_description(out, x.description.as_deref(), "")?;
+                write!(out, "input {}", x.name)?;
+                if x.is_one_of {
+                    write!(out, " @oneOf")?;
+                }
+                writeln!(out, " {{")?;
+                for field in x.input_fields.iter().filter(|f| !f.is_builtin()) {
+                    write_description(out, field.description.as_deref(), "  ")?;
+                    out.push_str("  ");
+                    Self::write_argument(out, field)?;
+                    writeln!(out)?;
+                }
+                writeln!(out, "}}")
+            }
+            MetaType::Object(x) => {
+                write_description(out, x.description.as_deref(), "")?;
+                write!(out, "type {}", x.name)?;
+                if !x.interface_names.is_empty() {
+                    write!(out, " implements {}", x.interface_names.join(" & "))?;
+                }
+                writeln!(out, " {{")?;
+                Self::write_fields(out, &x.fields)?;
+                writeln!(out, "}}")
+            }
+            _ => panic!("unknown meta type when translating"),
+        }
+    }
+
+    fn write_scalar_directives<S>(out: &mut String, input: &ScalarMeta<S>) -> fmt::Result {
+        for (name, arguments) in scalar_directives(input) {
+            write!(out, " @{}(", name)?;
+            for (i, (arg, value)) in arguments.into_iter().enumerate() {
+                if i > 0 {
+                    write!(out, ", ")?;
+                }
+                write!(out, "{}: ", arg)?;
+                match value {
+                    DirectiveValue::String(s) => write_string(out, s)?,
+                    DirectiveValue::Int(i) => write!(out, "{}", i)?,
+                }
+            }
+            write!(out, ")")?;
+        }
+        Ok(())
+    }
+
+    fn write_directive<S: ScalarValue>(out: &mut String, input: &DirectiveType<S>) -> fmt::Result {
+        write_description(out, input.description.as_deref(), "")?;
+        write!(out, "directive @{}", input.name)?;
+        if !input.arguments.is_empty() {
+            write!(out, "(")?;
+            for (i, arg) in input.arguments.iter().enumerate() {
+                if i > 0 {
+                    write!(out, ", ")?;
+                }
+                Self::write_argument(out, arg)?;
+            }
+            write!(out, ")")?;
+        }
+        let locations: Vec<_> = input.locations.iter().map(location_name).collect();
+        writeln!(out, " on {}", locations.join(" | "))
+    }
+
+    fn write_fields<S: ScalarValue>(out: &mut String, fields: &[Field<S>]) -> fmt::Result {
+        for field in fields.iter().filter(|f| !f.is_builtin()) {
+            write_description(out, field.description.as_deref(), "  ")?;
+            write!(out, "  {}", field.name)?;
+
+            let args: Vec<_> = field
+                .arguments
+                .iter()
+                .flatten()
+                .filter(|a| !a.is_builtin())
+                .collect();
+            if args.iter().any(|a| a.description.is_some()) {
+                writeln!(out, "(")?;
+                for arg in args {
+                    write_description(out, arg.description.as_deref(), "    ")?;
+                    out.push_str("    ");
+                    Self::write_argument(out, arg)?;
+                    writeln!(out)?;
+                }
+                write!(out, "  )")?;
+            } else if !args.is_empty() {
+                write!(out, "(")?;
+                for (i, arg) in args.into_iter().enumerate() {
+                    if i > 0 {
+                        write!(out, ", ")?;
+                    }
+                    Self::write_argument(out, arg)?;
+                }
+                write!(out, ")")?;
+            }
+
+            write!(out, ": {}", field.field_type)?;
+            write_deprecation(out, &field.deprecation_status)?;
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    fn write_argument<S: ScalarValue>(out: &mut String, input: &Argument<S>) -> fmt::Result {
+        write!(out, "{}: {}", input.name, input.arg_type)?;
+        if let Some(default) = &input.default_value {
+            write!(out, " = ")?;
+            write_value(out, default)?;
+        }
+        write_deprecation(out, &input.deprecation_status)
+    }
+}
+
+fn location_name(location: &DirectiveLocation) -> &'static str {
+    match location {
+        DirectiveLocation::Query => "QUERY",
+        DirectiveLocation::Mutation => "MUTATION",
+        DirectiveLocation::Subscription => "SUBSCRIPTION",
+        DirectiveLocation::Field => "FIELD",
+        DirectiveLocation::FragmentDefinition => "FRAGMENT_DEFINITION",
+        DirectiveLocation::FragmentSpread => "FRAGMENT_SPREAD",
+        DirectiveLocation::InlineFragment => "INLINE_FRAGMENT",
+        DirectiveLocation::Scalar => "SCALAR",
+        DirectiveLocation::InputObject => "INPUT_OBJECT",
+    }
+}
+
+fn write_description(out: &mut String, description: Option<&str>, indent: &str) -> fmt::Result {
+    let description = match description {
+        Some(d) => d.replace(r#"""""#, r#"\""""#),
+        None => return Ok(()),
+    };
+    if description.contains('\n') {
+        writeln!(out, r#"{}""""#, indent)?;
+        for line in description.lines() {
+            if line.is_empty() {
+                writeln!(out)?;
+            } else {
+                writeln!(out, "{}{}", indent, line)?;
+            }
+        }
+        writeln!(out, r#"{}""""#, indent)
+    } else {
+        writeln!(out, r#"{}"""{}""""#, indent, description)
+    }
+}
+
+fn write_deprecation(out: &mut String, status: &DeprecationStatus) -> fmt::Result {
+    match status {
+        DeprecationStatus::Current => Ok(()),
+        DeprecationStatus::Deprecated(None) => write!(out, " @deprecated"),
+        DeprecationStatus::Deprecated(Some(reason)) => {
+            write!(out, " @deprecated(reason: ")?;
+            write_string(out, reason)?;
+            write!(out, ")")
+        }
+    }
+}
+
+fn write_value<S: ScalarValue>(out: &mut String, input: &InputValue<S>) -> fmt::Result {
+    match input {
+        InputValue::Scalar(s) => match s.as_str() {
+            Some(s) => write_string(out, s),
+            None => write!(out, "{}", s),
+        },
+        InputValue::List(items) => {
+            write!(out, "[")?;
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    write!(out, ", ")?;
+                }
+                write_value(out, &item.item)?;
+            }
+            write!(out, "]")
+        }
+        InputValue::Object(fields) => {
+            write!(out, "{{")?;
+            for (i, (name, value)) in fields.iter().enumerate() {
+                if i > 0 {
+                    write!(out, ", ")?;
+                }
+                write!(out, "{}: ", name.item)?;
+                write_value(out, &value.item)?;
+            }
+            write!(out, "}}")
+        }
+        InputValue::Null | InputValue::Enum(_) | InputValue::Variable(_) => {
+            write!(out, "{}", input)
+        }
+    }
+}
+
+fn write_string(out: &mut String, s: &str) -> fmt::Result {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str(r#"\""#),
+            '\\' => out.push_str(r"\\"),
+            '\n' => out.push_str(r"\n"),
+            '\r' => out.push_str(r"\r"),
+            '\t' => out.push_str(r"\t"),
+            c if c.is_control() => write!(out, "\\u{:04X}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Ok(())
+}
@@ -157,6 +157,16 @@ pub(crate) fn schema_introspection_result() -> Value {
               "enumValues": None,
               "possibleTypes": None
             },
+            {
+              "kind": "SCALAR",
+              "name": "Int",
+              "description": None,
+              "fields": None,
+              "inputFields": None,
+              "interfaces": None,
+              "enumValues": None,
+              "possibleTypes": None
+            },
             {
               "kind": "OBJECT",
               "name": "__InputValue",
@@ -217,6 +227,34 @@ pub(crate) fn schema_introspection_result() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "isDeprecated",
+                  "description": None,
+                  "args": [],
+                  "type": {
+                    "kind": "NON_NULL",
+                    "name": None,
+                    "ofType": {
+                      "kind": "SCALAR",
+                      "name": "Boolean",
+                      "ofType": None
+                    }
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "deprecationReason",
+                  "description": None,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "inputFields": None,
@@ -270,7 +308,18 @@ pub(crate) fn schema_introspection_result() -> Value {
                 {
                   "name": "args",
                   "description": None,
-                  "args": [],
+                  "args": [
+                    {
+                      "name": "includeDeprecated",
+                      "description": None,
+                      "type": {
+                        "kind": "SCALAR",
+                        "name": "Boolean",
+                        "ofType": None
+                      },
+                      "defaultValue": "false"
+                    }
+                  ],
                   "type": {
                     "kind": "NON_NULL",
                     "name": None,
@@ -334,6 +383,45 @@ pub(crate) fn schema_introspection_result() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "removalDate",
+                  "description": None,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "extension",
+                  "description": None,
+                  "args": [
+                    {
+                      "name": "name",
+                      "description": None,
+                      "type": {
+                        "kind": "NON_NULL",
+                        "name": None,
+                        "ofType": {
+                          "kind": "SCALAR",
+                          "name": "String",
+                          "ofType": None
+                        }
+                      },
+                      "defaultValue": None
+                    }
+                  ],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "inputFields": None,
@@ -491,7 +579,18 @@ pub(crate) fn schema_introspection_result() -> Value {
                 {
                   "name": "inputFields",
                   "description": None,
-                  "args": [],
+                  "args": [
+                    {
+                      "name": "includeDeprecated",
+                      "description": None,
+                      "type": {
+                        "kind": "SCALAR",
+                        "name": "Boolean",
+                        "ofType": None
+                      },
+                      "defaultValue": "false"
+                    }
+                  ],
                   "type": {
                     "kind": "LIST",
                     "name": None,
@@ -578,6 +677,69 @@ pub(crate) fn schema_introspection_result() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "minLength",
+                  "description": None,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Int",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "maxLength",
+                  "description": None,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Int",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "isOneOf",
+                  "description": None,
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Boolean",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "extension",
+                  "description": None,
+                  "args": [
+                    {
+                      "name": "name",
+                      "description": None,
+                      "type": {
+                        "kind": "NON_NULL",
+                        "name": None,
+                        "ofType": {
+                          "kind": "SCALAR",
+                          "name": "String",
+                          "ofType": None
+                        }
+                      },
+                      "defaultValue": None
+                    }
+                  ],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "inputFields": None,
@@ -1023,6 +1185,18 @@ pub(crate) fn schema_introspection_result() -> Value {
                   "description": None,
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "SCALAR",
+                  "description": None,
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "INPUT_OBJECT",
+                  "description": None,
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "possibleTypes": None
@@ -1110,7 +1284,7 @@ pub(crate) fn schema_introspection_result() -> Value {
                 }
               ],
               "inputFields": None,
-              "interfaces": None,
+              "interfaces": [],
               "enumValues": None,
               "possibleTypes": [
                 {
@@ -1436,6 +1610,15 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
               "enumValues": None,
               "possibleTypes": None
             },
+            {
+              "kind": "SCALAR",
+              "name": "Int",
+              "fields": None,
+              "inputFields": None,
+              "interfaces": None,
+              "enumValues": None,
+              "possibleTypes": None
+            },
             {
               "kind": "OBJECT",
               "name": "__InputValue",
@@ -1491,6 +1674,32 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "isDeprecated",
+                  "args": [],
+                  "type": {
+                    "kind": "NON_NULL",
+                    "name": None,
+                    "ofType": {
+                      "kind": "SCALAR",
+                      "name": "Boolean",
+                      "ofType": None
+                    }
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "deprecationReason",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "inputFields": None,
@@ -1539,7 +1748,17 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                 },
                 {
                   "name": "args",
-                  "args": [],
+                  "args": [
+                    {
+                      "name": "includeDeprecated",
+                      "type": {
+                        "kind": "SCALAR",
+                        "name": "Boolean",
+                        "ofType": None
+                      },
+                      "defaultValue": "false"
+                    }
+                  ],
                   "type": {
                     "kind": "NON_NULL",
                     "name": None,
@@ -1600,6 +1819,42 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "removalDate",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "extension",
+                  "args": [
+                    {
+                      "name": "name",
+                      "type": {
+                        "kind": "NON_NULL",
+                        "name": None,
+                        "ofType": {
+                          "kind": "SCALAR",
+                          "name": "String",
+                          "ofType": None
+                        }
+                      },
+                      "defaultValue": None
+                    }
+                  ],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "inputFields": None,
@@ -1740,7 +1995,17 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                 },
                 {
                   "name": "inputFields",
-                  "args": [],
+                  "args": [
+                    {
+                      "name": "includeDeprecated",
+                      "type": {
+                        "kind": "SCALAR",
+                        "name": "Boolean",
+                        "ofType": None
+                      },
+                      "defaultValue": "false"
+                    }
+                  ],
                   "type": {
                     "kind": "LIST",
                     "name": None,
@@ -1823,6 +2088,64 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   },
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "minLength",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Int",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "maxLength",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Int",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "isOneOf",
+                  "args": [],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "Boolean",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "extension",
+                  "args": [
+                    {
+                      "name": "name",
+                      "type": {
+                        "kind": "NON_NULL",
+                        "name": None,
+                        "ofType": {
+                          "kind": "SCALAR",
+                          "name": "String",
+                          "ofType": None
+                        }
+                      },
+                      "defaultValue": None
+                    }
+                  ],
+                  "type": {
+                    "kind": "SCALAR",
+                    "name": "String",
+                    "ofType": None
+                  },
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "inputFields": None,
@@ -2231,6 +2554,16 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                   "name": "INLINE_FRAGMENT",
                   "isDeprecated": false,
                   "deprecationReason": None
+                },
+                {
+                  "name": "SCALAR",
+                  "isDeprecated": false,
+                  "deprecationReason": None
+                },
+                {
+                  "name": "INPUT_OBJECT",
+                  "isDeprecated": false,
+                  "deprecationReason": None
                 }
               ],
               "possibleTypes": None
@@ -2313,7 +2646,7 @@ pub(crate) fn schema_introspection_result_without_descriptions() -> Value {
                 }
               ],
               "inputFields": None,
-              "interfaces": None,
+              "interfaces": [],
               "enumValues": None,
               "possibleTypes": [
                 {
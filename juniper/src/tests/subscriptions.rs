@@ -3,9 +3,9 @@ use std::{iter, iter::FromIterator as _, pin::Pin};
 use futures::{stream, StreamExt as _};
 
 use crate::{
-    graphql_object, graphql_subscription, graphql_value, http::GraphQLRequest, Context,
-    DefaultScalarValue, EmptyMutation, ExecutionError, FieldError, GraphQLObject, Object, RootNode,
-    Value,
+    graphql_object, graphql_subscription, graphql_value, http::GraphQLRequest,
+    snapshot_then_updates, Context, DefaultScalarValue, EmptyMutation, ExecutionError, FieldError,
+    GraphQLObject, Object, RootNode, Value,
 };
 
 #[derive(Debug, Clone)]
@@ -41,6 +41,8 @@ fn run<O>(f: impl std::future::Future<Output = O>) -> O {
 
 type HumanStream = Pin<Box<dyn futures::Stream<Item = Human> + Send>>;
 
+type CounterStream = Pin<Box<dyn futures::Stream<Item = i32> + Send>>;
+
 struct MySubscription;
 
 #[graphql_subscription(context = MyContext)]
@@ -82,6 +84,11 @@ impl MySubscription {
             }
         }))
     }
+
+    async fn counter() -> CounterStream {
+        let updates = stream::iter(1..=2).map(|i| i * 10);
+        Box::pin(snapshot_then_updates(0, updates))
+    }
 }
 
 /// Create all variables, execute subscription
@@ -337,3 +344,91 @@ fn type_alias() {
     assert_eq!(names, vec!["aliasedHuman"]);
     assert_eq!(collected_values, expected_values);
 }
+
+#[test]
+fn yields_snapshot_before_updates() {
+    let query = r#"subscription { counter }"#.to_string();
+
+    let (names, collected_values) = create_and_execute(query).expect("Got error from stream");
+
+    assert_eq!(names, vec!["counter"]);
+    assert_eq!(
+        collected_values,
+        vec![vec![
+            Ok(graphql_value!(0)),
+            Ok(graphql_value!(10)),
+            Ok(graphql_value!(20)),
+        ]],
+    );
+}
+
+#[cfg(feature = "chrono")]
+mod snapshot_then_updates_of_dates {
+    use std::pin::Pin;
+
+    use chrono::{DateTime, Duration, TimeZone as _, Utc};
+    use futures::{stream, Stream, StreamExt as _};
+
+    use crate::{
+        graphql_object, graphql_subscription, snapshot_then_updates, EmptyMutation, RootNode,
+        Value, Variables,
+    };
+
+    type DateTimeStream = Pin<Box<dyn Stream<Item = DateTime<Utc>> + Send>>;
+
+    fn snapshot() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap()
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn now() -> DateTime<Utc> {
+            snapshot()
+        }
+    }
+
+    struct Subscription;
+
+    #[graphql_subscription]
+    impl Subscription {
+        async fn now() -> DateTimeStream {
+            let updates = stream::iter(1..=2).map(|i| snapshot() + Duration::minutes(i));
+            Box::pin(snapshot_then_updates(snapshot(), updates))
+        }
+    }
+
+    #[tokio::test]
+    async fn yields_snapshot_date_before_updates() {
+        let schema = RootNode::new(Query, EmptyMutation::new(), Subscription);
+
+        let (value, errs) = crate::resolve_into_stream(
+            "subscription { now }",
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(errs, vec![]);
+
+        let stream = match value {
+            Value::Object(obj) => obj.into_iter().find(|(name, _)| name == "now"),
+            _ => None,
+        };
+        let values = match stream {
+            Some((_, Value::Scalar(stream))) => stream.collect::<Vec<_>>().await,
+            _ => panic!("`now` is not resolved into a stream"),
+        };
+        assert_eq!(
+            values,
+            vec![
+                Ok(graphql_value!("2021-07-08T09:10:11+00:00")),
+                Ok(graphql_value!("2021-07-08T09:11:11+00:00")),
+                Ok(graphql_value!("2021-07-08T09:12:11+00:00")),
+            ],
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, TimeZone as _, Utc};
 use indexmap::IndexMap;
 
 use crate::{
@@ -5,10 +6,12 @@ use crate::{
     graphql_value,
     schema::{meta::MetaType, model::RootNode},
     types::{
+        async_await::GraphQLValueAsync,
         base::{Arguments, GraphQLType, GraphQLValue},
         scalars::{EmptyMutation, EmptySubscription},
     },
     value::ScalarValue,
+    BoxFuture,
 };
 
 pub struct NodeTypeInfo {
@@ -105,3 +108,113 @@ fn test_node() {
         )),
     );
 }
+
+type AsyncDateResolver = Box<dyn Fn() -> BoxFuture<'static, DateTime<Utc>> + Send + Sync + 'static>;
+
+pub struct AsyncNodeTypeInfo {
+    name: String,
+    resolvers: IndexMap<String, AsyncDateResolver>,
+}
+
+pub struct AsyncNode;
+
+impl<S> GraphQLType<S> for AsyncNode
+where
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.name)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = info
+            .resolvers
+            .keys()
+            .map(|name| registry.field::<DateTime<Utc>>(name, &()))
+            .collect::<Vec<_>>();
+
+        registry
+            .build_object_type::<AsyncNode>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<S> GraphQLValue<S> for AsyncNode
+where
+    S: ScalarValue,
+{
+    type Context = ();
+    type TypeInfo = AsyncNodeTypeInfo;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+}
+
+impl<S> GraphQLValueAsync<S> for AsyncNode
+where
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        _: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        let fut = (info.resolvers[field_name])();
+        Box::pin(async move {
+            let date = fut.await;
+            executor.resolve_async(&(), &date).await
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_async_node() {
+    let doc = r#"
+        {
+            createdAt,
+            updatedAt
+        }"#;
+    let mut resolvers = IndexMap::<String, AsyncDateResolver>::new();
+    resolvers.insert(
+        "createdAt".to_string(),
+        Box::new(|| {
+            Box::pin(async {
+                tokio::task::yield_now().await;
+                Utc.with_ymd_and_hms(2021, 1, 2, 3, 4, 5).unwrap()
+            })
+        }),
+    );
+    resolvers.insert(
+        "updatedAt".to_string(),
+        Box::new(|| Box::pin(async { Utc.timestamp_opt(61, 0).unwrap() })),
+    );
+    let node_info = AsyncNodeTypeInfo {
+        name: "MyAsyncNode".to_string(),
+        resolvers,
+    };
+    let schema: RootNode<_, _, _> = RootNode::new_with_info(
+        AsyncNode,
+        EmptyMutation::new(),
+        EmptySubscription::new(),
+        node_info,
+        (),
+        (),
+    );
+
+    assert_eq!(
+        crate::execute(doc, None, &schema, &Variables::new(), &()).await,
+        Ok((
+            graphql_value!({
+                "createdAt": "2021-01-02T03:04:05+00:00",
+                "updatedAt": "1970-01-01T00:01:01+00:00",
+            }),
+            vec![],
+        )),
+    );
+}
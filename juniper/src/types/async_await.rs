@@ -258,13 +258,18 @@ where
 
                 let pos = *start_pos;
                 let is_non_null = meta_field.field_type.is_non_null();
+                let type_name = meta_type.name().unwrap_or_default();
 
                 let response_name = response_name.to_string();
                 async_values.push(AsyncValueFuture::Field(async move {
                     // TODO: implement custom future type instead of
                     //       two-level boxing.
-                    let res = instance
-                        .resolve_field_async(info, f.name.item, &args, &sub_exec)
+                    let res = sub_exec
+                        .resolve_before_deadline_async(sub_exec.resolve_instrumented_async(
+                            type_name,
+                            f.name.item,
+                            instance.resolve_field_async(info, f.name.item, &args, &sub_exec),
+                        ))
                         .await;
 
                     let value = match res {
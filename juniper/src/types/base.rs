@@ -3,6 +3,7 @@ use indexmap::IndexMap;
 use crate::{
     ast::{Directive, FromInputValue, InputValue, Selection},
     executor::{ExecutionResult, Executor, Registry, Variables},
+    macros::helper::input_flattening::FlattenInputObject,
     parser::Spanning,
     schema::meta::{Argument, MetaType},
     value::{DefaultScalarValue, Object, ScalarValue, Value},
@@ -115,6 +116,62 @@ where
             .and_then(|args| args.get(key))
             .and_then(InputValue::convert)
     }
+
+    /// Get and convert an argument into the desired type, computing it with
+    /// the given `default` function if the argument is absent or `null`.
+    ///
+    /// This is used for arguments declared with
+    /// `#[graphql(default_with = ...)]`, whose default value isn't static.
+    ///
+    /// Returns `None` if the argument is present but type conversion fails.
+    pub fn get_or_else<T, F>(&self, key: &str, default: F) -> Option<T>
+    where
+        T: FromInputValue<S>,
+        F: FnOnce() -> T,
+    {
+        match self.args.as_ref().and_then(|args| args.get(key)) {
+            Some(v) if !v.is_null() => v.convert(),
+            _ => Some(default()),
+        }
+    }
+
+    /// Convert all the arguments, as fields of a single input object, into
+    /// the desired type.
+    ///
+    /// This is used for arguments declared with `#[graphql(flatten)]`, whose
+    /// type derives `GraphQLArguments` or `GraphQLInputObject`, with its fields
+    /// being separate arguments.
+    ///
+    /// Returns `Some` if the type conversion succeeds.
+    pub fn flatten<T>(&self) -> Option<T>
+    where
+        T: FlattenInputObject<S>,
+    {
+        let args = self
+            .args
+            .iter()
+            .flatten()
+            .map(|(name, value)| (*name, value))
+            .collect();
+        T::from_input_fields(&args)
+    }
+
+    /// Render all the arguments as `name: value` pairs ordered by name, so
+    /// equal arguments are always rendered the same way.
+    ///
+    /// This is used as the [`CacheKey::arguments`] of cached fields.
+    ///
+    /// [`CacheKey::arguments`]: crate::CacheKey::arguments
+    pub(crate) fn render(&self) -> String {
+        let mut args = self
+            .args
+            .iter()
+            .flatten()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect::<Vec<_>>();
+        args.sort_unstable();
+        args.join(", ")
+    }
 }
 
 /// Primary trait used to resolve GraphQL values.
@@ -467,23 +524,24 @@ where
                     f.selection_set.as_ref().map(|v| &v[..]),
                 );
 
-                let field_result = instance.resolve_field(
-                    info,
-                    f.name.item,
-                    &Arguments::new(
-                        f.arguments.as_ref().map(|m| {
-                            m.item
-                                .iter()
-                                .map(|&(ref k, ref v)| {
-                                    (k.item, v.item.clone().into_const(exec_vars))
-                                })
-                                .collect()
-                        }),
-                        &meta_field.arguments,
-                    ),
-                    &sub_exec,
+                let args = Arguments::new(
+                    f.arguments.as_ref().map(|m| {
+                        m.item
+                            .iter()
+                            .map(|&(ref k, ref v)| (k.item, v.item.clone().into_const(exec_vars)))
+                            .collect()
+                    }),
+                    &meta_field.arguments,
                 );
 
+                let field_result = sub_exec.resolve_before_deadline(|| {
+                    sub_exec.resolve_instrumented(
+                        meta_type.name().unwrap_or_default(),
+                        f.name.item,
+                        || instance.resolve_field(info, f.name.item, &args, &sub_exec),
+                    )
+                });
+
                 match field_result {
                     Ok(Value::Null) if meta_field.field_type.is_non_null() => return false,
                     Ok(v) => merge_key_into(result, response_name, v),
@@ -446,8 +446,8 @@ where
         .is_non_null();
     let mut result = Vec::with_capacity(iter.len());
 
-    for o in iter {
-        let val = executor.resolve(info, o)?;
+    for (i, o) in iter.enumerate() {
+        let val = executor.index_sub_executor(i).resolve(info, o)?;
         if stop_on_null && val.is_null() {
             return Ok(val);
         } else {
@@ -458,7 +458,7 @@ where
     Ok(Value::list(result))
 }
 
-async fn resolve_into_list_async<'a, 't, S, T, I>(
+pub(crate) async fn resolve_into_list_async<'a, 't, S, T, I>(
     executor: &'a Executor<'a, 'a, T::Context, S>,
     info: &'a T::TypeInfo,
     items: I,
@@ -470,7 +470,7 @@ where
     T::Context: Sync,
     S: ScalarValue + Send + Sync,
 {
-    use futures::stream::{FuturesOrdered, StreamExt as _};
+    use futures::stream::{self, StreamExt as _};
 
     let stop_on_null = executor
         .current_type()
@@ -478,11 +478,19 @@ where
         .expect("Current type is not a list type")
         .is_non_null();
 
-    let mut futures = items
-        .map(|it| async move { executor.resolve_into_value_async(info, it).await })
-        .collect::<FuturesOrdered<_>>();
+    let futures = items
+        .enumerate()
+        .map(|(i, it)| async move {
+            executor
+                .index_sub_executor(i)
+                .resolve_into_value_async(info, it)
+                .await
+        })
+        .collect::<Vec<_>>();
+    let limit = executor.max_concurrency().unwrap_or(futures.len()).max(1);
 
     let mut values = Vec::with_capacity(futures.len());
+    let mut futures = stream::iter(futures).buffered(limit);
     while let Some(value) = futures.next().await {
         if stop_on_null && value.is_null() {
             return Ok(value);
@@ -6,5 +6,6 @@ pub mod name;
 pub mod nullable;
 pub mod pointers;
 pub mod scalars;
+pub mod stream_list;
 pub mod subscriptions;
 pub mod utilities;
@@ -314,3 +314,126 @@ where
     S: ScalarValue,
 {
 }
+
+/// Enforces the contract of a nullable field, whose value is guaranteed to be non-null whenever
+/// the `condition` (usually derived from its arguments) holds.
+///
+/// GraphQL can't express nullability depending on arguments, so such a field has to be declared
+/// nullable. This helper documents the contract in the resolver and turns a `None` `value`
+/// produced while the `condition` holds into the error returned by the given `err` function,
+/// instead of silently resolving `null` for a position the client expects to be non-null.
+///
+/// ```rust
+/// # use juniper::{graphql_object, graphql_value, non_null_if, FieldError, FieldResult};
+/// struct User {
+///     email: Option<String>,
+/// }
+///
+/// #[graphql_object]
+/// impl User {
+///     /// Always non-null when `verified` is `true`.
+///     fn email(&self, verified: bool) -> FieldResult<Option<&str>> {
+///         non_null_if(verified, self.email.as_deref(), || {
+///             FieldError::new(
+///                 "Verified user has no email",
+///                 graphql_value!({"code": "UNEXPECTED_NULL"}),
+///             )
+///         })
+///     }
+/// }
+/// ```
+pub fn non_null_if<T, E>(
+    condition: bool,
+    value: Option<T>,
+    err: impl FnOnce() -> E,
+) -> Result<Option<T>, E> {
+    if condition && value.is_none() {
+        return Err(err());
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::non_null_if;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn enforces_argument_dependent_non_null_date() {
+        use chrono::{DateTime, TimeZone as _, Utc};
+
+        use crate::{
+            executor::ExecutionError, graphql_object, parser::SourcePosition,
+            schema::model::RootNode, EmptyMutation, EmptySubscription, FieldError, FieldResult,
+            Variables,
+        };
+
+        struct Release {
+            released_at: Option<DateTime<Utc>>,
+        }
+
+        #[graphql_object]
+        impl Release {
+            /// Always non-null when `released` is `true`.
+            fn released_at(&self, released: bool) -> FieldResult<Option<DateTime<Utc>>> {
+                non_null_if(released, self.released_at, || {
+                    FieldError::new(
+                        "Released release has no release date",
+                        graphql_value!({"code": "UNEXPECTED_NULL"}),
+                    )
+                })
+            }
+        }
+
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            fn release(id: i32) -> Release {
+                Release {
+                    released_at: Some(Utc.with_ymd_and_hms(2021, 7, 8, 9, 10, 11).unwrap())
+                        .filter(|_| id == 1),
+                }
+            }
+        }
+
+        let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+        let execute = |doc| crate::execute_sync(doc, None, &schema, &Variables::new(), &());
+
+        assert_eq!(
+            execute("{ release(id: 1) { releasedAt(released: true) } }"),
+            Ok((
+                graphql_value!({"release": {"releasedAt": "2021-07-08T09:10:11+00:00"}}),
+                vec![],
+            )),
+        );
+        assert_eq!(
+            execute("{ release(id: 2) { releasedAt(released: false) } }"),
+            Ok((graphql_value!({"release": {"releasedAt": None}}), vec![])),
+        );
+        assert_eq!(
+            execute("{ release(id: 2) { releasedAt(released: true) } }"),
+            Ok((
+                graphql_value!({"release": {"releasedAt": None}}),
+                vec![ExecutionError::new(
+                    SourcePosition::new(19, 0, 19),
+                    &["release", "releasedAt"],
+                    FieldError::new(
+                        "Released release has no release date",
+                        graphql_value!({"code": "UNEXPECTED_NULL"}),
+                    ),
+                )],
+            )),
+        );
+    }
+
+    #[test]
+    fn returns_value_unless_null_while_condition_holds() {
+        let err = || "unexpected null";
+
+        assert_eq!(non_null_if(true, Some(1), err), Ok(Some(1)));
+        assert_eq!(non_null_if(false, Some(1), err), Ok(Some(1)));
+        assert_eq!(non_null_if(false, None::<i32>, err), Ok(None));
+        assert_eq!(non_null_if(true, None::<i32>, err), Err("unexpected null"));
+    }
+}
@@ -50,7 +50,7 @@ impl fmt::Display for ID {
     }
 }
 
-#[crate::graphql_scalar(name = "ID")]
+#[crate::graphql_scalar(name = "ID", internal)]
 impl<S> GraphQLScalar for ID
 where
     S: ScalarValue,
@@ -77,7 +77,7 @@ where
     }
 }
 
-#[crate::graphql_scalar(name = "String")]
+#[crate::graphql_scalar(name = "String", internal)]
 impl<S> GraphQLScalar for String
 where
     S: ScalarValue,
@@ -217,7 +217,10 @@ where
     where
         S: 'r,
     {
-        registry.build_scalar_type::<String>(&()).into_meta()
+        registry
+            .build_scalar_type::<String>(&())
+            .builtin()
+            .into_meta()
     }
 }
 
@@ -266,7 +269,7 @@ where
     }
 }
 
-#[crate::graphql_scalar(name = "Boolean")]
+#[crate::graphql_scalar(name = "Boolean", internal)]
 impl<S> GraphQLScalar for bool
 where
     S: ScalarValue,
@@ -288,7 +291,7 @@ where
     }
 }
 
-#[crate::graphql_scalar(name = "Int")]
+#[crate::graphql_scalar(name = "Int", internal)]
 impl<S> GraphQLScalar for i32
 where
     S: ScalarValue,
@@ -306,8 +309,10 @@ where
 
     fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
         if let ScalarToken::Int(v) = value {
+            // The lexer only produces valid integer literals, so failing to
+            // parse one means it doesn't fit.
             v.parse()
-                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map_err(|_| ParseError::IntOverflow(v))
                 .map(|s: i32| s.into())
         } else {
             Err(ParseError::UnexpectedToken(Token::Scalar(value)))
@@ -315,7 +320,7 @@ where
     }
 }
 
-#[crate::graphql_scalar(name = "Float")]
+#[crate::graphql_scalar(name = "Float", internal)]
 impl<S> GraphQLScalar for f64
 where
     S: ScalarValue,
@@ -467,6 +472,87 @@ impl<T> Default for EmptySubscription<T> {
     }
 }
 
+/// Generates a GraphQL scalar wrapping a numeric type, which only accepts values within the
+/// given inclusive range on input.
+///
+/// The generated scalar is named after the struct, and is represented in responses the same
+/// way as the underlying type. Out-of-range literals are rejected during validation, while
+/// out-of-range variables are rejected before execution.
+///
+/// The underlying type must be a GraphQL scalar itself, so it's usually [`i32`] or [`f64`].
+///
+/// ```rust
+/// # use juniper::{bounded_scalar, graphql_object, EmptyMutation, EmptySubscription, RootNode};
+/// bounded_scalar!(
+///     /// Color temperature in kelvins.
+///     Kelvin, i32, 1000, 40000
+/// );
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn warmer(temperature: Kelvin) -> Option<Kelvin> {
+///         Kelvin::new(temperature.get() - 500)
+///     }
+/// }
+/// # fn main() {
+/// # let _ = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// assert_eq!(Kelvin::new(6500).map(Kelvin::get), Some(6500));
+/// assert_eq!(Kelvin::new(500), None);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bounded_scalar {
+    ($(#[$attr:meta])* $name:ident, $ty:ty, $min:expr, $max:expr $(,)?) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        pub struct $name($ty);
+
+        impl $name {
+            /// Minimum allowed value (inclusive).
+            pub const MIN: $ty = $min;
+
+            /// Maximum allowed value (inclusive).
+            pub const MAX: $ty = $max;
+
+            /// Wraps the given `value`, returning [`None`] if it's out of the allowed range.
+            pub fn new(value: $ty) -> Option<Self> {
+                if (Self::MIN..=Self::MAX).contains(&value) {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the wrapped value.
+            pub fn get(self) -> $ty {
+                self.0
+            }
+        }
+
+        #[$crate::graphql_scalar]
+        impl<S> GraphQLScalar for $name
+        where
+            S: $crate::ScalarValue,
+        {
+            fn resolve(&self) -> $crate::Value {
+                $crate::Value::scalar(self.0)
+            }
+
+            fn from_input_value(v: &$crate::InputValue) -> Option<$name> {
+                <$ty as $crate::FromInputValue<S>>::from_input_value(v).and_then($name::new)
+            }
+
+            fn from_str<'a>(
+                value: $crate::parser::ScalarToken<'a>,
+            ) -> $crate::ParseScalarResult<'a, S> {
+                <$ty as $crate::ParseScalarValue<S>>::from_str(value)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -579,3 +665,118 @@ mod tests {
         let _ = EmptyMutation::<Bar>::default();
     }
 }
+
+#[cfg(test)]
+mod bounded_scalar_test {
+    use crate::{
+        executor::Variables, graphql_object, schema::model::RootNode, FromInputValue, GraphQLError,
+        InputValue,
+    };
+
+    use super::{EmptyMutation, EmptySubscription};
+
+    crate::bounded_scalar!(Kelvin, i32, 1000, 40000);
+    crate::bounded_scalar!(
+        /// Fraction of a whole.
+        Ratio,
+        f64,
+        0.0,
+        1.0,
+    );
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn temperature() -> Kelvin {
+            Kelvin::new(6500).unwrap()
+        }
+
+        fn warmer(temperature: Kelvin) -> Option<Kelvin> {
+            Kelvin::new(temperature.get() - 500)
+        }
+
+        fn half(ratio: Ratio) -> Ratio {
+            Ratio::new(ratio.get() / 2.0).unwrap()
+        }
+    }
+
+    fn schema() -> RootNode<'static, Root, EmptyMutation, EmptySubscription> {
+        RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn enforces_range() {
+        assert_eq!(Kelvin::new(1000).map(Kelvin::get), Some(1000));
+        assert_eq!(Kelvin::new(40000).map(Kelvin::get), Some(40000));
+        assert_eq!(Kelvin::new(999), None);
+        assert_eq!(Kelvin::new(40001), None);
+
+        assert_eq!(Ratio::new(0.5).map(Ratio::get), Some(0.5));
+        assert_eq!(Ratio::new(-0.1), None);
+        assert_eq!(Ratio::new(1.5), None);
+
+        assert_eq!(
+            Kelvin::from_input_value(&InputValue::<crate::DefaultScalarValue>::scalar(2700)),
+            Kelvin::new(2700),
+        );
+        assert_eq!(
+            Kelvin::from_input_value(&InputValue::<crate::DefaultScalarValue>::scalar(100)),
+            None,
+        );
+        assert_eq!(
+            Ratio::from_input_value(&InputValue::<crate::DefaultScalarValue>::scalar(1)),
+            Ratio::new(1.0),
+        );
+    }
+
+    #[tokio::test]
+    async fn serializes_as_underlying_type() {
+        let doc = r#"{
+            temperature
+            warmer(temperature: 1500)
+            half(ratio: 0.5)
+        }"#;
+
+        assert_eq!(
+            crate::execute(doc, None, &schema(), &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({
+                    "temperature": 6500,
+                    "warmer": 1000,
+                    "half": 0.25,
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_out_of_range() {
+        for doc in &[
+            "{ warmer(temperature: 999) }",
+            "{ warmer(temperature: 50000) }",
+            "{ half(ratio: 1.5) }",
+        ] {
+            assert!(matches!(
+                crate::execute(doc, None, &schema(), &Variables::new(), &()).await,
+                Err(GraphQLError::ValidationError(_)),
+            ));
+        }
+
+        let vars = vec![("t".to_owned(), InputValue::scalar(100))]
+            .into_iter()
+            .collect();
+        assert!(matches!(
+            crate::execute(
+                "query($t: Kelvin!) { warmer(temperature: $t) }",
+                None,
+                &schema(),
+                &vars,
+                &(),
+            )
+            .await,
+            Err(GraphQLError::ValidationError(_)),
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+//! List resolved from the items of a [`Stream`].
+
+use std::{fmt, sync::Mutex};
+
+use futures::{stream::BoxStream, Stream, StreamExt as _};
+
+use crate::{
+    ast::Selection,
+    executor::{ExecutionResult, Executor, FieldError, Registry},
+    schema::meta::MetaType,
+    types::{
+        async_await::GraphQLValueAsync,
+        base::{GraphQLType, GraphQLValue},
+        containers::resolve_into_list_async,
+        marker::IsOutputType,
+    },
+    value::ScalarValue,
+};
+
+/// List whose items are produced by a [`Stream`]
+///
+/// This allows query and mutation fields to be resolved by streams the same
+/// way subscription fields are. The stream is collected into a list when the
+/// field is resolved, so it should be finite. The number of collected items
+/// may be bounded via [`RootNode::with_max_stream_items`], failing the field
+/// resolution if the stream yields more items.
+///
+/// Can only be resolved asynchronously.
+///
+/// [`RootNode::with_max_stream_items`]: crate::RootNode::with_max_stream_items
+pub struct StreamList<'a, T> {
+    stream: Mutex<Option<BoxStream<'a, T>>>,
+}
+
+impl<'a, T> StreamList<'a, T> {
+    /// Construct a new list resolved from the items of the given `stream`
+    pub fn new<St>(stream: St) -> Self
+    where
+        St: Stream<Item = T> + Send + 'a,
+    {
+        Self {
+            stream: Mutex::new(Some(stream.boxed())),
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for StreamList<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamList").finish()
+    }
+}
+
+impl<'a, S, T> GraphQLType<S> for StreamList<'a, T>
+where
+    T: GraphQLType<S>,
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<&'static str> {
+        None
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        registry.build_list_type::<T>(info, None).into_meta()
+    }
+}
+
+impl<'a, S, T> GraphQLValue<S> for StreamList<'a, T>
+where
+    T: GraphQLValue<S>,
+    S: ScalarValue,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn type_name(&self, _: &Self::TypeInfo) -> Option<&'static str> {
+        None
+    }
+
+    fn resolve(
+        &self,
+        _: &Self::TypeInfo,
+        _: Option<&[Selection<S>]>,
+        _: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        Err(FieldError::from(
+            "List of a stream can only be resolved asynchronously",
+        ))
+    }
+}
+
+impl<'a, S, T> GraphQLValueAsync<S> for StreamList<'a, T>
+where
+    T: GraphQLValueAsync<S> + Send,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_async<'b>(
+        &'b self,
+        info: &'b Self::TypeInfo,
+        _: Option<&'b [Selection<S>]>,
+        executor: &'b Executor<Self::Context, S>,
+    ) -> crate::BoxFuture<'b, ExecutionResult<S>> {
+        let stream = self.stream.lock().unwrap().take();
+        let max = executor.schema().max_stream_items;
+
+        Box::pin(async move {
+            let stream =
+                stream.ok_or_else(|| FieldError::from("List of a stream is already resolved"))?;
+            let items = match max {
+                Some(max) => {
+                    let items = stream.take(max.saturating_add(1)).collect::<Vec<_>>().await;
+                    if items.len() > max {
+                        return Err(FieldError::from(format!(
+                            "Stream yielded more than {} items",
+                            max,
+                        )));
+                    }
+                    items
+                }
+                None => stream.collect::<Vec<_>>().await,
+            };
+
+            resolve_into_list_async(executor, info, items.iter()).await
+        })
+    }
+}
+
+impl<'a, S, T> IsOutputType<S> for StreamList<'a, T>
+where
+    T: IsOutputType<S>,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use crate::{
+        graphql_object, EmptyMutation, EmptySubscription, FieldError, RootNode, Variables,
+    };
+
+    #[tokio::test]
+    async fn resolves_up_to_max_stream_items() {
+        use crate::{executor::ExecutionError, parser::SourcePosition, StreamList, Value};
+
+        struct Root;
+
+        #[graphql_object]
+        impl Root {
+            async fn dates(count: i32) -> StreamList<'static, DateTime<Utc>> {
+                StreamList::new(futures::stream::iter(
+                    (1..=count as u32)
+                        .map(|day| Utc.with_ymd_and_hms(2021, 7, day, 0, 0, 0).unwrap()),
+                ))
+            }
+        }
+
+        let schema = RootNode::new(
+            Root,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        )
+        .with_max_stream_items(2);
+
+        assert_eq!(
+            crate::execute("{ dates(count: 2) }", None, &schema, &Variables::new(), &()).await,
+            Ok((
+                graphql_value!({"dates": [
+                    "2021-07-01T00:00:00+00:00",
+                    "2021-07-02T00:00:00+00:00",
+                ]}),
+                vec![],
+            )),
+        );
+
+        assert_eq!(
+            crate::execute("{ dates(count: 3) }", None, &schema, &Variables::new(), &()).await,
+            Ok((
+                Value::null(),
+                vec![ExecutionError::new(
+                    SourcePosition::new(2, 0, 2),
+                    &["dates"],
+                    FieldError::new("Stream yielded more than 2 items", Value::null()),
+                )],
+            )),
+        );
+    }
+}
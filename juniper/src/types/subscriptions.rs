@@ -20,6 +20,13 @@ pub struct ExecutionOutput<S> {
     /// The output can have both data and errors.
     #[serde(bound(serialize = "S: ScalarValue"))]
     pub errors: Vec<ExecutionError<S>>,
+
+    /// The extensions of the output, e.g. a correlation ID of a subscription event.
+    #[serde(
+        bound(serialize = "S: ScalarValue"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub extensions: Option<Object<S>>,
 }
 
 impl<S> ExecutionOutput<S> {
@@ -28,8 +35,21 @@ impl<S> ExecutionOutput<S> {
         Self {
             data,
             errors: vec![],
+            extensions: None,
         }
     }
+
+    /// Puts the given correlation `id` into the extensions of this output (as the
+    /// `correlationId` entry), allowing clients to order and deduplicate subscription events.
+    pub fn with_correlation_id(mut self, id: i32) -> Self
+    where
+        S: ScalarValue,
+    {
+        self.extensions
+            .get_or_insert_with(|| Object::with_capacity(1))
+            .add_field("correlationId", Value::scalar(id));
+        self
+    }
 }
 
 /// Global subscription coordinator trait.
@@ -86,6 +106,42 @@ where
 /// [`GraphQLResponse`]: crate::http::GraphQLResponse
 pub trait SubscriptionConnection<S>: futures::Stream<Item = ExecutionOutput<S>> {}
 
+/// Puts sequential correlation IDs (starting from `1`) into the extensions of
+/// the [`ExecutionOutput`]s of the given `stream`, as their `correlationId`
+/// entries (see [`ExecutionOutput::with_correlation_id`]).
+///
+/// The IDs saturate at [`i32::MAX`], being the largest GraphQL `Int`, so the
+/// outputs following the `i32::MAX`th one share it.
+pub fn with_correlation_ids<S, St>(stream: St) -> impl futures::Stream<Item = ExecutionOutput<S>>
+where
+    S: ScalarValue,
+    St: futures::Stream<Item = ExecutionOutput<S>>,
+{
+    use futures::StreamExt as _;
+
+    stream.scan(0, |last_id: &mut i32, output| {
+        *last_id = last_id.saturating_add(1);
+        future::ready(Some(output.with_correlation_id(*last_id)))
+    })
+}
+
+/// Combines the `initial` value and the stream of its `updates` into a single
+/// stream, yielding the `initial` value first.
+///
+/// Useful for [subscription][1] fields, which should deliver the current
+/// state right away (e.g. the current time on a live dashboard), rather than
+/// waiting for the first update to happen.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Subscription
+pub fn snapshot_then_updates<T, St>(initial: T, updates: St) -> impl futures::Stream<Item = T>
+where
+    St: futures::Stream<Item = T>,
+{
+    use futures::StreamExt as _;
+
+    stream::once(future::ready(initial)).chain(updates)
+}
+
 /// Extension of [`GraphQLValue`] trait with asynchronous [subscription][1] execution logic.
 /// It should be used with [`GraphQLValue`] in order to implement [subscription][1] resolvers on
 /// [GraphQL objects][2].
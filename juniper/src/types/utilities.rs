@@ -1,7 +1,7 @@
 use crate::{
     ast::InputValue,
     schema::{
-        meta::{EnumMeta, InputObjectMeta, MetaType},
+        meta::{EnumMeta, InputObjectMeta, MetaType, ScalarMeta},
         model::{SchemaType, TypeType},
     },
     value::ScalarValue,
@@ -94,6 +94,13 @@ where
                         });
 
                         all_types_ok && remaining_required_fields.is_empty()
+                    } else if let MetaType::Scalar(ScalarMeta {
+                        accepts_objects: true,
+                        ref try_parse_fn,
+                        ..
+                    }) = *t
+                    {
+                        try_parse_fn(arg_value)
                     } else {
                         false
                     }
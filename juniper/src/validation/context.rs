@@ -3,7 +3,10 @@ use std::{
     fmt::{self, Debug},
 };
 
-use crate::ast::{Definition, Document, Type};
+use crate::{
+    ast::{Definition, Document, Type},
+    executor::Variables,
+};
 
 use crate::schema::{meta::MetaType, model::SchemaType};
 
@@ -26,6 +29,7 @@ pub struct ValidatorContext<'a, S: Debug + 'a> {
     input_type_literal_stack: Vec<Option<Type<'a>>>,
     parent_type_stack: Vec<Option<&'a MetaType<'a, S>>>,
     fragment_names: HashSet<&'a str>,
+    variables: Option<&'a Variables<S>>,
 }
 
 impl RuleError {
@@ -84,9 +88,18 @@ impl<'a, S: Debug> ValidatorContext<'a, S> {
                     _ => None,
                 })
                 .collect(),
+            variables: None,
         }
     }
 
+    /// Provides the values of the variables the document is going to be
+    /// executed with, so the rules depending on them (like the maximum
+    /// complexity one) may take them into account.
+    pub(crate) fn with_variables(mut self, variables: &'a Variables<S>) -> Self {
+        self.variables = Some(variables);
+        self
+    }
+
     #[doc(hidden)]
     pub fn append_errors(&mut self, mut errors: Vec<RuleError>) {
         self.errors.append(&mut errors);
@@ -190,4 +203,10 @@ impl<'a, S: Debug> ValidatorContext<'a, S> {
     pub fn is_known_fragment(&self, name: &str) -> bool {
         self.fragment_names.contains(name)
     }
+
+    /// Returns the values of the variables the document is going to be
+    /// executed with, if they're known during validation.
+    pub(crate) fn variables(&self) -> Option<&'a Variables<S>> {
+        self.variables
+    }
 }
@@ -3,6 +3,7 @@ use std::{collections::HashSet, fmt};
 use crate::{
     ast::{InputValue, Operation, VariableDefinitions},
     executor::Variables,
+    macros::helper::enum_value_eq,
     parser::{SourcePosition, Spanning},
     schema::{
         meta::{EnumMeta, InputObjectMeta, MetaType, ScalarMeta},
@@ -193,17 +194,29 @@ fn unify_scalar<'a, S>(
     path: &Path<'a>,
 ) -> Vec<RuleError>
 where
-    S: fmt::Debug,
+    S: ScalarValue,
 {
     let mut errors: Vec<RuleError> = vec![];
 
     if !(meta.try_parse_fn)(value) {
-        return vec![unification_error(
-            var_name,
-            var_pos,
-            path,
-            &format!(r#"Expected "{}""#, meta.name),
-        )];
+        // Integers not fitting into `Int` are deserialized as floats, so report
+        // them precisely rather than as of a wrong type.
+        let message = match value.as_float_value() {
+            Some(f)
+                if meta.name == "Int"
+                    && f.fract() == 0.0
+                    && (f < f64::from(i32::MIN) || f > f64::from(i32::MAX)) =>
+            {
+                format!(
+                    r#"Expected "Int", found {}, which is outside of the range [{}, {}]"#,
+                    f,
+                    i32::MIN,
+                    i32::MAX,
+                )
+            }
+            _ => format!(r#"Expected "{}""#, meta.name),
+        };
+        return vec![unification_error(var_name, var_pos, path, &message)];
     }
 
     match *value {
@@ -213,7 +226,7 @@ where
             path,
             &format!(r#"Expected "{}", found list"#, meta.name),
         )),
-        InputValue::Object(_) => errors.push(unification_error(
+        InputValue::Object(_) if !meta.accepts_objects => errors.push(unification_error(
             var_name,
             var_pos,
             path,
@@ -240,7 +253,7 @@ where
         // TODO: avoid this bad duplicate as_str() call. (value system refactor)
         InputValue::Scalar(ref scalar) if scalar.as_str().is_some() => {
             if let Some(name) = scalar.as_str() {
-                if !meta.values.iter().any(|ev| ev.name == *name) {
+                if !meta.values.iter().any(|ev| enum_value_eq(name, &ev.name)) {
                     errors.push(unification_error(
                         var_name,
                         var_pos,
@@ -251,7 +264,7 @@ where
             }
         }
         InputValue::Enum(ref name) => {
-            if !meta.values.iter().any(|ev| &ev.name == name) {
+            if !meta.values.iter().any(|ev| enum_value_eq(name, &ev.name)) {
                 errors.push(unification_error(
                     var_name,
                     var_pos,
@@ -0,0 +1,144 @@
+use crate::{
+    ast::Field,
+    parser::Spanning,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+pub struct FieldsInAllowlist;
+
+pub fn factory() -> FieldsInAllowlist {
+    FieldsInAllowlist
+}
+
+impl<'a, S> Visitor<'a, S> for FieldsInAllowlist
+where
+    S: ScalarValue,
+{
+    fn enter_field(
+        &mut self,
+        context: &mut ValidatorContext<'a, S>,
+        field: &'a Spanning<Field<S>>,
+    ) {
+        let field_name = &field.item.name;
+        let type_name = match context.parent_type().and_then(|t| t.name()) {
+            Some(name) => name,
+            None => return,
+        };
+
+        if !context.schema.is_field_allowed(type_name, field_name.item) {
+            context.report_error(
+                &error_message(field_name.item, type_name),
+                &[field_name.start],
+            );
+        }
+    }
+}
+
+fn error_message(field: &str, type_name: &str) -> String {
+    format!(
+        r#"Field "{}" on type "{}" is not allowed"#,
+        field, type_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        parser::SourcePosition,
+        schema::model::RootNode,
+        validation::{
+            expect_fails_rule_with_root, expect_passes_rule, expect_passes_rule_with_root,
+            test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+            RuleError,
+        },
+        value::DefaultScalarValue,
+    };
+
+    fn schema<'a>() -> RootNode<'a, QueryRoot, MutationRoot, SubscriptionRoot> {
+        RootNode::new(QueryRoot, MutationRoot, SubscriptionRoot).with_field_allowlist(vec![
+            "QueryRoot.dog",
+            "Dog.name",
+            "Dog.barks",
+        ])
+    }
+
+    #[test]
+    fn all_fields_allowed_by_default() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              name
+              nickname
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn allowed_fields() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            __typename
+            dog {
+              __typename
+              name
+              ... on Dog {
+                barks
+              }
+            }
+            __schema {
+              types {
+                name
+              }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn fields_not_in_allowlist() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            dog {
+              name
+              ...DogFields
+            }
+            cat {
+              name
+            }
+          }
+
+          fragment DogFields on Dog {
+            nickname
+          }
+        "#,
+            &[
+                RuleError::new(
+                    &error_message("cat", "QueryRoot"),
+                    &[SourcePosition::new(103, 6, 12)],
+                ),
+                RuleError::new(
+                    &error_message("name", "Cat"),
+                    &[SourcePosition::new(123, 7, 14)],
+                ),
+                RuleError::new(
+                    &error_message("nickname", "Dog"),
+                    &[SourcePosition::new(205, 12, 12)],
+                ),
+            ],
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use crate::{
+    ast::{Definition, Document, Fragment},
+    parser::Spanning,
+};
+
+/// Named fragments of a document, along with the measures (e.g. depth or complexity) of the ones
+/// spread already, shared by the rules measuring the size of operations.
+pub struct FragmentMeasures<'a, S: Debug + 'a, T> {
+    fragments: HashMap<&'a str, &'a Fragment<'a, S>>,
+    expanding: Vec<&'a str>,
+    measures: HashMap<&'a str, T>,
+}
+
+impl<'a, S: Debug, T: Clone> FragmentMeasures<'a, S, T> {
+    pub fn new() -> Self {
+        Self {
+            fragments: HashMap::new(),
+            expanding: Vec::new(),
+            measures: HashMap::new(),
+        }
+    }
+
+    /// Collects the named fragments of the given document.
+    pub fn collect(&mut self, defs: &'a Document<S>) {
+        for def in defs {
+            if let Definition::Fragment(Spanning { ref item, .. }) = *def {
+                self.fragments.insert(item.name.item, item);
+            }
+        }
+    }
+
+    /// Forgets the measures of the fragments spread so far, for the rules whose measures depend
+    /// on the operation being validated.
+    pub fn forget_measures(&mut self) {
+        self.measures.clear();
+    }
+
+    /// Returns the measure of the fragment named `name`, computing it via the given `measure`
+    /// function the first time the fragment is spread.
+    ///
+    /// Returns [`None`] for unknown fragments and for the ones being expanded already, so the
+    /// cyclic ones are only expanded once (they're reported by another rule anyway).
+    pub fn spread<F>(&mut self, name: &'a str, measure: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self, &'a Fragment<'a, S>) -> T,
+    {
+        if let Some(measured) = self.measures.get(name) {
+            return Some(measured.clone());
+        }
+        if self.expanding.contains(&name) {
+            return None;
+        }
+        let fragment = *self.fragments.get(name)?;
+
+        self.expanding.push(name);
+        let measured = measure(self, fragment);
+        self.expanding.pop();
+
+        self.measures.insert(name, measured.clone());
+        Some(measured)
+    }
+}
@@ -0,0 +1,329 @@
+use std::fmt::Debug;
+
+use crate::{
+    ast::{Document, InputValue, Operation, OperationType, Selection, Type, VariableDefinitions},
+    executor::Variables,
+    parser::Spanning,
+    schema::{meta::MetaType, model::SchemaType},
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+use super::fragment_measures::FragmentMeasures;
+
+/// Arguments limiting the number of elements of list fields, so multiplying their complexity.
+const MULTIPLIER_ARGS: &[&str] = &["first", "last"];
+
+pub struct MaxComplexity<'a, S: Debug + 'a> {
+    fragments: FragmentMeasures<'a, S, usize>,
+}
+
+pub fn factory<'a, S: Debug>() -> MaxComplexity<'a, S> {
+    MaxComplexity {
+        fragments: FragmentMeasures::new(),
+    }
+}
+
+/// Operation the complexity is computed for.
+struct Scope<'a, 's, S: Debug> {
+    schema: &'s SchemaType<'s, S>,
+    definitions: Option<&'a VariableDefinitions<'a, S>>,
+    values: Option<&'s Variables<S>>,
+}
+
+/// Computes the complexity of the given `selection_set` selected on the type of the given `meta`,
+/// with every field weighing `1` unless specified otherwise, and the complexity of the fields
+/// selected on a list field being multiplied by its `first` or `last` argument. Introspection
+/// fields are not counted, as their complexity is bounded by the schema.
+fn complexity<'a, S>(
+    fragments: &mut FragmentMeasures<'a, S, usize>,
+    scope: &Scope<'a, '_, S>,
+    meta: Option<&MetaType<S>>,
+    selection_set: &'a [Selection<S>],
+) -> usize
+where
+    S: ScalarValue,
+{
+    selection_set
+        .iter()
+        .map(|selection| match selection {
+            Selection::Field(f) if f.item.name.item.starts_with("__") => 0,
+            Selection::Field(f) => {
+                let field = meta.and_then(|m| m.field_by_name(f.item.name.item));
+                let weight = field.and_then(|f| f.complexity).unwrap_or(1);
+                let children = f.item.selection_set.as_ref().map_or(0, |s| {
+                    let meta = field.and_then(|f| {
+                        scope
+                            .schema
+                            .concrete_type_by_name(f.field_type.innermost_name())
+                    });
+                    complexity(fragments, scope, meta, s)
+                });
+                let multiplier = match field.map(|f| &f.field_type) {
+                    Some(Type::List(..)) | Some(Type::NonNullList(..)) => f
+                        .item
+                        .arguments
+                        .as_ref()
+                        .and_then(|args| {
+                            MULTIPLIER_ARGS
+                                .iter()
+                                .find_map(|name| args.item.get(name))
+                                .and_then(|arg| scope.int_value(&arg.item))
+                        })
+                        .unwrap_or(1),
+                    _ => 1,
+                };
+                weight.saturating_add(multiplier.saturating_mul(children))
+            }
+            Selection::InlineFragment(f) => {
+                let meta = match &f.item.type_condition {
+                    Some(cond) => scope.schema.concrete_type_by_name(cond.item),
+                    None => meta,
+                };
+                complexity(fragments, scope, meta, &f.item.selection_set)
+            }
+            Selection::FragmentSpread(spread) => fragments
+                .spread(spread.item.name.item, |fragments, f| {
+                    let meta = scope.schema.concrete_type_by_name(f.type_condition.item);
+                    complexity(fragments, scope, meta, &f.selection_set)
+                })
+                .unwrap_or(0),
+        })
+        .fold(0, usize::saturating_add)
+}
+
+impl<'a, 's, S> Scope<'a, 's, S>
+where
+    S: ScalarValue,
+{
+    /// Returns the non-negative integer of the given argument `value`, either specified literally
+    /// or via a variable.
+    ///
+    /// The value of a variable is the one the operation is executed with, falling back to its
+    /// default value when it's not provided (or not known, if the document is validated on its
+    /// own).
+    fn int_value(&self, value: &InputValue<S>) -> Option<usize> {
+        let value = match value {
+            InputValue::Variable(name) => match self.values.and_then(|v| v.get(name)) {
+                Some(value) => value,
+                None => self
+                    .definitions?
+                    .iter()
+                    .find(|(var, _)| var.item == name)
+                    .and_then(|(_, def)| def.default_value.as_ref())
+                    .map(|v| &v.item)?,
+            },
+            value => value,
+        };
+        value.as_int_value().map(|i| i.max(0) as usize)
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for MaxComplexity<'a, S>
+where
+    S: ScalarValue,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, defs: &'a Document<S>) {
+        if ctx.schema.max_complexity.is_some() {
+            self.fragments.collect(defs);
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        let max = match ctx.schema.max_complexity {
+            Some(max) => max,
+            None => return,
+        };
+
+        let scope = Scope {
+            schema: ctx.schema,
+            definitions: op.item.variable_definitions.as_ref().map(|v| &v.item),
+            values: ctx.variables(),
+        };
+        let root = match op.item.operation_type {
+            OperationType::Query => Some(ctx.schema.concrete_query_type()),
+            OperationType::Mutation => ctx.schema.concrete_mutation_type(),
+            OperationType::Subscription => ctx.schema.concrete_subscription_type(),
+        };
+
+        // The complexity of fragments depends on the variables of the operation they're spread in.
+        self.fragments.forget_measures();
+        let complexity = complexity(&mut self.fragments, &scope, root, &op.item.selection_set);
+        if complexity > max {
+            ctx.report_error(&error_message(complexity, max), &[op.start]);
+        }
+    }
+}
+
+fn error_message(complexity: usize, max: usize) -> String {
+    format!(
+        "Operation has complexity of {}, exceeding the maximum of {}",
+        complexity, max,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        graphql_object,
+        parser::SourcePosition,
+        schema::model::RootNode,
+        types::scalars::{EmptyMutation, EmptySubscription},
+        validation::{expect_fails_rule_with_root, expect_passes_rule_with_root, RuleError},
+        GraphQLError, InputValue, Variables,
+    };
+
+    struct Event;
+
+    #[graphql_object]
+    impl Event {
+        fn name() -> &'static str {
+            "Release"
+        }
+
+        #[graphql(complexity = 5)]
+        fn attendees() -> Vec<String> {
+            vec!["Alice".into()]
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn events(first: Option<i32>) -> Vec<Event> {
+            (0..first.unwrap_or(1).min(2)).map(|_| Event).collect()
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+    fn schema() -> Schema {
+        RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .with_max_complexity(100)
+    }
+
+    #[test]
+    fn weighted_fields_up_to_max() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            events(first: 16) {
+              name
+              attendees
+            }
+            __schema {
+              types {
+                name
+              }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn weighted_fields_beyond_max() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            events(first: 17) {
+              name
+              attendees
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(103, 100),
+                &[SourcePosition::new(11, 1, 10)],
+            )],
+        );
+    }
+
+    #[test]
+    fn multiplied_fragments() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            events(first: 150) {
+              ...Name
+            }
+          }
+
+          fragment Name on Event {
+            ... on Event {
+              name
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(151, 100),
+                &[SourcePosition::new(11, 1, 10)],
+            )],
+        );
+    }
+
+    #[test]
+    fn fragments_in_operations_with_different_defaults() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          query A($first: Int = 10) {
+            ...Events
+          }
+
+          query B($first: Int = 1000) {
+            ...Events
+          }
+
+          fragment Events on Query {
+            events(first: $first) {
+              name
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(1001, 100),
+                &[SourcePosition::new(84, 5, 10)],
+            )],
+        );
+    }
+
+    #[test]
+    fn provided_variables_override_defaults() {
+        let schema = schema();
+        let doc = r#"query($first: Int = 1) { events(first: $first) { name } }"#;
+
+        let mut vars = Variables::new();
+        vars.insert("first".to_owned(), InputValue::scalar(200));
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema, &vars, &()),
+            Err(GraphQLError::ValidationError(vec![RuleError::new(
+                &error_message(201, 100),
+                &[SourcePosition::new(0, 0, 0)],
+            )])),
+        );
+
+        vars.insert("first".to_owned(), InputValue::scalar(2));
+        assert_eq!(
+            crate::execute_sync(doc, None, &schema, &vars, &()),
+            Ok((
+                graphql_value!({"events": [{"name": "Release"}, {"name": "Release"}]}),
+                vec![],
+            )),
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Debug;
+
+use crate::{
+    ast::{Document, Operation, Selection},
+    parser::Spanning,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+use super::fragment_measures::FragmentMeasures;
+
+pub struct MaxDepth<'a, S: Debug + 'a> {
+    fragments: FragmentMeasures<'a, S, usize>,
+}
+
+pub fn factory<'a, S: Debug>() -> MaxDepth<'a, S> {
+    MaxDepth {
+        fragments: FragmentMeasures::new(),
+    }
+}
+
+/// Measures how deep the fields of the given `selection_set` are nested, with fragments adding no
+/// depth on their own. Introspection fields are not counted, as their depth is bounded by the
+/// schema.
+fn depth<'a, S: Debug>(
+    fragments: &mut FragmentMeasures<'a, S, usize>,
+    selection_set: &'a [Selection<S>],
+) -> usize {
+    selection_set
+        .iter()
+        .map(|selection| match selection {
+            Selection::Field(f) if f.item.name.item.starts_with("__") => 0,
+            Selection::Field(f) => {
+                1 + f
+                    .item
+                    .selection_set
+                    .as_ref()
+                    .map_or(0, |s| depth(fragments, s))
+            }
+            Selection::InlineFragment(f) => depth(fragments, &f.item.selection_set),
+            Selection::FragmentSpread(spread) => fragments
+                .spread(spread.item.name.item, |fragments, f| {
+                    depth(fragments, &f.selection_set)
+                })
+                .unwrap_or(0),
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+impl<'a, S> Visitor<'a, S> for MaxDepth<'a, S>
+where
+    S: ScalarValue,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, defs: &'a Document<S>) {
+        if ctx.schema.max_depth.is_some() {
+            self.fragments.collect(defs);
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        let max = match ctx.schema.max_depth {
+            Some(max) => max,
+            None => return,
+        };
+
+        let depth = depth(&mut self.fragments, &op.item.selection_set);
+        if depth > max {
+            ctx.report_error(&error_message(max), &[op.start]);
+        }
+    }
+}
+
+fn error_message(max: usize) -> String {
+    format!("Operation exceeds the maximum depth of {}", max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        parser::SourcePosition,
+        schema::model::RootNode,
+        validation::{
+            expect_fails_rule_with_root, expect_passes_rule, expect_passes_rule_with_root,
+            test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+            RuleError,
+        },
+        value::DefaultScalarValue,
+    };
+
+    fn schema<'a>() -> RootNode<'a, QueryRoot, MutationRoot, SubscriptionRoot> {
+        RootNode::new(QueryRoot, MutationRoot, SubscriptionRoot).with_max_depth(3)
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            human {
+              relatives {
+                relatives {
+                  relatives {
+                    name
+                  }
+                }
+              }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn nested_up_to_max_depth() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            human {
+              relatives {
+                name
+              }
+            }
+            __schema {
+              types {
+                fields {
+                  type {
+                    name
+                  }
+                }
+              }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn fragments_add_no_depth() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            human {
+              ...Relatives
+              ... on Human {
+                relatives {
+                  name
+                }
+              }
+            }
+          }
+
+          fragment Relatives on Human {
+            relatives {
+              name
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn nested_beyond_max_depth() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            human {
+              relatives {
+                relatives {
+                  name
+                }
+              }
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(3),
+                &[SourcePosition::new(11, 1, 10)],
+            )],
+        );
+    }
+
+    #[test]
+    fn nested_beyond_max_depth_via_fragments() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            human {
+              ...A
+            }
+          }
+
+          fragment A on Human {
+            relatives {
+              ...B
+            }
+          }
+
+          fragment B on Human {
+            ... on Human {
+              relatives {
+                name
+              }
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(3),
+                &[SourcePosition::new(11, 1, 10)],
+            )],
+        );
+    }
+
+    #[test]
+    fn measures_spread_fragments_once() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          query A {
+            human {
+              ...Relatives
+            }
+          }
+
+          query B {
+            human {
+              relatives {
+                ...Relatives
+              }
+            }
+          }
+
+          fragment Relatives on Human {
+            relatives {
+              name
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(3),
+                &[SourcePosition::new(105, 7, 10)],
+            )],
+        );
+    }
+
+    #[test]
+    fn cyclic_fragments() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            human {
+              ...A
+            }
+          }
+
+          fragment A on Human {
+            ...B
+          }
+
+          fragment B on Human {
+            ...A
+          }
+        "#,
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Debug;
+
+use crate::{
+    ast::{Document, Operation, Selection},
+    parser::Spanning,
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+use super::fragment_measures::FragmentMeasures;
+
+pub struct MaxRootFields<'a, S: Debug + 'a> {
+    fragments: FragmentMeasures<'a, S, usize>,
+}
+
+pub fn factory<'a, S: Debug>() -> MaxRootFields<'a, S> {
+    MaxRootFields {
+        fragments: FragmentMeasures::new(),
+    }
+}
+
+/// Counts the fields selected by the given `selection_set`, including the ones selected via
+/// fragments.
+fn count_fields<'a, S: Debug>(
+    fragments: &mut FragmentMeasures<'a, S, usize>,
+    selection_set: &'a [Selection<S>],
+) -> usize {
+    selection_set
+        .iter()
+        .map(|selection| match selection {
+            Selection::Field(_) => 1,
+            Selection::InlineFragment(f) => count_fields(fragments, &f.item.selection_set),
+            Selection::FragmentSpread(spread) => fragments
+                .spread(spread.item.name.item, |fragments, f| {
+                    count_fields(fragments, &f.selection_set)
+                })
+                .unwrap_or(0),
+        })
+        .sum()
+}
+
+impl<'a, S> Visitor<'a, S> for MaxRootFields<'a, S>
+where
+    S: ScalarValue,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, defs: &'a Document<S>) {
+        if ctx.schema.max_root_fields.is_some() {
+            self.fragments.collect(defs);
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        let max = match ctx.schema.max_root_fields {
+            Some(max) => max,
+            None => return,
+        };
+
+        let count = count_fields(&mut self.fragments, &op.item.selection_set);
+        if count > max {
+            ctx.report_error(&error_message(count, max), &[op.start]);
+        }
+    }
+}
+
+fn error_message(count: usize, max: usize) -> String {
+    format!(
+        "Operation selects {} root fields, exceeding the maximum of {}",
+        count, max,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_message, factory};
+
+    use crate::{
+        parser::SourcePosition,
+        schema::model::RootNode,
+        validation::{
+            expect_fails_rule_with_root, expect_passes_rule, expect_passes_rule_with_root,
+            test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+            RuleError,
+        },
+        value::DefaultScalarValue,
+    };
+
+    fn schema<'a>() -> RootNode<'a, QueryRoot, MutationRoot, SubscriptionRoot> {
+        RootNode::new(QueryRoot, MutationRoot, SubscriptionRoot).with_max_root_fields(2)
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              name
+            }
+            cat {
+              name
+            }
+            pet {
+              name
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn root_fields_up_to_max() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            a: dog {
+              name
+              nickname
+              barks
+            }
+            b: dog {
+              name
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn root_fields_beyond_max() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            dog {
+              name
+            }
+            ...Pets
+          }
+
+          fragment Pets on QueryRoot {
+            ... on QueryRoot {
+              cat {
+                name
+              }
+            }
+            pet {
+              name
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &error_message(3, 2),
+                &[SourcePosition::new(11, 1, 10)],
+            )],
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use std::{collections::HashSet, fmt::Debug};
+
+use crate::{
+    ast::{Document, Field, Operation, OperationType, Selection},
+    parser::Spanning,
+    schema::{meta::MetaType, model::SchemaType},
+    validation::{ValidatorContext, Visitor},
+    value::ScalarValue,
+};
+
+use super::fragment_measures::FragmentMeasures;
+
+/// Response keys of the fields selected on an object, along with the type conditions they're
+/// selected under.
+type Selected<'a> = Vec<(Vec<&'a str>, &'a str)>;
+
+pub struct MaxSelectionBreadth<'a, S: Debug + 'a> {
+    fragments: FragmentMeasures<'a, S, Selected<'a>>,
+}
+
+pub fn factory<'a, S: Debug>() -> MaxSelectionBreadth<'a, S> {
+    MaxSelectionBreadth {
+        fragments: FragmentMeasures::new(),
+    }
+}
+
+/// Collects the fields selected by the given `selection_set` under the given type `conditions`,
+/// including the ones selected via fragments. Introspection fields are not collected, as their
+/// breadth is bounded by the schema.
+fn collect_fields<'a, S: Debug>(
+    fragments: &mut FragmentMeasures<'a, S, Selected<'a>>,
+    selection_set: &'a [Selection<S>],
+    conditions: &[&'a str],
+    selected: &mut Selected<'a>,
+) {
+    for selection in selection_set {
+        match selection {
+            Selection::Field(f) if f.item.name.item.starts_with("__") => {}
+            Selection::Field(f) => {
+                let key = f.item.alias.as_ref().unwrap_or(&f.item.name).item;
+                selected.push((conditions.to_vec(), key));
+            }
+            Selection::InlineFragment(f) => {
+                let mut conditions = conditions.to_vec();
+                conditions.extend(f.item.type_condition.as_ref().map(|c| c.item));
+                collect_fields(fragments, &f.item.selection_set, &conditions, selected);
+            }
+            Selection::FragmentSpread(spread) => {
+                let fields = fragments.spread(spread.item.name.item, |fragments, f| {
+                    let mut fields = vec![];
+                    collect_fields(
+                        fragments,
+                        &f.selection_set,
+                        &[f.type_condition.item],
+                        &mut fields,
+                    );
+                    fields
+                });
+                for (fragment_conditions, key) in fields.into_iter().flatten() {
+                    let mut all_conditions = conditions.to_vec();
+                    all_conditions.extend(fragment_conditions);
+                    selected.push((all_conditions, key));
+                }
+            }
+        }
+    }
+}
+
+/// Counts the distinct fields of the given `selected` ones resolved on each concrete type of the
+/// given `meta`, returning the largest count.
+fn breadth<S>(schema: &SchemaType<S>, meta: &MetaType<S>, selected: &Selected) -> usize {
+    let concrete_types = if meta.is_abstract() {
+        schema.possible_types(meta)
+    } else {
+        vec![meta]
+    };
+    let applies = |condition: &str, concrete: &MetaType<S>| {
+        concrete.name() == Some(condition)
+            || matches!(
+                schema.concrete_type_by_name(condition),
+                Some(t) if t.is_abstract() && schema.is_possible_type(t, concrete)
+            )
+    };
+
+    concrete_types
+        .into_iter()
+        .map(|concrete| {
+            selected
+                .iter()
+                .filter(|(conditions, _)| conditions.iter().all(|c| applies(c, concrete)))
+                .map(|(_, key)| *key)
+                .collect::<HashSet<_>>()
+                .len()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+impl<'a, S: Debug> MaxSelectionBreadth<'a, S> {
+    fn breadth(
+        &mut self,
+        schema: &SchemaType<S>,
+        meta: &MetaType<S>,
+        selection_set: &'a [Selection<S>],
+    ) -> usize {
+        let mut selected = vec![];
+        collect_fields(&mut self.fragments, selection_set, &[], &mut selected);
+        breadth(schema, meta, &selected)
+    }
+}
+
+impl<'a, S> Visitor<'a, S> for MaxSelectionBreadth<'a, S>
+where
+    S: ScalarValue,
+{
+    fn enter_document(&mut self, ctx: &mut ValidatorContext<'a, S>, defs: &'a Document<S>) {
+        if ctx.schema.max_selection_breadth.is_some() {
+            self.fragments.collect(defs);
+        }
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut ValidatorContext<'a, S>,
+        op: &'a Spanning<Operation<S>>,
+    ) {
+        let max = match ctx.schema.max_selection_breadth {
+            Some(max) => max,
+            None => return,
+        };
+        let root = match op.item.operation_type {
+            OperationType::Query => Some(ctx.schema.concrete_query_type()),
+            OperationType::Mutation => ctx.schema.concrete_mutation_type(),
+            OperationType::Subscription => ctx.schema.concrete_subscription_type(),
+        };
+        let root = match root {
+            Some(root) => root,
+            None => return,
+        };
+
+        let count = self.breadth(ctx.schema, root, &op.item.selection_set);
+        if count > max {
+            ctx.report_error(&operation_error_message(count, max), &[op.start]);
+        }
+    }
+
+    fn enter_field(&mut self, ctx: &mut ValidatorContext<'a, S>, field: &'a Spanning<Field<S>>) {
+        let max = match ctx.schema.max_selection_breadth {
+            Some(max) => max,
+            None => return,
+        };
+        let selection_set = match field.item.selection_set {
+            Some(ref selection_set) => selection_set,
+            None => return,
+        };
+        let meta = match ctx.current_type() {
+            Some(meta) if meta.is_composite() => meta,
+            _ => return,
+        };
+        if matches!(meta.name(), Some(name) if name.starts_with("__")) {
+            return;
+        }
+
+        let count = self.breadth(ctx.schema, meta, selection_set);
+        if count > max {
+            ctx.report_error(
+                &field_error_message(field.item.name.item, count, max),
+                &[field.start],
+            );
+        }
+    }
+}
+
+fn operation_error_message(count: usize, max: usize) -> String {
+    format!(
+        "Operation selects {} root fields, exceeding the maximum breadth of {}",
+        count, max,
+    )
+}
+
+fn field_error_message(field_name: &str, count: usize, max: usize) -> String {
+    format!(
+        "Field \"{}\" selects {} fields, exceeding the maximum breadth of {}",
+        field_name, count, max,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{factory, field_error_message, operation_error_message};
+
+    use crate::{
+        parser::SourcePosition,
+        schema::model::RootNode,
+        validation::{
+            expect_fails_rule_with_root, expect_passes_rule, expect_passes_rule_with_root,
+            test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+            RuleError,
+        },
+        value::DefaultScalarValue,
+    };
+
+    fn schema<'a>() -> RootNode<'a, QueryRoot, MutationRoot, SubscriptionRoot> {
+        RootNode::new(QueryRoot, MutationRoot, SubscriptionRoot).with_max_selection_breadth(3)
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        expect_passes_rule::<_, _, DefaultScalarValue>(
+            factory,
+            r#"
+          {
+            dog {
+              name
+              nickname
+              barkVolume
+              barks
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn fields_up_to_max_breadth() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            dog {
+              __typename
+              name
+              nickname
+              barkVolume
+            }
+            __schema {
+              types {
+                name
+              }
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn introspection_query() {
+        expect_passes_rule_with_root(schema(), factory, crate::INTROSPECTION_QUERY);
+    }
+
+    #[test]
+    fn counts_repeated_fields_once() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            dog {
+              name
+              name
+              ...DogFields
+              ... on Dog {
+                nickname
+              }
+            }
+          }
+
+          fragment DogFields on Dog {
+            name
+            barks
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn counts_fields_per_concrete_type() {
+        expect_passes_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            pet {
+              name
+              ... on Dog {
+                nickname
+                barkVolume
+              }
+              ...CatFields
+            }
+          }
+
+          fragment CatFields on Cat {
+            meows
+            meowVolume
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn aliased_fields_beyond_max_breadth() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            dog {
+              name
+              otherName: name
+              nickname
+              barks
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &field_error_message("dog", 4, 3),
+                &[SourcePosition::new(25, 2, 12)],
+            )],
+        );
+    }
+
+    #[test]
+    fn concrete_type_fields_beyond_max_breadth() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            catOrDog {
+              ... on Pet {
+                name
+              }
+              ...DogFields
+            }
+          }
+
+          fragment DogFields on Dog {
+            nickname
+            ... on Canine {
+              barks
+            }
+            barkVolume
+          }
+        "#,
+            &[RuleError::new(
+                &field_error_message("catOrDog", 4, 3),
+                &[SourcePosition::new(25, 2, 12)],
+            )],
+        );
+    }
+
+    #[test]
+    fn root_fields_beyond_max_breadth() {
+        expect_fails_rule_with_root(
+            schema(),
+            factory,
+            r#"
+          {
+            dog {
+              name
+            }
+            ...Pets
+          }
+
+          fragment Pets on QueryRoot {
+            cat {
+              name
+            }
+            pet {
+              name
+            }
+            human {
+              name
+            }
+          }
+        "#,
+            &[RuleError::new(
+                &operation_error_message(4, 3),
+                &[SourcePosition::new(11, 1, 10)],
+            )],
+        );
+    }
+}
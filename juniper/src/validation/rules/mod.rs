@@ -1,12 +1,18 @@
 mod arguments_of_correct_type;
 mod default_values_of_correct_type;
+mod fields_in_allowlist;
 mod fields_on_correct_type;
+mod fragment_measures;
 mod fragments_on_composite_types;
 mod known_argument_names;
 mod known_directives;
 mod known_fragment_names;
 mod known_type_names;
 mod lone_anonymous_operation;
+mod max_complexity;
+mod max_depth;
+mod max_root_fields;
+mod max_selection_breadth;
 mod no_fragment_cycles;
 mod no_undefined_variables;
 mod no_unused_fragments;
@@ -38,6 +44,7 @@ where
     let mut mv = MultiVisitorNil
         .with(self::arguments_of_correct_type::factory())
         .with(self::default_values_of_correct_type::factory())
+        .with(self::fields_in_allowlist::factory())
         .with(self::fields_on_correct_type::factory())
         .with(self::fragments_on_composite_types::factory())
         .with(self::known_argument_names::factory())
@@ -45,6 +52,10 @@ where
         .with(self::known_fragment_names::factory())
         .with(self::known_type_names::factory())
         .with(self::lone_anonymous_operation::factory())
+        .with(self::max_complexity::factory())
+        .with(self::max_depth::factory())
+        .with(self::max_root_fields::factory())
+        .with(self::max_selection_breadth::factory())
         .with(self::no_fragment_cycles::factory())
         .with(self::no_undefined_variables::factory())
         .with(self::no_unused_fragments::factory())
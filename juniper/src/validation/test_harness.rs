@@ -829,8 +829,22 @@ where
     V: Visitor<'a, S> + 'a,
     F: Fn() -> V,
 {
-    let mut root = RootNode::new_with_scalar_value(r, m, s);
+    validate_with_root(RootNode::new_with_scalar_value(r, m, s), q, factory)
+}
 
+fn validate_with_root<'a, 'r, Q, M, Sub, V, F, S>(
+    mut root: RootNode<'r, Q, M, Sub, S>,
+    q: &'a str,
+    factory: F,
+) -> Vec<RuleError>
+where
+    S: ScalarValue + 'a,
+    Q: GraphQLType<S, TypeInfo = ()>,
+    M: GraphQLType<S, TypeInfo = ()>,
+    Sub: GraphQLType<S, TypeInfo = ()>,
+    V: Visitor<'a, S> + 'a,
+    F: Fn() -> V,
+{
     root.schema.add_directive(DirectiveType::new(
         "onQuery",
         &[DirectiveLocation::Query],
@@ -940,6 +954,59 @@ pub fn expect_fails_rule_with_schema<'a, Q, M, V, F, S>(
     }
 }
 
+/// Asserts that the given `q` document passes the rule created by `factory` when validated
+/// against the given `root` schema, allowing to test rules depending on its settings.
+pub fn expect_passes_rule_with_root<'a, 'r, Q, M, Sub, V, F, S>(
+    root: RootNode<'r, Q, M, Sub, S>,
+    factory: F,
+    q: &'a str,
+) where
+    S: ScalarValue + 'a,
+    Q: GraphQLType<S, TypeInfo = ()>,
+    M: GraphQLType<S, TypeInfo = ()>,
+    Sub: GraphQLType<S, TypeInfo = ()>,
+    V: Visitor<'a, S> + 'a,
+    F: Fn() -> V,
+{
+    let errs = validate_with_root(root, q, factory);
+
+    if !errs.is_empty() {
+        print_errors(&errs);
+        panic!("Expected rule to pass, but errors found");
+    }
+}
+
+/// Asserts that the given `q` document fails the rule created by `factory` with exactly the
+/// `expected_errors` when validated against the given `root` schema, allowing to test rules
+/// depending on its settings.
+pub fn expect_fails_rule_with_root<'a, 'r, Q, M, Sub, V, F, S>(
+    root: RootNode<'r, Q, M, Sub, S>,
+    factory: F,
+    q: &'a str,
+    expected_errors: &[RuleError],
+) where
+    S: ScalarValue + 'a,
+    Q: GraphQLType<S, TypeInfo = ()>,
+    M: GraphQLType<S, TypeInfo = ()>,
+    Sub: GraphQLType<S, TypeInfo = ()>,
+    V: Visitor<'a, S> + 'a,
+    F: Fn() -> V,
+{
+    let errs = validate_with_root(root, q, factory);
+
+    if errs.is_empty() {
+        panic!("Expected rule to fail, but no errors were found");
+    } else if errs != expected_errors {
+        println!("==> Expected errors:");
+        print_errors(expected_errors);
+
+        println!("\n==> Actual errors:");
+        print_errors(&errs);
+
+        panic!("Unexpected set of errors found");
+    }
+}
+
 fn print_errors(errs: &[RuleError]) {
     for err in errs {
         for p in err.locations() {
@@ -0,0 +1,64 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of extension data attached to GraphQL types and fields (e.g. their owners or
+//! SLAs), exposed to tooling via introspection.
+
+use std::collections::{BTreeMap, HashMap};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt as _, parse::ParseStream, token};
+
+use crate::{
+    common::parse::{
+        attr::{err, OptionExt as _},
+        ParseBufferExt as _,
+    },
+    util::span_container::SpanContainer,
+};
+
+/// Extension data specified via `extensions(key = "value", ...)` attribute's
+/// argument, keyed by its keys.
+pub(crate) type Attr = HashMap<String, SpanContainer<syn::LitStr>>;
+
+/// Extension data to attach to GraphQL type or field, sorted by its keys.
+pub(crate) type Definition = BTreeMap<String, String>;
+
+/// Parses the parenthesized `key = "value"` pairs following an `extensions`
+/// attribute's argument into the given [`Attr`], reporting about duplicated
+/// keys, if any.
+pub(crate) fn parse(input: ParseStream<'_>, into: &mut Attr) -> syn::Result<()> {
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let key = content.parse_any_ident()?;
+        content.parse::<token::Eq>()?;
+        let value = content.parse::<syn::LitStr>()?;
+        into.insert(
+            key.unraw().to_string(),
+            SpanContainer::new(key.span(), Some(value.span()), value),
+        )
+        .none_or_else(|_| err::dup_arg(&key))?;
+        if !content.is_empty() {
+            content.parse::<token::Comma>()?;
+        }
+    }
+    Ok(())
+}
+
+/// Converts the parsed [`Attr`] into a [`Definition`].
+#[must_use]
+pub(crate) fn definition(attr: &Attr) -> Definition {
+    attr.iter()
+        .map(|(key, value)| (key.clone(), value.value()))
+        .collect()
+}
+
+/// Returns generated code attaching the given extension data to the GraphQL
+/// type or field meta being built.
+#[must_use]
+pub(crate) fn meta_tokens(def: &Definition) -> TokenStream {
+    let (keys, values) = (def.keys(), def.values());
+    quote! {
+        #( .with_extension(#keys, #values) )*
+    }
+}
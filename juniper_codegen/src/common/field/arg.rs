@@ -49,7 +49,9 @@ pub(crate) struct Attr {
     /// Explicitly specified [default value][2] of this [GraphQL argument][1].
     ///
     /// If the exact default expression is not specified, then the [`Default`]
-    /// value is used.
+    /// value is used. If the expression is not a constant one (see
+    /// [`is_const_expr`]), it's evaluated at execution time, whenever the
+    /// argument is omitted, like the function of [`Attr::default_with`].
     ///
     /// If [`None`], then this [GraphQL argument][1] is considered as
     /// [required][2].
@@ -58,6 +60,18 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Required-Arguments
     pub(crate) default: Option<SpanContainer<Option<syn::Expr>>>,
 
+    /// Explicitly specified function (or closure) computing the default value
+    /// of this [GraphQL argument][1] at execution time, whenever it's omitted.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    pub(crate) default_with: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified [deprecation][2] of this [GraphQL argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021/#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021/#sec--deprecated
+    pub(crate) deprecated: Option<SpanContainer<Option<syn::LitStr>>>,
+
     /// Explicitly specified marker indicating that this method argument doesn't
     /// represent a [GraphQL argument][1], but is a [`Context`] being injected
     /// into a [GraphQL field][2] resolving function.
@@ -81,6 +95,16 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
     pub(crate) executor: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker indicating that this method argument
+    /// doesn't represent a single [GraphQL argument][1], but a struct (deriving
+    /// [`GraphQLInputObject`]) whose fields become separate
+    /// [GraphQL arguments][1] of a [GraphQL field][2].
+    ///
+    /// [`GraphQLInputObject`]: juniper::GraphQLInputObject
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) flatten: Option<SpanContainer<syn::Ident>>,
 }
 
 impl Parse for Attr {
@@ -121,6 +145,27 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "default_with" => {
+                    input.parse::<token::Eq>()?;
+                    let func = input.parse::<syn::Expr>()?;
+                    out.default_with
+                        .replace(SpanContainer::new(ident.span(), Some(func.span()), func))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "deprecated" => {
+                    let mut reason = None;
+                    if input.is_next::<token::Eq>() {
+                        input.parse::<token::Eq>()?;
+                        reason = Some(input.parse::<syn::LitStr>()?);
+                    }
+                    out.deprecated
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            reason.as_ref().map(|r| r.span()),
+                            reason,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     let span = ident.span();
                     out.context
@@ -133,6 +178,12 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(span, Some(span), ident))
                         .none_or_else(|_| err::dup_arg(span))?
                 }
+                "flatten" => {
+                    let span = ident.span();
+                    out.flatten
+                        .replace(SpanContainer::new(span, Some(span), ident))
+                        .none_or_else(|_| err::dup_arg(span))?
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -151,8 +202,11 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             default: try_merge_opt!(default: self, another),
+            default_with: try_merge_opt!(default_with: self, another),
+            deprecated: try_merge_opt!(deprecated: self, another),
             context: try_merge_opt!(context: self, another),
             executor: try_merge_opt!(executor: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
         })
     }
 
@@ -167,7 +221,10 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || attr.default_with.is_some()
+                || attr.deprecated.is_some()
                 || attr.executor.is_some()
+                || attr.flatten.is_some()
             {
                 return Err(syn::Error::new(
                     context.span(),
@@ -180,7 +237,10 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || attr.default_with.is_some()
+                || attr.deprecated.is_some()
                 || attr.context.is_some()
+                || attr.flatten.is_some()
             {
                 return Err(syn::Error::new(
                     executor.span(),
@@ -189,6 +249,27 @@ impl Attr {
             }
         }
 
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.default.is_some()
+                || attr.default_with.is_some()
+                || attr.deprecated.is_some()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
+        if let (Some(_), Some(default_with)) = (&attr.default, &attr.default_with) {
+            return Err(syn::Error::new(
+                default_with.span_ident(),
+                "`default_with` attribute argument is not composable with `default` one",
+            ));
+        }
+
         Ok(attr)
     }
 
@@ -204,6 +285,12 @@ impl Attr {
         if let Some(span) = &self.default {
             return Err(Self::err_disallowed(&span, "default"));
         }
+        if let Some(span) = &self.default_with {
+            return Err(Self::err_disallowed(&span, "default_with"));
+        }
+        if let Some(span) = &self.deprecated {
+            return Err(Self::err_disallowed(&span, "deprecated"));
+        }
         Ok(())
     }
 
@@ -253,6 +340,26 @@ pub(crate) struct OnField {
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/June2018/#sec-Required-Arguments
     pub(crate) default: Option<Option<syn::Expr>>,
+
+    /// Function computing the default value of this
+    /// [GraphQL field argument][1] at execution time.
+    ///
+    /// If [`Some`], then this [argument][1] is represented as a nullable one in
+    /// GraphQL schema, and the function is called whenever it's omitted or
+    /// `null`.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    pub(crate) default_with: Option<syn::Expr>,
+
+    /// [Deprecation][2] of this [GraphQL field argument][1] to put into
+    /// GraphQL schema.
+    ///
+    /// If inner [`Option`] is [`None`], then deprecation has no message
+    /// attached.
+    ///
+    /// [1]: https://spec.graphql.org/October2021/#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021/#sec--deprecated
+    pub(crate) deprecated: Option<Option<String>>,
 }
 
 /// Possible kinds of Rust method arguments for code generation.
@@ -274,6 +381,11 @@ pub(crate) enum OnMethod {
     /// [`Executor`]: juniper::Executor
     /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
     Executor,
+
+    /// Struct whose fields are separate [GraphQL field arguments][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    Flattened(syn::Type),
 }
 
 impl OnMethod {
@@ -300,12 +412,16 @@ impl OnMethod {
 
     /// Returns generated code for the [`marker::IsOutputType::mark`] method,
     /// which performs static checks for this argument, if it represents an
-    /// [`OnField`] one.
+    /// [`OnField`] or a [`OnMethod::Flattened`] one.
     ///
     /// [`marker::IsOutputType::mark`]: juniper::marker::IsOutputType::mark
     #[must_use]
     pub(crate) fn method_mark_tokens(&self, scalar: &scalar::Type) -> Option<TokenStream> {
-        let ty = &self.as_regular()?.ty;
+        let ty = match self {
+            Self::Regular(arg) => &arg.ty,
+            Self::Flattened(ty) => ty,
+            Self::Context(_) | Self::Executor => return None,
+        };
         Some(quote! {
             <#ty as ::juniper::marker::IsInputType<#scalar>>::mark();
         })
@@ -313,12 +429,23 @@ impl OnMethod {
 
     /// Returns generated code for the [`GraphQLType::meta`] method, which
     /// registers this argument in [`Registry`], if it represents an [`OnField`]
-    /// argument.
+    /// argument, or registers all the fields of this argument, if it
+    /// represents an [`OnMethod::Flattened`] one.
     ///
     /// [`GraphQLType::meta`]: juniper::GraphQLType::meta
     /// [`Registry`]: juniper::Registry
     #[must_use]
     pub(crate) fn method_meta_tokens(&self) -> Option<TokenStream> {
+        if let Self::Flattened(ty) = self {
+            return Some(quote! {
+                .extend_arguments(
+                    <#ty as ::juniper::input_flattening::FlattenInputObject<_>>::meta_fields(
+                        registry,
+                    ),
+                )
+            });
+        }
+
         let arg = self.as_regular()?;
 
         let (name, ty) = (&arg.name, &arg.ty);
@@ -328,46 +455,103 @@ impl OnMethod {
             .as_ref()
             .map(|desc| quote! { .description(#desc) });
 
+        let deprecated = arg.deprecated.as_ref().map(|reason| {
+            let reason = reason
+                .as_ref()
+                .map(|rsn| quote! { Some(#rsn) })
+                .unwrap_or_else(|| quote! { None });
+            quote! { .deprecated(#reason) }
+        });
+
         let method = if let Some(val) = &arg.default {
             let val = val
                 .as_ref()
                 .map(|v| quote! { (#v).into() })
                 .unwrap_or_else(|| quote! { <#ty as Default>::default() });
             quote! { .arg_with_default::<#ty>(#name, &#val, info) }
+        } else if arg.default_with.is_some() {
+            quote! { .arg::<::std::option::Option<#ty>>(#name, info) }
         } else {
             quote! { .arg::<#ty>(#name, info) }
         };
 
-        Some(quote! { .argument(registry#method#description) })
+        Some(quote! { .argument(registry#method#description#deprecated) })
     }
 
     /// Returns generated code for the [`GraphQLValue::resolve_field`] method,
     /// which provides the value of this [`OnMethod`] argument to be passed into
     /// a trait method call.
     ///
+    /// The converted values of [`OnMethod::Regular`] and
+    /// [`OnMethod::Flattened`] arguments are validated if their types implement
+    /// [`ValidateInput`] (e.g. the input objects declared with
+    /// `#[graphql(validate = ...)]`), returning the [`FieldError`] from the
+    /// resolving method (wrapped into a failed future if it `returns_future`)
+    /// on failure.
+    ///
+    /// [`FieldError`]: juniper::FieldError
     /// [`GraphQLValue::resolve_field`]: juniper::GraphQLValue::resolve_field
+    /// [`ValidateInput`]: juniper::input_validation::ValidateInput
     #[must_use]
-    pub(crate) fn method_resolve_field_tokens(&self, scalar: &scalar::Type) -> TokenStream {
-        match self {
+    pub(crate) fn method_resolve_field_tokens(
+        &self,
+        scalar: &scalar::Type,
+        returns_future: bool,
+    ) -> TokenStream {
+        let (value, error) = match self {
             Self::Regular(arg) => {
                 let (name, ty) = (&arg.name, &arg.ty);
                 let err_text = format!(
                     "Internal error: missing argument `{}` - validation must have failed",
                     &name,
                 );
-                quote! {
-                    args.get::<#ty>(#name)
-                        .or_else(::juniper::FromInputValue::<#scalar>::from_implicit_null)
-                        .expect(#err_text)
+                let value = if let Some(func) = &arg.default_with {
+                    quote! {
+                        args.get_or_else::<#ty, _>(#name, #func).expect(#err_text)
+                    }
+                } else {
+                    quote! {
+                        args.get::<#ty>(#name)
+                            .or_else(::juniper::FromInputValue::<#scalar>::from_implicit_null)
+                            .expect(#err_text)
+                    }
+                };
+                let error = quote! { ::juniper::input_validation::invalid_argument(#name, e) };
+                (value, error)
+            }
+
+            Self::Context(_) => {
+                return quote! {
+                    ::juniper::FromContext::from(executor.context())
                 }
             }
 
-            Self::Context(_) => quote! {
-                ::juniper::FromContext::from(executor.context())
-            },
+            Self::Executor => return quote! { &executor },
 
-            Self::Executor => quote! { &executor },
-        }
+            Self::Flattened(ty) => {
+                let value = quote! {
+                    args.flatten::<#ty>()
+                        .expect("Internal error: invalid flattened arguments - validation must have failed")
+                };
+                let error = quote! { ::juniper::input_validation::invalid_arguments(e) };
+                (value, error)
+            }
+        };
+
+        let error = if returns_future {
+            quote! { Box::pin(::juniper::futures::future::err(#error)) }
+        } else {
+            quote! { Err(#error) }
+        };
+        quote! {{
+            use ::juniper::input_validation::{NoValidation as _, ViaValidateInput as _};
+
+            let arg = #value;
+            if let Err(e) = (&::juniper::input_validation::MaybeValidate(&arg)).validate() {
+                return #error;
+            }
+            arg
+        }}
     }
 
     /// Parses an [`OnMethod`] argument from the given Rust method argument
@@ -398,6 +582,9 @@ impl OnMethod {
         if attr.executor.is_some() {
             return Some(Self::Executor);
         }
+        if attr.flatten.is_some() {
+            return Some(Self::Flattened(argument.ty.as_ref().clone()));
+        }
         if let syn::Pat::Ident(name) = &*argument.pat {
             let arg = match name.ident.unraw().to_string().as_str() {
                 "context" | "ctx" | "_context" | "_ctx" => {
@@ -440,12 +627,77 @@ impl OnMethod {
             );
             return None;
         }
+        if let Some(deprecated) = &attr.deprecated {
+            if attr.default.is_none() && attr.default_with.is_none() && !argument.ty.is_nullable() {
+                scope.emit_custom(
+                    deprecated.span_ident(),
+                    "only nullable or defaulted arguments may be deprecated",
+                );
+                return None;
+            }
+        }
+
+        let (default, default_with) = match attr.default.as_ref().map(|v| v.as_ref().clone()) {
+            Some(Some(expr)) if !is_const_expr(&expr) => {
+                (None, Some(syn::parse_quote! { || (#expr).into() }))
+            }
+            default => (default, attr.default_with.map(SpanContainer::into_inner)),
+        };
 
         Some(Self::Regular(OnField {
             name,
             ty: argument.ty.as_ref().clone(),
             description: attr.description.as_ref().map(|d| d.as_ref().value()),
-            default: attr.default.as_ref().map(|v| v.as_ref().clone()),
+            default,
+            default_with,
+            deprecated: attr
+                .deprecated
+                .as_deref()
+                .map(|d| d.as_ref().map(syn::LitStr::value)),
         }))
     }
 }
+
+/// Checks whether the given default value `expr` of an argument is a constant
+/// one, so it may be evaluated once to be put into GraphQL schema.
+///
+/// Literals, paths (like enum variants or constants) and the arrays, tuples,
+/// structs, tuple structs and operators composed of them are considered
+/// constant, as well as their conversions via `.into()`, `.to_owned()` or
+/// `.to_string()`, while other function and method calls, macros, blocks, etc.
+/// are not.
+fn is_const_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) | syn::Expr::Path(_) => true,
+        syn::Expr::Unary(e) => is_const_expr(&e.expr),
+        syn::Expr::Binary(e) => is_const_expr(&e.left) && is_const_expr(&e.right),
+        syn::Expr::Paren(e) => is_const_expr(&e.expr),
+        syn::Expr::Group(e) => is_const_expr(&e.expr),
+        syn::Expr::Cast(e) => is_const_expr(&e.expr),
+        syn::Expr::Reference(e) => is_const_expr(&e.expr),
+        syn::Expr::Array(e) => e.elems.iter().all(is_const_expr),
+        syn::Expr::Tuple(e) => e.elems.iter().all(is_const_expr),
+        syn::Expr::Struct(e) => e.rest.is_none() && e.fields.iter().all(|f| is_const_expr(&f.expr)),
+        // Tuple structs and enum variants (like `Some(1)`), as opposed to
+        // function calls, are conventionally named in `UpperCamelCase`.
+        syn::Expr::Call(e) => {
+            let is_constructor = match &*e.func {
+                syn::Expr::Path(p) => matches!(
+                    p.path.segments.last(),
+                    Some(s) if s.ident.to_string().starts_with(char::is_uppercase),
+                ),
+                _ => false,
+            };
+            is_constructor && e.args.iter().all(is_const_expr)
+        }
+        syn::Expr::MethodCall(e) => {
+            e.args.is_empty()
+                && e.turbofish.is_none()
+                && ["into", "to_owned", "to_string"]
+                    .iter()
+                    .any(|m| e.method == m)
+                && is_const_expr(&e.receiver)
+        }
+        _ => false,
+    }
+}
@@ -5,8 +5,10 @@
 
 pub(crate) mod arg;
 
+use std::convert::{TryFrom, TryInto as _};
+
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
@@ -16,7 +18,7 @@ use syn::{
 
 use crate::{
     common::{
-        gen,
+        extensions, gen,
         parse::{
             attr::{err, OptionExt as _},
             ParseBufferExt as _,
@@ -59,6 +61,12 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Deprecation
     pub(crate) deprecated: Option<SpanContainer<Option<syn::LitStr>>>,
 
+    /// Explicitly specified date (in RFC 3339 format) this deprecated
+    /// [GraphQL field][1] is going to be removed at.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) removal_date: Option<SpanContainer<syn::LitStr>>,
+
     /// Explicitly specified marker indicating that this method (or struct
     /// field) should be omitted by code generation and not considered as the
     /// [GraphQL field][1] definition.
@@ -79,6 +87,61 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
     /// [2]: https://spec.graphql.org/June2018/#sec-Objects
     pub(crate) downcast: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified [`RateLimit`] of this [GraphQL field][1], checked
+    /// against the [`RateLimiter`] of the context before resolving it.
+    ///
+    /// [`RateLimiter`]: juniper::RateLimiter
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) rate_limit: Option<SpanContainer<RateLimit>>,
+
+    /// Explicitly specified feature flag this [GraphQL field][1] is only
+    /// resolved behind, checked against the [`FeatureFlags`] of the context.
+    ///
+    /// [`FeatureFlags`]: juniper::FeatureFlags
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) feature_flag: Option<SpanContainer<syn::LitStr>>,
+
+    /// Explicitly specified authorization guard of this [GraphQL field][1],
+    /// called with the context before resolving it.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) guard: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified function returning the scope of the cached values
+    /// of this [GraphQL field][1], called with the context. Makes the field
+    /// cached in the [`Cache`] of the context.
+    ///
+    /// [`Cache`]: juniper::Cache
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) cache_key: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified maximum number of list elements of this
+    /// [GraphQL field][1] resolved concurrently.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) max_concurrency: Option<SpanContainer<usize>>,
+
+    /// Explicitly specified [`Timeout`] of resolving this async
+    /// [GraphQL field][1], measured by the [`Timer`] of the context.
+    ///
+    /// [`Timer`]: juniper::Timer
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) timeout: Option<SpanContainer<Timeout>>,
+
+    /// Explicitly specified weight of this [GraphQL field][1] in the query
+    /// complexity, checked against the maximum complexity of the schema.
+    ///
+    /// If [`None`], then the field weighs `1`.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) complexity: Option<SpanContainer<usize>>,
+
+    /// Explicitly specified extension data of this [GraphQL field][1], exposed
+    /// via introspection.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) extensions: extensions::Attr,
 }
 
 impl Parse for Attr {
@@ -115,6 +178,20 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "removal_date" => {
+                    input.parse::<token::Eq>()?;
+                    let date = input.parse::<syn::LitStr>()?;
+                    if !is_rfc3339_date_time(&date.value()) {
+                        return Err(syn::Error::new(
+                            date.span(),
+                            "`removal_date` attribute argument must be an RFC 3339 date-time, \
+                             e.g. `2022-01-01T00:00:00Z`",
+                        ));
+                    }
+                    out.removal_date
+                        .replace(SpanContainer::new(ident.span(), Some(date.span()), date))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
@@ -123,6 +200,75 @@ impl Parse for Attr {
                     .downcast
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "feature_flag" => {
+                    input.parse::<token::Eq>()?;
+                    let flag = input.parse::<syn::LitStr>()?;
+                    out.feature_flag
+                        .replace(SpanContainer::new(ident.span(), Some(flag.span()), flag))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<syn::ExprPath>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "cache_key" => {
+                    input.parse::<token::Eq>()?;
+                    let func = input.parse::<syn::ExprPath>()?;
+                    out.cache_key
+                        .replace(SpanContainer::new(ident.span(), Some(func.span()), func))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "complexity" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitInt>()?;
+                    out.complexity
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(lit.span()),
+                            lit.base10_parse::<usize>()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "max_concurrency" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitInt>()?;
+                    let max = lit.base10_parse::<usize>()?;
+                    if max == 0 {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "`max_concurrency` should be greater than zero",
+                        ));
+                    }
+                    out.max_concurrency
+                        .replace(SpanContainer::new(ident.span(), Some(lit.span()), max))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "rate_limit" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitStr>()?;
+                    out.rate_limit
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(lit.span()),
+                            lit.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "timeout" => {
+                    input.parse::<token::Eq>()?;
+                    let lit = input.parse::<syn::LitStr>()?;
+                    out.timeout
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(lit.span()),
+                            lit.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "extensions" => extensions::parse(input, &mut out.extensions)?,
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -141,8 +287,17 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
+            removal_date: try_merge_opt!(removal_date: self, another),
             ignore: try_merge_opt!(ignore: self, another),
             downcast: try_merge_opt!(downcast: self, another),
+            rate_limit: try_merge_opt!(rate_limit: self, another),
+            feature_flag: try_merge_opt!(feature_flag: self, another),
+            guard: try_merge_opt!(guard: self, another),
+            cache_key: try_merge_opt!(cache_key: self, another),
+            max_concurrency: try_merge_opt!(max_concurrency: self, another),
+            timeout: try_merge_opt!(timeout: self, another),
+            complexity: try_merge_opt!(complexity: self, another),
+            extensions: try_merge_hashmap!(extensions: self, another),
         })
     }
 
@@ -159,7 +314,16 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.deprecated.is_some()
+                || attr.removal_date.is_some()
                 || attr.downcast.is_some()
+                || attr.rate_limit.is_some()
+                || attr.feature_flag.is_some()
+                || attr.guard.is_some()
+                || attr.cache_key.is_some()
+                || attr.max_concurrency.is_some()
+                || attr.timeout.is_some()
+                || attr.complexity.is_some()
+                || !attr.extensions.is_empty()
             {
                 return Err(syn::Error::new(
                     ignore.span(),
@@ -172,7 +336,16 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.deprecated.is_some()
+                || attr.removal_date.is_some()
                 || attr.ignore.is_some()
+                || attr.rate_limit.is_some()
+                || attr.feature_flag.is_some()
+                || attr.guard.is_some()
+                || attr.cache_key.is_some()
+                || attr.max_concurrency.is_some()
+                || attr.timeout.is_some()
+                || attr.complexity.is_some()
+                || !attr.extensions.is_empty()
             {
                 return Err(syn::Error::new(
                     downcast.span(),
@@ -195,10 +368,120 @@ impl Attr {
             });
         }
 
+        if let Some(date) = &attr.removal_date {
+            if attr.deprecated.is_none() {
+                return Err(syn::Error::new(
+                    date.span_ident(),
+                    "`removal_date` attribute argument requires the field to be deprecated",
+                ));
+            }
+        }
+
         Ok(attr)
     }
 }
 
+/// Limit of the rate at which a [GraphQL field][1] may be resolved, parsed from
+/// a `<max>/<period>` string (e.g. `10/min`).
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RateLimit {
+    /// Maximum number of resolutions allowed during a single period.
+    max: u32,
+
+    /// Length of the period in seconds.
+    per_secs: u64,
+}
+
+impl TryFrom<syn::LitStr> for RateLimit {
+    type Error = syn::Error;
+
+    fn try_from(lit: syn::LitStr) -> syn::Result<Self> {
+        let err = || {
+            syn::Error::new(
+                lit.span(),
+                "invalid rate limit, expected `<max>/<period>` with period being one of `sec`, \
+                 `min`, `hour` or `day`, e.g. `10/min`",
+            )
+        };
+
+        let value = lit.value();
+        let (max, period) = value.split_once('/').ok_or_else(err)?;
+        let max = max.trim().parse::<u32>().map_err(|_| err())?;
+        let per_secs = match period.trim() {
+            "s" | "sec" | "second" => 1,
+            "m" | "min" | "minute" => 60,
+            "h" | "hour" => 3_600,
+            "d" | "day" => 86_400,
+            _ => return Err(err()),
+        };
+        Ok(Self { max, per_secs })
+    }
+}
+
+impl ToTokens for RateLimit {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let (max, per_secs) = (self.max, self.per_secs);
+        quote! {
+            ::juniper::RateLimit::new(#max, ::std::time::Duration::from_secs(#per_secs))
+        }
+        .to_tokens(into)
+    }
+}
+
+/// Maximum duration of resolving a [GraphQL field][1], parsed from the
+/// `<amount><unit>` form, e.g. `5s` or `500ms`.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Timeout {
+    /// Duration in milliseconds.
+    millis: u64,
+}
+
+impl TryFrom<syn::LitStr> for Timeout {
+    type Error = syn::Error;
+
+    fn try_from(lit: syn::LitStr) -> syn::Result<Self> {
+        let err = || {
+            syn::Error::new(
+                lit.span(),
+                "invalid timeout, expected `<amount><unit>` with unit being one of `ms`, `s`, \
+                 `min` or `h`, e.g. `5s`",
+            )
+        };
+
+        let value = lit.value();
+        let value = value.trim();
+        let unit_at = value.find(|c: char| !c.is_ascii_digit()).ok_or_else(err)?;
+        let (amount, unit) = value.split_at(unit_at);
+        let amount = amount.parse::<u64>().map_err(|_| err())?;
+        let multiplier = match unit.trim() {
+            "ms" => 1,
+            "s" | "sec" => 1_000,
+            "m" | "min" => 60_000,
+            "h" | "hour" => 3_600_000,
+            _ => return Err(err()),
+        };
+        let millis = amount
+            .checked_mul(multiplier)
+            .filter(|ms| *ms > 0)
+            .ok_or_else(err)?;
+        Ok(Self { millis })
+    }
+}
+
+impl ToTokens for Timeout {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let millis = self.millis;
+        quote! {
+            ::std::time::Duration::from_millis(#millis)
+        }
+        .to_tokens(into)
+    }
+}
+
 /// Representation of a [GraphQL field][1] for code generation.
 ///
 /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
@@ -230,6 +513,12 @@ pub(crate) struct Definition {
     /// [2]: https://spec.graphql.org/June2018/#sec-Deprecation
     pub(crate) deprecated: Option<Option<String>>,
 
+    /// Date (in RFC 3339 format) this deprecated [GraphQL field][1] is going
+    /// to be removed at, if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) removal_date: Option<String>,
+
     /// Ident of the Rust method (or struct field) representing this
     /// [GraphQL field][1].
     ///
@@ -256,6 +545,50 @@ pub(crate) struct Definition {
     ///
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
     pub(crate) is_async: bool,
+
+    /// [`RateLimit`] to check before resolving this [GraphQL field][1], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) rate_limit: Option<RateLimit>,
+
+    /// Feature flag to check before resolving this [GraphQL field][1], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) feature_flag: Option<String>,
+
+    /// Authorization guard to check before resolving this [GraphQL field][1],
+    /// if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) guard: Option<syn::ExprPath>,
+
+    /// Function returning the scope of the cached values of this
+    /// [GraphQL field][1], if it's cached.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) cache_key: Option<syn::ExprPath>,
+
+    /// Maximum number of list elements of this [GraphQL field][1] resolved
+    /// concurrently, if limited.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) max_concurrency: Option<usize>,
+
+    /// [`Timeout`] of resolving this [GraphQL field][1], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) timeout: Option<Timeout>,
+
+    /// Weight of this [GraphQL field][1] in the query complexity, if it
+    /// differs from the default `1`.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) complexity: Option<usize>,
+
+    /// Extension data of this [GraphQL field][1] to put into GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) extensions: extensions::Definition,
 }
 
 impl Definition {
@@ -284,23 +617,104 @@ impl Definition {
         }
     }
 
-    /// Returns generated code that panics about [GraphQL fields][1] tried to be
+    /// Returns generated code erroring about [GraphQL fields][1] tried to be
     /// resolved asynchronously in the [`GraphQLValue::resolve_field`] method
-    /// (which is synchronous itself).
+    /// (which is synchronous itself), as happens when executing a query via
+    /// `juniper::execute_sync()`.
     ///
     /// [`GraphQLValue::resolve_field`]: juniper::GraphQLValue::resolve_field
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
     #[must_use]
-    pub(crate) fn method_resolve_field_panic_async_field_tokens(
+    pub(crate) fn method_resolve_field_err_async_field_tokens(
         field_names: &[&str],
         scalar: &scalar::Type,
     ) -> TokenStream {
         quote! {
-            #( #field_names )|* => panic!(
-                "Tried to resolve async field `{}` on type `{}` with a sync resolver",
-                field,
-                <Self as ::juniper::GraphQLType<#scalar>>::name(info).unwrap(),
-            ),
+            #( #field_names )|* => Err(::juniper::FieldError::new(
+                format!(
+                    "Field `{}` of type `{}` is async and can't be resolved synchronously, \
+                     use `juniper::execute()` instead of `juniper::execute_sync()`",
+                    field,
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info).unwrap(),
+                ),
+                ::juniper::Value::null(),
+            )),
+        }
+    }
+
+    /// Returns generated code checking whether this [GraphQL field][1] may be
+    /// resolved: its feature flag against the [`FeatureFlags`] of the context,
+    /// its authorization guard against the context, and its [`RateLimit`]
+    /// against the [`RateLimiter`] of the context.
+    ///
+    /// Each check evaluates to [`Result`]`<(), `[`FieldError`]`>`.
+    ///
+    /// [`FeatureFlags`]: juniper::FeatureFlags
+    /// [`FieldError`]: juniper::FieldError
+    /// [`RateLimiter`]: juniper::RateLimiter
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    fn guard_checks_tokens(&self, scalar: &scalar::Type) -> Vec<TokenStream> {
+        let name = &self.name;
+
+        let feature_flag = self.feature_flag.as_ref().map(|flag| {
+            quote! { executor.check_feature_flag(#flag) }
+        });
+        let guard = self.guard.as_ref().map(|guard| {
+            quote! { executor.check_guard(#guard) }
+        });
+        let rate_limit = self.rate_limit.as_ref().map(|limit| {
+            let type_name = Self::type_name_tokens(scalar);
+            quote! {
+                #type_name.and_then(|ty| {
+                    executor.check_rate_limit(&format!("{}.{}", ty, #name), #limit)
+                })
+            }
+        });
+
+        feature_flag
+            .into_iter()
+            .chain(guard)
+            .chain(rate_limit)
+            .collect()
+    }
+
+    /// Returns generated code constructing the [`CacheKey`] of the value of
+    /// this [GraphQL field][1], scoped by the given `cache_key` function.
+    ///
+    /// Evaluates to [`Result`]`<`[`CacheKey`]`, `[`FieldError`]`>`.
+    ///
+    /// [`CacheKey`]: juniper::CacheKey
+    /// [`FieldError`]: juniper::FieldError
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    fn cache_key_tokens(&self, cache_key: &syn::ExprPath, scalar: &scalar::Type) -> TokenStream {
+        let name = &self.name;
+        let type_name = Self::type_name_tokens(scalar);
+
+        quote! {
+            #type_name.map(|ty| {
+                executor.cache_key(ty, #name, args, #cache_key(executor.context()))
+            })
+        }
+    }
+
+    /// Returns generated code retrieving the name of the type this
+    /// [GraphQL field][1] is resolved on, erroring if the type is unnamed.
+    ///
+    /// Evaluates to [`Result`]`<&`[`str`]`, `[`FieldError`]`>`.
+    ///
+    /// [`FieldError`]: juniper::FieldError
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    fn type_name_tokens(scalar: &scalar::Type) -> TokenStream {
+        quote! {
+            <Self as ::juniper::GraphQLType<#scalar>>::name(info).ok_or_else(|| {
+                ::juniper::FieldError::<#scalar>::from(format!(
+                    "Field `{}` is resolved on an unnamed type",
+                    field,
+                ))
+            })
         }
     }
 
@@ -333,12 +747,61 @@ impl Definition {
             >>::Type
         };
 
+        let args_check = self.flattened_arguments_check_tokens(scalar);
+
         quote! {
             #( #args_marks )*
+            #args_check
             <#resolved_ty as ::juniper::marker::IsOutputType<#scalar>>::mark();
         }
     }
 
+    /// Returns generated code checking statically that the arguments of this
+    /// [GraphQL field][1] flattened via `#[graphql(flatten)]` don't collide
+    /// with each other or with its other arguments, if it has any.
+    ///
+    /// Names of the flattened arguments don't depend on the scalar, so any
+    /// supported one is used for the generic `scalar`.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    fn flattened_arguments_check_tokens(&self, scalar: &scalar::Type) -> Option<TokenStream> {
+        let args = self.arguments.as_ref()?;
+        let flattened = args
+            .iter()
+            .filter_map(|arg| match arg {
+                MethodArgument::Flattened(ty) => Some(ty),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if flattened.is_empty() {
+            return None;
+        }
+
+        let own = args
+            .iter()
+            .filter_map(MethodArgument::as_regular)
+            .map(|arg| &arg.name);
+        let scalar = scalar.default_ty();
+        let msg = format!(
+            "arguments flattened into the `{}` field collide with its other arguments",
+            self.name,
+        );
+        Some(quote! {
+            const _: () = assert!(
+                !::juniper::input_flattening::InputObjectFieldNames {
+                    own: &[#( #own ),*],
+                    flattened: &[#(
+                        &<#flattened as ::juniper::input_flattening::FlattenInputObject<#scalar>>
+                            ::FIELD_NAMES
+                    ),*],
+                }
+                .have_collisions(),
+                #msg,
+            );
+        })
+    }
+
     /// Returns generated code for the [`GraphQLType::meta`] method, which
     /// registers this [GraphQL field][1] in [`Registry`].
     ///
@@ -371,6 +834,17 @@ impl Definition {
             quote! { .deprecated(#reason) }
         });
 
+        let removal_date = self
+            .removal_date
+            .as_ref()
+            .map(|date| quote! { .with_removal_date(#date) });
+
+        let complexity = self
+            .complexity
+            .map(|complexity| quote! { .complexity(#complexity) });
+
+        let extensions = extensions::meta_tokens(&self.extensions);
+
         let args = self
             .arguments
             .iter()
@@ -381,6 +855,9 @@ impl Definition {
                 #( #args )*
                 #description
                 #deprecated
+                #removal_date
+                #complexity
+                #extensions
         }
     }
 
@@ -409,7 +886,7 @@ impl Definition {
                 .as_ref()
                 .unwrap()
                 .iter()
-                .map(|arg| arg.method_resolve_field_tokens(scalar));
+                .map(|arg| arg.method_resolve_field_tokens(scalar, false));
 
             let rcv = self.has_receiver.then(|| {
                 quote! { self, }
@@ -426,10 +903,22 @@ impl Definition {
         };
 
         let resolving_code = gen::sync_resolving_code();
+        let mut resolving_code = quote! {
+            let res: #ty = #res;
+            #resolving_code
+        };
+        if let Some(cache_key) = &self.cache_key {
+            let key = self.cache_key_tokens(cache_key, scalar);
+            resolving_code = quote! {
+                executor.resolve_cached(#key?, || { #resolving_code })
+            };
+        }
+
+        let guards = self.guard_checks_tokens(scalar);
 
         Some(quote! {
             #name => {
-                let res: #ty = #res;
+                #( #guards?; )*
                 #resolving_code
             }
         })
@@ -455,7 +944,7 @@ impl Definition {
                 .as_ref()
                 .unwrap()
                 .iter()
-                .map(|arg| arg.method_resolve_field_tokens(scalar));
+                .map(|arg| arg.method_resolve_field_tokens(scalar, true));
 
             let rcv = self.has_receiver.then(|| {
                 quote! { self, }
@@ -474,11 +963,48 @@ impl Definition {
             fut = quote! { ::juniper::futures::future::ready(#fut) };
         }
 
-        let resolving_code = gen::async_resolving_code(Some(&ty));
+        let mut resolving_code = gen::async_resolving_code(Some(&ty), self.max_concurrency);
+        if let Some(timeout) = &self.timeout {
+            let type_name = Self::type_name_tokens(scalar);
+            resolving_code = quote! {
+                match #type_name {
+                    Ok(ty) => Box::pin(executor.resolve_with_timeout(
+                        &format!("{}.{}", ty, #name),
+                        #timeout,
+                        #resolving_code,
+                    )),
+                    Err(e) => Box::pin(::juniper::futures::future::err(e)),
+                }
+            };
+        }
+
+        let mut resolving_code = quote! {
+            let fut = #fut;
+            #resolving_code
+        };
+        if let Some(cache_key) = &self.cache_key {
+            let key = self.cache_key_tokens(cache_key, scalar);
+            // The closure may return early with an error of an invalid argument.
+            resolving_code = quote! {
+                match #key {
+                    Ok(key) => Box::pin(executor.resolve_cached_async(
+                        key,
+                        move || -> ::juniper::BoxFuture<'_, ::juniper::ExecutionResult<#scalar>> {
+                            #resolving_code
+                        },
+                    )),
+                    Err(e) => Box::pin(::juniper::futures::future::err(e)),
+                }
+            };
+        }
+
+        let guards = self.guard_checks_tokens(scalar);
 
         quote! {
             #name => {
-                let fut = #fut;
+                #( if let Err(e) = #guards {
+                    return Box::pin(::juniper::futures::future::err(e));
+                } )*
                 #resolving_code
             }
         }
@@ -504,7 +1030,7 @@ impl Definition {
                 .as_ref()
                 .unwrap()
                 .iter()
-                .map(|arg| arg.method_resolve_field_tokens(scalar));
+                .map(|arg| arg.method_resolve_field_tokens(scalar, false));
 
             let rcv = self.has_receiver.then(|| {
                 quote! { self, }
@@ -561,3 +1087,65 @@ pub(crate) fn all_different(fields: &[Definition]) -> bool {
     names.dedup();
     names.len() == fields.len()
 }
+
+/// Checks whether the given string is an [RFC 3339] date-time (e.g.
+/// `2022-01-01T00:00:00Z`), as expected by `removal_date` attribute's argument.
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+#[must_use]
+fn is_rfc3339_date_time(s: &str) -> bool {
+    let b = s.as_bytes();
+    let num = |from: usize, len: usize| -> Option<u32> {
+        let digits = b.get(from..from + len)?;
+        digits.iter().try_fold(0, |n, d| {
+            d.is_ascii_digit().then(|| n * 10 + u32::from(d - b'0'))
+        })
+    };
+    let sep = |at: usize, seps: &[u8]| matches!(b.get(at), Some(c) if seps.contains(c));
+
+    let is_date_time = matches!(
+        (
+            num(0, 4),
+            num(5, 2),
+            num(8, 2),
+            num(11, 2),
+            num(14, 2),
+            num(17, 2)
+        ),
+        (
+            Some(_),
+            Some(1..=12),
+            Some(1..=31),
+            Some(0..=23),
+            Some(0..=59),
+            Some(0..=60)
+        ),
+    ) && sep(4, b"-")
+        && sep(7, b"-")
+        && sep(10, b"Tt")
+        && sep(13, b":")
+        && sep(16, b":");
+    if !is_date_time {
+        return false;
+    }
+
+    let mut offset = 19;
+    if sep(offset, b".") {
+        let frac = b[offset + 1..]
+            .iter()
+            .take_while(|d| d.is_ascii_digit())
+            .count();
+        if frac == 0 {
+            return false;
+        }
+        offset += frac + 1;
+    }
+    match &b[offset..] {
+        [b'Z'] | [b'z'] => true,
+        [b'+' | b'-', _, _, b':', _, _] => matches!(
+            (num(offset + 1, 2), num(offset + 4, 2)),
+            (Some(0..=23), Some(0..=59)),
+        ),
+        _ => false,
+    }
+}
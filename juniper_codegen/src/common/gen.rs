@@ -27,17 +27,24 @@ pub(crate) fn sync_resolving_code() -> TokenStream {
 /// Optional `ty` argument may be used to annotate a concrete type of the resolving
 /// [GraphQL type][1] (the [`Future::Output`]).
 ///
+/// Optional `max_concurrency` argument limits the number of list elements resolved concurrently,
+/// if the resolving [GraphQL type][1] is a list.
+///
 /// [`Future`]: std::future::Future
 /// [`Future::Output`]: std::future::Future::Output
 /// [1]: https://spec.graphql.org/June2018/#sec-Types
-pub(crate) fn async_resolving_code(ty: Option<&syn::Type>) -> TokenStream {
+pub(crate) fn async_resolving_code(
+    ty: Option<&syn::Type>,
+    max_concurrency: Option<usize>,
+) -> TokenStream {
     let ty = ty.map(|t| quote! { : #t });
+    let max_concurrency = max_concurrency.map(|max| quote! { .with_max_concurrency(#max) });
 
     quote! {
         Box::pin(::juniper::futures::FutureExt::then(fut, move |res #ty| async move {
             match ::juniper::IntoResolvable::into(res, executor.context())? {
                 Some((ctx, r)) => {
-                    let subexec = executor.replaced_context(ctx);
+                    let subexec = executor.replaced_context(ctx)#max_concurrency;
                     subexec.resolve_with_ctx_async(info, &r).await
                 },
                 None => Ok(::juniper::Value::null()),
@@ -1,5 +1,6 @@
 //! Common functions, definitions and extensions for code generation, used by this crate.
 
+pub(crate) mod extensions;
 pub(crate) mod field;
 pub(crate) mod gen;
 pub(crate) mod parse;
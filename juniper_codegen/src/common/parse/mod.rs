@@ -119,6 +119,11 @@ pub(crate) trait TypeExt {
     /// Returns the topmost [`syn::Ident`] of this [`syn::TypePath`], if any.
     #[must_use]
     fn topmost_ident(&self) -> Option<&syn::Ident>;
+
+    /// Indicates whether this [`syn::Type`] is an [`Option`] or a `Nullable`,
+    /// so represents a nullable GraphQL type.
+    #[must_use]
+    fn is_nullable(&self) -> bool;
 }
 
 impl TypeExt for syn::Type {
@@ -240,6 +245,10 @@ impl TypeExt for syn::Type {
         .last()
         .map(|s| &s.ident)
     }
+
+    fn is_nullable(&self) -> bool {
+        matches!(self.topmost_ident(), Some(id) if id == "Option" || id == "Nullable")
+    }
 }
 
 /// Extension of [`syn::Generics`] providing common function widely used by this crate for parsing.
@@ -42,6 +42,13 @@ pub fn impl_enum(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Result<Toke
                     return None;
                 }
             };
+            field_attrs.reject_scalar_only(&error);
+            if let Some(validate) = &field_attrs.validate {
+                error.unsupported_attribute_within(
+                    validate.span_ident(),
+                    UnsupportedAttribute::Validate,
+                );
+            }
 
             let field_name = field.ident;
             let name = field_attrs
@@ -98,6 +105,8 @@ pub fn impl_enum(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Result<Toke
                 is_type_inferred: true,
                 is_async: false,
                 default: None,
+                validate: None,
+                is_flattened: false,
                 span,
             })
         })
@@ -124,6 +133,18 @@ pub fn impl_enum(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Result<Toke
         error.unsupported_attribute(scalar.span_ident(), UnsupportedAttribute::Scalar);
     }
 
+    if let Some(builder) = attrs.builder {
+        error.unsupported_attribute(builder.span_ident(), UnsupportedAttribute::Builder);
+    }
+
+    if let Some(validate) = attrs.validate {
+        error.unsupported_attribute(validate.span_ident(), UnsupportedAttribute::Validate);
+    }
+
+    if let Some(one_of) = attrs.one_of {
+        error.unsupported_attribute(one_of.span_ident(), UnsupportedAttribute::OneOf);
+    }
+
     if !attrs.is_internal && name.starts_with("__") {
         error.no_double_underscore(if let Some(name) = attrs.name {
             name.span_ident()
@@ -143,10 +164,12 @@ pub fn impl_enum(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Result<Toke
         fields,
         // NOTICE: only unit variants allow -> no generics possible
         generics: syn::Generics::default(),
-        interfaces: vec![],
         include_type_generics: true,
         generic_scalar: true,
         no_async: attrs.no_async.is_some(),
+        validate: None,
+        one_of: false,
+        is_arguments: false,
     };
 
     Ok(definition.into_enum_tokens())
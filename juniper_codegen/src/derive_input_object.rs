@@ -1,5 +1,6 @@
 #![allow(clippy::match_wild_err_arm)]
 use crate::{
+    common::parse::TypeExt as _,
     result::{GraphQLScope, UnsupportedAttribute},
     util::{self, span_container::SpanContainer, RenameRule},
 };
@@ -32,6 +33,7 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
         .map(SpanContainer::into_inner)
         .unwrap_or_else(|| ident.to_string());
 
+    let mut builder_fields = Vec::new();
     let fields = fields
         .into_iter()
         .filter_map(|field| {
@@ -46,9 +48,14 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
                     return None;
                 }
             };
+            field_attrs.reject_scalar_only(&error);
 
             let field_ident = field.ident.as_ref().unwrap();
+            let is_flattened = field_attrs.flatten.is_some();
             let name = match field_attrs.name {
+                // Flattened fields have no GraphQL name on their own, so are
+                // named in a way never colliding with the others.
+                _ if is_flattened => format!("...{}", field_ident.unraw()),
                 Some(ref name) => name.to_string(),
                 None => attrs
                     .rename
@@ -56,18 +63,21 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
                     .apply(&field_ident.unraw().to_string()),
             };
 
-            if let Some(span) = field_attrs.skip {
-                error.unsupported_attribute_within(span.span(), UnsupportedAttribute::Skip)
+            if is_flattened {
+                let flatten_span = field_attrs.flatten.as_ref().unwrap().span();
+                if field_attrs.name.is_some() || field_attrs.default.is_some() {
+                    error.emit_custom(
+                        flatten_span,
+                        "`flatten` can't be combined with `name` or `default`",
+                    );
+                }
             }
 
-            if let Some(span) = field_attrs.deprecation {
-                error.unsupported_attribute_within(
-                    span.span_ident(),
-                    UnsupportedAttribute::Deprecation,
-                )
+            if let Some(span) = field_attrs.skip {
+                error.unsupported_attribute_within(span.span(), UnsupportedAttribute::Skip)
             }
 
-            if name.starts_with("__") {
+            if !is_flattened && name.starts_with("__") {
                 error.no_double_underscore(if let Some(name) = field_attrs.name {
                     name.span_ident()
                 } else {
@@ -75,6 +85,30 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
                 });
             }
 
+            if attrs.one_of.is_some() {
+                if !is_option(&field.ty) {
+                    error.emit_custom(
+                        field.ty.span(),
+                        "fields of a `one_of` input object must be `Option`s",
+                    );
+                }
+                if let Some(default) = &field_attrs.default {
+                    error.emit_custom(
+                        default.span_ident(),
+                        "fields of a `one_of` input object can't have a default value",
+                    );
+                }
+            }
+
+            if let Some(deprecation) = &field_attrs.deprecation {
+                if field_attrs.default.is_none() && !field.ty.is_nullable() {
+                    error.emit_custom(
+                        deprecation.span_ident(),
+                        "only nullable or defaulted fields may be deprecated",
+                    );
+                }
+            }
+
             let resolver_code = quote!(#field_ident);
 
             let default = field_attrs
@@ -84,16 +118,24 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
                     None => quote! { Default::default() },
                 });
 
+            builder_fields.push(BuilderField {
+                ident: field_ident.clone(),
+                ty: field.ty.clone(),
+                default: default.clone(),
+            });
+
             Some(util::GraphQLTypeDefinitionField {
                 name,
                 _type: field.ty,
                 args: Vec::new(),
                 description: field_attrs.description.map(SpanContainer::into_inner),
-                deprecation: None,
+                deprecation: field_attrs.deprecation.map(SpanContainer::into_inner),
                 resolver_code,
                 is_type_inferred: true,
                 is_async: false,
                 default,
+                validate: field_attrs.validate.map(SpanContainer::into_inner),
+                is_flattened,
                 span,
             })
         })
@@ -133,6 +175,17 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
 
     proc_macro_error::abort_if_dirty();
 
+    let builder = if attrs.builder.is_some() {
+        Some(builder_tokens(
+            ident,
+            &ast.vis,
+            &ast.generics,
+            &builder_fields,
+        ))
+    } else {
+        None
+    };
+
     let definition = util::GraphQLTypeDefiniton {
         name,
         _type: syn::parse_str(&ast.ident.to_string()).unwrap(),
@@ -141,11 +194,120 @@ pub fn impl_input_object(ast: syn::DeriveInput, error: GraphQLScope) -> syn::Res
         description: attrs.description.map(SpanContainer::into_inner),
         fields,
         generics: ast.generics,
-        interfaces: vec![],
         include_type_generics: true,
         generic_scalar: true,
         no_async: attrs.no_async.is_some(),
+        validate: attrs.validate.map(SpanContainer::into_inner),
+        one_of: attrs.one_of.is_some(),
+        is_arguments: matches!(error, GraphQLScope::DeriveArguments),
     };
 
-    Ok(definition.into_input_object_tokens())
+    let mut output = definition.into_input_object_tokens();
+    output.extend(builder);
+    Ok(output)
+}
+
+/// Field of an input object, as seen by its builder.
+struct BuilderField {
+    ident: syn::Ident,
+    ty: syn::Type,
+    default: Option<TokenStream>,
+}
+
+/// Generates the builder of the input object `ident`, enabled via `#[graphql(builder)]`.
+///
+/// Fields having a default value or an `Option` type may be omitted, while the
+/// others are required to be set before building.
+fn builder_tokens(
+    ident: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    fields: &[BuilderField],
+) -> TokenStream {
+    let builder_ident = quote::format_ident!("{}Builder", ident);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let struct_doc = format!("Builder of the [`{}`] input object.", ident);
+    let struct_fields = fields.iter().map(|BuilderField { ident, ty, .. }| {
+        quote! { #ident: ::std::option::Option<#ty>, }
+    });
+    let empty_fields = fields.iter().map(|BuilderField { ident, .. }| {
+        quote! { #ident: ::std::option::Option::None, }
+    });
+    let filled_fields = fields.iter().map(|BuilderField { ident, .. }| {
+        quote! { #ident: ::std::option::Option::Some(input.#ident), }
+    });
+
+    let setters = fields.iter().map(|BuilderField { ident, ty, .. }| {
+        let doc = format!("Sets the `{}` field.", ident.unraw());
+        quote! {
+            #[doc = #doc]
+            pub fn #ident(mut self, value: impl ::std::convert::Into<#ty>) -> Self {
+                self.#ident = ::std::option::Option::Some(value.into());
+                self
+            }
+        }
+    });
+
+    let built_fields = fields.iter().map(|BuilderField { ident, ty, default }| {
+        let value = if let Some(default) = default {
+            quote! { self.#ident.unwrap_or_else(|| #default) }
+        } else if is_option(ty) {
+            quote! { self.#ident.unwrap_or_default() }
+        } else {
+            let msg = format!("missing required field `{}`", ident.unraw());
+            quote! { self.#ident.ok_or_else(|| ::std::string::String::from(#msg))? }
+        };
+        quote! { #ident: #value, }
+    });
+
+    quote! {
+        #[doc = #struct_doc]
+        #vis struct #builder_ident #generics #where_clause {
+            #( #struct_fields )*
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Creates a new builder of this input object.
+            pub fn builder() -> #builder_ident #ty_generics {
+                ::std::default::Default::default()
+            }
+        }
+
+        impl #impl_generics ::std::default::Default for #builder_ident #ty_generics
+            #where_clause
+        {
+            fn default() -> Self {
+                Self { #( #empty_fields )* }
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#ident #ty_generics>
+            for #builder_ident #ty_generics #where_clause
+        {
+            fn from(input: #ident #ty_generics) -> Self {
+                Self { #( #filled_fields )* }
+            }
+        }
+
+        impl #impl_generics #builder_ident #ty_generics #where_clause {
+            #( #setters )*
+
+            /// Builds the input object, failing if any of its required fields
+            /// hasn't been set.
+            pub fn build(self) -> ::std::result::Result<#ident #ty_generics, ::std::string::String> {
+                ::std::result::Result::Ok(#ident { #( #built_fields )* })
+            }
+        }
+    }
+}
+
+/// Checks whether the given `ty` is an `Option`, so may be omitted.
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            matches!(path.segments.last(), Some(seg) if seg.ident == "Option")
+        }
+        _ => false,
+    }
 }
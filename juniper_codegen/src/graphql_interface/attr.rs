@@ -8,7 +8,7 @@ use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
 use crate::{
     common::{
-        field,
+        extensions, field,
         parse::{self, TypeExt as _},
         scalar,
     },
@@ -198,6 +198,12 @@ fn expand_on_trait(
 
         fields,
         implementers,
+        interfaces: attr
+            .interfaces
+            .iter()
+            .map(|ty| ty.as_ref().clone())
+            .collect(),
+        extensions: extensions::definition(&attr.extensions),
     };
 
     // Attach the `juniper::AsDynGraphQLValue` on top of the trait if dynamic dispatch is used.
@@ -455,6 +461,16 @@ impl TraitMethod {
         };
         ty.lifetimes_anonymized();
 
+        if let Some(timeout) = &attr.timeout {
+            if method.sig.asyncness.is_none() {
+                ERR.emit_custom(
+                    timeout.span_ident(),
+                    "`timeout` attribute argument is only applicable to async resolvers",
+                );
+                return None;
+            }
+        }
+
         let description = attr.description.as_ref().map(|d| d.as_ref().value());
         let deprecated = attr
             .deprecated
@@ -466,10 +482,19 @@ impl TraitMethod {
             ty,
             description,
             deprecated,
+            removal_date: attr.removal_date.as_ref().map(|date| date.value()),
             ident: method_ident.clone(),
             arguments: Some(arguments),
             has_receiver: method.sig.receiver().is_some(),
             is_async: method.sig.asyncness.is_some(),
+            rate_limit: attr.rate_limit.as_deref().copied(),
+            feature_flag: attr.feature_flag.as_ref().map(|flag| flag.value()),
+            guard: attr.guard.as_deref().cloned(),
+            cache_key: attr.cache_key.as_deref().cloned(),
+            max_concurrency: attr.max_concurrency.as_deref().copied(),
+            timeout: attr.timeout.as_deref().copied(),
+            complexity: attr.complexity.as_deref().copied(),
+            extensions: extensions::definition(&attr.extensions),
         })
     }
 }
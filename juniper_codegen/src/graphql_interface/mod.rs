@@ -21,7 +21,7 @@ use syn::{
 
 use crate::{
     common::{
-        field, gen,
+        extensions, field, gen,
         parse::{
             attr::{err, OptionExt as _},
             GenericsExt as _, ParseBufferExt as _,
@@ -79,6 +79,18 @@ struct TraitAttr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Objects
     implementers: HashSet<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified Rust types of other [GraphQL interfaces][1] this
+    /// [GraphQL interface][1] type implements.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    interfaces: HashSet<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified extension data of this [GraphQL interface][1]
+    /// type, exposed via introspection.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    extensions: extensions::Attr,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL interface][1] type with.
     ///
@@ -192,6 +204,18 @@ impl Parse for TraitAttr {
                             .none_or_else(|_| err::dup_arg(impler_span))?;
                     }
                 }
+                "impl" | "implements" | "interfaces" => {
+                    input.parse::<token::Eq>()?;
+                    for iface in input.parse_maybe_wrapped_and_punctuated::<
+                        syn::Type, token::Bracket, token::Comma,
+                    >()? {
+                        let iface_span = iface.span();
+                        out
+                            .interfaces
+                            .replace(SpanContainer::new(ident.span(), Some(iface_span), iface))
+                            .none_or_else(|_| err::dup_arg(iface_span))?;
+                    }
+                }
                 "dyn" => {
                     input.parse::<token::Eq>()?;
                     let alias = input.parse::<syn::Ident>()?;
@@ -199,6 +223,7 @@ impl Parse for TraitAttr {
                         .replace(SpanContainer::new(ident.span(), Some(alias.span()), alias))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "extensions" => extensions::parse(input, &mut out.extensions)?,
                 "enum" => {
                     input.parse::<token::Eq>()?;
                     let alias = input.parse::<syn::Ident>()?;
@@ -256,6 +281,8 @@ impl TraitAttr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             implementers: try_merge_hashset!(implementers: self, another => span_joined),
+            interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
+            extensions: try_merge_hashmap!(extensions: self, another),
             r#dyn: try_merge_opt!(r#dyn: self, another),
             r#enum: try_merge_opt!(r#enum: self, another),
             asyncness: try_merge_opt!(asyncness: self, another),
@@ -431,6 +458,18 @@ struct Definition {
     ///
     /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
     implementers: Vec<Implementer>,
+
+    /// Rust types of other [GraphQL interfaces][1] this [GraphQL interface][1]
+    /// implements.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    interfaces: Vec<syn::Type>,
+
+    /// Extension data of this [GraphQL interface][1] to put into GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    extensions: extensions::Definition,
 }
 
 impl ToTokens for Definition {
@@ -532,6 +571,22 @@ impl Definition {
 
         let fields_meta = self.fields.iter().map(|f| f.method_meta_tokens(None));
 
+        // Sorting is required to preserve/guarantee the order of interfaces registered in schema.
+        let mut interface_tys: Vec<_> = self.interfaces.iter().collect();
+        interface_tys.sort_unstable_by(|a, b| {
+            let (a, b) = (quote!(#a).to_string(), quote!(#b).to_string());
+            a.cmp(&b)
+        });
+        let interfaces = (!interface_tys.is_empty()).then(|| {
+            quote! {
+                .interfaces(&[
+                    #( registry.get_type::<#interface_tys>(info), )*
+                ])
+            }
+        });
+
+        let extensions = extensions::meta_tokens(&self.extensions);
+
         quote! {
             #[automatically_derived]
             impl#impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
@@ -554,6 +609,8 @@ impl Definition {
                     ];
                     registry.build_interface_type::<#ty>(info, &fields)
                         #description
+                        #interfaces
+                        #extensions
                         .into_meta()
                 }
             }
@@ -578,14 +635,14 @@ impl Definition {
             .fields
             .iter()
             .filter_map(|f| f.method_resolve_field_tokens(scalar, Some(&trait_ty)));
-        let async_fields_panic = {
+        let async_fields_err = {
             let names = self
                 .fields
                 .iter()
                 .filter_map(|f| f.is_async.then(|| f.name.as_str()))
                 .collect::<Vec<_>>();
             (!names.is_empty()).then(|| {
-                field::Definition::method_resolve_field_panic_async_field_tokens(&names, scalar)
+                field::Definition::method_resolve_field_err_async_field_tokens(&names, scalar)
             })
         };
         let no_field_panic = field::Definition::method_resolve_field_panic_no_field_tokens(scalar);
@@ -623,7 +680,7 @@ impl Definition {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
-                        #async_fields_panic
+                        #async_fields_err
                         _ => #no_field_panic,
                     }
                 }
@@ -872,7 +929,7 @@ impl Implementer {
 
         let downcast = self.downcast_call_tokens(trait_ty, None);
 
-        let resolving_code = gen::async_resolving_code(None);
+        let resolving_code = gen::async_resolving_code(None, None);
 
         Some(quote! {
             if type_name == <#ty as ::juniper::GraphQLType<#scalar>>::name(info).unwrap() {
@@ -1362,7 +1419,7 @@ impl EnumType {
     /// [0]: juniper::GraphQLValueAsync::resolve_into_type_async
     #[must_use]
     fn method_resolve_into_type_async_tokens(&self) -> TokenStream {
-        let resolving_code = gen::async_resolving_code(None);
+        let resolving_code = gen::async_resolving_code(None, None);
 
         let match_arms = self.variants.iter().map(|ty| {
             let variant = Self::variant_ident(ty);
@@ -1559,7 +1616,7 @@ impl TraitObjectType {
     /// [0]: juniper::GraphQLValueAsync::resolve_into_type_async
     #[must_use]
     fn method_resolve_into_type_async_tokens(&self) -> TokenStream {
-        let resolving_code = gen::async_resolving_code(None);
+        let resolving_code = gen::async_resolving_code(None, None);
 
         quote! {
             let fut = ::juniper::futures::future::ready(self.as_dyn_graphql_value_async());
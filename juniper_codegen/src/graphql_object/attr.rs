@@ -8,7 +8,7 @@ use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
 use crate::{
     common::{
-        field,
+        extensions, field,
         parse::{self, TypeExt as _},
         scalar,
     },
@@ -127,6 +127,7 @@ where
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        extensions: extensions::definition(&attr.extensions),
         _operation: PhantomData,
     };
 
@@ -216,6 +217,16 @@ fn parse_field(
     };
     ty.lifetimes_anonymized();
 
+    if let Some(timeout) = &attr.timeout {
+        if method.sig.asyncness.is_none() {
+            ERR.emit_custom(
+                timeout.span_ident(),
+                "`timeout` attribute argument is only applicable to async resolvers",
+            );
+            return None;
+        }
+    }
+
     let description = attr.description.as_ref().map(|d| d.as_ref().value());
     let deprecated = attr
         .deprecated
@@ -227,10 +238,19 @@ fn parse_field(
         ty,
         description,
         deprecated,
+        removal_date: attr.removal_date.as_ref().map(|date| date.value()),
         ident: method_ident.clone(),
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        rate_limit: attr.rate_limit.as_deref().copied(),
+        feature_flag: attr.feature_flag.as_ref().map(|flag| flag.value()),
+        guard: attr.guard.as_deref().cloned(),
+        cache_key: attr.cache_key.as_deref().cloned(),
+        max_concurrency: attr.max_concurrency.as_deref().copied(),
+        timeout: attr.timeout.as_deref().copied(),
+        complexity: attr.complexity.as_deref().copied(),
+        extensions: extensions::definition(&attr.extensions),
     })
 }
 
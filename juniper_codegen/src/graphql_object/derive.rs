@@ -8,7 +8,7 @@ use quote::ToTokens;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
 
 use crate::{
-    common::{field, parse::TypeExt as _, scalar},
+    common::{extensions, field, parse::TypeExt as _, scalar},
     result::GraphQLScope,
     util::{span_container::SpanContainer, RenameRule},
 };
@@ -104,6 +104,7 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        extensions: extensions::definition(&attr.extensions),
         _operation: PhantomData,
     })
 }
@@ -141,6 +142,14 @@ fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Defin
     let mut ty = field.ty.unparenthesized().clone();
     ty.lifetimes_anonymized();
 
+    if let Some(timeout) = &attr.timeout {
+        ERR.emit_custom(
+            timeout.span_ident(),
+            "`timeout` attribute argument is only applicable to async resolvers",
+        );
+        return None;
+    }
+
     let description = attr.description.as_ref().map(|d| d.as_ref().value());
     let deprecated = attr
         .deprecated
@@ -152,9 +161,18 @@ fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Defin
         ty,
         description,
         deprecated,
+        removal_date: attr.removal_date.as_ref().map(|date| date.value()),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
         is_async: false,
+        rate_limit: attr.rate_limit.as_deref().copied(),
+        feature_flag: attr.feature_flag.as_ref().map(|flag| flag.value()),
+        guard: attr.guard.as_deref().cloned(),
+        cache_key: attr.cache_key.as_deref().cloned(),
+        max_concurrency: attr.max_concurrency.as_deref().copied(),
+        timeout: attr.timeout.as_deref().copied(),
+        complexity: attr.complexity.as_deref().copied(),
+        extensions: extensions::definition(&attr.extensions),
     })
 }
@@ -18,7 +18,7 @@ use syn::{
 
 use crate::{
     common::{
-        field,
+        extensions, field,
         parse::{
             attr::{err, OptionExt as _},
             ParseBufferExt as _, TypeExt,
@@ -80,6 +80,12 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Interfaces
     pub(crate) interfaces: HashSet<SpanContainer<syn::Type>>,
 
+    /// Explicitly specified extension data of this [GraphQL object][1] type,
+    /// exposed via introspection.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) extensions: extensions::Attr,
+
     /// Explicitly specified [`RenameRule`] for all fields of this
     /// [GraphQL object][1] type.
     ///
@@ -147,6 +153,7 @@ impl Parse for Attr {
                             .none_or_else(|_| err::dup_arg(iface_span))?;
                     }
                 }
+                "extensions" => extensions::parse(input, &mut out.extensions)?,
                 "rename_all" => {
                     input.parse::<token::Eq>()?;
                     let val = input.parse::<syn::LitStr>()?;
@@ -181,6 +188,7 @@ impl Attr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
+            extensions: try_merge_hashmap!(extensions: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
@@ -257,6 +265,11 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [2]: https://spec.graphql.org/June2018/#sec-Interfaces
     pub(crate) interfaces: HashSet<syn::Type>,
 
+    /// Extension data of this [GraphQL object][1] to put into GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) extensions: extensions::Definition,
+
     /// [GraphQL operation][1] this [`Definition`] should generate code for.
     ///
     /// Either [GraphQL query][2] or [GraphQL subscription][3].
@@ -399,6 +412,8 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             }
         });
 
+        let extensions = extensions::meta_tokens(&self.extensions);
+
         quote! {
             #[automatically_derived]
             impl#impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
@@ -419,6 +434,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                     registry.build_object_type::<#ty>(info, &fields)
                         #description
                         #interfaces
+                        #extensions
                         .into_meta()
                 }
             }
@@ -493,14 +509,14 @@ impl Definition<Query> {
             .fields
             .iter()
             .filter_map(|f| f.method_resolve_field_tokens(scalar, None));
-        let async_fields_panic = {
+        let async_fields_err = {
             let names = self
                 .fields
                 .iter()
                 .filter_map(|f| f.is_async.then(|| f.name.as_str()))
                 .collect::<Vec<_>>();
             (!names.is_empty()).then(|| {
-                field::Definition::method_resolve_field_panic_async_field_tokens(&names, scalar)
+                field::Definition::method_resolve_field_err_async_field_tokens(&names, scalar)
             })
         };
         let no_field_panic = field::Definition::method_resolve_field_panic_no_field_tokens(scalar);
@@ -526,7 +542,7 @@ impl Definition<Query> {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
-                        #async_fields_panic
+                        #async_fields_err
                         _ => #no_field_panic,
                     }
                 }
@@ -2,13 +2,13 @@
 
 use proc_macro2::TokenStream;
 use proc_macro_error::ResultExt as _;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _, Data, Fields};
 
 use crate::{
     common::{parse::TypeExt as _, scalar},
     result::GraphQLScope,
-    util::span_container::SpanContainer,
+    util::{filter_attrs, span_container::SpanContainer},
 };
 
 use super::{
@@ -25,15 +25,20 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 
     match &ast.data {
         Data::Enum(_) => expand_enum(ast),
-        Data::Struct(_) => expand_struct(ast),
+        Data::Struct(_) => expand_struct(ast).map(ToTokens::into_token_stream),
         _ => Err(ERR.custom_error(ast.span(), "can only be derived for enums and structs")),
     }
-    .map(ToTokens::into_token_stream)
 }
 
 /// Expands into generated code a `#[derive(GraphQLUnion)]` macro placed on a
 /// Rust enum.
-fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
+///
+/// Along with the [GraphQL union][1] itself, this generates a
+/// [GraphQL object][2] for each enum variant with named fields.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Unions
+/// [2]: https://spec.graphql.org/June2018/#sec-Objects
+fn expand_enum(ast: syn::DeriveInput) -> syn::Result<TokenStream> {
     let attr = Attr::from_attrs("graphql", &ast.attrs)?;
 
     let enum_span = ast.span();
@@ -53,13 +58,20 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
         );
     }
 
-    let mut variants: Vec<_> = match ast.data {
+    let enum_def = EnumDefinition {
+        ident: &enum_ident,
+        vis: &ast.vis,
+        generics: &ast.generics,
+        name: &name,
+        attr: &attr,
+    };
+    let (mut variants, objects): (Vec<_>, Vec<_>) = match ast.data {
         Data::Enum(data) => data.variants,
         _ => unreachable!(),
     }
     .into_iter()
-    .filter_map(|var| parse_variant_from_enum_variant(var, &enum_ident, &attr))
-    .collect();
+    .filter_map(|var| parse_variant_from_enum_variant(var, &enum_def))
+    .unzip();
 
     proc_macro_error::abort_if_dirty();
 
@@ -78,7 +90,7 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
 
     proc_macro_error::abort_if_dirty();
 
-    Ok(Definition {
+    let definition = Definition {
         name,
         ty: parse_quote! { #enum_ident },
         is_trait_object: false,
@@ -90,20 +102,48 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
         scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
         generics: ast.generics,
         variants,
+    };
+
+    Ok(quote! {
+        #definition
+        #( #objects )*
     })
 }
 
-/// Parses given Rust enum `var`iant as [GraphQL union][1] variant.
+/// Rust enum the `#[derive(GraphQLUnion)]` macro is placed on, as needed for
+/// parsing its variants.
+struct EnumDefinition<'a> {
+    /// Identifier of the enum.
+    ident: &'a syn::Ident,
+
+    /// Visibility of the enum.
+    vis: &'a syn::Visibility,
+
+    /// Generics of the enum.
+    generics: &'a syn::Generics,
+
+    /// Name of the [GraphQL union][1] represented by the enum.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Unions
+    name: &'a str,
+
+    /// Attributes placed on the enum.
+    attr: &'a Attr,
+}
+
+/// Parses given Rust enum `var`iant as [GraphQL union][1] variant, along with
+/// the code generated for the [GraphQL object][2] of its named fields, if it
+/// has them.
 ///
 /// On failure returns [`None`] and internally fills up [`proc_macro_error`]
 /// with the corresponding errors.
 ///
 /// [1]: https://spec.graphql.org/June2018/#sec-Unions
+/// [2]: https://spec.graphql.org/June2018/#sec-Objects
 fn parse_variant_from_enum_variant(
     var: syn::Variant,
-    enum_ident: &syn::Ident,
-    enum_attr: &Attr,
-) -> Option<VariantDefinition> {
+    enum_def: &EnumDefinition<'_>,
+) -> Option<(VariantDefinition, Option<TokenStream>)> {
     let attr = VariantAttr::from_attrs("graphql", &var.attrs)
         .map_err(|e| proc_macro_error::emit_error!(e))
         .ok()?;
@@ -111,8 +151,29 @@ fn parse_variant_from_enum_variant(
         return None;
     }
 
+    let enum_ident = enum_def.ident;
+    let enum_attr = enum_def.attr;
     let var_ident = var.ident;
 
+    if let Fields::Named(fields) = &var.fields {
+        if attr.external_resolver.is_some() {
+            ERR.emit_custom(
+                var_ident.span(),
+                "variant with named fields cannot have an external resolver function",
+            );
+            return None;
+        }
+        if !enum_def.generics.params.is_empty() {
+            ERR.emit_custom(
+                fields.span(),
+                "variant with named fields is not supported on generic enums",
+            );
+            return None;
+        }
+        let (variant, object) = parse_named_variant(&var_ident, &var.attrs, fields, enum_def);
+        return Some((variant, Some(object)));
+    }
+
     let ty = match var.fields {
         Fields::Unnamed(fields) => {
             let mut iter = fields.unnamed.iter();
@@ -128,7 +189,8 @@ fn parse_variant_from_enum_variant(
     .map_err(|span| {
         ERR.emit_custom(
             span,
-            "enum allows only unnamed variants with a single field, e.g. `Some(T)`",
+            "enum allows only unnamed variants with a single field, e.g. `Some(T)`, \
+             or variants with named fields, e.g. `Some { value: T }`",
         )
     })
     .ok()?;
@@ -163,12 +225,93 @@ fn parse_variant_from_enum_variant(
         matches!(self, #enum_path(_))
     };
 
-    Some(VariantDefinition {
-        ty,
-        resolver_code,
-        resolver_check,
+    Some((
+        VariantDefinition {
+            ty,
+            resolver_code,
+            resolver_check,
+            context: None,
+        },
+        None,
+    ))
+}
+
+/// Parses given Rust enum variant with named `fields` as [GraphQL union][1]
+/// variant, generating the [GraphQL object][2] it's resolved into.
+///
+/// The [GraphQL object][2] is named after the [GraphQL union][1] and the
+/// variant (e.g. `StatusActive` for `Status::Active { .. }`), and has a field
+/// for each of the variant fields. It's backed by a hidden Rust struct
+/// borrowing the enum value.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Unions
+/// [2]: https://spec.graphql.org/June2018/#sec-Objects
+fn parse_named_variant(
+    var_ident: &syn::Ident,
+    var_attrs: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+    enum_def: &EnumDefinition<'_>,
+) -> (VariantDefinition, TokenStream) {
+    let enum_ident = enum_def.ident;
+    let enum_attr = enum_def.attr;
+    let vis = enum_def.vis;
+
+    let obj_ident = format_ident!("__{}{}", enum_ident.unraw(), var_ident.unraw());
+    let obj_name = format!("{}{}", enum_def.name, var_ident.unraw());
+
+    let context = enum_attr
+        .context
+        .as_ref()
+        .map(|ctx| ctx.as_ref().clone())
+        .unwrap_or_else(|| parse_quote! { () });
+    let scalar = enum_attr.scalar.as_deref().map(|scalar| match scalar {
+        scalar::AttrValue::Concrete(ty) => quote! { , scalar = #ty },
+        scalar::AttrValue::Generic(pred) => quote! { , scalar = #pred },
+    });
+
+    let doc_attrs = filter_attrs("doc", var_attrs);
+    let field_methods = fields.named.iter().map(|field| {
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_ty = &field.ty;
+        let field_attrs =
+            filter_attrs("doc", &field.attrs).chain(filter_attrs("graphql", &field.attrs));
+
+        quote! {
+            #( #field_attrs )*
+            fn #field_ident(&self) -> &'__v #field_ty {
+                match self.0 {
+                    #enum_ident::#var_ident { #field_ident, .. } => #field_ident,
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    });
+
+    let object = quote! {
+        #[doc(hidden)]
+        #vis struct #obj_ident<'__v>(&'__v #enum_ident);
+
+        #( #doc_attrs )*
+        #[::juniper::graphql_object(name = #obj_name, context = #context #scalar)]
+        impl<'__v> #obj_ident<'__v> {
+            #( #field_methods )*
+        }
+    };
+
+    let enum_path = quote! { #enum_ident::#var_ident };
+    let variant = VariantDefinition {
+        ty: parse_quote! { #obj_ident<'static> },
+        resolver_code: parse_quote! {
+            match self { #enum_path { .. } => Some(#obj_ident(self)), _ => None, }
+        },
+        resolver_check: parse_quote! {
+            matches!(self, #enum_path { .. })
+        },
         context: None,
-    })
+    };
+
+    (variant, object)
 }
 
 /// Expands into generated code a `#[derive(GraphQLUnion)]` macro placed on a
@@ -1,7 +1,7 @@
 #![allow(clippy::collapsible_if)]
 
 use crate::{
-    result::GraphQLScope,
+    result::{GraphQLScope, UnsupportedAttribute},
     util::{self, span_container::SpanContainer},
 };
 use proc_macro2::TokenStream;
@@ -158,6 +158,10 @@ pub fn build_scalar(
     let attrs = syn::parse2::<util::FieldAttributes>(attributes)?;
     let input = syn::parse2::<ScalarCodegenInput>(body)?;
 
+    if let Some(validate) = &attrs.validate {
+        error.unsupported_attribute(validate.span_ident(), UnsupportedAttribute::Validate);
+    }
+
     let impl_for_type = input.impl_for_type.ok_or_else(|| {
         error.custom_error(
             body_span,
@@ -206,6 +210,58 @@ pub fn build_scalar(
         Some(val) => quote!(.description(#val)),
         None => quote!(),
     };
+    let pattern = match attrs.pattern {
+        Some(val) => quote!(.with_pattern(#val)),
+        None => quote!(),
+    };
+    let unit = match attrs.unit {
+        Some(val) => quote!(.with_unit(#val)),
+        None => quote!(),
+    };
+    if let (Some(min), Some(max)) = (&attrs.min_length, &attrs.max_length) {
+        if min.inner() > max.inner() {
+            return Err(error.custom_error(
+                min.span_joined(),
+                "`min_length` must not be greater than `max_length`",
+            ));
+        }
+    }
+    let min_length = attrs.min_length.map(SpanContainer::into_inner);
+    let max_length = attrs.max_length.map(SpanContainer::into_inner);
+    let with_min_length = min_length.map(|len| quote!(.with_min_length(#len)));
+    let with_max_length = max_length.map(|len| quote!(.with_max_length(#len)));
+    // Length constraints are checked before the `from_input_value` body, so it never sees the
+    // string inputs violating them.
+    let length_check = if min_length.is_some() || max_length.is_some() {
+        let too_short = min_length.map(|len| quote!(__len < #len));
+        let too_long = max_length.map(|len| quote!(__len > #len));
+        let checks = too_short.into_iter().chain(too_long);
+        quote! {
+            if let Some(__len) = #from_input_value_arg.as_string_value().map(|s| s.chars().count()) {
+                if #( #checks )||* {
+                    return None;
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+    // String inputs are passed through the transforms in order, the first one receiving the `&str`
+    // and the last one returning the scalar itself, so the `from_input_value` body only sees the
+    // inputs of other kinds.
+    let transform = attrs.transform.map(|fns| {
+        let fns = fns.into_inner();
+        quote! {
+            if let Some(__v) = #from_input_value_arg.as_string_value() {
+                return ::std::option::Option::Some(__v) #( .and_then(#fns) )*;
+            }
+        }
+    });
+    let builtin = if attrs.internal.is_some() {
+        quote!(.builtin())
+    } else {
+        quote!()
+    };
     let async_generic_type = match input.custom_data_type_is_struct {
         true => quote!(__S),
         _ => quote!(#custom_data_type),
@@ -273,6 +329,11 @@ pub fn build_scalar(
             {
                 registry.build_scalar_type::<Self>(info)
                     #description
+                    #pattern
+                    #unit
+                    #with_min_length
+                    #with_max_length
+                    #builtin
                     .into_meta()
             }
         }
@@ -310,6 +371,8 @@ pub fn build_scalar(
         #generic_type_bound
         {
             fn from_input_value(#from_input_value_arg: &::juniper::InputValue<#generic_type>) -> #from_input_value_result {
+                #length_check
+                #transform
                 #from_input_value_body
             }
         }
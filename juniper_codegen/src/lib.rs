@@ -143,6 +143,59 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
     }
 }
 
+/// `#[derive(GraphQLArguments)]` macro for deriving a struct grouping the
+/// [arguments][1] of a field, which is used as a single resolver parameter
+/// marked with `#[graphql(flatten)]`. Its fields may be inlined the same way
+/// into the fields of a `#[derive(GraphQLInputObject)]` struct.
+///
+/// The struct is declared the same way as a `#[derive(GraphQLInputObject)]`
+/// one, with each of its fields being a separate argument of the field. Every
+/// struct field may be validated via `#[graphql(validate = path::to::fn)]`,
+/// calling the function with a reference to its value and failing the
+/// resolution of the field with the returned error, if any:
+///
+/// ```rust
+/// use juniper::{graphql_object, GraphQLArguments};
+///
+/// fn positive(n: &i32) -> Result<(), &'static str> {
+///     if *n > 0 {
+///         Ok(())
+///     } else {
+///         Err("must be positive")
+///     }
+/// }
+///
+/// #[derive(GraphQLArguments)]
+/// struct Page {
+///     #[graphql(default = "1", validate = positive)]
+///     number: i32,
+///     #[graphql(default = "20", validate = positive)]
+///     size: i32,
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn items(#[graphql(flatten)] page: Page) -> Vec<i32> {
+///         let start = (page.number - 1) * page.size;
+///         (start..start + page.size).collect()
+///     }
+/// }
+/// ```
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Field-Arguments
+#[proc_macro_error]
+#[proc_macro_derive(GraphQLArguments, attributes(graphql))]
+pub fn derive_arguments(input: TokenStream) -> TokenStream {
+    let ast = syn::parse::<syn::DeriveInput>(input).unwrap();
+    let gen = derive_input_object::impl_input_object(ast, GraphQLScope::DeriveArguments);
+    match gen {
+        Ok(gen) => gen.into(),
+        Err(err) => proc_macro_error::abort!(err),
+    }
+}
+
 /// This custom derive macro implements the #[derive(GraphQLScalarValue)]
 /// derive.
 ///
@@ -221,7 +274,10 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 ///     name = "MyName",
 ///     // You can also specify a description here.
 ///     // If present, doc comments will be ignored.
-///     description = "An opaque identifier, represented as a string")]
+///     description = "An opaque identifier, represented as a string",
+///     // You can also advertise a regular expression valid values match,
+///     // exposed via the `@pattern` directive for client-side validation.
+///     pattern = "^[0-9a-f]+$")]
 /// impl<S> GraphQLScalar for UserID
 /// where
 ///     S: juniper::ScalarValue
@@ -242,6 +298,26 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// # fn main() { }
 /// ```
 ///
+/// Scalars representing measurements may also advertise the unit their values
+/// are measured in via `unit = "seconds"`, exposed via the `@unit` directive.
+///
+/// String-backed scalars may constrain the length (in characters) of their
+/// values via `min_length = 2, max_length = 3`. Input strings violating it are
+/// rejected before reaching `from_input_value`, and the constraint is exposed
+/// via the `@length` directive.
+///
+/// String inputs needing several normalization steps may be passed through a
+/// pipeline of functions via `transform = [trim, parse_date]`. They're applied
+/// in order (after any length constraint is checked), the first one receiving
+/// the `&str` and each returning an `Option` of what the next one receives, the
+/// last one returning the scalar itself. Any of them returning `None` rejects
+/// the input. Inputs of other kinds are still handled by `from_input_value`.
+///
+/// The `internal` argument marks the built-in `Int`, `Float`, `String`,
+/// `Boolean` and `ID` scalars defined by Juniper itself, and is not meant to be
+/// used outside of it. Any other type named after one of them fails the schema
+/// it's part of with a `SchemaError::ShadowedBuiltinScalar`.
+///
 /// In addition to implementing `GraphQLType` for the type in question,
 /// `FromInputValue` and `ToInputValue` is also implemented. This makes the type
 /// usable as arguments and default values.
@@ -425,6 +501,52 @@ pub fn graphql_scalar(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Implementing other interfaces
+///
+/// A [GraphQL interface][1] may implement other [GraphQL interfaces][1], specified via an `impl`
+/// attribute's argument. It has to declare all the fields of the implemented interfaces, and to
+/// implement the interfaces they implement in turn, otherwise `RootNode::try_new()` fails with a
+/// `SchemaError` (and `RootNode::new()` panics).
+///
+/// ```
+/// # use juniper::{graphql_interface, GraphQLObject};
+/// #
+/// #[graphql_interface(for = Human)]
+/// trait Node {
+///     fn id(&self) -> &str;
+/// }
+///
+/// #[graphql_interface(impl = NodeValue, for = Human)]
+/// trait Character {
+///     fn id(&self) -> &str;
+///
+///     fn name(&self) -> &str;
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(impl = [NodeValue, CharacterValue])]
+/// struct Human {
+///     id: String,
+///     name: String,
+/// }
+/// #[graphql_interface]
+/// impl Node for Human {
+///     fn id(&self) -> &str {
+///         &self.id
+///     }
+/// }
+/// #[graphql_interface]
+/// impl Character for Human {
+///     fn id(&self) -> &str {
+///         &self.id
+///     }
+///
+///     fn name(&self) -> &str {
+///         &self.name
+///     }
+/// }
+/// ```
+///
 /// # Custom context
 ///
 /// By default, the generated implementation tries to infer [`Context`] type from signatures of
@@ -896,7 +1018,9 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///
 /// A field of [GraphQL object][1] may be deprecated by specifying a
 /// `deprecated` attribute's argument, or with regular Rust `#[deprecated]`
-/// attribute.
+/// attribute. The date a deprecated field is going to be removed at may be
+/// specified in the RFC 3339 format with a `removal_date` attribute's argument,
+/// exposed via the `removalDate` field on introspection.
 ///
 /// The default value of a field argument may be specified with a `default`
 /// attribute argument (if no exact value is specified then [`Default::default`]
@@ -916,7 +1040,7 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// )]
 /// impl HumanWithAttrs {
 ///     #[graphql(name = "id", desc = "ID of the human.")]
-///     #[graphql(deprecated = "Don't use it")]
+///     #[graphql(deprecated = "Don't use it", removal_date = "2022-01-01T00:00:00Z")]
 ///     fn some_id(
 ///         &self,
 ///         #[graphql(name = "number", desc = "Arbitrary number.")]
@@ -981,6 +1105,27 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Extension data
+///
+/// Arbitrary `key = "value"` metadata (ownership, SLA, etc.) may be attached to
+/// a [GraphQL object][1] type and its fields with an `extensions` attribute's
+/// argument. It's exposed to tooling via the `extension(name: String!)` field
+/// of `__Type` and `__Field` on introspection.
+///
+/// ```
+/// # use juniper::graphql_object;
+/// #
+/// struct Report;
+///
+/// #[graphql_object(extensions(owner = "billing"))]
+/// impl Report {
+///     #[graphql(extensions(sla = "24h"))]
+///     fn generated_at() -> &'static str {
+///         "2021-07-01"
+///     }
+/// }
+/// ```
+///
 /// # Ignoring methods
 ///
 /// To omit some method to be assumed as a [GraphQL object][1] field and ignore
@@ -1361,6 +1506,33 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// }
 /// ```
 ///
+/// # Enum variants with named fields
+///
+/// Enum variants may carry their data in named fields rather than in a separate type. For each
+/// such variant a [GraphQL object][2] is generated, named after the [GraphQL union][1] and the
+/// variant (`StatusActive` and `StatusSuspended` below), exposing the variant's fields. These
+/// fields may be configured with `#[graphql]` attributes the same way as
+/// `#[derive(GraphQLObject)]` fields.
+///
+/// Named fields variants are not supported for generic enums or along with external resolver
+/// functions.
+///
+/// ```
+/// use juniper::GraphQLUnion;
+///
+/// #[derive(GraphQLUnion)]
+/// enum Status {
+///     Active {
+///         since: i32,
+///     },
+///     /// Status of a suspended account.
+///     Suspended {
+///         #[graphql(name = "reason")]
+///         why: String,
+///     },
+/// }
+/// ```
+///
 /// # External resolver functions
 ///
 /// To use a custom logic for resolving a [GraphQL union][1] variant, an external resolver function
@@ -1479,6 +1651,7 @@ pub fn graphql_subscription(attr: TokenStream, body: TokenStream) -> TokenStream
 /// [`Context`]: juniper::Context
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/June2018/#sec-Unions
+/// [2]: https://spec.graphql.org/June2018/#sec-Objects
 /// [4]: https://doc.rust-lang.org/stable/std/primitive.unit.html
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLUnion, attributes(graphql))]
@@ -16,6 +16,7 @@ pub enum GraphQLScope {
     UnionAttr,
     UnionDerive,
     DeriveInputObject,
+    DeriveArguments,
     DeriveEnum,
     DeriveScalar,
     ImplScalar,
@@ -28,6 +29,7 @@ impl GraphQLScope {
             Self::ObjectAttr | Self::ObjectDerive => "#sec-Objects",
             Self::UnionAttr | Self::UnionDerive => "#sec-Unions",
             Self::DeriveInputObject => "#sec-Input-Objects",
+            Self::DeriveArguments => "#sec-Field-Arguments",
             Self::DeriveEnum => "#sec-Enums",
             Self::DeriveScalar | Self::ImplScalar => "#sec-Scalars",
         }
@@ -41,6 +43,7 @@ impl fmt::Display for GraphQLScope {
             Self::ObjectAttr | Self::ObjectDerive => "object",
             Self::UnionAttr | Self::UnionDerive => "union",
             Self::DeriveInputObject => "input object",
+            Self::DeriveArguments => "arguments",
             Self::DeriveEnum => "enum",
             Self::DeriveScalar | Self::ImplScalar => "scalar",
         };
@@ -55,8 +58,16 @@ pub enum UnsupportedAttribute {
     Skip,
     Interface,
     Scalar,
-    Deprecation,
     Default,
+    Builder,
+    Validate,
+    OneOf,
+    Pattern,
+    Unit,
+    MinLength,
+    MaxLength,
+    Transform,
+    Internal,
 }
 
 impl GraphQLScope {
@@ -18,7 +18,10 @@ use syn::{
     token, Attribute, Ident, Lit, Meta, MetaList, MetaNameValue, NestedMeta,
 };
 
-use crate::common::parse::ParseBufferExt as _;
+use crate::{
+    common::parse::ParseBufferExt as _,
+    result::{GraphQLScope, UnsupportedAttribute},
+};
 
 /// Compares a path to a one-segment string value,
 /// return true if equal.
@@ -302,6 +305,12 @@ pub struct ObjectAttributes {
     pub no_async: Option<SpanContainer<()>>,
     pub is_internal: bool,
     pub rename: Option<RenameRule>,
+    /// Only relevant for input object derive.
+    pub builder: Option<SpanContainer<()>>,
+    /// Only relevant for input object derive.
+    pub validate: Option<SpanContainer<syn::ExprPath>>,
+    /// Only relevant for input object derive.
+    pub one_of: Option<SpanContainer<()>>,
 }
 
 impl Parse for ObjectAttributes {
@@ -367,6 +376,17 @@ impl Parse for ObjectAttributes {
                     input.parse::<token::Eq>()?;
                     output.rename = Some(input.parse::<RenameRule>()?);
                 }
+                "builder" => {
+                    output.builder = Some(SpanContainer::new(ident.span(), None, ()));
+                }
+                "validate" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::ExprPath>()?;
+                    output.validate = Some(SpanContainer::new(ident.span(), Some(val.span()), val));
+                }
+                "one_of" => {
+                    output.one_of = Some(SpanContainer::new(ident.span(), None, ()));
+                }
                 _ => {
                     return Err(syn::Error::new(ident.span(), "unknown attribute"));
                 }
@@ -456,8 +476,16 @@ enum FieldAttribute {
     Description(SpanContainer<syn::LitStr>),
     Deprecation(SpanContainer<DeprecationAttr>),
     Skip(SpanContainer<syn::Ident>),
+    Flatten(SpanContainer<syn::Ident>),
     Arguments(HashMap<String, FieldAttributeArgument>),
     Default(Box<SpanContainer<Option<syn::Expr>>>),
+    Validate(SpanContainer<syn::ExprPath>),
+    Pattern(SpanContainer<syn::LitStr>),
+    Unit(SpanContainer<syn::LitStr>),
+    MinLength(SpanContainer<usize>),
+    MaxLength(SpanContainer<usize>),
+    Transform(SpanContainer<Vec<syn::ExprPath>>),
+    Internal(SpanContainer<syn::Ident>),
 }
 
 impl Parse for FieldAttribute {
@@ -488,6 +516,64 @@ impl Parse for FieldAttribute {
                     lit,
                 )))
             }
+            "internal" => Ok(FieldAttribute::Internal(SpanContainer::new(
+                ident.span(),
+                None,
+                ident,
+            ))),
+            "pattern" => {
+                input.parse::<token::Eq>()?;
+                let lit = input.parse::<syn::LitStr>()?;
+                Ok(FieldAttribute::Pattern(SpanContainer::new(
+                    ident.span(),
+                    Some(lit.span()),
+                    lit,
+                )))
+            }
+            "unit" => {
+                input.parse::<token::Eq>()?;
+                let lit = input.parse::<syn::LitStr>()?;
+                Ok(FieldAttribute::Unit(SpanContainer::new(
+                    ident.span(),
+                    Some(lit.span()),
+                    lit,
+                )))
+            }
+            "min_length" => {
+                input.parse::<token::Eq>()?;
+                let lit = input.parse::<syn::LitInt>()?;
+                Ok(FieldAttribute::MinLength(SpanContainer::new(
+                    ident.span(),
+                    Some(lit.span()),
+                    lit.base10_parse()?,
+                )))
+            }
+            "max_length" => {
+                input.parse::<token::Eq>()?;
+                let lit = input.parse::<syn::LitInt>()?;
+                Ok(FieldAttribute::MaxLength(SpanContainer::new(
+                    ident.span(),
+                    Some(lit.span()),
+                    lit.base10_parse()?,
+                )))
+            }
+            "transform" => {
+                input.parse::<token::Eq>()?;
+                let content;
+                let brackets = syn::bracketed!(content in input);
+                let fns = Punctuated::<syn::ExprPath, token::Comma>::parse_terminated(&content)?;
+                if fns.is_empty() {
+                    return Err(syn::Error::new(
+                        brackets.span,
+                        "`transform` must list at least one function",
+                    ));
+                }
+                Ok(FieldAttribute::Transform(SpanContainer::new(
+                    ident.span(),
+                    Some(brackets.span),
+                    fns.into_iter().collect(),
+                )))
+            }
             "deprecated" | "deprecation" => {
                 let reason = if input.peek(token::Eq) {
                     input.parse::<token::Eq>()?;
@@ -508,6 +594,11 @@ impl Parse for FieldAttribute {
                 None,
                 ident,
             ))),
+            "flatten" => Ok(FieldAttribute::Flatten(SpanContainer::new(
+                ident.span(),
+                None,
+                ident,
+            ))),
             "arguments" => {
                 let arg_content;
                 syn::parenthesized!(arg_content in input);
@@ -532,6 +623,15 @@ impl Parse for FieldAttribute {
 
                 Ok(FieldAttribute::Default(Box::new(default_expr)))
             }
+            "validate" => {
+                input.parse::<token::Eq>()?;
+                let val = input.parse::<syn::ExprPath>()?;
+                Ok(FieldAttribute::Validate(SpanContainer::new(
+                    ident.span(),
+                    Some(val.span()),
+                    val,
+                )))
+            }
             _ => Err(syn::Error::new(ident.span(), "unknown attribute")),
         }
     }
@@ -544,10 +644,26 @@ pub struct FieldAttributes {
     pub deprecation: Option<SpanContainer<DeprecationAttr>>,
     // Only relevant for GraphQLObject derive.
     pub skip: Option<SpanContainer<syn::Ident>>,
+    /// Only relevant for input objects.
+    pub flatten: Option<SpanContainer<syn::Ident>>,
     /// Only relevant for object macro.
     pub arguments: HashMap<String, FieldAttributeArgument>,
     /// Only relevant for object input objects.
     pub default: Option<SpanContainer<Option<syn::Expr>>>,
+    /// Only relevant for input objects.
+    pub validate: Option<SpanContainer<syn::ExprPath>>,
+    /// Only relevant for scalar macro.
+    pub pattern: Option<SpanContainer<String>>,
+    /// Only relevant for scalar macro.
+    pub unit: Option<SpanContainer<String>>,
+    /// Only relevant for scalar macro.
+    pub min_length: Option<SpanContainer<usize>>,
+    /// Only relevant for scalar macro.
+    pub max_length: Option<SpanContainer<usize>>,
+    /// Only relevant for scalar macro.
+    pub transform: Option<SpanContainer<Vec<syn::ExprPath>>>,
+    /// Only relevant for scalar macro.
+    pub internal: Option<SpanContainer<syn::Ident>>,
 }
 
 impl Parse for FieldAttributes {
@@ -570,12 +686,36 @@ impl Parse for FieldAttributes {
                 FieldAttribute::Skip(ident) => {
                     output.skip = Some(ident);
                 }
+                FieldAttribute::Flatten(ident) => {
+                    output.flatten = Some(ident);
+                }
                 FieldAttribute::Arguments(args) => {
                     output.arguments = args;
                 }
                 FieldAttribute::Default(expr) => {
                     output.default = Some(*expr);
                 }
+                FieldAttribute::Validate(func) => {
+                    output.validate = Some(func);
+                }
+                FieldAttribute::Pattern(pattern) => {
+                    output.pattern = Some(pattern.map(|val| val.value()));
+                }
+                FieldAttribute::Unit(unit) => {
+                    output.unit = Some(unit.map(|val| val.value()));
+                }
+                FieldAttribute::MinLength(len) => {
+                    output.min_length = Some(len);
+                }
+                FieldAttribute::MaxLength(len) => {
+                    output.max_length = Some(len);
+                }
+                FieldAttribute::Transform(fns) => {
+                    output.transform = Some(fns);
+                }
+                FieldAttribute::Internal(ident) => {
+                    output.internal = Some(ident);
+                }
             }
         }
 
@@ -612,6 +752,29 @@ impl FieldAttributes {
 
         Ok(output)
     }
+
+    /// Reports the attributes only relevant for the `#[graphql_scalar]` macro
+    /// as unsupported inside of the given `scope`.
+    pub fn reject_scalar_only(&self, scope: &GraphQLScope) {
+        if let Some(pattern) = &self.pattern {
+            scope.unsupported_attribute_within(pattern.span_ident(), UnsupportedAttribute::Pattern);
+        }
+        if let Some(unit) = &self.unit {
+            scope.unsupported_attribute_within(unit.span_ident(), UnsupportedAttribute::Unit);
+        }
+        if let Some(len) = &self.min_length {
+            scope.unsupported_attribute_within(len.span_ident(), UnsupportedAttribute::MinLength);
+        }
+        if let Some(len) = &self.max_length {
+            scope.unsupported_attribute_within(len.span_ident(), UnsupportedAttribute::MaxLength);
+        }
+        if let Some(fns) = &self.transform {
+            scope.unsupported_attribute_within(fns.span_ident(), UnsupportedAttribute::Transform);
+        }
+        if let Some(ident) = &self.internal {
+            scope.unsupported_attribute_within(ident.span_ident(), UnsupportedAttribute::Internal);
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -633,6 +796,8 @@ pub struct GraphQLTypeDefinitionField {
     pub is_type_inferred: bool,
     pub is_async: bool,
     pub default: Option<TokenStream>,
+    pub validate: Option<syn::ExprPath>,
+    pub is_flattened: bool,
     pub span: Span,
 }
 
@@ -660,7 +825,6 @@ pub struct GraphQLTypeDefiniton {
     pub description: Option<String>,
     pub fields: Vec<GraphQLTypeDefinitionField>,
     pub generics: syn::Generics,
-    pub interfaces: Vec<syn::Type>,
     // Due to syn parsing differences,
     // when parsing an impl the type generics are included in the type
     // directly, but in syn::DeriveInput, the type generics are
@@ -675,6 +839,16 @@ pub struct GraphQLTypeDefiniton {
     pub generic_scalar: bool,
     // FIXME: make this redundant.
     pub no_async: bool,
+    // Function validating the input object after its conversion.
+    // Only relevant for input object derive.
+    pub validate: Option<syn::ExprPath>,
+    // Whether exactly one field of the input object must be set.
+    // Only relevant for input object derive.
+    pub one_of: bool,
+    // Whether the input object groups the arguments of a field, so the
+    // validation errors refer to its fields as arguments.
+    // Only relevant for input object derive.
+    pub is_arguments: bool,
 }
 
 impl GraphQLTypeDefiniton {
@@ -752,7 +926,9 @@ impl GraphQLTypeDefiniton {
             let resolver_code = &variant.resolver_code;
 
             quote!(
-                Some(#variant_name) => Some(#resolver_code),
+                if ::juniper::enum_value_eq(v, #variant_name) {
+                    return Some(#resolver_code);
+                }
             )
         });
 
@@ -761,8 +937,7 @@ impl GraphQLTypeDefiniton {
             let resolver_code = &variant.resolver_code;
 
             quote!(
-                &#resolver_code =>
-                    ::juniper::InputValue::scalar(#variant_name.to_string()),
+                &#resolver_code => ::juniper::InputValue::enum_value(#variant_name),
             )
         });
 
@@ -862,12 +1037,11 @@ impl GraphQLTypeDefiniton {
             {
                 fn from_input_value(v: &::juniper::InputValue<#scalar>) -> Option<#ty>
                 {
-                    match v.as_enum_value().or_else(|| {
+                    let v = v.as_enum_value().or_else(|| {
                         v.as_string_value()
-                    }) {
-                        #( #from_inputs )*
-                        _ => None,
-                    }
+                    })?;
+                    #( #from_inputs )*
+                    None
                 }
             }
 
@@ -920,6 +1094,15 @@ impl GraphQLTypeDefiniton {
                 let field_ty = &field._type;
                 let field_name = &field.name;
 
+                if field.is_flattened {
+                    return quote! {
+                        fields.extend(
+                            <#field_ty as ::juniper::input_flattening::FlattenInputObject<#scalar>>
+                                ::meta_fields(registry),
+                        );
+                    };
+                }
+
                 let description = match field.description.as_ref() {
                     Some(description) => quote!( .description(#description) ),
                     None => quote!(),
@@ -950,11 +1133,11 @@ impl GraphQLTypeDefiniton {
                 };
 
                 quote!(
-                    {
+                    fields.push({
                         #create_meta_field
                         #description
                         #deprecation
-                    },
+                    });
                 )
             })
             .collect::<Vec<_>>();
@@ -966,6 +1149,15 @@ impl GraphQLTypeDefiniton {
                 let field_ident = &field.resolver_code;
                 let field_name = &field.name;
 
+                if field.is_flattened {
+                    let field_ty = &field._type;
+                    return quote! {
+                        #field_ident:
+                            <#field_ty as ::juniper::input_flattening::FlattenInputObject<#scalar>>
+                                ::from_input_fields(obj)?,
+                    };
+                }
+
                 // Build from_input clause.
                 let from_input_default = match field.default {
                     Some(ref def) => {
@@ -994,13 +1186,155 @@ impl GraphQLTypeDefiniton {
             .map(|field| {
                 let field_name = &field.name;
                 let field_ident = &field.resolver_code;
+                if field.is_flattened {
+                    return quote! {
+                        ::juniper::input_flattening::FlattenInputObject::<#scalar>::to_input_fields(
+                            &self.#field_ident,
+                            fields,
+                        );
+                    };
+                }
                 // Build to_input clause.
                 quote!(
-                    (#field_name, self.#field_ident.to_input_value()),
+                    fields.push((
+                        ::juniper::Spanning::unlocated(#field_name.to_owned()),
+                        ::juniper::Spanning::unlocated(
+                            ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#field_ident),
+                        ),
+                    ));
                 )
             })
             .collect::<Vec<_>>();
 
+        let field_kind = if self.is_arguments {
+            "argument"
+        } else {
+            "field"
+        };
+        let field_validates = self
+            .fields
+            .iter()
+            .map(|field| {
+                let field_ident = &field.resolver_code;
+                let field_name = &field.name;
+                // Errors of flattened fields already mention the failed field.
+                let map_err = if field.is_flattened {
+                    quote!()
+                } else {
+                    quote!( .map_err(|e| format!("{} `{}`: {}", #field_kind, #field_name, e)) )
+                };
+                let custom = field.validate.as_ref().map(|func| {
+                    quote!(
+                        #func(&self.#field_ident) #map_err ?;
+                    )
+                });
+                quote!(
+                    (&::juniper::input_validation::MaybeValidate(&self.#field_ident)).validate() #map_err ?;
+                    #custom
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let validate = match self.validate.as_ref() {
+            Some(func) => quote!( #func(self).map_err(|e| e.to_string()) ),
+            None => quote!(Ok(())),
+        };
+
+        // All the fields of `@oneOf` input objects are `Option`s, so a set one
+        // is the one being `Some`, regardless of whether it was omitted or
+        // explicitly `null` otherwise.
+        let one_of_check = if self.one_of {
+            let field_names = self.fields.iter().map(|field| &field.name);
+            let field_idents = self.fields.iter().map(|field| &field.resolver_code);
+            let expected = self
+                .fields
+                .iter()
+                .map(|field| format!("`{}`", field.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some(quote! {
+                let set = [ #( (#field_names, self.#field_idents.is_some()), )* ]
+                    .iter()
+                    .filter(|(_, is_set)| *is_set)
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect::<Vec<_>>();
+                if set.len() != 1 {
+                    return Err(format!(
+                        "exactly one of the fields {} must be set, but {}",
+                        #expected,
+                        if set.is_empty() {
+                            "none is".to_owned()
+                        } else {
+                            format!("{} are", set.join(", "))
+                        },
+                    ));
+                }
+            })
+        } else {
+            None
+        };
+        let one_of = if self.one_of {
+            Some(quote!( .one_of() ))
+        } else {
+            None
+        };
+        let own_names = self
+            .fields
+            .iter()
+            .filter(|field| !field.is_flattened)
+            .map(|field| &field.name)
+            .collect::<Vec<_>>();
+        let flattened_names = |scalar: &TokenStream| {
+            self.fields
+                .iter()
+                .filter(|field| field.is_flattened)
+                .map(|field| {
+                    let field_ty = &field._type;
+                    quote! {
+                        &<#field_ty as ::juniper::input_flattening::FlattenInputObject<#scalar>>
+                            ::FIELD_NAMES
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let field_names = {
+            let flattened_names = flattened_names(&scalar);
+            quote! {
+                ::juniper::input_flattening::InputObjectFieldNames {
+                    own: &[#( #own_names ),*],
+                    flattened: &[#( #flattened_names ),*],
+                }
+            }
+        };
+        // Collisions of flattened fields are checked during the constant
+        // evaluation, so are reported as compilation errors. Names of the
+        // flattened fields don't depend on the scalar, so any supported one
+        // is used for the generic scalar.
+        let check_collisions = if self.fields.iter().any(|field| field.is_flattened) {
+            let scalar = self
+                .scalar
+                .as_ref()
+                .map(|s| quote!( #s ))
+                .unwrap_or_else(|| quote!(::juniper::DefaultScalarValue));
+            let flattened_names = flattened_names(&scalar);
+            let msg = format!(
+                "fields flattened into the `{}` input object collide with its other fields",
+                name,
+            );
+            Some(quote! {
+                const _: () = assert!(
+                    !::juniper::input_flattening::InputObjectFieldNames {
+                        own: &[#( #own_names ),*],
+                        flattened: &[#( #flattened_names ),*],
+                    }
+                    .have_collisions(),
+                    #msg,
+                );
+            })
+        } else {
+            None
+        };
+
         let description = self
             .description
             .as_ref()
@@ -1009,7 +1343,7 @@ impl GraphQLTypeDefiniton {
         // Preserve the original type_generics before modification,
         // since alteration makes them invalid if self.generic_scalar
         // is specified.
-        let (_, type_generics, _) = self.generics.split_for_impl();
+        let (orig_impl_generics, type_generics, orig_where_clause) = self.generics.split_for_impl();
 
         let mut generics = self.generics.clone();
 
@@ -1052,6 +1386,63 @@ impl GraphQLTypeDefiniton {
             quote! { <#field_ty as ::juniper::marker::IsInputType<#scalar>>::mark(); }
         });
 
+        let meta_fields = quote! {
+            let mut fields = Vec::new();
+            #( #meta_fields )*
+            fields
+        };
+        let from_input_fields = quote! {
+            Some(#ty {
+                #( #from_inputs )*
+            })
+        };
+
+        // Both the structs grouping arguments and the regular input objects may
+        // be flattened into other ones, so their fields are converted via the
+        // `FlattenInputObject` implementation.
+        let flatten = quote! {
+            impl#impl_generics ::juniper::input_flattening::FlattenInputObject<#scalar>
+                for #ty #type_generics_tokens
+                #where_clause
+            {
+                const FIELD_NAMES: ::juniper::input_flattening::InputObjectFieldNames =
+                    #field_names;
+
+                fn meta_fields<'r>(
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> Vec<::juniper::meta::Argument<'r, #scalar>>
+                where #scalar: 'r
+                {
+                    #meta_fields
+                }
+
+                fn from_input_fields(
+                    obj: &::juniper::input_flattening::InputFields<'_, #scalar>,
+                ) -> Option<Self> {
+                    #from_input_fields
+                }
+
+                fn to_input_fields(
+                    &self,
+                    fields: &mut ::juniper::input_flattening::ObjectFields<#scalar>,
+                ) {
+                    #( #to_inputs )*
+                }
+            }
+        };
+        let meta_fields = quote! {
+            <Self as ::juniper::input_flattening::FlattenInputObject<#scalar>>
+                ::meta_fields(registry)
+        };
+        let from_input_fields = quote! {
+            <Self as ::juniper::input_flattening::FlattenInputObject<#scalar>>
+                ::from_input_fields(&obj)
+        };
+        let to_input_fields = quote! {
+            ::juniper::input_flattening::FlattenInputObject::<#scalar>
+                ::to_input_fields(self, fields);
+        };
+
         let mut body = quote!(
             impl#impl_generics ::juniper::marker::IsInputType<#scalar> for #ty #type_generics_tokens
                 #where_clause {
@@ -1073,11 +1464,10 @@ impl GraphQLTypeDefiniton {
                 ) -> ::juniper::meta::MetaType<'r, #scalar>
                 where #scalar: 'r
                 {
-                    let fields = &[
-                        #( #meta_fields )*
-                    ];
-                    registry.build_input_object_type::<#ty>(&(), fields)
+                    let fields = #meta_fields;
+                    registry.build_input_object_type::<#ty>(&(), &fields)
                     #description
+                    #one_of
                     .into_meta()
                 }
             }
@@ -1099,9 +1489,19 @@ impl GraphQLTypeDefiniton {
                 fn from_input_value(value: &::juniper::InputValue<#scalar>) -> Option<Self>
                 {
                     let obj = value.to_object_value()?;
-                    Some(#ty {
-                        #( #from_inputs )*
-                    })
+                    #from_input_fields
+                }
+            }
+
+            impl#orig_impl_generics ::juniper::input_validation::ValidateInput for #ty #type_generics_tokens
+                #orig_where_clause
+            {
+                fn validate_input(&self) -> Result<(), String> {
+                    use ::juniper::input_validation::{NoValidation as _, ViaValidateInput as _};
+
+                    #one_of_check
+                    #( #field_validates )*
+                    #validate
                 }
             }
 
@@ -1109,11 +1509,16 @@ impl GraphQLTypeDefiniton {
                 #where_clause
             {
                 fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
-                    ::juniper::InputValue::object(vec![
-                        #( #to_inputs )*
-                    ].into_iter().collect())
+                    let mut object = Vec::new();
+                    let fields = &mut object;
+                    #to_input_fields
+                    ::juniper::InputValue::Object(object)
                 }
             }
+
+            #flatten
+
+            #check_collisions
         );
 
         if !self.no_async {
@@ -39,6 +39,7 @@ where
     S: ScalarValue + Send + Sync,
 {
     root_node: juniper::RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    correlation_ids: bool,
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT, CtxT, S>
@@ -55,7 +56,17 @@ where
 {
     /// Builds new [`Coordinator`] with specified `root_node`
     pub fn new(root_node: juniper::RootNode<'a, QueryT, MutationT, SubscriptionT, S>) -> Self {
-        Self { root_node }
+        Self {
+            root_node,
+            correlation_ids: false,
+        }
+    }
+
+    /// Makes the [`Connection`]s of this [`Coordinator`] put correlation IDs
+    /// into their payloads, see [`Connection::with_correlation_ids`] for details
+    pub fn with_correlation_ids(mut self) -> Self {
+        self.correlation_ids = true;
+        self
     }
 }
 
@@ -80,8 +91,16 @@ where
         req: &'a GraphQLRequest<S>,
         context: &'a CtxT,
     ) -> BoxFuture<'a, Result<Self::Connection, Self::Error>> {
+        let correlation_ids = self.correlation_ids;
         juniper::http::resolve_into_stream(req, &self.root_node, context)
-            .map_ok(|(stream, errors)| Connection::from_stream(stream, errors))
+            .map_ok(move |(stream, errors)| {
+                let connection = Connection::from_stream(stream, errors);
+                if correlation_ids {
+                    connection.with_correlation_ids()
+                } else {
+                    connection
+                }
+            })
             .boxed()
     }
 }
@@ -111,6 +130,15 @@ where
             stream: whole_responses_stream(stream, errors),
         }
     }
+
+    /// Puts sequential correlation IDs (starting from `1`) into the
+    /// extensions of the payloads of this [`Connection`], see
+    /// [`juniper::with_correlation_ids`] for details
+    pub fn with_correlation_ids(self) -> Self {
+        Self {
+            stream: juniper::with_correlation_ids(self.stream).boxed(),
+        }
+    }
 }
 
 impl<'a, S> SubscriptionConnection<S> for Connection<'a, S> where S: ScalarValue + Send + Sync + 'a {}
@@ -148,6 +176,7 @@ where
         return stream::once(future::ready(ExecutionOutput {
             data: Value::null(),
             errors,
+            extensions: None,
         }))
         .boxed();
     }
@@ -161,6 +190,7 @@ where
             Err(err) => ExecutionOutput {
                 data: Value::null(),
                 errors: vec![err],
+                extensions: None,
             },
         })),
         Value::List(list) => {
@@ -240,6 +270,7 @@ where
                     Poll::Ready(Some(ExecutionOutput {
                         data: Value::Object(obj),
                         errors,
+                        extensions: None,
                     }))
                 } else {
                     Poll::Pending
@@ -266,6 +297,7 @@ mod whole_responses_stream {
                 "field error",
                 graphql_value!(None),
             ))],
+            extensions: None,
         }];
         let expected = serde_json::to_string(&expected).unwrap();
 
@@ -401,3 +433,92 @@ mod whole_responses_stream {
         assert_eq!(result, expected);
     }
 }
+
+#[cfg(test)]
+mod correlation_ids {
+    use chrono::{DateTime, TimeZone as _, Utc};
+    use futures::{stream, Stream, StreamExt as _};
+    use juniper::{
+        graphql_object, graphql_subscription, http::GraphQLRequest, DefaultScalarValue,
+        EmptyMutation, RootNode, SubscriptionCoordinator as _,
+    };
+
+    use super::*;
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn empty() -> bool {
+            true
+        }
+    }
+
+    struct Subscription;
+
+    type DateStream = Pin<Box<dyn Stream<Item = DateTime<Utc>> + Send>>;
+
+    #[graphql_subscription]
+    impl Subscription {
+        async fn dates() -> DateStream {
+            Box::pin(stream::iter(
+                (1..=3).map(|day| Utc.with_ymd_and_hms(2021, 7, day, 0, 0, 0).unwrap()),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn sequential_in_payloads() {
+        let coordinator = Coordinator::new(RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            Subscription,
+        ))
+        .with_correlation_ids();
+        let request =
+            GraphQLRequest::<DefaultScalarValue>::new("subscription { dates }".into(), None, None);
+
+        let connection = coordinator.subscribe(&request, &()).await.unwrap();
+        let result = connection.collect::<Vec<_>>().await;
+
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!([
+                {
+                    "data": {"dates": "2021-07-01T00:00:00+00:00"},
+                    "errors": [],
+                    "extensions": {"correlationId": 1},
+                },
+                {
+                    "data": {"dates": "2021-07-02T00:00:00+00:00"},
+                    "errors": [],
+                    "extensions": {"correlationId": 2},
+                },
+                {
+                    "data": {"dates": "2021-07-03T00:00:00+00:00"},
+                    "errors": [],
+                    "extensions": {"correlationId": 3},
+                },
+            ]),
+        );
+    }
+
+    #[tokio::test]
+    async fn absent_by_default() {
+        let coordinator = Coordinator::new(RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            Subscription,
+        ));
+        let request =
+            GraphQLRequest::<DefaultScalarValue>::new("subscription { dates }".into(), None, None);
+
+        let connection = coordinator.subscribe(&request, &()).await.unwrap();
+        let result = connection.take(1).collect::<Vec<_>>().await;
+
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!([{"data": {"dates": "2021-07-01T00:00:00+00:00"}, "errors": []}]),
+        );
+    }
+}